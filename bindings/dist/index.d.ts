@@ -1,6 +1,6 @@
 import { Buffer } from "buffer";
 import { AssembledTransaction, Client as ContractClient, ClientOptions as ContractClientOptions, MethodOptions } from '@stellar/stellar-sdk/contract';
-import type { u32, i32, i128, Option } from '@stellar/stellar-sdk/contract';
+import type { u32, i32, u64, i128, u256, Option } from '@stellar/stellar-sdk/contract';
 export * from '@stellar/stellar-sdk';
 export * as contract from '@stellar/stellar-sdk/contract';
 export * as rpc from '@stellar/stellar-sdk/rpc';
@@ -16,7 +16,65 @@ export type DataKey = {
 } | {
     tag: "Position";
     values: readonly [string, i32];
+} | {
+    tag: "OrderBins";
+    values: readonly [i32];
+} | {
+    tag: "OrderEpoch";
+    values: void;
+} | {
+    tag: "FilledOrders";
+    values: readonly [u32];
+} | {
+    tag: "LimitOrder";
+    values: readonly [string, i32];
+} | {
+    tag: "Reserves";
+    values: void;
+} | {
+    tag: "BinTotals";
+    values: void;
+} | {
+    tag: "Incentives";
+    values: void;
+} | {
+    tag: "IncentiveCount";
+    values: void;
+} | {
+    tag: "Incentive";
+    values: readonly [u32];
+} | {
+    tag: "RewardPerShare";
+    values: readonly [u32, i32];
+} | {
+    tag: "RewardVecIds";
+    values: readonly [u32];
+} | {
+    tag: "PositionRewards";
+    values: readonly [string, i32];
+} | {
+    tag: "PositionIds";
+    values: readonly [string];
+} | {
+    tag: "Operator";
+    values: readonly [string, string];
+} | {
+    tag: "VecBitmap";
+    values: readonly [VecIndex, u32, u32];
 };
+/**
+ * The bitmaps of vecs in storage, see `bitmap.rs`.
+ */
+export declare enum VecIndex {
+    /**
+     * The vecs that have liquidity.
+     */
+    Bins = 0,
+    /**
+     * The vecs that have limit orders.
+     */
+    Orders = 1
+}
 /**
  * stored as vec[shares]
  */
@@ -24,6 +82,19 @@ export interface BinShares {
     bin_id: i32;
     shares: i128;
 }
+/**
+ * A bin with its total shares and price, as returned by the bin getters.
+ */
+export interface BinInfo {
+    bin_id: i32;
+    /**
+     * the price of y in x, in 128.128 fixed point
+     */
+    price: u256;
+    reserve_x: i128;
+    reserve_y: i128;
+    shares: i128;
+}
 /**
  * stored as vec[reserve_x|reserve_y] if not active bin
  * otherwise if active_bin, it is stored as vec[reserve_x, reserve_y]
@@ -35,24 +106,172 @@ export interface Bin {
 }
 /**
  * bin_id_or_offset is either the bin id or the offset from the current active bin
- * amount specifies the token amount. The tokens depends on which side of the active been is
- * being deposited. If the bin is the active bin, then the tokens is split based on the
+ * amount specifies the token amount or the amount of shares, depending on the value of `is_remove`
+ * I didn't like how the DepositArgs showed up in stellar expert, so I changed it from a sum type to a struct
  */
 export interface DepositArgs {
     amount: i128;
     bin_id_or_offset: i32;
     is_remove: boolean;
 }
+/**
+ * How liquidity is spread over the bins around the active bin, for example when rebalancing a position.
+ */
+export declare enum LiquidityShape {
+    /**
+     * The same weight in every bin.
+     */
+    Spot = 0,
+    /**
+     * Most of the liquidity around the active bin, decreasing towards the edges.
+     */
+    Curve = 1,
+    /**
+     * Most of the liquidity at the edges, decreasing towards the active bin.
+     */
+    BidAsk = 2
+}
 export interface Config {
     active_bin: i32;
+    /**
+     * may add incentives
+     */
+    admin: string;
     bin_step: u32;
+    /**
+     * the decimals of token x, read from the token when the pool is created
+     */
+    decimals_x: u32;
+    /**
+     * the decimals of token y, read from the token when the pool is created
+     */
+    decimals_y: u32;
     fee: u32;
+    /**
+     * fee charged on flash loans in bps
+     */
+    flash_loan_fee: u32;
     token_x: string;
     token_y: string;
+    /**
+     * the TTL in ledgers that entries are extended to, capped to the max TTL of the network
+     */
+    ttl_extend_to: u32;
+    /**
+     * entries with a TTL below this amount of ledgers are extended when they are written or bumped
+     */
+    ttl_threshold: u32;
 }
 export interface Position {
     bin_shares: Array<BinShares>;
 }
+/**
+ * The limit orders in a bin.
+ *
+ * All orders in a bin sell the same token. x if the bin is left of the active bin and y if it is right of it.
+ * The orders placed in a bin are filled together, as one batch with its own `epoch`.
+ */
+export interface OrderBin {
+    bin_id: i32;
+    epoch: u32;
+    /**
+     * the amount of the other token received for the part that was sold
+     */
+    proceeds: i128;
+    /**
+     * the part of `total` that has not been sold yet
+     */
+    remaining: i128;
+    sell_x: boolean;
+    /**
+     * sum of the amounts of the orders
+     */
+    total: i128;
+}
+/**
+ * What is left to claim of a batch of orders that was filled.
+ */
+export interface FilledOrders {
+    proceeds: i128;
+    total: i128;
+}
+export interface LimitOrder {
+    amount: i128;
+    bin_id: i32;
+    epoch: u32;
+    sell_x: boolean;
+}
+/**
+ * The amounts of the tokens that the pool owes to LPs and limit orders.
+ *
+ * This is the sum of the bin reserves and of the tokens in limit orders, including the proceeds that have not been claimed yet.
+ * Tokens that are sent to the pool without going through the pool are not part of it, see `skim` and `sync`.
+ */
+export interface Reserves {
+    reserve_x: i128;
+    reserve_y: i128;
+}
+/**
+ * The sums of the reserves and shares of all bins.
+ *
+ * The sum of the shares of different bins does not mean much by itself, it is kept to check that shares are not created or lost.
+ */
+export interface BinTotals {
+    total_reserve_x: i128;
+    total_reserve_y: i128;
+    total_shares: i128;
+}
+/**
+ * The bins that an incentive pays rewards to.
+ */
+export type RewardRange = {
+    tag: "Active";
+    values: readonly [u32];
+} | {
+    tag: "Bins";
+    values: readonly [i32, i32];
+};
+/**
+ * Rewards of `token` that are paid to the LPs of the bins in `range`, from `start` to `end`.
+ *
+ * `amount` is paid at the same rate over the whole time, and divided equally over the bins in the range that have shares.
+ * Rewards for a time without shares in the range are not paid, and go back to the manager with `reclaim_incentive`.
+ */
+export interface Incentive {
+    amount: i128;
+    /**
+     * the part of `amount` that was claimed by LPs
+     */
+    claimed: i128;
+    /**
+     * ledger timestamp
+     */
+    end: u64;
+    /**
+     * the rewards until this ledger timestamp were added to the bins
+     */
+    last_update: u64;
+    manager: string;
+    range: RewardRange;
+    /**
+     * ledger timestamp
+     */
+    start: u64;
+    token: string;
+}
+/**
+ * The rewards of a position.
+ */
+export interface PositionRewards {
+    /**
+     * per incentive, the reward per share of the bins of the position when its rewards were last settled
+     */
+    checkpoints: Map<u32, Map<i32, i128>>;
+    /**
+     * per incentive, the rewards that were settled but not claimed yet
+     */
+    pending: Map<u32, i128>;
+}
 export declare const Errors: {
     2: {
         message: string;
@@ -81,13 +300,79 @@ export declare const Errors: {
     37: {
         message: string;
     };
+    40: {
+        message: string;
+    };
+    41: {
+        message: string;
+    };
+    42: {
+        message: string;
+    };
+    43: {
+        message: string;
+    };
+    44: {
+        message: string;
+    };
 };
+/**
+ * The change of one bin in a `liquidity` event.
+ * Positive amounts and shares were added to the bin, negative ones were removed from it.
+ */
+export interface BinChange {
+    amount_x: i128;
+    amount_y: i128;
+    bin_id: i32;
+    shares: i128;
+}
+/**
+ * The part of a swap that went through the reserves of one bin.
+ */
+export interface BinSwap {
+    /**
+     * the input that was added to the reserve, including the fee
+     */
+    amount_in: i128;
+    /**
+     * the output that was taken from the reserve
+     */
+    amount_out: i128;
+    bin_id: i32;
+}
+export interface SwapEvent {
+    /**
+     * the active bin after the swap
+     */
+    active_bin: i32;
+    amount_in: i128;
+    amount_out: i128;
+    /**
+     * the bins that were swapped against, in the order of the swap.
+     * The input and output that are not in these bins went to and came from limit orders.
+     */
+    bins: Array<BinSwap>;
+    is_x_in: boolean;
+}
 export interface Client {
     /**
-     * Construct and simulate a upgrade transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Construct and simulate a modify_liquidity transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Allows `from` to create or modify an existing position.
+     * `offset_from_active` specifies if the `bin_id_or_offset` param of `DepositArgs` is a pointer to the bin or if it is offset from the current active bin.
+     *
+     * NOTE: `args` must be ordered by bin_id ascending order
+     *
+     * The position specified by `position_id` wil be modified or created if not exists.
+     * An account can have multiple positions.
+     *
+     * returns a pair with the amounts deposited removed or added: (x_token_amount, y_token_amount)
+     * a positive number means that we deposited that amount and a negative number means that we withdrew that amount.
      */
-    upgrade: ({ wasm_hash }: {
-        wasm_hash: Buffer;
+    modify_liquidity: ({ from, position_id, args, offset_from_active }: {
+        from: string;
+        position_id: i32;
+        args: Array<DepositArgs>;
+        offset_from_active: boolean;
     }, options?: {
         /**
          * The fee to pay for the transaction. Default: BASE_FEE
@@ -101,16 +386,14 @@ export interface Client {
          * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
          */
         simulate?: boolean;
-    }) => Promise<AssembledTransaction<null>>;
+    }) => Promise<AssembledTransaction<readonly [i128, i128]>>;
     /**
-     * Construct and simulate a modify_liquidity transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-     * Allows `from` to create or modify an existing position.
-     * `offset_from_active` specifies if the `bin_id_or_offset` param of `ActionsArgs` is a pointer to the bin or if it is offset from the current active bin.
-     *
-     * returns a pair with the amounts deposited removed or added: (x_token_amount, y_token_amount)
+     * Construct and simulate a modify_liquidity_to transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Like `modify_liquidity`, but the withdrawn amounts are sent to `to`. Deposits are still paid by `from`.
      */
-    modify_liquidity: ({ from, position_id, args, offset_from_active }: {
+    modify_liquidity_to: ({ from, to, position_id, args, offset_from_active }: {
         from: string;
+        to: string;
         position_id: i32;
         args: Array<DepositArgs>;
         offset_from_active: boolean;
@@ -129,13 +412,21 @@ export interface Client {
         simulate?: boolean;
     }) => Promise<AssembledTransaction<readonly [i128, i128]>>;
     /**
-     * Construct and simulate a swap_exact_amount_in transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Construct and simulate a remove_position_pct transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Allows `owner` to withdraw a percentage of every bin in a position.
+     * `bps` is the percentage of the shares to burn in basis points, so `10_000` withdraws everything.
+     *
+     * The shares to burn are rounded down per bin.
+     * When all of the shares are burned, the position is deleted.
+     *
+     * returns a pair with the amounts withdrawn: (x_token_amount, y_token_amount)
      */
-    swap_exact_amount_in: ({ from, amount_in, min_amount_out, in_token }: {
-        from: string;
-        amount_in: i128;
-        min_amount_out: i128;
-        in_token: string;
+    remove_position_pct: ({ owner, position_id, bps, min_x, min_y }: {
+        owner: string;
+        position_id: i32;
+        bps: u32;
+        min_x: i128;
+        min_y: i128;
     }, options?: {
         /**
          * The fee to pay for the transaction. Default: BASE_FEE
@@ -149,12 +440,31 @@ export interface Client {
          * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
          */
         simulate?: boolean;
-    }) => Promise<AssembledTransaction<i128>>;
+    }) => Promise<AssembledTransaction<readonly [i128, i128]>>;
     /**
-     * Construct and simulate a get_bin_vec transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Construct and simulate a rebalance_position transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Allows `owner` to move all the liquidity of a position to the bins around the current active bin.
+     *
+     * All shares of the position are withdrawn and redeposited in the bins `[active_bin - width, active_bin + width]`, spread according to `shape`.
+     * Fails with `ErrInvalidWidth` if `width` is more than `MAX_WIDTH`. Bins of the range outside of the supported bins are skipped.
+     * If `swap_imbalance` is set, the withdrawn tokens are first swapped through this pool, so that the value of x and y is about equal.
+     * The swap may move the active bin, the liquidity is centered on the active bin after the swap.
+     * The swap is skipped when the other liquidity of the pool can't take it, for example when the position is the only liquidity.
+     *
+     * Tokens that could not be redeposited, because of rounding or the distribution of the active bin, are sent back to `owner`.
+     *
+     * `min_x` and `min_y` are the minimum amounts that must be redeposited.
+     *
+     * returns a pair with the amounts redeposited: (x_token_amount, y_token_amount)
      */
-    get_bin_vec: ({ vec_id }: {
-        vec_id: i32;
+    rebalance_position: ({ owner, position_id, width, shape, swap_imbalance, min_x, min_y }: {
+        owner: string;
+        position_id: i32;
+        width: u32;
+        shape: LiquidityShape;
+        swap_imbalance: boolean;
+        min_x: i128;
+        min_y: i128;
     }, options?: {
         /**
          * The fee to pay for the transaction. Default: BASE_FEE
@@ -168,12 +478,19 @@ export interface Client {
          * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
          */
         simulate?: boolean;
-    }) => Promise<AssembledTransaction<Array<Bin>>>;
+    }) => Promise<AssembledTransaction<readonly [i128, i128]>>;
     /**
-     * Construct and simulate a get_shares_vec transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Construct and simulate a batch_transfer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Moves `shares` of the position `position_id` of `from` to the position with the same id of `to`, which is created if not exists.
+     *
+     * The shares of each bin work like a multi token, where the bin id is the token id. Only the owner of the shares moves, the bins stay the same.
+     * The rewards that the shares earned until now stay with `from`.
      */
-    get_shares_vec: ({ vec_id }: {
-        vec_id: i32;
+    batch_transfer: ({ from, to, position_id, shares }: {
+        from: string;
+        to: string;
+        position_id: i32;
+        shares: Array<BinShares>;
     }, options?: {
         /**
          * The fee to pay for the transaction. Default: BASE_FEE
@@ -187,13 +504,17 @@ export interface Client {
          * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
          */
         simulate?: boolean;
-    }) => Promise<AssembledTransaction<Array<BinShares>>>;
+    }) => Promise<AssembledTransaction<null>>;
     /**
-     * Construct and simulate a get_position transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Construct and simulate a batch_transfer_from transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Like `batch_transfer`, but authorized by `operator`, which must be approved by `from` with `set_approval_for_all`.
      */
-    get_position: ({ from, position_id }: {
+    batch_transfer_from: ({ operator, from, to, position_id, shares }: {
+        operator: string;
         from: string;
+        to: string;
         position_id: i32;
+        shares: Array<BinShares>;
     }, options?: {
         /**
          * The fee to pay for the transaction. Default: BASE_FEE
@@ -207,7 +528,867 @@ export interface Client {
          * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
          */
         simulate?: boolean;
-    }) => Promise<AssembledTransaction<Option<Position>>>;
+    }) => Promise<AssembledTransaction<null>>;
+    /**
+     * Construct and simulate a set_approval_for_all transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Allows or disallows `operator` to transfer all shares of all positions of `owner`.
+     */
+    set_approval_for_all: ({ owner, operator, approved }: {
+        owner: string;
+        operator: string;
+        approved: boolean;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<null>>;
+    /**
+     * Construct and simulate a swap_exact_amount_in transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     */
+    swap_exact_amount_in: ({ from, amount_in, min_amount_out, in_token }: {
+        from: string;
+        amount_in: i128;
+        min_amount_out: i128;
+        in_token: string;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<i128>>;
+    /**
+     * Construct and simulate a swap_exact_amount_in_to transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Like `swap_exact_amount_in`, but the output is sent to `to`, for example to pay someone in the other token.
+     */
+    swap_exact_amount_in_to: ({ from, to, amount_in, min_amount_out, in_token }: {
+        from: string;
+        to: string;
+        amount_in: i128;
+        min_amount_out: i128;
+        in_token: string;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<i128>>;
+    /**
+     * Construct and simulate a quote_exact_amount_in transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * returns what `swap_exact_amount_in` would send for `amount_in` of `in_token`, without changing the pool.
+     *
+     * Panics with "Not enough liquidity" like the swap. A fee on the transfer of `in_token` is not taken into account.
+     */
+    quote_exact_amount_in: ({ amount_in, in_token }: {
+        amount_in: i128;
+        in_token: string;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<i128>>;
+    /**
+     * Construct and simulate a flash_swap transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Swaps `amount_in` of `in_token` like `swap_exact_amount_in`, but the output is sent to `receiver` before the input is paid.
+     *
+     * After the output is transferred, `on_flash_swap` of the `FlashSwapReceiver` interface is invoked on `receiver` with `data`.
+     * Before the callback returns, `receiver` must transfer `amount_in` of `in_token` to the pool, for example with the proceeds of
+     * selling the output somewhere else.
+     *
+     * returns the amount of the other token sent to `receiver`
+     */
+    flash_swap: ({ receiver, amount_in, min_amount_out, in_token, data }: {
+        receiver: string;
+        amount_in: i128;
+        min_amount_out: i128;
+        in_token: string;
+        data: Buffer;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<i128>>;
+    /**
+     * Construct and simulate a flash_loan transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Lends `amount` of `token` to `receiver` for the duration of this call.
+     *
+     * The tokens are transferred to `receiver` and `on_flash_loan` of the `FlashLoanReceiver` interface is invoked with `data`.
+     * Before the callback returns, `receiver` must transfer the amount plus the fee back to the pool.
+     * The fee is `flash_loan_fee` bps of `amount`, rounded up, and is added to the reserves of the active bin.
+     * So a loan with a fee needs liquidity in the active bin.
+     *
+     * The host does not allow a contract to be re-entered, so the callback can't call back into the pool
+     * (e.g. `swap_exact_amount_in` or `modify_liquidity`) while the loan is outstanding.
+     *
+     * returns the fee that was paid
+     */
+    flash_loan: ({ receiver, token, amount, data }: {
+        receiver: string;
+        token: string;
+        amount: i128;
+        data: Buffer;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<i128>>;
+    /**
+     * Construct and simulate a place_limit_order transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Allows `owner` to place a limit order of `amount` in `bin_id`.
+     *
+     * If the bin is left of the active bin, the order sells x for y, otherwise it sells y for x.
+     * Orders are tracked separately from the liquidity of the LPs. Swaps use the orders after the liquidity of the bin,
+     * and once all orders in the bin are sold, they can be claimed with `claim_limit_order`.
+     * Contrary to liquidity, the proceeds of a filled order can't be swapped back.
+     *
+     * Orders can't be placed in the active bin, or in a bin where the orders are partially filled.
+     */
+    place_limit_order: ({ owner, order_id, bin_id, amount }: {
+        owner: string;
+        order_id: i32;
+        bin_id: i32;
+        amount: i128;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<null>>;
+    /**
+     * Construct and simulate a cancel_limit_order transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Allows `owner` to cancel an order that is not filled yet.
+     *
+     * If the order is partially filled, `owner` receives their part of what is left and of what was received.
+     *
+     * returns a pair with the amounts sent to `owner`: (x_token_amount, y_token_amount)
+     */
+    cancel_limit_order: ({ owner, order_id }: {
+        owner: string;
+        order_id: i32;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<readonly [i128, i128]>>;
+    /**
+     * Construct and simulate a claim_limit_order transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Allows `owner` to claim the proceeds of a filled order.
+     *
+     * returns the amount of the bought token sent to `owner`
+     */
+    claim_limit_order: ({ owner, order_id }: {
+        owner: string;
+        order_id: i32;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<i128>>;
+    /**
+     * Construct and simulate a add_incentive transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Adds an incentive that pays `amount` of `token` to the LPs of the bins in `range`, at the same rate from `start` to `end`.
+     *
+     * Only the admin of the pool can add incentives, `manager` pays them and can reclaim what is left.
+     *
+     * `start` and `end` are ledger timestamps, a `start` in the past starts now, and `end` is at most `MAX_INCENTIVE_DURATION` from now.
+     * The rewards of every moment are divided equally over the bins in the range that have shares, and within a bin by shares.
+     * LPs claim them per position and incentive with `claim_rewards`. The tokens of the pool can't be rewards, as they would be taken by `skim`.
+     *
+     * The pool has at most `MAX_INCENTIVES` incentives, an incentive counts until it is reclaimed with `reclaim_incentive`.
+     *
+     * returns the id of the incentive
+     */
+    add_incentive: ({ manager, token, amount, start, end, range }: {
+        manager: string;
+        token: string;
+        amount: i128;
+        start: u64;
+        end: u64;
+        range: RewardRange;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<u32>>;
+    /**
+     * Construct and simulate a claim_rewards transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Allows `owner` to claim the rewards that position `position_id` earned from incentive `incentive_id`.
+     *
+     * Every incentive is claimed on its own, so a reward token that can't be transferred doesn't block the others.
+     * Rewards stay claimable after the shares are removed, until `REWARD_CLAIM_PERIOD` after the end of their incentive.
+     *
+     * returns the amount sent to `owner`
+     */
+    claim_rewards: ({ owner, position_id, incentive_id }: {
+        owner: string;
+        position_id: i32;
+        incentive_id: u32;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<i128>>;
+    /**
+     * Construct and simulate a reclaim_incentive transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Allows the manager of incentive `id` to take back what was not claimed, once `REWARD_CLAIM_PERIOD` passed after its end.
+     *
+     * This includes the rewards of the times that no bin in the range had shares.
+     * The incentive is removed, so its rewards that were not claimed can't be claimed anymore.
+     *
+     * returns the amount sent to the manager
+     */
+    reclaim_incentive: ({ id }: {
+        id: u32;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<i128>>;
+    /**
+     * Construct and simulate a skim transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Sends the tokens that the pool holds on top of its reserves to `to`.
+     *
+     * These are tokens that were transferred to the pool directly, or that were added by a token that rebases up.
+     * Anyone can call this, so tokens that are sent to the pool by accident can be taken by anyone.
+     *
+     * returns a pair with the amounts sent: (x_token_amount, y_token_amount)
+     */
+    skim: ({ to }: {
+        to: string;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<readonly [i128, i128]>>;
+    /**
+     * Construct and simulate a sync transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Adds the tokens that the pool holds on top of its reserves to the reserves of the active bin, so they go to the LPs of that bin.
+     *
+     * Like `skim`, this is for tokens that were transferred directly or that rebased up. If a token rebased down or was clawed back,
+     * the balance is below the reserves. The reserves are then lowered to the balance and the shortfall is taken pro rata from the bins nearest to the active bin, see `take_from_bins`.
+     * Fails if those bins can't cover the shortfall, the tokens of the limit orders are never taken.
+     *
+     * returns a pair with the amounts added: (x_token_amount, y_token_amount)
+     */
+    sync: (options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<readonly [i128, i128]>>;
+    /**
+     * Construct and simulate a bump transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Extends the TTL of the bins in `bins` with their limit orders, the positions in `positions` with their rewards and the position ids of their owners,
+     * the limit orders in `orders` with their filled orders, the approvals of the (owner, operator) pairs in `operators`, the incentives and of the contract instance.
+     *
+     * Bins, positions, orders and approvals are also extended when they are written, this allows anyone to keep idle pools, positions, open orders
+     * and approvals from being archived.
+     * Incentives are only written until their end, so they need to be bumped until they are reclaimed.
+     * Entries that don't exist are skipped. The thresholds come from `ttl_threshold` and `ttl_extend_to` in the config.
+     */
+    bump: ({ bins, positions, orders, operators }: {
+        bins: Array<i32>;
+        positions: Array<readonly [string, i32]>;
+        orders: Array<readonly [string, i32]>;
+        operators: Array<readonly [string, string]>;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<null>>;
+    /**
+     * Construct and simulate a get_bin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * returns the bin with id `bin_id`
+     */
+    get_bin: ({ bin_id }: {
+        bin_id: i32;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Bin>>;
+    /**
+     * Construct and simulate a get_bins_range transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * returns the bins from `from_bin` up to and including `to_bin`, with their total shares and price.
+     *
+     * The range may span multiple vecs, but can't be larger than `MAX_BINS_IN_RANGE` bins and must be within the supported bins.
+     */
+    get_bins_range: ({ from_bin, to_bin }: {
+        from_bin: i32;
+        to_bin: i32;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Array<BinInfo>>>;
+    /**
+     * Construct and simulate a get_active_bin_reserves transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * returns the active bin with its total shares and price
+     */
+    get_active_bin_reserves: (options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<BinInfo>>;
+    /**
+     * Construct and simulate a get_price_from_id transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * returns the amount of the other token that you get for 1 `in_token` in bin `bin_id`, before fees and in 128.128 fixed point
+     *
+     * Fails with `ErrBinIdOutOfRange` if the bin is outside of the supported bins.
+     */
+    get_price_from_id: ({ bin_id, in_token }: {
+        bin_id: i32;
+        in_token: string;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<u256>>;
+    /**
+     * Construct and simulate a get_id_from_price transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * returns the id of the bin for `price`, the price of y in x in 128.128 fixed point.
+     *
+     * Prices between two bins are rounded down to the lower bin, see `price::id_from_price`.
+     * Fails with `ErrPriceOutOfRange` if the price is outside of the supported bins.
+     */
+    get_id_from_price: ({ price }: {
+        price: u256;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<i32>>;
+    /**
+     * Construct and simulate a get_bin_vec transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Bins are grouped together in a `BinVec` of size `BIN_VEC_SIZE`
+     *
+     * the `get_vec_id_for_bin` can be used to convert a bin_id to a vec_id
+     *
+     * This function will return the group of bins specified by the `vec_id`
+     */
+    get_bin_vec: ({ vec_id }: {
+        vec_id: i32;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Array<Bin>>>;
+    /**
+     * Construct and simulate a get_shares_vec transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Each Bin is treated as a separate "vault" and has its own shares.
+     *
+     *
+     * the `get_vec_id_for_bin` can be used to convert a bin_id to a vec_id
+     *
+     * This function will return the group of bin shares specified by the `vec_id`
+     */
+    get_shares_vec: ({ vec_id }: {
+        vec_id: i32;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Array<BinShares>>>;
+    /**
+     * Construct and simulate a get_position transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * returns the `Position` for a given position id for a user
+     *
+     * Each user can have multiple positions.
+     */
+    get_position: ({ from, position_id }: {
+        from: string;
+        position_id: i32;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Option<Position>>>;
+    /**
+     * Construct and simulate a get_position_ids transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * returns the ids of the positions of `owner`
+     */
+    get_position_ids: ({ owner }: {
+        owner: string;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Array<i32>>>;
+    /**
+     * Construct and simulate a balance_of transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * returns the shares of `owner` in the bin `bin_id`, summed over all positions of `owner`
+     */
+    balance_of: ({ owner, bin_id }: {
+        owner: string;
+        bin_id: i32;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<i128>>;
+    /**
+     * Construct and simulate a balance_of_batch transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * returns `balance_of` for each pair of `owners` and `bin_ids`, which must have the same length
+     */
+    balance_of_batch: ({ owners, bin_ids }: {
+        owners: Array<string>;
+        bin_ids: Array<i32>;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Array<i128>>>;
+    /**
+     * Construct and simulate a is_approved_for_all transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * returns whether `operator` may transfer the shares of `owner`
+     */
+    is_approved_for_all: ({ owner, operator }: {
+        owner: string;
+        operator: string;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<boolean>>;
+    /**
+     * Construct and simulate a get_incentive transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * returns the incentive with id `id`, if it was not reclaimed
+     */
+    get_incentive: ({ id }: {
+        id: u32;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Option<Incentive>>>;
+    /**
+     * Construct and simulate a get_incentives transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * returns the ids of the incentives that were not reclaimed
+     */
+    get_incentives: (options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Array<u32>>>;
+    /**
+     * Construct and simulate a get_pending_rewards transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * returns what `claim_rewards` would send for the position now, per incentive: vec[(incentive_id, amount)]
+     */
+    get_pending_rewards: ({ owner, position_id }: {
+        owner: string;
+        position_id: i32;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Array<readonly [u32, i128]>>>;
+    /**
+     * Construct and simulate a get_limit_order transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * returns the limit order for a given order id for a user
+     */
+    get_limit_order: ({ owner, order_id }: {
+        owner: string;
+        order_id: i32;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Option<LimitOrder>>>;
+    /**
+     * Construct and simulate a get_order_bin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * returns the unfilled limit orders in a bin
+     */
+    get_order_bin: ({ bin_id }: {
+        bin_id: i32;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Option<OrderBin>>>;
+    /**
+     * Construct and simulate a get_human_price_from_id transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * returns the human price in bin `bin_id`: the amount of whole x tokens that 1 whole y token is worth, in 128.128 fixed point.
+     *
+     * Contrary to `get_price_from_id`, this takes the decimals of the tokens into account.
+     * Fails with `ErrPriceOutOfRange` if the human price does not fit in 256 bits, which happens in the top bins when y has many more decimals than x.
+     */
+    get_human_price_from_id: ({ bin_id }: {
+        bin_id: i32;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<u256>>;
+    /**
+     * Construct and simulate a get_id_from_human_price transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * returns the id of the bin for a human price: the amount of whole x tokens that 1 whole y token is worth, in 128.128 fixed point.
+     *
+     * Prices between two bins are rounded down to the lower bin. Use `price::id_from_human_price` to pick the active bin of a new pool.
+     */
+    get_id_from_human_price: ({ human_price }: {
+        human_price: u256;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<i32>>;
+    /**
+     * Construct and simulate a get_supported_bins transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * returns the lowest and highest bin id that are supported for the bin step of the pool.
+     *
+     * Liquidity and limit orders can only be placed in these bins, see `price::get_max_bin_id`.
+     */
+    get_supported_bins: (options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<readonly [i32, i32]>>;
+    /**
+     * Construct and simulate a get_reserves transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * returns the amounts of the tokens that the pool owes to LPs and limit orders, see `skim` and `sync` for tokens that are not part of it.
+     */
+    get_reserves: (options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Reserves>>;
+    /**
+     * Construct and simulate a get_bin_totals transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * returns the sums of the reserves and shares of all bins. These are also part of `get_reserves`, which includes the limit orders.
+     */
+    get_bin_totals: (options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<BinTotals>>;
     /**
      * Construct and simulate a get_config transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
      */
@@ -244,12 +1425,49 @@ export declare class Client extends ContractClient {
     }): Promise<AssembledTransaction<T>>;
     constructor(options: ContractClientOptions);
     readonly fromJSON: {
-        upgrade: (json: string) => AssembledTransaction<null>;
         modify_liquidity: (json: string) => AssembledTransaction<readonly [bigint, bigint]>;
+        modify_liquidity_to: (json: string) => AssembledTransaction<readonly [bigint, bigint]>;
+        remove_position_pct: (json: string) => AssembledTransaction<readonly [bigint, bigint]>;
+        rebalance_position: (json: string) => AssembledTransaction<readonly [bigint, bigint]>;
+        batch_transfer: (json: string) => AssembledTransaction<null>;
+        batch_transfer_from: (json: string) => AssembledTransaction<null>;
+        set_approval_for_all: (json: string) => AssembledTransaction<null>;
         swap_exact_amount_in: (json: string) => AssembledTransaction<bigint>;
+        swap_exact_amount_in_to: (json: string) => AssembledTransaction<bigint>;
+        quote_exact_amount_in: (json: string) => AssembledTransaction<bigint>;
+        flash_swap: (json: string) => AssembledTransaction<bigint>;
+        flash_loan: (json: string) => AssembledTransaction<bigint>;
+        place_limit_order: (json: string) => AssembledTransaction<null>;
+        cancel_limit_order: (json: string) => AssembledTransaction<readonly [bigint, bigint]>;
+        claim_limit_order: (json: string) => AssembledTransaction<bigint>;
+        add_incentive: (json: string) => AssembledTransaction<number>;
+        claim_rewards: (json: string) => AssembledTransaction<bigint>;
+        reclaim_incentive: (json: string) => AssembledTransaction<bigint>;
+        skim: (json: string) => AssembledTransaction<readonly [bigint, bigint]>;
+        sync: (json: string) => AssembledTransaction<readonly [bigint, bigint]>;
+        bump: (json: string) => AssembledTransaction<null>;
+        get_bin: (json: string) => AssembledTransaction<Bin>;
+        get_bins_range: (json: string) => AssembledTransaction<BinInfo[]>;
+        get_active_bin_reserves: (json: string) => AssembledTransaction<BinInfo>;
+        get_price_from_id: (json: string) => AssembledTransaction<bigint>;
+        get_id_from_price: (json: string) => AssembledTransaction<number>;
         get_bin_vec: (json: string) => AssembledTransaction<Bin[]>;
         get_shares_vec: (json: string) => AssembledTransaction<BinShares[]>;
         get_position: (json: string) => AssembledTransaction<Option<Position>>;
+        get_position_ids: (json: string) => AssembledTransaction<number[]>;
+        balance_of: (json: string) => AssembledTransaction<bigint>;
+        balance_of_batch: (json: string) => AssembledTransaction<bigint[]>;
+        is_approved_for_all: (json: string) => AssembledTransaction<boolean>;
+        get_incentive: (json: string) => AssembledTransaction<Option<Incentive>>;
+        get_incentives: (json: string) => AssembledTransaction<number[]>;
+        get_pending_rewards: (json: string) => AssembledTransaction<(readonly [number, bigint])[]>;
+        get_limit_order: (json: string) => AssembledTransaction<Option<LimitOrder>>;
+        get_order_bin: (json: string) => AssembledTransaction<Option<OrderBin>>;
+        get_human_price_from_id: (json: string) => AssembledTransaction<bigint>;
+        get_id_from_human_price: (json: string) => AssembledTransaction<number>;
+        get_supported_bins: (json: string) => AssembledTransaction<readonly [number, number]>;
+        get_reserves: (json: string) => AssembledTransaction<Reserves>;
+        get_bin_totals: (json: string) => AssembledTransaction<BinTotals>;
         get_config: (json: string) => AssembledTransaction<Config>;
     };
 }
@@ -7,6 +7,38 @@ if (typeof window !== 'undefined') {
     //@ts-ignore Buffer exists
     window.Buffer = window.Buffer || Buffer;
 }
+/**
+ * The bitmaps of vecs in storage, see `bitmap.rs`.
+ */
+export var VecIndex;
+(function (VecIndex) {
+    /**
+     * The vecs that have liquidity.
+     */
+    VecIndex[VecIndex["Bins"] = 0] = "Bins";
+    /**
+     * The vecs that have limit orders.
+     */
+    VecIndex[VecIndex["Orders"] = 1] = "Orders";
+})(VecIndex || (VecIndex = {}));
+/**
+ * How liquidity is spread over the bins around the active bin, for example when rebalancing a position.
+ */
+export var LiquidityShape;
+(function (LiquidityShape) {
+    /**
+     * The same weight in every bin.
+     */
+    LiquidityShape[LiquidityShape["Spot"] = 0] = "Spot";
+    /**
+     * Most of the liquidity around the active bin, decreasing towards the edges.
+     */
+    LiquidityShape[LiquidityShape["Curve"] = 1] = "Curve";
+    /**
+     * Most of the liquidity at the edges, decreasing towards the active bin.
+     */
+    LiquidityShape[LiquidityShape["BidAsk"] = 2] = "BidAsk";
+})(LiquidityShape || (LiquidityShape = {}));
 export const Errors = {
     2: { message: "ErrNegative" },
     18: { message: "ErrMathApprox" },
@@ -16,7 +48,12 @@ export const Errors = {
     33: { message: "ErrMulOverflow" },
     34: { message: "ErrCPowBaseTooLow" },
     35: { message: "ErrCPowBaseTooHigh" },
-    37: { message: "ErrNegativeOrZero" }
+    37: { message: "ErrNegativeOrZero" },
+    40: { message: "ErrBinIdOutOfRange" },
+    41: { message: "ErrPriceOutOfRange" },
+    42: { message: "ErrInvalidBinStep" },
+    43: { message: "ErrTransferAmountMismatch" },
+    44: { message: "ErrInvalidWidth" }
 };
 export class Client extends ContractClient {
     options;
@@ -28,30 +65,118 @@ export class Client extends ContractClient {
         return ContractClient.deploy({ conf }, options);
     }
     constructor(options) {
-        super(new ContractSpec(["AAAAAAAAAAAAAAANX19jb25zdHJ1Y3RvcgAAAAAAAAEAAAAAAAAABGNvbmYAAAfQAAAABkNvbmZpZwAAAAAAAA==",
-            "AAAAAAAAAAAAAAAHdXBncmFkZQAAAAABAAAAAAAAAAl3YXNtX2hhc2gAAAAAAAPuAAAAIAAAAAA=",
-            "AAAAAAAAAS1BbGxvd3MgYGZyb21gIHRvIGNyZWF0ZSBvciBtb2RpZnkgYW4gZXhpc3RpbmcgcG9zaXRpb24uCmBvZmZzZXRfZnJvbV9hY3RpdmVgIHNwZWNpZmllcyBpZiB0aGUgYGJpbl9pZF9vcl9vZmZzZXRgIHBhcmFtIG9mIGBBY3Rpb25zQXJnc2AgaXMgYSBwb2ludGVyIHRvIHRoZSBiaW4gb3IgaWYgaXQgaXMgb2Zmc2V0IGZyb20gdGhlIGN1cnJlbnQgYWN0aXZlIGJpbi4KCnJldHVybnMgYSBwYWlyIHdpdGggdGhlIGFtb3VudHMgZGVwb3NpdGVkIHJlbW92ZWQgb3IgYWRkZWQ6ICh4X3Rva2VuX2Ftb3VudCwgeV90b2tlbl9hbW91bnQpAAAAAAAAEG1vZGlmeV9saXF1aWRpdHkAAAAEAAAAAAAAAARmcm9tAAAAEwAAAAAAAAALcG9zaXRpb25faWQAAAAABQAAAAAAAAAEYXJncwAAA+oAAAfQAAAAC0RlcG9zaXRBcmdzAAAAAAAAAAASb2Zmc2V0X2Zyb21fYWN0aXZlAAAAAAABAAAAAQAAA+0AAAACAAAACwAAAAs=",
+        super(new ContractSpec(["AAAAAAAAAGVDcmVhdGVzIHRoZSBwb29sLiBgZGVjaW1hbHNfeGAgYW5kIGBkZWNpbWFsc195YCBvZiBgY29uZmAgYXJlIGlnbm9yZWQsIHRoZXkgYXJlIHJlYWQgZnJvbSB0aGUgdG9rZW5zLgAAAAAAAA1fX2NvbnN0cnVjdG9yAAAAAAAAAQAAAAAAAAAEY29uZgAAB9AAAAAGQ29uZmlnAAAAAAAA",
+            "AAAAAAAAAlBBbGxvd3MgYGZyb21gIHRvIGNyZWF0ZSBvciBtb2RpZnkgYW4gZXhpc3RpbmcgcG9zaXRpb24uCmBvZmZzZXRfZnJvbV9hY3RpdmVgIHNwZWNpZmllcyBpZiB0aGUgYGJpbl9pZF9vcl9vZmZzZXRgIHBhcmFtIG9mIGBEZXBvc2l0QXJnc2AgaXMgYSBwb2ludGVyIHRvIHRoZSBiaW4gb3IgaWYgaXQgaXMgb2Zmc2V0IGZyb20gdGhlIGN1cnJlbnQgYWN0aXZlIGJpbi4KCk5PVEU6IGBhcmdzYCBtdXN0IGJlIG9yZGVyZWQgYnkgYmluX2lkIGFzY2VuZGluZyBvcmRlcgoKVGhlIHBvc2l0aW9uIHNwZWNpZmllZCBieSBgcG9zaXRpb25faWRgIHdpbCBiZSBtb2RpZmllZCBvciBjcmVhdGVkIGlmIG5vdCBleGlzdHMuCkFuIGFjY291bnQgY2FuIGhhdmUgbXVsdGlwbGUgcG9zaXRpb25zLgoKcmV0dXJucyBhIHBhaXIgd2l0aCB0aGUgYW1vdW50cyBkZXBvc2l0ZWQgcmVtb3ZlZCBvciBhZGRlZDogKHhfdG9rZW5fYW1vdW50LCB5X3Rva2VuX2Ftb3VudCkKYSBwb3NpdGl2ZSBudW1iZXIgbWVhbnMgdGhhdCB3ZSBkZXBvc2l0ZWQgdGhhdCBhbW91bnQgYW5kIGEgbmVnYXRpdmUgbnVtYmVyIG1lYW5zIHRoYXQgd2Ugd2l0aGRyZXcgdGhhdCBhbW91bnQuAAAAEG1vZGlmeV9saXF1aWRpdHkAAAAEAAAAAAAAAARmcm9tAAAAEwAAAAAAAAALcG9zaXRpb25faWQAAAAABQAAAAAAAAAEYXJncwAAA+oAAAfQAAAAC0RlcG9zaXRBcmdzAAAAAAAAAAASb2Zmc2V0X2Zyb21fYWN0aXZlAAAAAAABAAAAAQAAA+0AAAACAAAACwAAAAs=",
+            "AAAAAAAAAGdMaWtlIGBtb2RpZnlfbGlxdWlkaXR5YCwgYnV0IHRoZSB3aXRoZHJhd24gYW1vdW50cyBhcmUgc2VudCB0byBgdG9gLiBEZXBvc2l0cyBhcmUgc3RpbGwgcGFpZCBieSBgZnJvbWAuAAAAABNtb2RpZnlfbGlxdWlkaXR5X3RvAAAAAAUAAAAAAAAABGZyb20AAAATAAAAAAAAAAJ0bwAAAAAAEwAAAAAAAAALcG9zaXRpb25faWQAAAAABQAAAAAAAAAEYXJncwAAA+oAAAfQAAAAC0RlcG9zaXRBcmdzAAAAAAAAAAASb2Zmc2V0X2Zyb21fYWN0aXZlAAAAAAABAAAAAQAAA+0AAAACAAAACwAAAAs=",
+            "AAAAAAAAAVtBbGxvd3MgYG93bmVyYCB0byB3aXRoZHJhdyBhIHBlcmNlbnRhZ2Ugb2YgZXZlcnkgYmluIGluIGEgcG9zaXRpb24uCmBicHNgIGlzIHRoZSBwZXJjZW50YWdlIG9mIHRoZSBzaGFyZXMgdG8gYnVybiBpbiBiYXNpcyBwb2ludHMsIHNvIGAxMF8wMDBgIHdpdGhkcmF3cyBldmVyeXRoaW5nLgoKVGhlIHNoYXJlcyB0byBidXJuIGFyZSByb3VuZGVkIGRvd24gcGVyIGJpbi4KV2hlbiBhbGwgb2YgdGhlIHNoYXJlcyBhcmUgYnVybmVkLCB0aGUgcG9zaXRpb24gaXMgZGVsZXRlZC4KCnJldHVybnMgYSBwYWlyIHdpdGggdGhlIGFtb3VudHMgd2l0aGRyYXduOiAoeF90b2tlbl9hbW91bnQsIHlfdG9rZW5fYW1vdW50KQAAAAATcmVtb3ZlX3Bvc2l0aW9uX3BjdAAAAAAFAAAAAAAAAAVvd25lcgAAAAAAABMAAAAAAAAAC3Bvc2l0aW9uX2lkAAAAAAUAAAAAAAAAA2JwcwAAAAAEAAAAAAAAAAVtaW5feAAAAAAAAAsAAAAAAAAABW1pbl95AAAAAAAACwAAAAEAAAPtAAAAAgAAAAsAAAAL",
+            "AAAAAAAAA99BbGxvd3MgYG93bmVyYCB0byBtb3ZlIGFsbCB0aGUgbGlxdWlkaXR5IG9mIGEgcG9zaXRpb24gdG8gdGhlIGJpbnMgYXJvdW5kIHRoZSBjdXJyZW50IGFjdGl2ZSBiaW4uCgpBbGwgc2hhcmVzIG9mIHRoZSBwb3NpdGlvbiBhcmUgd2l0aGRyYXduIGFuZCByZWRlcG9zaXRlZCBpbiB0aGUgYmlucyBgW2FjdGl2ZV9iaW4gLSB3aWR0aCwgYWN0aXZlX2JpbiArIHdpZHRoXWAsIHNwcmVhZCBhY2NvcmRpbmcgdG8gYHNoYXBlYC4KRmFpbHMgd2l0aCBgRXJySW52YWxpZFdpZHRoYCBpZiBgd2lkdGhgIGlzIG1vcmUgdGhhbiBgTUFYX1dJRFRIYC4gQmlucyBvZiB0aGUgcmFuZ2Ugb3V0c2lkZSBvZiB0aGUgc3VwcG9ydGVkIGJpbnMgYXJlIHNraXBwZWQuCklmIGBzd2FwX2ltYmFsYW5jZWAgaXMgc2V0LCB0aGUgd2l0aGRyYXduIHRva2VucyBhcmUgZmlyc3Qgc3dhcHBlZCB0aHJvdWdoIHRoaXMgcG9vbCwgc28gdGhhdCB0aGUgdmFsdWUgb2YgeCBhbmQgeSBpcyBhYm91dCBlcXVhbC4KVGhlIHN3YXAgbWF5IG1vdmUgdGhlIGFjdGl2ZSBiaW4sIHRoZSBsaXF1aWRpdHkgaXMgY2VudGVyZWQgb24gdGhlIGFjdGl2ZSBiaW4gYWZ0ZXIgdGhlIHN3YXAuClRoZSBzd2FwIGlzIHNraXBwZWQgd2hlbiB0aGUgb3RoZXIgbGlxdWlkaXR5IG9mIHRoZSBwb29sIGNhbid0IHRha2UgaXQsIGZvciBleGFtcGxlIHdoZW4gdGhlIHBvc2l0aW9uIGlzIHRoZSBvbmx5IGxpcXVpZGl0eS4KClRva2VucyB0aGF0IGNvdWxkIG5vdCBiZSByZWRlcG9zaXRlZCwgYmVjYXVzZSBvZiByb3VuZGluZyBvciB0aGUgZGlzdHJpYnV0aW9uIG9mIHRoZSBhY3RpdmUgYmluLCBhcmUgc2VudCBiYWNrIHRvIGBvd25lcmAuCgpgbWluX3hgIGFuZCBgbWluX3lgIGFyZSB0aGUgbWluaW11bSBhbW91bnRzIHRoYXQgbXVzdCBiZSByZWRlcG9zaXRlZC4KCnJldHVybnMgYSBwYWlyIHdpdGggdGhlIGFtb3VudHMgcmVkZXBvc2l0ZWQ6ICh4X3Rva2VuX2Ftb3VudCwgeV90b2tlbl9hbW91bnQpAAAAABJyZWJhbGFuY2VfcG9zaXRpb24AAAAAAAcAAAAAAAAABW93bmVyAAAAAAAAEwAAAAAAAAALcG9zaXRpb25faWQAAAAABQAAAAAAAAAFd2lkdGgAAAAAAAAEAAAAAAAAAAVzaGFwZQAAAAAAB9AAAAAOTGlxdWlkaXR5U2hhcGUAAAAAAAAAAAAOc3dhcF9pbWJhbGFuY2UAAAAAAAEAAAAAAAAABW1pbl94AAAAAAAACwAAAAAAAAAFbWluX3kAAAAAAAALAAAAAQAAA+0AAAACAAAACwAAAAs=",
+            "AAAAAAAAAU5Nb3ZlcyBgc2hhcmVzYCBvZiB0aGUgcG9zaXRpb24gYHBvc2l0aW9uX2lkYCBvZiBgZnJvbWAgdG8gdGhlIHBvc2l0aW9uIHdpdGggdGhlIHNhbWUgaWQgb2YgYHRvYCwgd2hpY2ggaXMgY3JlYXRlZCBpZiBub3QgZXhpc3RzLgoKVGhlIHNoYXJlcyBvZiBlYWNoIGJpbiB3b3JrIGxpa2UgYSBtdWx0aSB0b2tlbiwgd2hlcmUgdGhlIGJpbiBpZCBpcyB0aGUgdG9rZW4gaWQuIE9ubHkgdGhlIG93bmVyIG9mIHRoZSBzaGFyZXMgbW92ZXMsIHRoZSBiaW5zIHN0YXkgdGhlIHNhbWUuClRoZSByZXdhcmRzIHRoYXQgdGhlIHNoYXJlcyBlYXJuZWQgdW50aWwgbm93IHN0YXkgd2l0aCBgZnJvbWAuAAAAAAAOYmF0Y2hfdHJhbnNmZXIAAAAAAAQAAAAAAAAABGZyb20AAAATAAAAAAAAAAJ0bwAAAAAAEwAAAAAAAAALcG9zaXRpb25faWQAAAAABQAAAAAAAAAGc2hhcmVzAAAAAAPqAAAH0AAAAAlCaW5TaGFyZXMAAAAAAAAA",
+            "AAAAAAAAAHJMaWtlIGBiYXRjaF90cmFuc2ZlcmAsIGJ1dCBhdXRob3JpemVkIGJ5IGBvcGVyYXRvcmAsIHdoaWNoIG11c3QgYmUgYXBwcm92ZWQgYnkgYGZyb21gIHdpdGggYHNldF9hcHByb3ZhbF9mb3JfYWxsYC4AAAAAABNiYXRjaF90cmFuc2Zlcl9mcm9tAAAAAAUAAAAAAAAACG9wZXJhdG9yAAAAEwAAAAAAAAAEZnJvbQAAABMAAAAAAAAAAnRvAAAAAAATAAAAAAAAAAtwb3NpdGlvbl9pZAAAAAAFAAAAAAAAAAZzaGFyZXMAAAAAA+oAAAfQAAAACUJpblNoYXJlcwAAAAAAAAA=",
+            "AAAAAAAAAFJBbGxvd3Mgb3IgZGlzYWxsb3dzIGBvcGVyYXRvcmAgdG8gdHJhbnNmZXIgYWxsIHNoYXJlcyBvZiBhbGwgcG9zaXRpb25zIG9mIGBvd25lcmAuAAAAAAAUc2V0X2FwcHJvdmFsX2Zvcl9hbGwAAAADAAAAAAAAAAVvd25lcgAAAAAAABMAAAAAAAAACG9wZXJhdG9yAAAAEwAAAAAAAAAIYXBwcm92ZWQAAAABAAAAAA==",
             "AAAAAAAAAAAAAAAUc3dhcF9leGFjdF9hbW91bnRfaW4AAAAEAAAAAAAAAARmcm9tAAAAEwAAAAAAAAAJYW1vdW50X2luAAAAAAAACwAAAAAAAAAObWluX2Ftb3VudF9vdXQAAAAAAAsAAAAAAAAACGluX3Rva2VuAAAAEwAAAAEAAAAL",
-            "AAAAAAAAAAAAAAALZ2V0X2Jpbl92ZWMAAAAAAQAAAAAAAAAGdmVjX2lkAAAAAAAFAAAAAQAAA+oAAAfQAAAAA0JpbgA=",
-            "AAAAAAAAAAAAAAAOZ2V0X3NoYXJlc192ZWMAAAAAAAEAAAAAAAAABnZlY19pZAAAAAAABQAAAAEAAAPqAAAH0AAAAAlCaW5TaGFyZXMAAAA=",
-            "AAAAAAAAAAAAAAAMZ2V0X3Bvc2l0aW9uAAAAAgAAAAAAAAAEZnJvbQAAABMAAAAAAAAAC3Bvc2l0aW9uX2lkAAAAAAUAAAABAAAD6AAAB9AAAAAIUG9zaXRpb24=",
+            "AAAAAAAAAGtMaWtlIGBzd2FwX2V4YWN0X2Ftb3VudF9pbmAsIGJ1dCB0aGUgb3V0cHV0IGlzIHNlbnQgdG8gYHRvYCwgZm9yIGV4YW1wbGUgdG8gcGF5IHNvbWVvbmUgaW4gdGhlIG90aGVyIHRva2VuLgAAAAAXc3dhcF9leGFjdF9hbW91bnRfaW5fdG8AAAAABQAAAAAAAAAEZnJvbQAAABMAAAAAAAAAAnRvAAAAAAATAAAAAAAAAAlhbW91bnRfaW4AAAAAAAALAAAAAAAAAA5taW5fYW1vdW50X291dAAAAAAACwAAAAAAAAAIaW5fdG9rZW4AAAATAAAAAQAAAAs=",
+            "AAAAAAAAANpyZXR1cm5zIHdoYXQgYHN3YXBfZXhhY3RfYW1vdW50X2luYCB3b3VsZCBzZW5kIGZvciBgYW1vdW50X2luYCBvZiBgaW5fdG9rZW5gLCB3aXRob3V0IGNoYW5naW5nIHRoZSBwb29sLgoKUGFuaWNzIHdpdGggIk5vdCBlbm91Z2ggbGlxdWlkaXR5IiBsaWtlIHRoZSBzd2FwLiBBIGZlZSBvbiB0aGUgdHJhbnNmZXIgb2YgYGluX3Rva2VuYCBpcyBub3QgdGFrZW4gaW50byBhY2NvdW50LgAAAAAAFXF1b3RlX2V4YWN0X2Ftb3VudF9pbgAAAAAAAAIAAAAAAAAACWFtb3VudF9pbgAAAAAAAAsAAAAAAAAACGluX3Rva2VuAAAAEwAAAAEAAAAL",
+            "AAAAAAAAAdNTd2FwcyBgYW1vdW50X2luYCBvZiBgaW5fdG9rZW5gIGxpa2UgYHN3YXBfZXhhY3RfYW1vdW50X2luYCwgYnV0IHRoZSBvdXRwdXQgaXMgc2VudCB0byBgcmVjZWl2ZXJgIGJlZm9yZSB0aGUgaW5wdXQgaXMgcGFpZC4KCkFmdGVyIHRoZSBvdXRwdXQgaXMgdHJhbnNmZXJyZWQsIGBvbl9mbGFzaF9zd2FwYCBvZiB0aGUgYEZsYXNoU3dhcFJlY2VpdmVyYCBpbnRlcmZhY2UgaXMgaW52b2tlZCBvbiBgcmVjZWl2ZXJgIHdpdGggYGRhdGFgLgpCZWZvcmUgdGhlIGNhbGxiYWNrIHJldHVybnMsIGByZWNlaXZlcmAgbXVzdCB0cmFuc2ZlciBgYW1vdW50X2luYCBvZiBgaW5fdG9rZW5gIHRvIHRoZSBwb29sLCBmb3IgZXhhbXBsZSB3aXRoIHRoZSBwcm9jZWVkcyBvZgpzZWxsaW5nIHRoZSBvdXRwdXQgc29tZXdoZXJlIGVsc2UuCgpyZXR1cm5zIHRoZSBhbW91bnQgb2YgdGhlIG90aGVyIHRva2VuIHNlbnQgdG8gYHJlY2VpdmVyYAAAAAAKZmxhc2hfc3dhcAAAAAAABQAAAAAAAAAIcmVjZWl2ZXIAAAATAAAAAAAAAAlhbW91bnRfaW4AAAAAAAALAAAAAAAAAA5taW5fYW1vdW50X291dAAAAAAACwAAAAAAAAAIaW5fdG9rZW4AAAATAAAAAAAAAARkYXRhAAAADgAAAAEAAAAL",
+            "AAAAAAAAAphMZW5kcyBgYW1vdW50YCBvZiBgdG9rZW5gIHRvIGByZWNlaXZlcmAgZm9yIHRoZSBkdXJhdGlvbiBvZiB0aGlzIGNhbGwuCgpUaGUgdG9rZW5zIGFyZSB0cmFuc2ZlcnJlZCB0byBgcmVjZWl2ZXJgIGFuZCBgb25fZmxhc2hfbG9hbmAgb2YgdGhlIGBGbGFzaExvYW5SZWNlaXZlcmAgaW50ZXJmYWNlIGlzIGludm9rZWQgd2l0aCBgZGF0YWAuCkJlZm9yZSB0aGUgY2FsbGJhY2sgcmV0dXJucywgYHJlY2VpdmVyYCBtdXN0IHRyYW5zZmVyIHRoZSBhbW91bnQgcGx1cyB0aGUgZmVlIGJhY2sgdG8gdGhlIHBvb2wuClRoZSBmZWUgaXMgYGZsYXNoX2xvYW5fZmVlYCBicHMgb2YgYGFtb3VudGAsIHJvdW5kZWQgdXAsIGFuZCBpcyBhZGRlZCB0byB0aGUgcmVzZXJ2ZXMgb2YgdGhlIGFjdGl2ZSBiaW4uClNvIGEgbG9hbiB3aXRoIGEgZmVlIG5lZWRzIGxpcXVpZGl0eSBpbiB0aGUgYWN0aXZlIGJpbi4KClRoZSBob3N0IGRvZXMgbm90IGFsbG93IGEgY29udHJhY3QgdG8gYmUgcmUtZW50ZXJlZCwgc28gdGhlIGNhbGxiYWNrIGNhbid0IGNhbGwgYmFjayBpbnRvIHRoZSBwb29sCihlLmcuIGBzd2FwX2V4YWN0X2Ftb3VudF9pbmAgb3IgYG1vZGlmeV9saXF1aWRpdHlgKSB3aGlsZSB0aGUgbG9hbiBpcyBvdXRzdGFuZGluZy4KCnJldHVybnMgdGhlIGZlZSB0aGF0IHdhcyBwYWlkAAAACmZsYXNoX2xvYW4AAAAAAAQAAAAAAAAACHJlY2VpdmVyAAAAEwAAAAAAAAAFdG9rZW4AAAAAAAATAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAAAAAABGRhdGEAAAAOAAAAAQAAAAs=",
+            "AAAAAAAAAg5BbGxvd3MgYG93bmVyYCB0byBwbGFjZSBhIGxpbWl0IG9yZGVyIG9mIGBhbW91bnRgIGluIGBiaW5faWRgLgoKSWYgdGhlIGJpbiBpcyBsZWZ0IG9mIHRoZSBhY3RpdmUgYmluLCB0aGUgb3JkZXIgc2VsbHMgeCBmb3IgeSwgb3RoZXJ3aXNlIGl0IHNlbGxzIHkgZm9yIHguCk9yZGVycyBhcmUgdHJhY2tlZCBzZXBhcmF0ZWx5IGZyb20gdGhlIGxpcXVpZGl0eSBvZiB0aGUgTFBzLiBTd2FwcyB1c2UgdGhlIG9yZGVycyBhZnRlciB0aGUgbGlxdWlkaXR5IG9mIHRoZSBiaW4sCmFuZCBvbmNlIGFsbCBvcmRlcnMgaW4gdGhlIGJpbiBhcmUgc29sZCwgdGhleSBjYW4gYmUgY2xhaW1lZCB3aXRoIGBjbGFpbV9saW1pdF9vcmRlcmAuCkNvbnRyYXJ5IHRvIGxpcXVpZGl0eSwgdGhlIHByb2NlZWRzIG9mIGEgZmlsbGVkIG9yZGVyIGNhbid0IGJlIHN3YXBwZWQgYmFjay4KCk9yZGVycyBjYW4ndCBiZSBwbGFjZWQgaW4gdGhlIGFjdGl2ZSBiaW4sIG9yIGluIGEgYmluIHdoZXJlIHRoZSBvcmRlcnMgYXJlIHBhcnRpYWxseSBmaWxsZWQuAAAAAAARcGxhY2VfbGltaXRfb3JkZXIAAAAAAAAEAAAAAAAAAAVvd25lcgAAAAAAABMAAAAAAAAACG9yZGVyX2lkAAAABQAAAAAAAAAGYmluX2lkAAAAAAAFAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAA",
+            "AAAAAAAAAPVBbGxvd3MgYG93bmVyYCB0byBjYW5jZWwgYW4gb3JkZXIgdGhhdCBpcyBub3QgZmlsbGVkIHlldC4KCklmIHRoZSBvcmRlciBpcyBwYXJ0aWFsbHkgZmlsbGVkLCBgb3duZXJgIHJlY2VpdmVzIHRoZWlyIHBhcnQgb2Ygd2hhdCBpcyBsZWZ0IGFuZCBvZiB3aGF0IHdhcyByZWNlaXZlZC4KCnJldHVybnMgYSBwYWlyIHdpdGggdGhlIGFtb3VudHMgc2VudCB0byBgb3duZXJgOiAoeF90b2tlbl9hbW91bnQsIHlfdG9rZW5fYW1vdW50KQAAAAAAABJjYW5jZWxfbGltaXRfb3JkZXIAAAAAAAIAAAAAAAAABW93bmVyAAAAAAAAEwAAAAAAAAAIb3JkZXJfaWQAAAAFAAAAAQAAA+0AAAACAAAACwAAAAs=",
+            "AAAAAAAAAG9BbGxvd3MgYG93bmVyYCB0byBjbGFpbSB0aGUgcHJvY2VlZHMgb2YgYSBmaWxsZWQgb3JkZXIuCgpyZXR1cm5zIHRoZSBhbW91bnQgb2YgdGhlIGJvdWdodCB0b2tlbiBzZW50IHRvIGBvd25lcmAAAAAAEWNsYWltX2xpbWl0X29yZGVyAAAAAAAAAgAAAAAAAAAFb3duZXIAAAAAAAATAAAAAAAAAAhvcmRlcl9pZAAAAAUAAAABAAAACw==",
+            "AAAAAAAAAvxBZGRzIGFuIGluY2VudGl2ZSB0aGF0IHBheXMgYGFtb3VudGAgb2YgYHRva2VuYCB0byB0aGUgTFBzIG9mIHRoZSBiaW5zIGluIGByYW5nZWAsIGF0IHRoZSBzYW1lIHJhdGUgZnJvbSBgc3RhcnRgIHRvIGBlbmRgLgoKT25seSB0aGUgYWRtaW4gb2YgdGhlIHBvb2wgY2FuIGFkZCBpbmNlbnRpdmVzLCBgbWFuYWdlcmAgcGF5cyB0aGVtIGFuZCBjYW4gcmVjbGFpbSB3aGF0IGlzIGxlZnQuCgpgc3RhcnRgIGFuZCBgZW5kYCBhcmUgbGVkZ2VyIHRpbWVzdGFtcHMsIGEgYHN0YXJ0YCBpbiB0aGUgcGFzdCBzdGFydHMgbm93LCBhbmQgYGVuZGAgaXMgYXQgbW9zdCBgTUFYX0lOQ0VOVElWRV9EVVJBVElPTmAgZnJvbSBub3cuClRoZSByZXdhcmRzIG9mIGV2ZXJ5IG1vbWVudCBhcmUgZGl2aWRlZCBlcXVhbGx5IG92ZXIgdGhlIGJpbnMgaW4gdGhlIHJhbmdlIHRoYXQgaGF2ZSBzaGFyZXMsIGFuZCB3aXRoaW4gYSBiaW4gYnkgc2hhcmVzLgpMUHMgY2xhaW0gdGhlbSBwZXIgcG9zaXRpb24gYW5kIGluY2VudGl2ZSB3aXRoIGBjbGFpbV9yZXdhcmRzYC4gVGhlIHRva2VucyBvZiB0aGUgcG9vbCBjYW4ndCBiZSByZXdhcmRzLCBhcyB0aGV5IHdvdWxkIGJlIHRha2VuIGJ5IGBza2ltYC4KClRoZSBwb29sIGhhcyBhdCBtb3N0IGBNQVhfSU5DRU5USVZFU2AgaW5jZW50aXZlcywgYW4gaW5jZW50aXZlIGNvdW50cyB1bnRpbCBpdCBpcyByZWNsYWltZWQgd2l0aCBgcmVjbGFpbV9pbmNlbnRpdmVgLgoKcmV0dXJucyB0aGUgaWQgb2YgdGhlIGluY2VudGl2ZQAAAA1hZGRfaW5jZW50aXZlAAAAAAAABgAAAAAAAAAHbWFuYWdlcgAAAAATAAAAAAAAAAV0b2tlbgAAAAAAABMAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAAFc3RhcnQAAAAAAAAGAAAAAAAAAANlbmQAAAAABgAAAAAAAAAFcmFuZ2UAAAAAAAfQAAAAC1Jld2FyZFJhbmdlAAAAAAEAAAAE",
+            "AAAAAAAAAWpBbGxvd3MgYG93bmVyYCB0byBjbGFpbSB0aGUgcmV3YXJkcyB0aGF0IHBvc2l0aW9uIGBwb3NpdGlvbl9pZGAgZWFybmVkIGZyb20gaW5jZW50aXZlIGBpbmNlbnRpdmVfaWRgLgoKRXZlcnkgaW5jZW50aXZlIGlzIGNsYWltZWQgb24gaXRzIG93biwgc28gYSByZXdhcmQgdG9rZW4gdGhhdCBjYW4ndCBiZSB0cmFuc2ZlcnJlZCBkb2Vzbid0IGJsb2NrIHRoZSBvdGhlcnMuClJld2FyZHMgc3RheSBjbGFpbWFibGUgYWZ0ZXIgdGhlIHNoYXJlcyBhcmUgcmVtb3ZlZCwgdW50aWwgYFJFV0FSRF9DTEFJTV9QRVJJT0RgIGFmdGVyIHRoZSBlbmQgb2YgdGhlaXIgaW5jZW50aXZlLgoKcmV0dXJucyB0aGUgYW1vdW50IHNlbnQgdG8gYG93bmVyYAAAAAAADWNsYWltX3Jld2FyZHMAAAAAAAADAAAAAAAAAAVvd25lcgAAAAAAABMAAAAAAAAAC3Bvc2l0aW9uX2lkAAAAAAUAAAAAAAAADGluY2VudGl2ZV9pZAAAAAQAAAABAAAACw==",
+            "AAAAAAAAAUZBbGxvd3MgdGhlIG1hbmFnZXIgb2YgaW5jZW50aXZlIGBpZGAgdG8gdGFrZSBiYWNrIHdoYXQgd2FzIG5vdCBjbGFpbWVkLCBvbmNlIGBSRVdBUkRfQ0xBSU1fUEVSSU9EYCBwYXNzZWQgYWZ0ZXIgaXRzIGVuZC4KClRoaXMgaW5jbHVkZXMgdGhlIHJld2FyZHMgb2YgdGhlIHRpbWVzIHRoYXQgbm8gYmluIGluIHRoZSByYW5nZSBoYWQgc2hhcmVzLgpUaGUgaW5jZW50aXZlIGlzIHJlbW92ZWQsIHNvIGl0cyByZXdhcmRzIHRoYXQgd2VyZSBub3QgY2xhaW1lZCBjYW4ndCBiZSBjbGFpbWVkIGFueW1vcmUuCgpyZXR1cm5zIHRoZSBhbW91bnQgc2VudCB0byB0aGUgbWFuYWdlcgAAAAAAEXJlY2xhaW1faW5jZW50aXZlAAAAAAAAAQAAAAAAAAACaWQAAAAAAAQAAAABAAAACw==",
+            "AAAAAAAAAVdTZW5kcyB0aGUgdG9rZW5zIHRoYXQgdGhlIHBvb2wgaG9sZHMgb24gdG9wIG9mIGl0cyByZXNlcnZlcyB0byBgdG9gLgoKVGhlc2UgYXJlIHRva2VucyB0aGF0IHdlcmUgdHJhbnNmZXJyZWQgdG8gdGhlIHBvb2wgZGlyZWN0bHksIG9yIHRoYXQgd2VyZSBhZGRlZCBieSBhIHRva2VuIHRoYXQgcmViYXNlcyB1cC4KQW55b25lIGNhbiBjYWxsIHRoaXMsIHNvIHRva2VucyB0aGF0IGFyZSBzZW50IHRvIHRoZSBwb29sIGJ5IGFjY2lkZW50IGNhbiBiZSB0YWtlbiBieSBhbnlvbmUuCgpyZXR1cm5zIGEgcGFpciB3aXRoIHRoZSBhbW91bnRzIHNlbnQ6ICh4X3Rva2VuX2Ftb3VudCwgeV90b2tlbl9hbW91bnQpAAAAAARza2ltAAAAAQAAAAAAAAACdG8AAAAAABMAAAABAAAD7QAAAAIAAAALAAAACw==",
+            "AAAAAAAAAltBZGRzIHRoZSB0b2tlbnMgdGhhdCB0aGUgcG9vbCBob2xkcyBvbiB0b3Agb2YgaXRzIHJlc2VydmVzIHRvIHRoZSByZXNlcnZlcyBvZiB0aGUgYWN0aXZlIGJpbiwgc28gdGhleSBnbyB0byB0aGUgTFBzIG9mIHRoYXQgYmluLgoKTGlrZSBgc2tpbWAsIHRoaXMgaXMgZm9yIHRva2VucyB0aGF0IHdlcmUgdHJhbnNmZXJyZWQgZGlyZWN0bHkgb3IgdGhhdCByZWJhc2VkIHVwLiBJZiBhIHRva2VuIHJlYmFzZWQgZG93biBvciB3YXMgY2xhd2VkIGJhY2ssCnRoZSBiYWxhbmNlIGlzIGJlbG93IHRoZSByZXNlcnZlcy4gVGhlIHJlc2VydmVzIGFyZSB0aGVuIGxvd2VyZWQgdG8gdGhlIGJhbGFuY2UgYW5kIHRoZSBzaG9ydGZhbGwgaXMgdGFrZW4gcHJvIHJhdGEgZnJvbSB0aGUgYmlucyBuZWFyZXN0IHRvIHRoZSBhY3RpdmUgYmluLCBzZWUgYHRha2VfZnJvbV9iaW5zYC4KRmFpbHMgaWYgdGhvc2UgYmlucyBjYW4ndCBjb3ZlciB0aGUgc2hvcnRmYWxsLCB0aGUgdG9rZW5zIG9mIHRoZSBsaW1pdCBvcmRlcnMgYXJlIG5ldmVyIHRha2VuLgoKcmV0dXJucyBhIHBhaXIgd2l0aCB0aGUgYW1vdW50cyBhZGRlZDogKHhfdG9rZW5fYW1vdW50LCB5X3Rva2VuX2Ftb3VudCkAAAAABHN5bmMAAAAAAAAAAQAAA+0AAAACAAAACwAAAAs=",
+            "AAAAAAAAArlFeHRlbmRzIHRoZSBUVEwgb2YgdGhlIGJpbnMgaW4gYGJpbnNgIHdpdGggdGhlaXIgbGltaXQgb3JkZXJzLCB0aGUgcG9zaXRpb25zIGluIGBwb3NpdGlvbnNgIHdpdGggdGhlaXIgcmV3YXJkcyBhbmQgdGhlIHBvc2l0aW9uIGlkcyBvZiB0aGVpciBvd25lcnMsCnRoZSBsaW1pdCBvcmRlcnMgaW4gYG9yZGVyc2Agd2l0aCB0aGVpciBmaWxsZWQgb3JkZXJzLCB0aGUgYXBwcm92YWxzIG9mIHRoZSAob3duZXIsIG9wZXJhdG9yKSBwYWlycyBpbiBgb3BlcmF0b3JzYCwgdGhlIGluY2VudGl2ZXMgYW5kIG9mIHRoZSBjb250cmFjdCBpbnN0YW5jZS4KCkJpbnMsIHBvc2l0aW9ucywgb3JkZXJzIGFuZCBhcHByb3ZhbHMgYXJlIGFsc28gZXh0ZW5kZWQgd2hlbiB0aGV5IGFyZSB3cml0dGVuLCB0aGlzIGFsbG93cyBhbnlvbmUgdG8ga2VlcCBpZGxlIHBvb2xzLCBwb3NpdGlvbnMsIG9wZW4gb3JkZXJzCmFuZCBhcHByb3ZhbHMgZnJvbSBiZWluZyBhcmNoaXZlZC4KSW5jZW50aXZlcyBhcmUgb25seSB3cml0dGVuIHVudGlsIHRoZWlyIGVuZCwgc28gdGhleSBuZWVkIHRvIGJlIGJ1bXBlZCB1bnRpbCB0aGV5IGFyZSByZWNsYWltZWQuCkVudHJpZXMgdGhhdCBkb24ndCBleGlzdCBhcmUgc2tpcHBlZC4gVGhlIHRocmVzaG9sZHMgY29tZSBmcm9tIGB0dGxfdGhyZXNob2xkYCBhbmQgYHR0bF9leHRlbmRfdG9gIGluIHRoZSBjb25maWcuAAAAAAAABGJ1bXAAAAAEAAAAAAAAAARiaW5zAAAD6gAAAAUAAAAAAAAACXBvc2l0aW9ucwAAAAAAA+oAAAPtAAAAAgAAABMAAAAFAAAAAAAAAAZvcmRlcnMAAAAAA+oAAAPtAAAAAgAAABMAAAAFAAAAAAAAAAlvcGVyYXRvcnMAAAAAAAPqAAAD7QAAAAIAAAATAAAAEwAAAAA=",
+            "AAAAAAAAACByZXR1cm5zIHRoZSBiaW4gd2l0aCBpZCBgYmluX2lkYAAAAAdnZXRfYmluAAAAAAEAAAAAAAAABmJpbl9pZAAAAAAABQAAAAEAAAfQAAAAA0JpbgA=",
+            "AAAAAAAAAN1yZXR1cm5zIHRoZSBiaW5zIGZyb20gYGZyb21fYmluYCB1cCB0byBhbmQgaW5jbHVkaW5nIGB0b19iaW5gLCB3aXRoIHRoZWlyIHRvdGFsIHNoYXJlcyBhbmQgcHJpY2UuCgpUaGUgcmFuZ2UgbWF5IHNwYW4gbXVsdGlwbGUgdmVjcywgYnV0IGNhbid0IGJlIGxhcmdlciB0aGFuIGBNQVhfQklOU19JTl9SQU5HRWAgYmlucyBhbmQgbXVzdCBiZSB3aXRoaW4gdGhlIHN1cHBvcnRlZCBiaW5zLgAAAAAAAA5nZXRfYmluc19yYW5nZQAAAAAAAgAAAAAAAAAIZnJvbV9iaW4AAAAFAAAAAAAAAAZ0b19iaW4AAAAAAAUAAAABAAAD6gAAB9AAAAAHQmluSW5mbwA=",
+            "AAAAAAAAADZyZXR1cm5zIHRoZSBhY3RpdmUgYmluIHdpdGggaXRzIHRvdGFsIHNoYXJlcyBhbmQgcHJpY2UAAAAAABdnZXRfYWN0aXZlX2Jpbl9yZXNlcnZlcwAAAAAAAAAAAQAAB9AAAAAHQmluSW5mbwA=",
+            "AAAAAAAAAMlyZXR1cm5zIHRoZSBhbW91bnQgb2YgdGhlIG90aGVyIHRva2VuIHRoYXQgeW91IGdldCBmb3IgMSBgaW5fdG9rZW5gIGluIGJpbiBgYmluX2lkYCwgYmVmb3JlIGZlZXMgYW5kIGluIDEyOC4xMjggZml4ZWQgcG9pbnQKCkZhaWxzIHdpdGggYEVyckJpbklkT3V0T2ZSYW5nZWAgaWYgdGhlIGJpbiBpcyBvdXRzaWRlIG9mIHRoZSBzdXBwb3J0ZWQgYmlucy4AAAAAAAARZ2V0X3ByaWNlX2Zyb21faWQAAAAAAAACAAAAAAAAAAZiaW5faWQAAAAAAAUAAAAAAAAACGluX3Rva2VuAAAAEwAAAAEAAAAM",
+            "AAAAAAAAAPlyZXR1cm5zIHRoZSBpZCBvZiB0aGUgYmluIGZvciBgcHJpY2VgLCB0aGUgcHJpY2Ugb2YgeSBpbiB4IGluIDEyOC4xMjggZml4ZWQgcG9pbnQuCgpQcmljZXMgYmV0d2VlbiB0d28gYmlucyBhcmUgcm91bmRlZCBkb3duIHRvIHRoZSBsb3dlciBiaW4sIHNlZSBgcHJpY2U6OmlkX2Zyb21fcHJpY2VgLgpGYWlscyB3aXRoIGBFcnJQcmljZU91dE9mUmFuZ2VgIGlmIHRoZSBwcmljZSBpcyBvdXRzaWRlIG9mIHRoZSBzdXBwb3J0ZWQgYmlucy4AAAAAAAARZ2V0X2lkX2Zyb21fcHJpY2UAAAAAAAABAAAAAAAAAAVwcmljZQAAAAAAAAwAAAABAAAABQ==",
+            "AAAAAAAAAMtCaW5zIGFyZSBncm91cGVkIHRvZ2V0aGVyIGluIGEgYEJpblZlY2Agb2Ygc2l6ZSBgQklOX1ZFQ19TSVpFYAoKdGhlIGBnZXRfdmVjX2lkX2Zvcl9iaW5gIGNhbiBiZSB1c2VkIHRvIGNvbnZlcnQgYSBiaW5faWQgdG8gYSB2ZWNfaWQKClRoaXMgZnVuY3Rpb24gd2lsbCByZXR1cm4gdGhlIGdyb3VwIG9mIGJpbnMgc3BlY2lmaWVkIGJ5IHRoZSBgdmVjX2lkYAAAAAALZ2V0X2Jpbl92ZWMAAAAAAQAAAAAAAAAGdmVjX2lkAAAAAAAFAAAAAQAAA+oAAAfQAAAAA0JpbgA=",
+            "AAAAAAAAANVFYWNoIEJpbiBpcyB0cmVhdGVkIGFzIGEgc2VwYXJhdGUgInZhdWx0IiBhbmQgaGFzIGl0cyBvd24gc2hhcmVzLgoKCnRoZSBgZ2V0X3ZlY19pZF9mb3JfYmluYCBjYW4gYmUgdXNlZCB0byBjb252ZXJ0IGEgYmluX2lkIHRvIGEgdmVjX2lkCgpUaGlzIGZ1bmN0aW9uIHdpbGwgcmV0dXJuIHRoZSBncm91cCBvZiBiaW4gc2hhcmVzIHNwZWNpZmllZCBieSB0aGUgYHZlY19pZGAAAAAAAAAOZ2V0X3NoYXJlc192ZWMAAAAAAAEAAAAAAAAABnZlY19pZAAAAAAABQAAAAEAAAPqAAAH0AAAAAlCaW5TaGFyZXMAAAA=",
+            "AAAAAAAAAGFyZXR1cm5zIHRoZSBgUG9zaXRpb25gIGZvciBhIGdpdmVuIHBvc2l0aW9uIGlkIGZvciBhIHVzZXIKCkVhY2ggdXNlciBjYW4gaGF2ZSBtdWx0aXBsZSBwb3NpdGlvbnMuAAAAAAAADGdldF9wb3NpdGlvbgAAAAIAAAAAAAAABGZyb20AAAATAAAAAAAAAAtwb3NpdGlvbl9pZAAAAAAFAAAAAQAAA+gAAAfQAAAACFBvc2l0aW9u",
+            "AAAAAAAAACtyZXR1cm5zIHRoZSBpZHMgb2YgdGhlIHBvc2l0aW9ucyBvZiBgb3duZXJgAAAAABBnZXRfcG9zaXRpb25faWRzAAAAAQAAAAAAAAAFb3duZXIAAAAAAAATAAAAAQAAA+oAAAAF",
+            "AAAAAAAAAFdyZXR1cm5zIHRoZSBzaGFyZXMgb2YgYG93bmVyYCBpbiB0aGUgYmluIGBiaW5faWRgLCBzdW1tZWQgb3ZlciBhbGwgcG9zaXRpb25zIG9mIGBvd25lcmAAAAAACmJhbGFuY2Vfb2YAAAAAAAIAAAAAAAAABW93bmVyAAAAAAAAEwAAAAAAAAAGYmluX2lkAAAAAAAFAAAAAQAAAAs=",
+            "AAAAAAAAAF1yZXR1cm5zIGBiYWxhbmNlX29mYCBmb3IgZWFjaCBwYWlyIG9mIGBvd25lcnNgIGFuZCBgYmluX2lkc2AsIHdoaWNoIG11c3QgaGF2ZSB0aGUgc2FtZSBsZW5ndGgAAAAAAAAQYmFsYW5jZV9vZl9iYXRjaAAAAAIAAAAAAAAABm93bmVycwAAAAAD6gAAABMAAAAAAAAAB2Jpbl9pZHMAAAAD6gAAAAUAAAABAAAD6gAAAAs=",
+            "AAAAAAAAAD1yZXR1cm5zIHdoZXRoZXIgYG9wZXJhdG9yYCBtYXkgdHJhbnNmZXIgdGhlIHNoYXJlcyBvZiBgb3duZXJgAAAAAAAAE2lzX2FwcHJvdmVkX2Zvcl9hbGwAAAAAAgAAAAAAAAAFb3duZXIAAAAAAAATAAAAAAAAAAhvcGVyYXRvcgAAABMAAAABAAAAAQ==",
+            "AAAAAAAAADtyZXR1cm5zIHRoZSBpbmNlbnRpdmUgd2l0aCBpZCBgaWRgLCBpZiBpdCB3YXMgbm90IHJlY2xhaW1lZAAAAAANZ2V0X2luY2VudGl2ZQAAAAAAAAEAAAAAAAAAAmlkAAAAAAAEAAAAAQAAA+gAAAfQAAAACUluY2VudGl2ZQAAAA==",
+            "AAAAAAAAADlyZXR1cm5zIHRoZSBpZHMgb2YgdGhlIGluY2VudGl2ZXMgdGhhdCB3ZXJlIG5vdCByZWNsYWltZWQAAAAAAAAOZ2V0X2luY2VudGl2ZXMAAAAAAAAAAAABAAAD6gAAAAQ=",
+            "AAAAAAAAAGhyZXR1cm5zIHdoYXQgYGNsYWltX3Jld2FyZHNgIHdvdWxkIHNlbmQgZm9yIHRoZSBwb3NpdGlvbiBub3csIHBlciBpbmNlbnRpdmU6IHZlY1soaW5jZW50aXZlX2lkLCBhbW91bnQpXQAAABNnZXRfcGVuZGluZ19yZXdhcmRzAAAAAAIAAAAAAAAABW93bmVyAAAAAAAAEwAAAAAAAAALcG9zaXRpb25faWQAAAAABQAAAAEAAAPqAAAD7QAAAAIAAAAEAAAACw==",
+            "AAAAAAAAADdyZXR1cm5zIHRoZSBsaW1pdCBvcmRlciBmb3IgYSBnaXZlbiBvcmRlciBpZCBmb3IgYSB1c2VyAAAAAA9nZXRfbGltaXRfb3JkZXIAAAAAAgAAAAAAAAAFb3duZXIAAAAAAAATAAAAAAAAAAhvcmRlcl9pZAAAAAUAAAABAAAD6AAAB9AAAAAKTGltaXRPcmRlcgAA",
+            "AAAAAAAAACpyZXR1cm5zIHRoZSB1bmZpbGxlZCBsaW1pdCBvcmRlcnMgaW4gYSBiaW4AAAAAAA1nZXRfb3JkZXJfYmluAAAAAAAAAQAAAAAAAAAGYmluX2lkAAAAAAAFAAAAAQAAA+gAAAfQAAAACE9yZGVyQmlu",
+            "AAAAAAAAAWNyZXR1cm5zIHRoZSBodW1hbiBwcmljZSBpbiBiaW4gYGJpbl9pZGA6IHRoZSBhbW91bnQgb2Ygd2hvbGUgeCB0b2tlbnMgdGhhdCAxIHdob2xlIHkgdG9rZW4gaXMgd29ydGgsIGluIDEyOC4xMjggZml4ZWQgcG9pbnQuCgpDb250cmFyeSB0byBgZ2V0X3ByaWNlX2Zyb21faWRgLCB0aGlzIHRha2VzIHRoZSBkZWNpbWFscyBvZiB0aGUgdG9rZW5zIGludG8gYWNjb3VudC4KRmFpbHMgd2l0aCBgRXJyUHJpY2VPdXRPZlJhbmdlYCBpZiB0aGUgaHVtYW4gcHJpY2UgZG9lcyBub3QgZml0IGluIDI1NiBiaXRzLCB3aGljaCBoYXBwZW5zIGluIHRoZSB0b3AgYmlucyB3aGVuIHkgaGFzIG1hbnkgbW9yZSBkZWNpbWFscyB0aGFuIHguAAAAABdnZXRfaHVtYW5fcHJpY2VfZnJvbV9pZAAAAAABAAAAAAAAAAZiaW5faWQAAAAAAAUAAAABAAAADA==",
+            "AAAAAAAAAQNyZXR1cm5zIHRoZSBpZCBvZiB0aGUgYmluIGZvciBhIGh1bWFuIHByaWNlOiB0aGUgYW1vdW50IG9mIHdob2xlIHggdG9rZW5zIHRoYXQgMSB3aG9sZSB5IHRva2VuIGlzIHdvcnRoLCBpbiAxMjguMTI4IGZpeGVkIHBvaW50LgoKUHJpY2VzIGJldHdlZW4gdHdvIGJpbnMgYXJlIHJvdW5kZWQgZG93biB0byB0aGUgbG93ZXIgYmluLiBVc2UgYHByaWNlOjppZF9mcm9tX2h1bWFuX3ByaWNlYCB0byBwaWNrIHRoZSBhY3RpdmUgYmluIG9mIGEgbmV3IHBvb2wuAAAAABdnZXRfaWRfZnJvbV9odW1hbl9wcmljZQAAAAABAAAAAAAAAAtodW1hbl9wcmljZQAAAAAMAAAAAQAAAAU=",
+            "AAAAAAAAALFyZXR1cm5zIHRoZSBsb3dlc3QgYW5kIGhpZ2hlc3QgYmluIGlkIHRoYXQgYXJlIHN1cHBvcnRlZCBmb3IgdGhlIGJpbiBzdGVwIG9mIHRoZSBwb29sLgoKTGlxdWlkaXR5IGFuZCBsaW1pdCBvcmRlcnMgY2FuIG9ubHkgYmUgcGxhY2VkIGluIHRoZXNlIGJpbnMsIHNlZSBgcHJpY2U6OmdldF9tYXhfYmluX2lkYC4AAAAAAAASZ2V0X3N1cHBvcnRlZF9iaW5zAAAAAAAAAAAAAQAAA+0AAAACAAAABQAAAAU=",
+            "AAAAAAAAAIdyZXR1cm5zIHRoZSBhbW91bnRzIG9mIHRoZSB0b2tlbnMgdGhhdCB0aGUgcG9vbCBvd2VzIHRvIExQcyBhbmQgbGltaXQgb3JkZXJzLCBzZWUgYHNraW1gIGFuZCBgc3luY2AgZm9yIHRva2VucyB0aGF0IGFyZSBub3QgcGFydCBvZiBpdC4AAAAADGdldF9yZXNlcnZlcwAAAAAAAAABAAAH0AAAAAhSZXNlcnZlcw==",
+            "AAAAAAAAAIByZXR1cm5zIHRoZSBzdW1zIG9mIHRoZSByZXNlcnZlcyBhbmQgc2hhcmVzIG9mIGFsbCBiaW5zLiBUaGVzZSBhcmUgYWxzbyBwYXJ0IG9mIGBnZXRfcmVzZXJ2ZXNgLCB3aGljaCBpbmNsdWRlcyB0aGUgbGltaXQgb3JkZXJzLgAAAA5nZXRfYmluX3RvdGFscwAAAAAAAAAAAAEAAAfQAAAACUJpblRvdGFscwAAAA==",
             "AAAAAAAAAAAAAAAKZ2V0X2NvbmZpZwAAAAAAAAAAAAEAAAfQAAAABkNvbmZpZwAA",
-            "AAAAAgAAAAAAAAAAAAAAB0RhdGFLZXkAAAAABAAAAAAAAAAAAAAABkNvbmZpZwAAAAAAAQAAAAAAAAAGQmluVmVjAAAAAAABAAAABQAAAAEAAAAAAAAAC0JpblNoYXJlVmVjAAAAAAEAAAAFAAAAAQAAAAAAAAAIUG9zaXRpb24AAAACAAAAEwAAAAU=",
+            "AAAAAgAAAAAAAAAAAAAAB0RhdGFLZXkAAAAAEwAAAAAAAAAAAAAABkNvbmZpZwAAAAAAAQAAAAAAAAAGQmluVmVjAAAAAAABAAAABQAAAAEAAAAAAAAAC0JpblNoYXJlVmVjAAAAAAEAAAAFAAAAAQAAAAAAAAAIUG9zaXRpb24AAAACAAAAEwAAAAUAAAABAAAAAAAAAAlPcmRlckJpbnMAAAAAAAABAAAABQAAAAAAAAAAAAAACk9yZGVyRXBvY2gAAAAAAAEAAAAAAAAADEZpbGxlZE9yZGVycwAAAAEAAAAEAAAAAQAAAAAAAAAKTGltaXRPcmRlcgAAAAAAAgAAABMAAAAFAAAAAAAAAAAAAAAIUmVzZXJ2ZXMAAAAAAAAAAAAAAAlCaW5Ub3RhbHMAAAAAAAAAAAAAAAAAAApJbmNlbnRpdmVzAAAAAAAAAAAAAAAAAA5JbmNlbnRpdmVDb3VudAAAAAAAAQAAAAAAAAAJSW5jZW50aXZlAAAAAAAAAQAAAAQAAAABAAAAAAAAAA5SZXdhcmRQZXJTaGFyZQAAAAAAAgAAAAQAAAAFAAAAAQAAAAAAAAAMUmV3YXJkVmVjSWRzAAAAAQAAAAQAAAABAAAAAAAAAA9Qb3NpdGlvblJld2FyZHMAAAAAAgAAABMAAAAFAAAAAQAAAAAAAAALUG9zaXRpb25JZHMAAAAAAQAAABMAAAABAAAAAAAAAAhPcGVyYXRvcgAAAAIAAAATAAAAEwAAAAEAAAAAAAAACVZlY0JpdG1hcAAAAAAAAAMAAAfQAAAACFZlY0luZGV4AAAABAAAAAQ=",
+            "AAAAAwAAADBUaGUgYml0bWFwcyBvZiB2ZWNzIGluIHN0b3JhZ2UsIHNlZSBgYml0bWFwLnJzYC4AAAAAAAAACFZlY0luZGV4AAAAAgAAAB1UaGUgdmVjcyB0aGF0IGhhdmUgbGlxdWlkaXR5LgAAAAAAAARCaW5zAAAAAAAAACBUaGUgdmVjcyB0aGF0IGhhdmUgbGltaXQgb3JkZXJzLgAAAAZPcmRlcnMAAAAAAAE=",
             "AAAAAQAAABVzdG9yZWQgYXMgdmVjW3NoYXJlc10AAAAAAAAAAAAACUJpblNoYXJlcwAAAAAAAAIAAAAAAAAABmJpbl9pZAAAAAAABQAAAAAAAAAGc2hhcmVzAAAAAAAL",
+            "AAAAAQAAAEZBIGJpbiB3aXRoIGl0cyB0b3RhbCBzaGFyZXMgYW5kIHByaWNlLCBhcyByZXR1cm5lZCBieSB0aGUgYmluIGdldHRlcnMuAAAAAAAAAAAAB0JpbkluZm8AAAAABQAAAAAAAAAGYmluX2lkAAAAAAAFAAAAK3RoZSBwcmljZSBvZiB5IGluIHgsIGluIDEyOC4xMjggZml4ZWQgcG9pbnQAAAAABXByaWNlAAAAAAAADAAAAAAAAAAJcmVzZXJ2ZV94AAAAAAAACwAAAAAAAAAJcmVzZXJ2ZV95AAAAAAAACwAAAAAAAAAGc2hhcmVzAAAAAAAL",
             "AAAAAQAAAHdzdG9yZWQgYXMgdmVjW3Jlc2VydmVfeHxyZXNlcnZlX3ldIGlmIG5vdCBhY3RpdmUgYmluCm90aGVyd2lzZSBpZiBhY3RpdmVfYmluLCBpdCBpcyBzdG9yZWQgYXMgdmVjW3Jlc2VydmVfeCwgcmVzZXJ2ZV95XQAAAAAAAAAAA0JpbgAAAAADAAAAAAAAAAZiaW5faWQAAAAAAAUAAAAAAAAACXJlc2VydmVfeAAAAAAAAAsAAAAAAAAACXJlc2VydmVfeQAAAAAAAAs=",
-            "AAAAAQAAAP5iaW5faWRfb3Jfb2Zmc2V0IGlzIGVpdGhlciB0aGUgYmluIGlkIG9yIHRoZSBvZmZzZXQgZnJvbSB0aGUgY3VycmVudCBhY3RpdmUgYmluCmFtb3VudCBzcGVjaWZpZXMgdGhlIHRva2VuIGFtb3VudC4gVGhlIHRva2VucyBkZXBlbmRzIG9uIHdoaWNoIHNpZGUgb2YgdGhlIGFjdGl2ZSBiZWVuIGlzCmJlaW5nIGRlcG9zaXRlZC4gSWYgdGhlIGJpbiBpcyB0aGUgYWN0aXZlIGJpbiwgdGhlbiB0aGUgdG9rZW5zIGlzIHNwbGl0IGJhc2VkIG9uIHRoZQAAAAAAAAAAAAtEZXBvc2l0QXJncwAAAAADAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAAAAAAEGJpbl9pZF9vcl9vZmZzZXQAAAAFAAAAAAAAAAlpc19yZW1vdmUAAAAAAAAB",
-            "AAAAAQAAAAAAAAAAAAAABkNvbmZpZwAAAAAABQAAAAAAAAAKYWN0aXZlX2JpbgAAAAAABQAAAAAAAAAIYmluX3N0ZXAAAAAEAAAAAAAAAANmZWUAAAAABAAAAAAAAAAHdG9rZW5feAAAAAATAAAAAAAAAAd0b2tlbl95AAAAABM=",
+            "AAAAAQAAARtiaW5faWRfb3Jfb2Zmc2V0IGlzIGVpdGhlciB0aGUgYmluIGlkIG9yIHRoZSBvZmZzZXQgZnJvbSB0aGUgY3VycmVudCBhY3RpdmUgYmluCmFtb3VudCBzcGVjaWZpZXMgdGhlIHRva2VuIGFtb3VudCBvciB0aGUgYW1vdW50IG9mIHNoYXJlcywgZGVwZW5kaW5nIG9uIHRoZSB2YWx1ZSBvZiBgaXNfcmVtb3ZlYApJIGRpZG4ndCBsaWtlIGhvdyB0aGUgRGVwb3NpdEFyZ3Mgc2hvd2VkIHVwIGluIHN0ZWxsYXIgZXhwZXJ0LCBzbyBJIGNoYW5nZWQgaXQgZnJvbSBhIHN1bSB0eXBlIHRvIGEgc3RydWN0AAAAAAAAAAALRGVwb3NpdEFyZ3MAAAAAAwAAAAAAAAAGYW1vdW50AAAAAAALAAAAAAAAABBiaW5faWRfb3Jfb2Zmc2V0AAAABQAAAAAAAAAJaXNfcmVtb3ZlAAAAAAAAAQ==",
+            "AAAAAwAAAGVIb3cgbGlxdWlkaXR5IGlzIHNwcmVhZCBvdmVyIHRoZSBiaW5zIGFyb3VuZCB0aGUgYWN0aXZlIGJpbiwgZm9yIGV4YW1wbGUgd2hlbiByZWJhbGFuY2luZyBhIHBvc2l0aW9uLgAAAAAAAAAAAAAOTGlxdWlkaXR5U2hhcGUAAAAAAAMAAAAdVGhlIHNhbWUgd2VpZ2h0IGluIGV2ZXJ5IGJpbi4AAAAAAAAEU3BvdAAAAAAAAABKTW9zdCBvZiB0aGUgbGlxdWlkaXR5IGFyb3VuZCB0aGUgYWN0aXZlIGJpbiwgZGVjcmVhc2luZyB0b3dhcmRzIHRoZSBlZGdlcy4AAAAAAAVDdXJ2ZQAAAAAAAAEAAABGTW9zdCBvZiB0aGUgbGlxdWlkaXR5IGF0IHRoZSBlZGdlcywgZGVjcmVhc2luZyB0b3dhcmRzIHRoZSBhY3RpdmUgYmluLgAAAAAABkJpZEFzawAAAAAAAg==",
+            "AAAAAQAAAAAAAAAAAAAABkNvbmZpZwAAAAAACwAAAAAAAAAKYWN0aXZlX2JpbgAAAAAABQAAABJtYXkgYWRkIGluY2VudGl2ZXMAAAAAAAVhZG1pbgAAAAAAABMAAAAAAAAACGJpbl9zdGVwAAAABAAAAEV0aGUgZGVjaW1hbHMgb2YgdG9rZW4geCwgcmVhZCBmcm9tIHRoZSB0b2tlbiB3aGVuIHRoZSBwb29sIGlzIGNyZWF0ZWQAAAAAAAAKZGVjaW1hbHNfeAAAAAAABAAAAEV0aGUgZGVjaW1hbHMgb2YgdG9rZW4geSwgcmVhZCBmcm9tIHRoZSB0b2tlbiB3aGVuIHRoZSBwb29sIGlzIGNyZWF0ZWQAAAAAAAAKZGVjaW1hbHNfeQAAAAAABAAAAAAAAAADZmVlAAAAAAQAAAAhZmVlIGNoYXJnZWQgb24gZmxhc2ggbG9hbnMgaW4gYnBzAAAAAAAADmZsYXNoX2xvYW5fZmVlAAAAAAAEAAAAAAAAAAd0b2tlbl94AAAAABMAAAAAAAAAB3Rva2VuX3kAAAAAEwAAAFV0aGUgVFRMIGluIGxlZGdlcnMgdGhhdCBlbnRyaWVzIGFyZSBleHRlbmRlZCB0bywgY2FwcGVkIHRvIHRoZSBtYXggVFRMIG9mIHRoZSBuZXR3b3JrAAAAAAAADXR0bF9leHRlbmRfdG8AAAAAAAAEAAAAXGVudHJpZXMgd2l0aCBhIFRUTCBiZWxvdyB0aGlzIGFtb3VudCBvZiBsZWRnZXJzIGFyZSBleHRlbmRlZCB3aGVuIHRoZXkgYXJlIHdyaXR0ZW4gb3IgYnVtcGVkAAAADXR0bF90aHJlc2hvbGQAAAAAAAAE",
             "AAAAAQAAAAAAAAAAAAAACFBvc2l0aW9uAAAAAQAAAAAAAAAKYmluX3NoYXJlcwAAAAAD6gAAB9AAAAAJQmluU2hhcmVzAAAA",
-            "AAAABAAAAAAAAAAAAAAABUVycm9yAAAAAAAACQAAAAAAAAALRXJyTmVnYXRpdmUAAAAAAgAAAAAAAAANRXJyTWF0aEFwcHJveAAAAAAAABIAAAAAAAAADkVyckFkZE92ZXJmbG93AAAAAAAeAAAAAAAAAA9FcnJTdWJVbmRlcmZsb3cAAAAAHwAAAAAAAAAORXJyRGl2SW50ZXJuYWwAAAAAACAAAAAAAAAADkVyck11bE92ZXJmbG93AAAAAAAhAAAAAAAAABFFcnJDUG93QmFzZVRvb0xvdwAAAAAAACIAAAAAAAAAEkVyckNQb3dCYXNlVG9vSGlnaAAAAAAAIwAAAAAAAAARRXJyTmVnYXRpdmVPclplcm8AAAAAAAAl"]), options);
+            "AAAAAQAAANpUaGUgbGltaXQgb3JkZXJzIGluIGEgYmluLgoKQWxsIG9yZGVycyBpbiBhIGJpbiBzZWxsIHRoZSBzYW1lIHRva2VuLiB4IGlmIHRoZSBiaW4gaXMgbGVmdCBvZiB0aGUgYWN0aXZlIGJpbiBhbmQgeSBpZiBpdCBpcyByaWdodCBvZiBpdC4KVGhlIG9yZGVycyBwbGFjZWQgaW4gYSBiaW4gYXJlIGZpbGxlZCB0b2dldGhlciwgYXMgb25lIGJhdGNoIHdpdGggaXRzIG93biBgZXBvY2hgLgAAAAAAAAAAAAhPcmRlckJpbgAAAAYAAAAAAAAABmJpbl9pZAAAAAAABQAAAAAAAAAFZXBvY2gAAAAAAAAEAAAAQXRoZSBhbW91bnQgb2YgdGhlIG90aGVyIHRva2VuIHJlY2VpdmVkIGZvciB0aGUgcGFydCB0aGF0IHdhcyBzb2xkAAAAAAAACHByb2NlZWRzAAAACwAAAC50aGUgcGFydCBvZiBgdG90YWxgIHRoYXQgaGFzIG5vdCBiZWVuIHNvbGQgeWV0AAAAAAAJcmVtYWluaW5nAAAAAAAACwAAAAAAAAAGc2VsbF94AAAAAAABAAAAIHN1bSBvZiB0aGUgYW1vdW50cyBvZiB0aGUgb3JkZXJzAAAABXRvdGFsAAAAAAAACw==",
+            "AAAAAQAAADtXaGF0IGlzIGxlZnQgdG8gY2xhaW0gb2YgYSBiYXRjaCBvZiBvcmRlcnMgdGhhdCB3YXMgZmlsbGVkLgAAAAAAAAAADEZpbGxlZE9yZGVycwAAAAIAAAAAAAAACHByb2NlZWRzAAAACwAAAAAAAAAFdG90YWwAAAAAAAAL",
+            "AAAAAQAAAAAAAAAAAAAACkxpbWl0T3JkZXIAAAAAAAQAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAAGYmluX2lkAAAAAAAFAAAAAAAAAAVlcG9jaAAAAAAAAAQAAAAAAAAABnNlbGxfeAAAAAAAAQ==",
+            "AAAAAQAAAS9UaGUgYW1vdW50cyBvZiB0aGUgdG9rZW5zIHRoYXQgdGhlIHBvb2wgb3dlcyB0byBMUHMgYW5kIGxpbWl0IG9yZGVycy4KClRoaXMgaXMgdGhlIHN1bSBvZiB0aGUgYmluIHJlc2VydmVzIGFuZCBvZiB0aGUgdG9rZW5zIGluIGxpbWl0IG9yZGVycywgaW5jbHVkaW5nIHRoZSBwcm9jZWVkcyB0aGF0IGhhdmUgbm90IGJlZW4gY2xhaW1lZCB5ZXQuClRva2VucyB0aGF0IGFyZSBzZW50IHRvIHRoZSBwb29sIHdpdGhvdXQgZ29pbmcgdGhyb3VnaCB0aGUgcG9vbCBhcmUgbm90IHBhcnQgb2YgaXQsIHNlZSBgc2tpbWAgYW5kIGBzeW5jYC4AAAAAAAAAAAhSZXNlcnZlcwAAAAIAAAAAAAAACXJlc2VydmVfeAAAAAAAAAsAAAAAAAAACXJlc2VydmVfeQAAAAAAAAs=",
+            "AAAAAQAAALBUaGUgc3VtcyBvZiB0aGUgcmVzZXJ2ZXMgYW5kIHNoYXJlcyBvZiBhbGwgYmlucy4KClRoZSBzdW0gb2YgdGhlIHNoYXJlcyBvZiBkaWZmZXJlbnQgYmlucyBkb2VzIG5vdCBtZWFuIG11Y2ggYnkgaXRzZWxmLCBpdCBpcyBrZXB0IHRvIGNoZWNrIHRoYXQgc2hhcmVzIGFyZSBub3QgY3JlYXRlZCBvciBsb3N0LgAAAAAAAAAJQmluVG90YWxzAAAAAAAAAwAAAAAAAAAPdG90YWxfcmVzZXJ2ZV94AAAAAAsAAAAAAAAAD3RvdGFsX3Jlc2VydmVfeQAAAAALAAAAAAAAAAx0b3RhbF9zaGFyZXMAAAAL",
+            "AAAAAgAAACtUaGUgYmlucyB0aGF0IGFuIGluY2VudGl2ZSBwYXlzIHJld2FyZHMgdG8uAAAAAAAAAAALUmV3YXJkUmFuZ2UAAAAAAgAAAAEAAABkdGhlIGJpbnMgd2l0aGluIHRoaXMgbWFueSBiaW5zIG9mIHRoZSBhY3RpdmUgYmluLCB3aGVyZXZlciB0aGUgYWN0aXZlIGJpbiBpcyB3aGVuIHRoZSByZXdhcmRzIGFjY3J1ZQAAAAZBY3RpdmUAAAAAAAEAAAAEAAAAAQAAADd0aGUgYmlucyBmcm9tIHRoZSBmaXJzdCB0byB0aGUgc2Vjb25kIGJpbiBpZCwgaW5jbHVzaXZlAAAAAARCaW5zAAAAAgAAAAUAAAAF",
+            "AAAAAQAAAUVSZXdhcmRzIG9mIGB0b2tlbmAgdGhhdCBhcmUgcGFpZCB0byB0aGUgTFBzIG9mIHRoZSBiaW5zIGluIGByYW5nZWAsIGZyb20gYHN0YXJ0YCB0byBgZW5kYC4KCmBhbW91bnRgIGlzIHBhaWQgYXQgdGhlIHNhbWUgcmF0ZSBvdmVyIHRoZSB3aG9sZSB0aW1lLCBhbmQgZGl2aWRlZCBlcXVhbGx5IG92ZXIgdGhlIGJpbnMgaW4gdGhlIHJhbmdlIHRoYXQgaGF2ZSBzaGFyZXMuClJld2FyZHMgZm9yIGEgdGltZSB3aXRob3V0IHNoYXJlcyBpbiB0aGUgcmFuZ2UgYXJlIG5vdCBwYWlkLCBhbmQgZ28gYmFjayB0byB0aGUgbWFuYWdlciB3aXRoIGByZWNsYWltX2luY2VudGl2ZWAuAAAAAAAAAAAAAAlJbmNlbnRpdmUAAAAAAAAIAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAsdGhlIHBhcnQgb2YgYGFtb3VudGAgdGhhdCB3YXMgY2xhaW1lZCBieSBMUHMAAAAHY2xhaW1lZAAAAAALAAAAEGxlZGdlciB0aW1lc3RhbXAAAAADZW5kAAAAAAYAAAA+dGhlIHJld2FyZHMgdW50aWwgdGhpcyBsZWRnZXIgdGltZXN0YW1wIHdlcmUgYWRkZWQgdG8gdGhlIGJpbnMAAAAAAAtsYXN0X3VwZGF0ZQAAAAAGAAAAAAAAAAdtYW5hZ2VyAAAAABMAAAAAAAAABXJhbmdlAAAAAAAH0AAAAAtSZXdhcmRSYW5nZQAAAAAQbGVkZ2VyIHRpbWVzdGFtcAAAAAVzdGFydAAAAAAAAAYAAAAAAAAABXRva2VuAAAAAAAAEw==",
+            "AAAAAQAAABpUaGUgcmV3YXJkcyBvZiBhIHBvc2l0aW9uLgAAAAAAAAAAAA9Qb3NpdGlvblJld2FyZHMAAAAAAgAAAGJwZXIgaW5jZW50aXZlLCB0aGUgcmV3YXJkIHBlciBzaGFyZSBvZiB0aGUgYmlucyBvZiB0aGUgcG9zaXRpb24gd2hlbiBpdHMgcmV3YXJkcyB3ZXJlIGxhc3Qgc2V0dGxlZAAAAAAAC2NoZWNrcG9pbnRzAAAAA+wAAAAEAAAD7AAAAAUAAAALAAAAQHBlciBpbmNlbnRpdmUsIHRoZSByZXdhcmRzIHRoYXQgd2VyZSBzZXR0bGVkIGJ1dCBub3QgY2xhaW1lZCB5ZXQAAAAHcGVuZGluZwAAAAPsAAAABAAAAAs=",
+            "AAAABAAAAAAAAAAAAAAABUVycm9yAAAAAAAADgAAAAAAAAALRXJyTmVnYXRpdmUAAAAAAgAAAAAAAAANRXJyTWF0aEFwcHJveAAAAAAAABIAAAAAAAAADkVyckFkZE92ZXJmbG93AAAAAAAeAAAAAAAAAA9FcnJTdWJVbmRlcmZsb3cAAAAAHwAAAAAAAAAORXJyRGl2SW50ZXJuYWwAAAAAACAAAAAAAAAADkVyck11bE92ZXJmbG93AAAAAAAhAAAAAAAAABFFcnJDUG93QmFzZVRvb0xvdwAAAAAAACIAAAAAAAAAEkVyckNQb3dCYXNlVG9vSGlnaAAAAAAAIwAAAAAAAAARRXJyTmVnYXRpdmVPclplcm8AAAAAAAAlAAAAAAAAABJFcnJCaW5JZE91dE9mUmFuZ2UAAAAAACgAAAAAAAAAEkVyclByaWNlT3V0T2ZSYW5nZQAAAAAAKQAAAAAAAAARRXJySW52YWxpZEJpblN0ZXAAAAAAAAAqAAAAAAAAABlFcnJUcmFuc2ZlckFtb3VudE1pc21hdGNoAAAAAAAAKwAAAAAAAAAPRXJySW52YWxpZFdpZHRoAAAAACw=",
+            "AAAAAQAAAIRUaGUgY2hhbmdlIG9mIG9uZSBiaW4gaW4gYSBgbGlxdWlkaXR5YCBldmVudC4KUG9zaXRpdmUgYW1vdW50cyBhbmQgc2hhcmVzIHdlcmUgYWRkZWQgdG8gdGhlIGJpbiwgbmVnYXRpdmUgb25lcyB3ZXJlIHJlbW92ZWQgZnJvbSBpdC4AAAAAAAAACUJpbkNoYW5nZQAAAAAAAAQAAAAAAAAACGFtb3VudF94AAAACwAAAAAAAAAIYW1vdW50X3kAAAALAAAAAAAAAAZiaW5faWQAAAAAAAUAAAAAAAAABnNoYXJlcwAAAAAACw==",
+            "AAAAAQAAAD1UaGUgcGFydCBvZiBhIHN3YXAgdGhhdCB3ZW50IHRocm91Z2ggdGhlIHJlc2VydmVzIG9mIG9uZSBiaW4uAAAAAAAAAAAAAAdCaW5Td2FwAAAAAAMAAAA6dGhlIGlucHV0IHRoYXQgd2FzIGFkZGVkIHRvIHRoZSByZXNlcnZlLCBpbmNsdWRpbmcgdGhlIGZlZQAAAAAACWFtb3VudF9pbgAAAAAAAAsAAAAqdGhlIG91dHB1dCB0aGF0IHdhcyB0YWtlbiBmcm9tIHRoZSByZXNlcnZlAAAAAAAKYW1vdW50X291dAAAAAAACwAAAAAAAAAGYmluX2lkAAAAAAAF",
+            "AAAAAQAAAAAAAAAAAAAACVN3YXBFdmVudAAAAAAAAAUAAAAddGhlIGFjdGl2ZSBiaW4gYWZ0ZXIgdGhlIHN3YXAAAAAAAAAKYWN0aXZlX2JpbgAAAAAABQAAAAAAAAAJYW1vdW50X2luAAAAAAAACwAAAAAAAAAKYW1vdW50X291dAAAAAAACwAAAJF0aGUgYmlucyB0aGF0IHdlcmUgc3dhcHBlZCBhZ2FpbnN0LCBpbiB0aGUgb3JkZXIgb2YgdGhlIHN3YXAuClRoZSBpbnB1dCBhbmQgb3V0cHV0IHRoYXQgYXJlIG5vdCBpbiB0aGVzZSBiaW5zIHdlbnQgdG8gYW5kIGNhbWUgZnJvbSBsaW1pdCBvcmRlcnMuAAAAAAAABGJpbnMAAAPqAAAH0AAAAAdCaW5Td2FwAAAAAAAAAAAHaXNfeF9pbgAAAAAB"]), options);
         this.options = options;
     }
     fromJSON = {
-        upgrade: (this.txFromJSON),
         modify_liquidity: (this.txFromJSON),
+        modify_liquidity_to: (this.txFromJSON),
+        remove_position_pct: (this.txFromJSON),
+        rebalance_position: (this.txFromJSON),
+        batch_transfer: (this.txFromJSON),
+        batch_transfer_from: (this.txFromJSON),
+        set_approval_for_all: (this.txFromJSON),
         swap_exact_amount_in: (this.txFromJSON),
+        swap_exact_amount_in_to: (this.txFromJSON),
+        quote_exact_amount_in: (this.txFromJSON),
+        flash_swap: (this.txFromJSON),
+        flash_loan: (this.txFromJSON),
+        place_limit_order: (this.txFromJSON),
+        cancel_limit_order: (this.txFromJSON),
+        claim_limit_order: (this.txFromJSON),
+        add_incentive: (this.txFromJSON),
+        claim_rewards: (this.txFromJSON),
+        reclaim_incentive: (this.txFromJSON),
+        skim: (this.txFromJSON),
+        sync: (this.txFromJSON),
+        bump: (this.txFromJSON),
+        get_bin: (this.txFromJSON),
+        get_bins_range: (this.txFromJSON),
+        get_active_bin_reserves: (this.txFromJSON),
+        get_price_from_id: (this.txFromJSON),
+        get_id_from_price: (this.txFromJSON),
         get_bin_vec: (this.txFromJSON),
         get_shares_vec: (this.txFromJSON),
         get_position: (this.txFromJSON),
+        get_position_ids: (this.txFromJSON),
+        balance_of: (this.txFromJSON),
+        balance_of_batch: (this.txFromJSON),
+        is_approved_for_all: (this.txFromJSON),
+        get_incentive: (this.txFromJSON),
+        get_incentives: (this.txFromJSON),
+        get_pending_rewards: (this.txFromJSON),
+        get_limit_order: (this.txFromJSON),
+        get_order_bin: (this.txFromJSON),
+        get_human_price_from_id: (this.txFromJSON),
+        get_id_from_human_price: (this.txFromJSON),
+        get_supported_bins: (this.txFromJSON),
+        get_reserves: (this.txFromJSON),
+        get_bin_totals: (this.txFromJSON),
         get_config: (this.txFromJSON)
     };
 }
@@ -33,7 +33,22 @@ if (typeof window !== 'undefined') {
 
 
 
-export type DataKey = {tag: "Config", values: void} | {tag: "BinVec", values: readonly [i32]} | {tag: "BinShareVec", values: readonly [i32]} | {tag: "Position", values: readonly [string, i32]};
+export type DataKey = {tag: "Config", values: void} | {tag: "BinVec", values: readonly [i32]} | {tag: "BinShareVec", values: readonly [i32]} | {tag: "Position", values: readonly [string, i32]} | {tag: "OrderBins", values: readonly [i32]} | {tag: "OrderEpoch", values: void} | {tag: "FilledOrders", values: readonly [u32]} | {tag: "LimitOrder", values: readonly [string, i32]} | {tag: "Reserves", values: void} | {tag: "BinTotals", values: void} | {tag: "Incentives", values: void} | {tag: "IncentiveCount", values: void} | {tag: "Incentive", values: readonly [u32]} | {tag: "RewardPerShare", values: readonly [u32, i32]} | {tag: "RewardVecIds", values: readonly [u32]} | {tag: "PositionRewards", values: readonly [string, i32]} | {tag: "PositionIds", values: readonly [string]} | {tag: "Operator", values: readonly [string, string]} | {tag: "VecBitmap", values: readonly [VecIndex, u32, u32]};
+
+
+/**
+ * The bitmaps of vecs in storage, see `bitmap.rs`.
+ */
+export enum VecIndex {
+  /**
+   * The vecs that have liquidity.
+   */
+  Bins = 0,
+  /**
+   * The vecs that have limit orders.
+   */
+  Orders = 1,
+}
 
 
 /**
@@ -45,6 +60,21 @@ export interface BinShares {
 }
 
 
+/**
+ * A bin with its total shares and price, as returned by the bin getters.
+ */
+export interface BinInfo {
+  bin_id: i32;
+  /**
+   * the price of y in x, in 128.128 fixed point
+   */
+  price: u256;
+  reserve_x: i128;
+  reserve_y: i128;
+  shares: i128;
+}
+
+
 /**
  * stored as vec[reserve_x|reserve_y] if not active bin
  * otherwise if active_bin, it is stored as vec[reserve_x, reserve_y]
@@ -68,12 +98,55 @@ export interface DepositArgs {
 }
 
 
+/**
+ * How liquidity is spread over the bins around the active bin, for example when rebalancing a position.
+ */
+export enum LiquidityShape {
+  /**
+   * The same weight in every bin.
+   */
+  Spot = 0,
+  /**
+   * Most of the liquidity around the active bin, decreasing towards the edges.
+   */
+  Curve = 1,
+  /**
+   * Most of the liquidity at the edges, decreasing towards the active bin.
+   */
+  BidAsk = 2,
+}
+
+
 export interface Config {
   active_bin: i32;
+  /**
+   * may add incentives
+   */
+  admin: string;
   bin_step: u32;
+  /**
+   * the decimals of token x, read from the token when the pool is created
+   */
+  decimals_x: u32;
+  /**
+   * the decimals of token y, read from the token when the pool is created
+   */
+  decimals_y: u32;
   fee: u32;
+  /**
+   * fee charged on flash loans in bps
+   */
+  flash_loan_fee: u32;
   token_x: string;
   token_y: string;
+  /**
+   * the TTL in ledgers that entries are extended to, capped to the max TTL of the network
+   */
+  ttl_extend_to: u32;
+  /**
+   * entries with a TTL below this amount of ledgers are extended when they are written or bumped
+   */
+  ttl_threshold: u32;
 }
 
 
@@ -81,6 +154,123 @@ export interface Position {
   bin_shares: Array<BinShares>;
 }
 
+
+/**
+ * The limit orders in a bin.
+ * 
+ * All orders in a bin sell the same token. x if the bin is left of the active bin and y if it is right of it.
+ * The orders placed in a bin are filled together, as one batch with its own `epoch`.
+ */
+export interface OrderBin {
+  bin_id: i32;
+  epoch: u32;
+  /**
+   * the amount of the other token received for the part that was sold
+   */
+  proceeds: i128;
+  /**
+   * the part of `total` that has not been sold yet
+   */
+  remaining: i128;
+  sell_x: boolean;
+  /**
+   * sum of the amounts of the orders
+   */
+  total: i128;
+}
+
+
+/**
+ * What is left to claim of a batch of orders that was filled.
+ */
+export interface FilledOrders {
+  proceeds: i128;
+  total: i128;
+}
+
+
+export interface LimitOrder {
+  amount: i128;
+  bin_id: i32;
+  epoch: u32;
+  sell_x: boolean;
+}
+
+
+/**
+ * The amounts of the tokens that the pool owes to LPs and limit orders.
+ * 
+ * This is the sum of the bin reserves and of the tokens in limit orders, including the proceeds that have not been claimed yet.
+ * Tokens that are sent to the pool without going through the pool are not part of it, see `skim` and `sync`.
+ */
+export interface Reserves {
+  reserve_x: i128;
+  reserve_y: i128;
+}
+
+
+/**
+ * The sums of the reserves and shares of all bins.
+ * 
+ * The sum of the shares of different bins does not mean much by itself, it is kept to check that shares are not created or lost.
+ */
+export interface BinTotals {
+  total_reserve_x: i128;
+  total_reserve_y: i128;
+  total_shares: i128;
+}
+
+
+/**
+ * The bins that an incentive pays rewards to.
+ */
+export type RewardRange = {tag: "Active", values: readonly [u32]} | {tag: "Bins", values: readonly [i32, i32]};
+
+
+/**
+ * Rewards of `token` that are paid to the LPs of the bins in `range`, from `start` to `end`.
+ * 
+ * `amount` is paid at the same rate over the whole time, and divided equally over the bins in the range that have shares.
+ * Rewards for a time without shares in the range are not paid, and go back to the manager with `reclaim_incentive`.
+ */
+export interface Incentive {
+  amount: i128;
+  /**
+   * the part of `amount` that was claimed by LPs
+   */
+  claimed: i128;
+  /**
+   * ledger timestamp
+   */
+  end: u64;
+  /**
+   * the rewards until this ledger timestamp were added to the bins
+   */
+  last_update: u64;
+  manager: string;
+  range: RewardRange;
+  /**
+   * ledger timestamp
+   */
+  start: u64;
+  token: string;
+}
+
+
+/**
+ * The rewards of a position.
+ */
+export interface PositionRewards {
+  /**
+   * per incentive, the reward per share of the bins of the position when its rewards were last settled
+   */
+  checkpoints: Map<u32, Map<i32, i128>>;
+  /**
+   * per incentive, the rewards that were settled but not claimed yet
+   */
+  pending: Map<u32, i128>;
+}
+
 export const Errors = {
   2: {message:"ErrNegative"},
 
@@ -98,9 +288,63 @@ export const Errors = {
 
   35: {message:"ErrCPowBaseTooHigh"},
 
-  37: {message:"ErrNegativeOrZero"}
+  37: {message:"ErrNegativeOrZero"},
+
+  40: {message:"ErrBinIdOutOfRange"},
+
+  41: {message:"ErrPriceOutOfRange"},
+
+  42: {message:"ErrInvalidBinStep"},
+
+  43: {message:"ErrTransferAmountMismatch"},
+
+  44: {message:"ErrInvalidWidth"}
+}
+
+/**
+ * The change of one bin in a `liquidity` event.
+ * Positive amounts and shares were added to the bin, negative ones were removed from it.
+ */
+export interface BinChange {
+  amount_x: i128;
+  amount_y: i128;
+  bin_id: i32;
+  shares: i128;
+}
+
+
+/**
+ * The part of a swap that went through the reserves of one bin.
+ */
+export interface BinSwap {
+  /**
+   * the input that was added to the reserve, including the fee
+   */
+  amount_in: i128;
+  /**
+   * the output that was taken from the reserve
+   */
+  amount_out: i128;
+  bin_id: i32;
+}
+
+
+export interface SwapEvent {
+  /**
+   * the active bin after the swap
+   */
+  active_bin: i32;
+  amount_in: i128;
+  amount_out: i128;
+  /**
+   * the bins that were swapped against, in the order of the swap.
+   * The input and output that are not in these bins went to and came from limit orders.
+   */
+  bins: Array<BinSwap>;
+  is_x_in: boolean;
 }
 
+
 export interface Client {
   /**
    * Construct and simulate a modify_liquidity transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
@@ -133,9 +377,10 @@ export interface Client {
   }) => Promise<AssembledTransaction<readonly [i128, i128]>>
 
   /**
-   * Construct and simulate a swap_exact_amount_in transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a modify_liquidity_to transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Like `modify_liquidity`, but the withdrawn amounts are sent to `to`. Deposits are still paid by `from`.
    */
-  swap_exact_amount_in: ({from, amount_in, min_amount_out, in_token}: {from: string, amount_in: i128, min_amount_out: i128, in_token: string}, options?: {
+  modify_liquidity_to: ({from, to, position_id, args, offset_from_active}: {from: string, to: string, position_id: i32, args: Array<DepositArgs>, offset_from_active: boolean}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -150,17 +395,19 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<i128>>
+  }) => Promise<AssembledTransaction<readonly [i128, i128]>>
 
   /**
-   * Construct and simulate a get_bin_vec transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Bins are grouped together in a `BinVec` of size `BIN_VEC_SIZE`
+   * Construct and simulate a remove_position_pct transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Allows `owner` to withdraw a percentage of every bin in a position.
+   * `bps` is the percentage of the shares to burn in basis points, so `10_000` withdraws everything.
    * 
-   * the `get_vec_id_for_bin` can be used to convert a bin_id to a vec_id
+   * The shares to burn are rounded down per bin.
+   * When all of the shares are burned, the position is deleted.
    * 
-   * This function will return the group of bins specified by the `vec_id`
+   * returns a pair with the amounts withdrawn: (x_token_amount, y_token_amount)
    */
-  get_bin_vec: ({vec_id}: {vec_id: i32}, options?: {
+  remove_position_pct: ({owner, position_id, bps, min_x, min_y}: {owner: string, position_id: i32, bps: u32, min_x: i128, min_y: i128}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -175,18 +422,25 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<Array<Bin>>>
+  }) => Promise<AssembledTransaction<readonly [i128, i128]>>
 
   /**
-   * Construct and simulate a get_shares_vec transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Each Bin is treated as a separate "vault" and has its own shares.
+   * Construct and simulate a rebalance_position transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Allows `owner` to move all the liquidity of a position to the bins around the current active bin.
    * 
+   * All shares of the position are withdrawn and redeposited in the bins `[active_bin - width, active_bin + width]`, spread according to `shape`.
+   * Fails with `ErrInvalidWidth` if `width` is more than `MAX_WIDTH`. Bins of the range outside of the supported bins are skipped.
+   * If `swap_imbalance` is set, the withdrawn tokens are first swapped through this pool, so that the value of x and y is about equal.
+   * The swap may move the active bin, the liquidity is centered on the active bin after the swap.
+   * The swap is skipped when the other liquidity of the pool can't take it, for example when the position is the only liquidity.
    * 
-   * the `get_vec_id_for_bin` can be used to convert a bin_id to a vec_id
+   * Tokens that could not be redeposited, because of rounding or the distribution of the active bin, are sent back to `owner`.
    * 
-   * This function will return the group of bin shares specified by the `vec_id`
+   * `min_x` and `min_y` are the minimum amounts that must be redeposited.
+   * 
+   * returns a pair with the amounts redeposited: (x_token_amount, y_token_amount)
    */
-  get_shares_vec: ({vec_id}: {vec_id: i32}, options?: {
+  rebalance_position: ({owner, position_id, width, shape, swap_imbalance, min_x, min_y}: {owner: string, position_id: i32, width: u32, shape: LiquidityShape, swap_imbalance: boolean, min_x: i128, min_y: i128}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -201,15 +455,16 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<Array<BinShares>>>
+  }) => Promise<AssembledTransaction<readonly [i128, i128]>>
 
   /**
-   * Construct and simulate a get_position transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * returns the `Position` for a given position id for a user
+   * Construct and simulate a batch_transfer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Moves `shares` of the position `position_id` of `from` to the position with the same id of `to`, which is created if not exists.
    * 
-   * Each user can have multiple positions.
+   * The shares of each bin work like a multi token, where the bin id is the token id. Only the owner of the shares moves, the bins stay the same.
+   * The rewards that the shares earned until now stay with `from`.
    */
-  get_position: ({from, position_id}: {from: string, position_id: i32}, options?: {
+  batch_transfer: ({from, to, position_id, shares}: {from: string, to: string, position_id: i32, shares: Array<BinShares>}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -224,7 +479,897 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<Option<Position>>>
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a batch_transfer_from transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Like `batch_transfer`, but authorized by `operator`, which must be approved by `from` with `set_approval_for_all`.
+   */
+  batch_transfer_from: ({operator, from, to, position_id, shares}: {operator: string, from: string, to: string, position_id: i32, shares: Array<BinShares>}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a set_approval_for_all transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Allows or disallows `operator` to transfer all shares of all positions of `owner`.
+   */
+  set_approval_for_all: ({owner, operator, approved}: {owner: string, operator: string, approved: boolean}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a swap_exact_amount_in transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  swap_exact_amount_in: ({from, amount_in, min_amount_out, in_token}: {from: string, amount_in: i128, min_amount_out: i128, in_token: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<i128>>
+
+  /**
+   * Construct and simulate a swap_exact_amount_in_to transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Like `swap_exact_amount_in`, but the output is sent to `to`, for example to pay someone in the other token.
+   */
+  swap_exact_amount_in_to: ({from, to, amount_in, min_amount_out, in_token}: {from: string, to: string, amount_in: i128, min_amount_out: i128, in_token: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<i128>>
+
+  /**
+   * Construct and simulate a quote_exact_amount_in transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * returns what `swap_exact_amount_in` would send for `amount_in` of `in_token`, without changing the pool.
+   * 
+   * Panics with "Not enough liquidity" like the swap. A fee on the transfer of `in_token` is not taken into account.
+   */
+  quote_exact_amount_in: ({amount_in, in_token}: {amount_in: i128, in_token: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<i128>>
+
+  /**
+   * Construct and simulate a flash_swap transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Swaps `amount_in` of `in_token` like `swap_exact_amount_in`, but the output is sent to `receiver` before the input is paid.
+   * 
+   * After the output is transferred, `on_flash_swap` of the `FlashSwapReceiver` interface is invoked on `receiver` with `data`.
+   * Before the callback returns, `receiver` must transfer `amount_in` of `in_token` to the pool, for example with the proceeds of
+   * selling the output somewhere else.
+   * 
+   * returns the amount of the other token sent to `receiver`
+   */
+  flash_swap: ({receiver, amount_in, min_amount_out, in_token, data}: {receiver: string, amount_in: i128, min_amount_out: i128, in_token: string, data: Buffer}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<i128>>
+
+  /**
+   * Construct and simulate a flash_loan transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Lends `amount` of `token` to `receiver` for the duration of this call.
+   * 
+   * The tokens are transferred to `receiver` and `on_flash_loan` of the `FlashLoanReceiver` interface is invoked with `data`.
+   * Before the callback returns, `receiver` must transfer the amount plus the fee back to the pool.
+   * The fee is `flash_loan_fee` bps of `amount`, rounded up, and is added to the reserves of the active bin.
+   * So a loan with a fee needs liquidity in the active bin.
+   * 
+   * The host does not allow a contract to be re-entered, so the callback can't call back into the pool
+   * (e.g. `swap_exact_amount_in` or `modify_liquidity`) while the loan is outstanding.
+   * 
+   * returns the fee that was paid
+   */
+  flash_loan: ({receiver, token, amount, data}: {receiver: string, token: string, amount: i128, data: Buffer}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<i128>>
+
+  /**
+   * Construct and simulate a place_limit_order transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Allows `owner` to place a limit order of `amount` in `bin_id`.
+   * 
+   * If the bin is left of the active bin, the order sells x for y, otherwise it sells y for x.
+   * Orders are tracked separately from the liquidity of the LPs. Swaps use the orders after the liquidity of the bin,
+   * and once all orders in the bin are sold, they can be claimed with `claim_limit_order`.
+   * Contrary to liquidity, the proceeds of a filled order can't be swapped back.
+   * 
+   * Orders can't be placed in the active bin, or in a bin where the orders are partially filled.
+   */
+  place_limit_order: ({owner, order_id, bin_id, amount}: {owner: string, order_id: i32, bin_id: i32, amount: i128}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a cancel_limit_order transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Allows `owner` to cancel an order that is not filled yet.
+   * 
+   * If the order is partially filled, `owner` receives their part of what is left and of what was received.
+   * 
+   * returns a pair with the amounts sent to `owner`: (x_token_amount, y_token_amount)
+   */
+  cancel_limit_order: ({owner, order_id}: {owner: string, order_id: i32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<readonly [i128, i128]>>
+
+  /**
+   * Construct and simulate a claim_limit_order transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Allows `owner` to claim the proceeds of a filled order.
+   * 
+   * returns the amount of the bought token sent to `owner`
+   */
+  claim_limit_order: ({owner, order_id}: {owner: string, order_id: i32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<i128>>
+
+  /**
+   * Construct and simulate a add_incentive transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Adds an incentive that pays `amount` of `token` to the LPs of the bins in `range`, at the same rate from `start` to `end`.
+   * 
+   * Only the admin of the pool can add incentives, `manager` pays them and can reclaim what is left.
+   * 
+   * `start` and `end` are ledger timestamps, a `start` in the past starts now, and `end` is at most `MAX_INCENTIVE_DURATION` from now.
+   * The rewards of every moment are divided equally over the bins in the range that have shares, and within a bin by shares.
+   * LPs claim them per position and incentive with `claim_rewards`. The tokens of the pool can't be rewards, as they would be taken by `skim`.
+   * 
+   * The pool has at most `MAX_INCENTIVES` incentives, an incentive counts until it is reclaimed with `reclaim_incentive`.
+   * 
+   * returns the id of the incentive
+   */
+  add_incentive: ({manager, token, amount, start, end, range}: {manager: string, token: string, amount: i128, start: u64, end: u64, range: RewardRange}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<u32>>
+
+  /**
+   * Construct and simulate a claim_rewards transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Allows `owner` to claim the rewards that position `position_id` earned from incentive `incentive_id`.
+   * 
+   * Every incentive is claimed on its own, so a reward token that can't be transferred doesn't block the others.
+   * Rewards stay claimable after the shares are removed, until `REWARD_CLAIM_PERIOD` after the end of their incentive.
+   * 
+   * returns the amount sent to `owner`
+   */
+  claim_rewards: ({owner, position_id, incentive_id}: {owner: string, position_id: i32, incentive_id: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<i128>>
+
+  /**
+   * Construct and simulate a reclaim_incentive transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Allows the manager of incentive `id` to take back what was not claimed, once `REWARD_CLAIM_PERIOD` passed after its end.
+   * 
+   * This includes the rewards of the times that no bin in the range had shares.
+   * The incentive is removed, so its rewards that were not claimed can't be claimed anymore.
+   * 
+   * returns the amount sent to the manager
+   */
+  reclaim_incentive: ({id}: {id: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<i128>>
+
+  /**
+   * Construct and simulate a skim transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Sends the tokens that the pool holds on top of its reserves to `to`.
+   * 
+   * These are tokens that were transferred to the pool directly, or that were added by a token that rebases up.
+   * Anyone can call this, so tokens that are sent to the pool by accident can be taken by anyone.
+   * 
+   * returns a pair with the amounts sent: (x_token_amount, y_token_amount)
+   */
+  skim: ({to}: {to: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<readonly [i128, i128]>>
+
+  /**
+   * Construct and simulate a sync transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Adds the tokens that the pool holds on top of its reserves to the reserves of the active bin, so they go to the LPs of that bin.
+   * 
+   * Like `skim`, this is for tokens that were transferred directly or that rebased up. If a token rebased down or was clawed back,
+   * the balance is below the reserves. The reserves are then lowered to the balance and the shortfall is taken pro rata from the bins nearest to the active bin, see `take_from_bins`.
+   * Fails if those bins can't cover the shortfall, the tokens of the limit orders are never taken.
+   * 
+   * returns a pair with the amounts added: (x_token_amount, y_token_amount)
+   */
+  sync: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<readonly [i128, i128]>>
+
+  /**
+   * Construct and simulate a bump transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Extends the TTL of the bins in `bins` with their limit orders, the positions in `positions` with their rewards and the position ids of their owners,
+   * the limit orders in `orders` with their filled orders, the approvals of the (owner, operator) pairs in `operators`, the incentives and of the contract instance.
+   * 
+   * Bins, positions, orders and approvals are also extended when they are written, this allows anyone to keep idle pools, positions, open orders
+   * and approvals from being archived.
+   * Incentives are only written until their end, so they need to be bumped until they are reclaimed.
+   * Entries that don't exist are skipped. The thresholds come from `ttl_threshold` and `ttl_extend_to` in the config.
+   */
+  bump: ({bins, positions, orders, operators}: {bins: Array<i32>, positions: Array<readonly [string, i32]>, orders: Array<readonly [string, i32]>, operators: Array<readonly [string, string]>}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a get_bin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * returns the bin with id `bin_id`
+   */
+  get_bin: ({bin_id}: {bin_id: i32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Bin>>
+
+  /**
+   * Construct and simulate a get_bins_range transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * returns the bins from `from_bin` up to and including `to_bin`, with their total shares and price.
+   * 
+   * The range may span multiple vecs, but can't be larger than `MAX_BINS_IN_RANGE` bins and must be within the supported bins.
+   */
+  get_bins_range: ({from_bin, to_bin}: {from_bin: i32, to_bin: i32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Array<BinInfo>>>
+
+  /**
+   * Construct and simulate a get_active_bin_reserves transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * returns the active bin with its total shares and price
+   */
+  get_active_bin_reserves: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<BinInfo>>
+
+  /**
+   * Construct and simulate a get_price_from_id transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * returns the amount of the other token that you get for 1 `in_token` in bin `bin_id`, before fees and in 128.128 fixed point
+   * 
+   * Fails with `ErrBinIdOutOfRange` if the bin is outside of the supported bins.
+   */
+  get_price_from_id: ({bin_id, in_token}: {bin_id: i32, in_token: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<u256>>
+
+  /**
+   * Construct and simulate a get_id_from_price transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * returns the id of the bin for `price`, the price of y in x in 128.128 fixed point.
+   * 
+   * Prices between two bins are rounded down to the lower bin, see `price::id_from_price`.
+   * Fails with `ErrPriceOutOfRange` if the price is outside of the supported bins.
+   */
+  get_id_from_price: ({price}: {price: u256}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<i32>>
+
+  /**
+   * Construct and simulate a get_bin_vec transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Bins are grouped together in a `BinVec` of size `BIN_VEC_SIZE`
+   * 
+   * the `get_vec_id_for_bin` can be used to convert a bin_id to a vec_id
+   * 
+   * This function will return the group of bins specified by the `vec_id`
+   */
+  get_bin_vec: ({vec_id}: {vec_id: i32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Array<Bin>>>
+
+  /**
+   * Construct and simulate a get_shares_vec transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Each Bin is treated as a separate "vault" and has its own shares.
+   * 
+   * 
+   * the `get_vec_id_for_bin` can be used to convert a bin_id to a vec_id
+   * 
+   * This function will return the group of bin shares specified by the `vec_id`
+   */
+  get_shares_vec: ({vec_id}: {vec_id: i32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Array<BinShares>>>
+
+  /**
+   * Construct and simulate a get_position transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * returns the `Position` for a given position id for a user
+   * 
+   * Each user can have multiple positions.
+   */
+  get_position: ({from, position_id}: {from: string, position_id: i32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Option<Position>>>
+
+  /**
+   * Construct and simulate a get_position_ids transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * returns the ids of the positions of `owner`
+   */
+  get_position_ids: ({owner}: {owner: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Array<i32>>>
+
+  /**
+   * Construct and simulate a balance_of transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * returns the shares of `owner` in the bin `bin_id`, summed over all positions of `owner`
+   */
+  balance_of: ({owner, bin_id}: {owner: string, bin_id: i32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<i128>>
+
+  /**
+   * Construct and simulate a balance_of_batch transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * returns `balance_of` for each pair of `owners` and `bin_ids`, which must have the same length
+   */
+  balance_of_batch: ({owners, bin_ids}: {owners: Array<string>, bin_ids: Array<i32>}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Array<i128>>>
+
+  /**
+   * Construct and simulate a is_approved_for_all transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * returns whether `operator` may transfer the shares of `owner`
+   */
+  is_approved_for_all: ({owner, operator}: {owner: string, operator: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<boolean>>
+
+  /**
+   * Construct and simulate a get_incentive transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * returns the incentive with id `id`, if it was not reclaimed
+   */
+  get_incentive: ({id}: {id: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Option<Incentive>>>
+
+  /**
+   * Construct and simulate a get_incentives transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * returns the ids of the incentives that were not reclaimed
+   */
+  get_incentives: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Array<u32>>>
+
+  /**
+   * Construct and simulate a get_pending_rewards transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * returns what `claim_rewards` would send for the position now, per incentive: vec[(incentive_id, amount)]
+   */
+  get_pending_rewards: ({owner, position_id}: {owner: string, position_id: i32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Array<readonly [u32, i128]>>>
+
+  /**
+   * Construct and simulate a get_limit_order transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * returns the limit order for a given order id for a user
+   */
+  get_limit_order: ({owner, order_id}: {owner: string, order_id: i32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Option<LimitOrder>>>
+
+  /**
+   * Construct and simulate a get_order_bin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * returns the unfilled limit orders in a bin
+   */
+  get_order_bin: ({bin_id}: {bin_id: i32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Option<OrderBin>>>
+
+  /**
+   * Construct and simulate a get_human_price_from_id transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * returns the human price in bin `bin_id`: the amount of whole x tokens that 1 whole y token is worth, in 128.128 fixed point.
+   * 
+   * Contrary to `get_price_from_id`, this takes the decimals of the tokens into account.
+   * Fails with `ErrPriceOutOfRange` if the human price does not fit in 256 bits, which happens in the top bins when y has many more decimals than x.
+   */
+  get_human_price_from_id: ({bin_id}: {bin_id: i32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<u256>>
+
+  /**
+   * Construct and simulate a get_id_from_human_price transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * returns the id of the bin for a human price: the amount of whole x tokens that 1 whole y token is worth, in 128.128 fixed point.
+   * 
+   * Prices between two bins are rounded down to the lower bin. Use `price::id_from_human_price` to pick the active bin of a new pool.
+   */
+  get_id_from_human_price: ({human_price}: {human_price: u256}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<i32>>
+
+  /**
+   * Construct and simulate a get_supported_bins transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * returns the lowest and highest bin id that are supported for the bin step of the pool.
+   * 
+   * Liquidity and limit orders can only be placed in these bins, see `price::get_max_bin_id`.
+   */
+  get_supported_bins: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<readonly [i32, i32]>>
+
+  /**
+   * Construct and simulate a get_reserves transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * returns the amounts of the tokens that the pool owes to LPs and limit orders, see `skim` and `sync` for tokens that are not part of it.
+   */
+  get_reserves: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Reserves>>
+
+  /**
+   * Construct and simulate a get_bin_totals transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * returns the sums of the reserves and shares of all bins. These are also part of `get_reserves`, which includes the limit orders.
+   */
+  get_bin_totals: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<BinTotals>>
 
   /**
    * Construct and simulate a get_config transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
@@ -266,29 +1411,119 @@ export class Client extends ContractClient {
   }
   constructor(public readonly options: ContractClientOptions) {
     super(
-      new ContractSpec([ "AAAAAAAAAAAAAAANX19jb25zdHJ1Y3RvcgAAAAAAAAEAAAAAAAAABGNvbmYAAAfQAAAABkNvbmZpZwAAAAAAAA==",
+      new ContractSpec([ "AAAAAAAAAGVDcmVhdGVzIHRoZSBwb29sLiBgZGVjaW1hbHNfeGAgYW5kIGBkZWNpbWFsc195YCBvZiBgY29uZmAgYXJlIGlnbm9yZWQsIHRoZXkgYXJlIHJlYWQgZnJvbSB0aGUgdG9rZW5zLgAAAAAAAA1fX2NvbnN0cnVjdG9yAAAAAAAAAQAAAAAAAAAEY29uZgAAB9AAAAAGQ29uZmlnAAAAAAAA",
         "AAAAAAAAAlBBbGxvd3MgYGZyb21gIHRvIGNyZWF0ZSBvciBtb2RpZnkgYW4gZXhpc3RpbmcgcG9zaXRpb24uCmBvZmZzZXRfZnJvbV9hY3RpdmVgIHNwZWNpZmllcyBpZiB0aGUgYGJpbl9pZF9vcl9vZmZzZXRgIHBhcmFtIG9mIGBEZXBvc2l0QXJnc2AgaXMgYSBwb2ludGVyIHRvIHRoZSBiaW4gb3IgaWYgaXQgaXMgb2Zmc2V0IGZyb20gdGhlIGN1cnJlbnQgYWN0aXZlIGJpbi4KCk5PVEU6IGBhcmdzYCBtdXN0IGJlIG9yZGVyZWQgYnkgYmluX2lkIGFzY2VuZGluZyBvcmRlcgoKVGhlIHBvc2l0aW9uIHNwZWNpZmllZCBieSBgcG9zaXRpb25faWRgIHdpbCBiZSBtb2RpZmllZCBvciBjcmVhdGVkIGlmIG5vdCBleGlzdHMuCkFuIGFjY291bnQgY2FuIGhhdmUgbXVsdGlwbGUgcG9zaXRpb25zLgoKcmV0dXJucyBhIHBhaXIgd2l0aCB0aGUgYW1vdW50cyBkZXBvc2l0ZWQgcmVtb3ZlZCBvciBhZGRlZDogKHhfdG9rZW5fYW1vdW50LCB5X3Rva2VuX2Ftb3VudCkKYSBwb3NpdGl2ZSBudW1iZXIgbWVhbnMgdGhhdCB3ZSBkZXBvc2l0ZWQgdGhhdCBhbW91bnQgYW5kIGEgbmVnYXRpdmUgbnVtYmVyIG1lYW5zIHRoYXQgd2Ugd2l0aGRyZXcgdGhhdCBhbW91bnQuAAAAEG1vZGlmeV9saXF1aWRpdHkAAAAEAAAAAAAAAARmcm9tAAAAEwAAAAAAAAALcG9zaXRpb25faWQAAAAABQAAAAAAAAAEYXJncwAAA+oAAAfQAAAAC0RlcG9zaXRBcmdzAAAAAAAAAAASb2Zmc2V0X2Zyb21fYWN0aXZlAAAAAAABAAAAAQAAA+0AAAACAAAACwAAAAs=",
+        "AAAAAAAAAGdMaWtlIGBtb2RpZnlfbGlxdWlkaXR5YCwgYnV0IHRoZSB3aXRoZHJhd24gYW1vdW50cyBhcmUgc2VudCB0byBgdG9gLiBEZXBvc2l0cyBhcmUgc3RpbGwgcGFpZCBieSBgZnJvbWAuAAAAABNtb2RpZnlfbGlxdWlkaXR5X3RvAAAAAAUAAAAAAAAABGZyb20AAAATAAAAAAAAAAJ0bwAAAAAAEwAAAAAAAAALcG9zaXRpb25faWQAAAAABQAAAAAAAAAEYXJncwAAA+oAAAfQAAAAC0RlcG9zaXRBcmdzAAAAAAAAAAASb2Zmc2V0X2Zyb21fYWN0aXZlAAAAAAABAAAAAQAAA+0AAAACAAAACwAAAAs=",
+        "AAAAAAAAAVtBbGxvd3MgYG93bmVyYCB0byB3aXRoZHJhdyBhIHBlcmNlbnRhZ2Ugb2YgZXZlcnkgYmluIGluIGEgcG9zaXRpb24uCmBicHNgIGlzIHRoZSBwZXJjZW50YWdlIG9mIHRoZSBzaGFyZXMgdG8gYnVybiBpbiBiYXNpcyBwb2ludHMsIHNvIGAxMF8wMDBgIHdpdGhkcmF3cyBldmVyeXRoaW5nLgoKVGhlIHNoYXJlcyB0byBidXJuIGFyZSByb3VuZGVkIGRvd24gcGVyIGJpbi4KV2hlbiBhbGwgb2YgdGhlIHNoYXJlcyBhcmUgYnVybmVkLCB0aGUgcG9zaXRpb24gaXMgZGVsZXRlZC4KCnJldHVybnMgYSBwYWlyIHdpdGggdGhlIGFtb3VudHMgd2l0aGRyYXduOiAoeF90b2tlbl9hbW91bnQsIHlfdG9rZW5fYW1vdW50KQAAAAATcmVtb3ZlX3Bvc2l0aW9uX3BjdAAAAAAFAAAAAAAAAAVvd25lcgAAAAAAABMAAAAAAAAAC3Bvc2l0aW9uX2lkAAAAAAUAAAAAAAAAA2JwcwAAAAAEAAAAAAAAAAVtaW5feAAAAAAAAAsAAAAAAAAABW1pbl95AAAAAAAACwAAAAEAAAPtAAAAAgAAAAsAAAAL",
+        "AAAAAAAAA99BbGxvd3MgYG93bmVyYCB0byBtb3ZlIGFsbCB0aGUgbGlxdWlkaXR5IG9mIGEgcG9zaXRpb24gdG8gdGhlIGJpbnMgYXJvdW5kIHRoZSBjdXJyZW50IGFjdGl2ZSBiaW4uCgpBbGwgc2hhcmVzIG9mIHRoZSBwb3NpdGlvbiBhcmUgd2l0aGRyYXduIGFuZCByZWRlcG9zaXRlZCBpbiB0aGUgYmlucyBgW2FjdGl2ZV9iaW4gLSB3aWR0aCwgYWN0aXZlX2JpbiArIHdpZHRoXWAsIHNwcmVhZCBhY2NvcmRpbmcgdG8gYHNoYXBlYC4KRmFpbHMgd2l0aCBgRXJySW52YWxpZFdpZHRoYCBpZiBgd2lkdGhgIGlzIG1vcmUgdGhhbiBgTUFYX1dJRFRIYC4gQmlucyBvZiB0aGUgcmFuZ2Ugb3V0c2lkZSBvZiB0aGUgc3VwcG9ydGVkIGJpbnMgYXJlIHNraXBwZWQuCklmIGBzd2FwX2ltYmFsYW5jZWAgaXMgc2V0LCB0aGUgd2l0aGRyYXduIHRva2VucyBhcmUgZmlyc3Qgc3dhcHBlZCB0aHJvdWdoIHRoaXMgcG9vbCwgc28gdGhhdCB0aGUgdmFsdWUgb2YgeCBhbmQgeSBpcyBhYm91dCBlcXVhbC4KVGhlIHN3YXAgbWF5IG1vdmUgdGhlIGFjdGl2ZSBiaW4sIHRoZSBsaXF1aWRpdHkgaXMgY2VudGVyZWQgb24gdGhlIGFjdGl2ZSBiaW4gYWZ0ZXIgdGhlIHN3YXAuClRoZSBzd2FwIGlzIHNraXBwZWQgd2hlbiB0aGUgb3RoZXIgbGlxdWlkaXR5IG9mIHRoZSBwb29sIGNhbid0IHRha2UgaXQsIGZvciBleGFtcGxlIHdoZW4gdGhlIHBvc2l0aW9uIGlzIHRoZSBvbmx5IGxpcXVpZGl0eS4KClRva2VucyB0aGF0IGNvdWxkIG5vdCBiZSByZWRlcG9zaXRlZCwgYmVjYXVzZSBvZiByb3VuZGluZyBvciB0aGUgZGlzdHJpYnV0aW9uIG9mIHRoZSBhY3RpdmUgYmluLCBhcmUgc2VudCBiYWNrIHRvIGBvd25lcmAuCgpgbWluX3hgIGFuZCBgbWluX3lgIGFyZSB0aGUgbWluaW11bSBhbW91bnRzIHRoYXQgbXVzdCBiZSByZWRlcG9zaXRlZC4KCnJldHVybnMgYSBwYWlyIHdpdGggdGhlIGFtb3VudHMgcmVkZXBvc2l0ZWQ6ICh4X3Rva2VuX2Ftb3VudCwgeV90b2tlbl9hbW91bnQpAAAAABJyZWJhbGFuY2VfcG9zaXRpb24AAAAAAAcAAAAAAAAABW93bmVyAAAAAAAAEwAAAAAAAAALcG9zaXRpb25faWQAAAAABQAAAAAAAAAFd2lkdGgAAAAAAAAEAAAAAAAAAAVzaGFwZQAAAAAAB9AAAAAOTGlxdWlkaXR5U2hhcGUAAAAAAAAAAAAOc3dhcF9pbWJhbGFuY2UAAAAAAAEAAAAAAAAABW1pbl94AAAAAAAACwAAAAAAAAAFbWluX3kAAAAAAAALAAAAAQAAA+0AAAACAAAACwAAAAs=",
+        "AAAAAAAAAU5Nb3ZlcyBgc2hhcmVzYCBvZiB0aGUgcG9zaXRpb24gYHBvc2l0aW9uX2lkYCBvZiBgZnJvbWAgdG8gdGhlIHBvc2l0aW9uIHdpdGggdGhlIHNhbWUgaWQgb2YgYHRvYCwgd2hpY2ggaXMgY3JlYXRlZCBpZiBub3QgZXhpc3RzLgoKVGhlIHNoYXJlcyBvZiBlYWNoIGJpbiB3b3JrIGxpa2UgYSBtdWx0aSB0b2tlbiwgd2hlcmUgdGhlIGJpbiBpZCBpcyB0aGUgdG9rZW4gaWQuIE9ubHkgdGhlIG93bmVyIG9mIHRoZSBzaGFyZXMgbW92ZXMsIHRoZSBiaW5zIHN0YXkgdGhlIHNhbWUuClRoZSByZXdhcmRzIHRoYXQgdGhlIHNoYXJlcyBlYXJuZWQgdW50aWwgbm93IHN0YXkgd2l0aCBgZnJvbWAuAAAAAAAOYmF0Y2hfdHJhbnNmZXIAAAAAAAQAAAAAAAAABGZyb20AAAATAAAAAAAAAAJ0bwAAAAAAEwAAAAAAAAALcG9zaXRpb25faWQAAAAABQAAAAAAAAAGc2hhcmVzAAAAAAPqAAAH0AAAAAlCaW5TaGFyZXMAAAAAAAAA",
+        "AAAAAAAAAHJMaWtlIGBiYXRjaF90cmFuc2ZlcmAsIGJ1dCBhdXRob3JpemVkIGJ5IGBvcGVyYXRvcmAsIHdoaWNoIG11c3QgYmUgYXBwcm92ZWQgYnkgYGZyb21gIHdpdGggYHNldF9hcHByb3ZhbF9mb3JfYWxsYC4AAAAAABNiYXRjaF90cmFuc2Zlcl9mcm9tAAAAAAUAAAAAAAAACG9wZXJhdG9yAAAAEwAAAAAAAAAEZnJvbQAAABMAAAAAAAAAAnRvAAAAAAATAAAAAAAAAAtwb3NpdGlvbl9pZAAAAAAFAAAAAAAAAAZzaGFyZXMAAAAAA+oAAAfQAAAACUJpblNoYXJlcwAAAAAAAAA=",
+        "AAAAAAAAAFJBbGxvd3Mgb3IgZGlzYWxsb3dzIGBvcGVyYXRvcmAgdG8gdHJhbnNmZXIgYWxsIHNoYXJlcyBvZiBhbGwgcG9zaXRpb25zIG9mIGBvd25lcmAuAAAAAAAUc2V0X2FwcHJvdmFsX2Zvcl9hbGwAAAADAAAAAAAAAAVvd25lcgAAAAAAABMAAAAAAAAACG9wZXJhdG9yAAAAEwAAAAAAAAAIYXBwcm92ZWQAAAABAAAAAA==",
         "AAAAAAAAAAAAAAAUc3dhcF9leGFjdF9hbW91bnRfaW4AAAAEAAAAAAAAAARmcm9tAAAAEwAAAAAAAAAJYW1vdW50X2luAAAAAAAACwAAAAAAAAAObWluX2Ftb3VudF9vdXQAAAAAAAsAAAAAAAAACGluX3Rva2VuAAAAEwAAAAEAAAAL",
+        "AAAAAAAAAGtMaWtlIGBzd2FwX2V4YWN0X2Ftb3VudF9pbmAsIGJ1dCB0aGUgb3V0cHV0IGlzIHNlbnQgdG8gYHRvYCwgZm9yIGV4YW1wbGUgdG8gcGF5IHNvbWVvbmUgaW4gdGhlIG90aGVyIHRva2VuLgAAAAAXc3dhcF9leGFjdF9hbW91bnRfaW5fdG8AAAAABQAAAAAAAAAEZnJvbQAAABMAAAAAAAAAAnRvAAAAAAATAAAAAAAAAAlhbW91bnRfaW4AAAAAAAALAAAAAAAAAA5taW5fYW1vdW50X291dAAAAAAACwAAAAAAAAAIaW5fdG9rZW4AAAATAAAAAQAAAAs=",
+        "AAAAAAAAANpyZXR1cm5zIHdoYXQgYHN3YXBfZXhhY3RfYW1vdW50X2luYCB3b3VsZCBzZW5kIGZvciBgYW1vdW50X2luYCBvZiBgaW5fdG9rZW5gLCB3aXRob3V0IGNoYW5naW5nIHRoZSBwb29sLgoKUGFuaWNzIHdpdGggIk5vdCBlbm91Z2ggbGlxdWlkaXR5IiBsaWtlIHRoZSBzd2FwLiBBIGZlZSBvbiB0aGUgdHJhbnNmZXIgb2YgYGluX3Rva2VuYCBpcyBub3QgdGFrZW4gaW50byBhY2NvdW50LgAAAAAAFXF1b3RlX2V4YWN0X2Ftb3VudF9pbgAAAAAAAAIAAAAAAAAACWFtb3VudF9pbgAAAAAAAAsAAAAAAAAACGluX3Rva2VuAAAAEwAAAAEAAAAL",
+        "AAAAAAAAAdNTd2FwcyBgYW1vdW50X2luYCBvZiBgaW5fdG9rZW5gIGxpa2UgYHN3YXBfZXhhY3RfYW1vdW50X2luYCwgYnV0IHRoZSBvdXRwdXQgaXMgc2VudCB0byBgcmVjZWl2ZXJgIGJlZm9yZSB0aGUgaW5wdXQgaXMgcGFpZC4KCkFmdGVyIHRoZSBvdXRwdXQgaXMgdHJhbnNmZXJyZWQsIGBvbl9mbGFzaF9zd2FwYCBvZiB0aGUgYEZsYXNoU3dhcFJlY2VpdmVyYCBpbnRlcmZhY2UgaXMgaW52b2tlZCBvbiBgcmVjZWl2ZXJgIHdpdGggYGRhdGFgLgpCZWZvcmUgdGhlIGNhbGxiYWNrIHJldHVybnMsIGByZWNlaXZlcmAgbXVzdCB0cmFuc2ZlciBgYW1vdW50X2luYCBvZiBgaW5fdG9rZW5gIHRvIHRoZSBwb29sLCBmb3IgZXhhbXBsZSB3aXRoIHRoZSBwcm9jZWVkcyBvZgpzZWxsaW5nIHRoZSBvdXRwdXQgc29tZXdoZXJlIGVsc2UuCgpyZXR1cm5zIHRoZSBhbW91bnQgb2YgdGhlIG90aGVyIHRva2VuIHNlbnQgdG8gYHJlY2VpdmVyYAAAAAAKZmxhc2hfc3dhcAAAAAAABQAAAAAAAAAIcmVjZWl2ZXIAAAATAAAAAAAAAAlhbW91bnRfaW4AAAAAAAALAAAAAAAAAA5taW5fYW1vdW50X291dAAAAAAACwAAAAAAAAAIaW5fdG9rZW4AAAATAAAAAAAAAARkYXRhAAAADgAAAAEAAAAL",
+        "AAAAAAAAAphMZW5kcyBgYW1vdW50YCBvZiBgdG9rZW5gIHRvIGByZWNlaXZlcmAgZm9yIHRoZSBkdXJhdGlvbiBvZiB0aGlzIGNhbGwuCgpUaGUgdG9rZW5zIGFyZSB0cmFuc2ZlcnJlZCB0byBgcmVjZWl2ZXJgIGFuZCBgb25fZmxhc2hfbG9hbmAgb2YgdGhlIGBGbGFzaExvYW5SZWNlaXZlcmAgaW50ZXJmYWNlIGlzIGludm9rZWQgd2l0aCBgZGF0YWAuCkJlZm9yZSB0aGUgY2FsbGJhY2sgcmV0dXJucywgYHJlY2VpdmVyYCBtdXN0IHRyYW5zZmVyIHRoZSBhbW91bnQgcGx1cyB0aGUgZmVlIGJhY2sgdG8gdGhlIHBvb2wuClRoZSBmZWUgaXMgYGZsYXNoX2xvYW5fZmVlYCBicHMgb2YgYGFtb3VudGAsIHJvdW5kZWQgdXAsIGFuZCBpcyBhZGRlZCB0byB0aGUgcmVzZXJ2ZXMgb2YgdGhlIGFjdGl2ZSBiaW4uClNvIGEgbG9hbiB3aXRoIGEgZmVlIG5lZWRzIGxpcXVpZGl0eSBpbiB0aGUgYWN0aXZlIGJpbi4KClRoZSBob3N0IGRvZXMgbm90IGFsbG93IGEgY29udHJhY3QgdG8gYmUgcmUtZW50ZXJlZCwgc28gdGhlIGNhbGxiYWNrIGNhbid0IGNhbGwgYmFjayBpbnRvIHRoZSBwb29sCihlLmcuIGBzd2FwX2V4YWN0X2Ftb3VudF9pbmAgb3IgYG1vZGlmeV9saXF1aWRpdHlgKSB3aGlsZSB0aGUgbG9hbiBpcyBvdXRzdGFuZGluZy4KCnJldHVybnMgdGhlIGZlZSB0aGF0IHdhcyBwYWlkAAAACmZsYXNoX2xvYW4AAAAAAAQAAAAAAAAACHJlY2VpdmVyAAAAEwAAAAAAAAAFdG9rZW4AAAAAAAATAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAAAAAABGRhdGEAAAAOAAAAAQAAAAs=",
+        "AAAAAAAAAg5BbGxvd3MgYG93bmVyYCB0byBwbGFjZSBhIGxpbWl0IG9yZGVyIG9mIGBhbW91bnRgIGluIGBiaW5faWRgLgoKSWYgdGhlIGJpbiBpcyBsZWZ0IG9mIHRoZSBhY3RpdmUgYmluLCB0aGUgb3JkZXIgc2VsbHMgeCBmb3IgeSwgb3RoZXJ3aXNlIGl0IHNlbGxzIHkgZm9yIHguCk9yZGVycyBhcmUgdHJhY2tlZCBzZXBhcmF0ZWx5IGZyb20gdGhlIGxpcXVpZGl0eSBvZiB0aGUgTFBzLiBTd2FwcyB1c2UgdGhlIG9yZGVycyBhZnRlciB0aGUgbGlxdWlkaXR5IG9mIHRoZSBiaW4sCmFuZCBvbmNlIGFsbCBvcmRlcnMgaW4gdGhlIGJpbiBhcmUgc29sZCwgdGhleSBjYW4gYmUgY2xhaW1lZCB3aXRoIGBjbGFpbV9saW1pdF9vcmRlcmAuCkNvbnRyYXJ5IHRvIGxpcXVpZGl0eSwgdGhlIHByb2NlZWRzIG9mIGEgZmlsbGVkIG9yZGVyIGNhbid0IGJlIHN3YXBwZWQgYmFjay4KCk9yZGVycyBjYW4ndCBiZSBwbGFjZWQgaW4gdGhlIGFjdGl2ZSBiaW4sIG9yIGluIGEgYmluIHdoZXJlIHRoZSBvcmRlcnMgYXJlIHBhcnRpYWxseSBmaWxsZWQuAAAAAAARcGxhY2VfbGltaXRfb3JkZXIAAAAAAAAEAAAAAAAAAAVvd25lcgAAAAAAABMAAAAAAAAACG9yZGVyX2lkAAAABQAAAAAAAAAGYmluX2lkAAAAAAAFAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAA",
+        "AAAAAAAAAPVBbGxvd3MgYG93bmVyYCB0byBjYW5jZWwgYW4gb3JkZXIgdGhhdCBpcyBub3QgZmlsbGVkIHlldC4KCklmIHRoZSBvcmRlciBpcyBwYXJ0aWFsbHkgZmlsbGVkLCBgb3duZXJgIHJlY2VpdmVzIHRoZWlyIHBhcnQgb2Ygd2hhdCBpcyBsZWZ0IGFuZCBvZiB3aGF0IHdhcyByZWNlaXZlZC4KCnJldHVybnMgYSBwYWlyIHdpdGggdGhlIGFtb3VudHMgc2VudCB0byBgb3duZXJgOiAoeF90b2tlbl9hbW91bnQsIHlfdG9rZW5fYW1vdW50KQAAAAAAABJjYW5jZWxfbGltaXRfb3JkZXIAAAAAAAIAAAAAAAAABW93bmVyAAAAAAAAEwAAAAAAAAAIb3JkZXJfaWQAAAAFAAAAAQAAA+0AAAACAAAACwAAAAs=",
+        "AAAAAAAAAG9BbGxvd3MgYG93bmVyYCB0byBjbGFpbSB0aGUgcHJvY2VlZHMgb2YgYSBmaWxsZWQgb3JkZXIuCgpyZXR1cm5zIHRoZSBhbW91bnQgb2YgdGhlIGJvdWdodCB0b2tlbiBzZW50IHRvIGBvd25lcmAAAAAAEWNsYWltX2xpbWl0X29yZGVyAAAAAAAAAgAAAAAAAAAFb3duZXIAAAAAAAATAAAAAAAAAAhvcmRlcl9pZAAAAAUAAAABAAAACw==",
+        "AAAAAAAAAvxBZGRzIGFuIGluY2VudGl2ZSB0aGF0IHBheXMgYGFtb3VudGAgb2YgYHRva2VuYCB0byB0aGUgTFBzIG9mIHRoZSBiaW5zIGluIGByYW5nZWAsIGF0IHRoZSBzYW1lIHJhdGUgZnJvbSBgc3RhcnRgIHRvIGBlbmRgLgoKT25seSB0aGUgYWRtaW4gb2YgdGhlIHBvb2wgY2FuIGFkZCBpbmNlbnRpdmVzLCBgbWFuYWdlcmAgcGF5cyB0aGVtIGFuZCBjYW4gcmVjbGFpbSB3aGF0IGlzIGxlZnQuCgpgc3RhcnRgIGFuZCBgZW5kYCBhcmUgbGVkZ2VyIHRpbWVzdGFtcHMsIGEgYHN0YXJ0YCBpbiB0aGUgcGFzdCBzdGFydHMgbm93LCBhbmQgYGVuZGAgaXMgYXQgbW9zdCBgTUFYX0lOQ0VOVElWRV9EVVJBVElPTmAgZnJvbSBub3cuClRoZSByZXdhcmRzIG9mIGV2ZXJ5IG1vbWVudCBhcmUgZGl2aWRlZCBlcXVhbGx5IG92ZXIgdGhlIGJpbnMgaW4gdGhlIHJhbmdlIHRoYXQgaGF2ZSBzaGFyZXMsIGFuZCB3aXRoaW4gYSBiaW4gYnkgc2hhcmVzLgpMUHMgY2xhaW0gdGhlbSBwZXIgcG9zaXRpb24gYW5kIGluY2VudGl2ZSB3aXRoIGBjbGFpbV9yZXdhcmRzYC4gVGhlIHRva2VucyBvZiB0aGUgcG9vbCBjYW4ndCBiZSByZXdhcmRzLCBhcyB0aGV5IHdvdWxkIGJlIHRha2VuIGJ5IGBza2ltYC4KClRoZSBwb29sIGhhcyBhdCBtb3N0IGBNQVhfSU5DRU5USVZFU2AgaW5jZW50aXZlcywgYW4gaW5jZW50aXZlIGNvdW50cyB1bnRpbCBpdCBpcyByZWNsYWltZWQgd2l0aCBgcmVjbGFpbV9pbmNlbnRpdmVgLgoKcmV0dXJucyB0aGUgaWQgb2YgdGhlIGluY2VudGl2ZQAAAA1hZGRfaW5jZW50aXZlAAAAAAAABgAAAAAAAAAHbWFuYWdlcgAAAAATAAAAAAAAAAV0b2tlbgAAAAAAABMAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAAFc3RhcnQAAAAAAAAGAAAAAAAAAANlbmQAAAAABgAAAAAAAAAFcmFuZ2UAAAAAAAfQAAAAC1Jld2FyZFJhbmdlAAAAAAEAAAAE",
+        "AAAAAAAAAWpBbGxvd3MgYG93bmVyYCB0byBjbGFpbSB0aGUgcmV3YXJkcyB0aGF0IHBvc2l0aW9uIGBwb3NpdGlvbl9pZGAgZWFybmVkIGZyb20gaW5jZW50aXZlIGBpbmNlbnRpdmVfaWRgLgoKRXZlcnkgaW5jZW50aXZlIGlzIGNsYWltZWQgb24gaXRzIG93biwgc28gYSByZXdhcmQgdG9rZW4gdGhhdCBjYW4ndCBiZSB0cmFuc2ZlcnJlZCBkb2Vzbid0IGJsb2NrIHRoZSBvdGhlcnMuClJld2FyZHMgc3RheSBjbGFpbWFibGUgYWZ0ZXIgdGhlIHNoYXJlcyBhcmUgcmVtb3ZlZCwgdW50aWwgYFJFV0FSRF9DTEFJTV9QRVJJT0RgIGFmdGVyIHRoZSBlbmQgb2YgdGhlaXIgaW5jZW50aXZlLgoKcmV0dXJucyB0aGUgYW1vdW50IHNlbnQgdG8gYG93bmVyYAAAAAAADWNsYWltX3Jld2FyZHMAAAAAAAADAAAAAAAAAAVvd25lcgAAAAAAABMAAAAAAAAAC3Bvc2l0aW9uX2lkAAAAAAUAAAAAAAAADGluY2VudGl2ZV9pZAAAAAQAAAABAAAACw==",
+        "AAAAAAAAAUZBbGxvd3MgdGhlIG1hbmFnZXIgb2YgaW5jZW50aXZlIGBpZGAgdG8gdGFrZSBiYWNrIHdoYXQgd2FzIG5vdCBjbGFpbWVkLCBvbmNlIGBSRVdBUkRfQ0xBSU1fUEVSSU9EYCBwYXNzZWQgYWZ0ZXIgaXRzIGVuZC4KClRoaXMgaW5jbHVkZXMgdGhlIHJld2FyZHMgb2YgdGhlIHRpbWVzIHRoYXQgbm8gYmluIGluIHRoZSByYW5nZSBoYWQgc2hhcmVzLgpUaGUgaW5jZW50aXZlIGlzIHJlbW92ZWQsIHNvIGl0cyByZXdhcmRzIHRoYXQgd2VyZSBub3QgY2xhaW1lZCBjYW4ndCBiZSBjbGFpbWVkIGFueW1vcmUuCgpyZXR1cm5zIHRoZSBhbW91bnQgc2VudCB0byB0aGUgbWFuYWdlcgAAAAAAEXJlY2xhaW1faW5jZW50aXZlAAAAAAAAAQAAAAAAAAACaWQAAAAAAAQAAAABAAAACw==",
+        "AAAAAAAAAVdTZW5kcyB0aGUgdG9rZW5zIHRoYXQgdGhlIHBvb2wgaG9sZHMgb24gdG9wIG9mIGl0cyByZXNlcnZlcyB0byBgdG9gLgoKVGhlc2UgYXJlIHRva2VucyB0aGF0IHdlcmUgdHJhbnNmZXJyZWQgdG8gdGhlIHBvb2wgZGlyZWN0bHksIG9yIHRoYXQgd2VyZSBhZGRlZCBieSBhIHRva2VuIHRoYXQgcmViYXNlcyB1cC4KQW55b25lIGNhbiBjYWxsIHRoaXMsIHNvIHRva2VucyB0aGF0IGFyZSBzZW50IHRvIHRoZSBwb29sIGJ5IGFjY2lkZW50IGNhbiBiZSB0YWtlbiBieSBhbnlvbmUuCgpyZXR1cm5zIGEgcGFpciB3aXRoIHRoZSBhbW91bnRzIHNlbnQ6ICh4X3Rva2VuX2Ftb3VudCwgeV90b2tlbl9hbW91bnQpAAAAAARza2ltAAAAAQAAAAAAAAACdG8AAAAAABMAAAABAAAD7QAAAAIAAAALAAAACw==",
+        "AAAAAAAAAltBZGRzIHRoZSB0b2tlbnMgdGhhdCB0aGUgcG9vbCBob2xkcyBvbiB0b3Agb2YgaXRzIHJlc2VydmVzIHRvIHRoZSByZXNlcnZlcyBvZiB0aGUgYWN0aXZlIGJpbiwgc28gdGhleSBnbyB0byB0aGUgTFBzIG9mIHRoYXQgYmluLgoKTGlrZSBgc2tpbWAsIHRoaXMgaXMgZm9yIHRva2VucyB0aGF0IHdlcmUgdHJhbnNmZXJyZWQgZGlyZWN0bHkgb3IgdGhhdCByZWJhc2VkIHVwLiBJZiBhIHRva2VuIHJlYmFzZWQgZG93biBvciB3YXMgY2xhd2VkIGJhY2ssCnRoZSBiYWxhbmNlIGlzIGJlbG93IHRoZSByZXNlcnZlcy4gVGhlIHJlc2VydmVzIGFyZSB0aGVuIGxvd2VyZWQgdG8gdGhlIGJhbGFuY2UgYW5kIHRoZSBzaG9ydGZhbGwgaXMgdGFrZW4gcHJvIHJhdGEgZnJvbSB0aGUgYmlucyBuZWFyZXN0IHRvIHRoZSBhY3RpdmUgYmluLCBzZWUgYHRha2VfZnJvbV9iaW5zYC4KRmFpbHMgaWYgdGhvc2UgYmlucyBjYW4ndCBjb3ZlciB0aGUgc2hvcnRmYWxsLCB0aGUgdG9rZW5zIG9mIHRoZSBsaW1pdCBvcmRlcnMgYXJlIG5ldmVyIHRha2VuLgoKcmV0dXJucyBhIHBhaXIgd2l0aCB0aGUgYW1vdW50cyBhZGRlZDogKHhfdG9rZW5fYW1vdW50LCB5X3Rva2VuX2Ftb3VudCkAAAAABHN5bmMAAAAAAAAAAQAAA+0AAAACAAAACwAAAAs=",
+        "AAAAAAAAArlFeHRlbmRzIHRoZSBUVEwgb2YgdGhlIGJpbnMgaW4gYGJpbnNgIHdpdGggdGhlaXIgbGltaXQgb3JkZXJzLCB0aGUgcG9zaXRpb25zIGluIGBwb3NpdGlvbnNgIHdpdGggdGhlaXIgcmV3YXJkcyBhbmQgdGhlIHBvc2l0aW9uIGlkcyBvZiB0aGVpciBvd25lcnMsCnRoZSBsaW1pdCBvcmRlcnMgaW4gYG9yZGVyc2Agd2l0aCB0aGVpciBmaWxsZWQgb3JkZXJzLCB0aGUgYXBwcm92YWxzIG9mIHRoZSAob3duZXIsIG9wZXJhdG9yKSBwYWlycyBpbiBgb3BlcmF0b3JzYCwgdGhlIGluY2VudGl2ZXMgYW5kIG9mIHRoZSBjb250cmFjdCBpbnN0YW5jZS4KCkJpbnMsIHBvc2l0aW9ucywgb3JkZXJzIGFuZCBhcHByb3ZhbHMgYXJlIGFsc28gZXh0ZW5kZWQgd2hlbiB0aGV5IGFyZSB3cml0dGVuLCB0aGlzIGFsbG93cyBhbnlvbmUgdG8ga2VlcCBpZGxlIHBvb2xzLCBwb3NpdGlvbnMsIG9wZW4gb3JkZXJzCmFuZCBhcHByb3ZhbHMgZnJvbSBiZWluZyBhcmNoaXZlZC4KSW5jZW50aXZlcyBhcmUgb25seSB3cml0dGVuIHVudGlsIHRoZWlyIGVuZCwgc28gdGhleSBuZWVkIHRvIGJlIGJ1bXBlZCB1bnRpbCB0aGV5IGFyZSByZWNsYWltZWQuCkVudHJpZXMgdGhhdCBkb24ndCBleGlzdCBhcmUgc2tpcHBlZC4gVGhlIHRocmVzaG9sZHMgY29tZSBmcm9tIGB0dGxfdGhyZXNob2xkYCBhbmQgYHR0bF9leHRlbmRfdG9gIGluIHRoZSBjb25maWcuAAAAAAAABGJ1bXAAAAAEAAAAAAAAAARiaW5zAAAD6gAAAAUAAAAAAAAACXBvc2l0aW9ucwAAAAAAA+oAAAPtAAAAAgAAABMAAAAFAAAAAAAAAAZvcmRlcnMAAAAAA+oAAAPtAAAAAgAAABMAAAAFAAAAAAAAAAlvcGVyYXRvcnMAAAAAAAPqAAAD7QAAAAIAAAATAAAAEwAAAAA=",
+        "AAAAAAAAACByZXR1cm5zIHRoZSBiaW4gd2l0aCBpZCBgYmluX2lkYAAAAAdnZXRfYmluAAAAAAEAAAAAAAAABmJpbl9pZAAAAAAABQAAAAEAAAfQAAAAA0JpbgA=",
+        "AAAAAAAAAN1yZXR1cm5zIHRoZSBiaW5zIGZyb20gYGZyb21fYmluYCB1cCB0byBhbmQgaW5jbHVkaW5nIGB0b19iaW5gLCB3aXRoIHRoZWlyIHRvdGFsIHNoYXJlcyBhbmQgcHJpY2UuCgpUaGUgcmFuZ2UgbWF5IHNwYW4gbXVsdGlwbGUgdmVjcywgYnV0IGNhbid0IGJlIGxhcmdlciB0aGFuIGBNQVhfQklOU19JTl9SQU5HRWAgYmlucyBhbmQgbXVzdCBiZSB3aXRoaW4gdGhlIHN1cHBvcnRlZCBiaW5zLgAAAAAAAA5nZXRfYmluc19yYW5nZQAAAAAAAgAAAAAAAAAIZnJvbV9iaW4AAAAFAAAAAAAAAAZ0b19iaW4AAAAAAAUAAAABAAAD6gAAB9AAAAAHQmluSW5mbwA=",
+        "AAAAAAAAADZyZXR1cm5zIHRoZSBhY3RpdmUgYmluIHdpdGggaXRzIHRvdGFsIHNoYXJlcyBhbmQgcHJpY2UAAAAAABdnZXRfYWN0aXZlX2Jpbl9yZXNlcnZlcwAAAAAAAAAAAQAAB9AAAAAHQmluSW5mbwA=",
+        "AAAAAAAAAMlyZXR1cm5zIHRoZSBhbW91bnQgb2YgdGhlIG90aGVyIHRva2VuIHRoYXQgeW91IGdldCBmb3IgMSBgaW5fdG9rZW5gIGluIGJpbiBgYmluX2lkYCwgYmVmb3JlIGZlZXMgYW5kIGluIDEyOC4xMjggZml4ZWQgcG9pbnQKCkZhaWxzIHdpdGggYEVyckJpbklkT3V0T2ZSYW5nZWAgaWYgdGhlIGJpbiBpcyBvdXRzaWRlIG9mIHRoZSBzdXBwb3J0ZWQgYmlucy4AAAAAAAARZ2V0X3ByaWNlX2Zyb21faWQAAAAAAAACAAAAAAAAAAZiaW5faWQAAAAAAAUAAAAAAAAACGluX3Rva2VuAAAAEwAAAAEAAAAM",
+        "AAAAAAAAAPlyZXR1cm5zIHRoZSBpZCBvZiB0aGUgYmluIGZvciBgcHJpY2VgLCB0aGUgcHJpY2Ugb2YgeSBpbiB4IGluIDEyOC4xMjggZml4ZWQgcG9pbnQuCgpQcmljZXMgYmV0d2VlbiB0d28gYmlucyBhcmUgcm91bmRlZCBkb3duIHRvIHRoZSBsb3dlciBiaW4sIHNlZSBgcHJpY2U6OmlkX2Zyb21fcHJpY2VgLgpGYWlscyB3aXRoIGBFcnJQcmljZU91dE9mUmFuZ2VgIGlmIHRoZSBwcmljZSBpcyBvdXRzaWRlIG9mIHRoZSBzdXBwb3J0ZWQgYmlucy4AAAAAAAARZ2V0X2lkX2Zyb21fcHJpY2UAAAAAAAABAAAAAAAAAAVwcmljZQAAAAAAAAwAAAABAAAABQ==",
         "AAAAAAAAAMtCaW5zIGFyZSBncm91cGVkIHRvZ2V0aGVyIGluIGEgYEJpblZlY2Agb2Ygc2l6ZSBgQklOX1ZFQ19TSVpFYAoKdGhlIGBnZXRfdmVjX2lkX2Zvcl9iaW5gIGNhbiBiZSB1c2VkIHRvIGNvbnZlcnQgYSBiaW5faWQgdG8gYSB2ZWNfaWQKClRoaXMgZnVuY3Rpb24gd2lsbCByZXR1cm4gdGhlIGdyb3VwIG9mIGJpbnMgc3BlY2lmaWVkIGJ5IHRoZSBgdmVjX2lkYAAAAAALZ2V0X2Jpbl92ZWMAAAAAAQAAAAAAAAAGdmVjX2lkAAAAAAAFAAAAAQAAA+oAAAfQAAAAA0JpbgA=",
         "AAAAAAAAANVFYWNoIEJpbiBpcyB0cmVhdGVkIGFzIGEgc2VwYXJhdGUgInZhdWx0IiBhbmQgaGFzIGl0cyBvd24gc2hhcmVzLgoKCnRoZSBgZ2V0X3ZlY19pZF9mb3JfYmluYCBjYW4gYmUgdXNlZCB0byBjb252ZXJ0IGEgYmluX2lkIHRvIGEgdmVjX2lkCgpUaGlzIGZ1bmN0aW9uIHdpbGwgcmV0dXJuIHRoZSBncm91cCBvZiBiaW4gc2hhcmVzIHNwZWNpZmllZCBieSB0aGUgYHZlY19pZGAAAAAAAAAOZ2V0X3NoYXJlc192ZWMAAAAAAAEAAAAAAAAABnZlY19pZAAAAAAABQAAAAEAAAPqAAAH0AAAAAlCaW5TaGFyZXMAAAA=",
         "AAAAAAAAAGFyZXR1cm5zIHRoZSBgUG9zaXRpb25gIGZvciBhIGdpdmVuIHBvc2l0aW9uIGlkIGZvciBhIHVzZXIKCkVhY2ggdXNlciBjYW4gaGF2ZSBtdWx0aXBsZSBwb3NpdGlvbnMuAAAAAAAADGdldF9wb3NpdGlvbgAAAAIAAAAAAAAABGZyb20AAAATAAAAAAAAAAtwb3NpdGlvbl9pZAAAAAAFAAAAAQAAA+gAAAfQAAAACFBvc2l0aW9u",
+        "AAAAAAAAACtyZXR1cm5zIHRoZSBpZHMgb2YgdGhlIHBvc2l0aW9ucyBvZiBgb3duZXJgAAAAABBnZXRfcG9zaXRpb25faWRzAAAAAQAAAAAAAAAFb3duZXIAAAAAAAATAAAAAQAAA+oAAAAF",
+        "AAAAAAAAAFdyZXR1cm5zIHRoZSBzaGFyZXMgb2YgYG93bmVyYCBpbiB0aGUgYmluIGBiaW5faWRgLCBzdW1tZWQgb3ZlciBhbGwgcG9zaXRpb25zIG9mIGBvd25lcmAAAAAACmJhbGFuY2Vfb2YAAAAAAAIAAAAAAAAABW93bmVyAAAAAAAAEwAAAAAAAAAGYmluX2lkAAAAAAAFAAAAAQAAAAs=",
+        "AAAAAAAAAF1yZXR1cm5zIGBiYWxhbmNlX29mYCBmb3IgZWFjaCBwYWlyIG9mIGBvd25lcnNgIGFuZCBgYmluX2lkc2AsIHdoaWNoIG11c3QgaGF2ZSB0aGUgc2FtZSBsZW5ndGgAAAAAAAAQYmFsYW5jZV9vZl9iYXRjaAAAAAIAAAAAAAAABm93bmVycwAAAAAD6gAAABMAAAAAAAAAB2Jpbl9pZHMAAAAD6gAAAAUAAAABAAAD6gAAAAs=",
+        "AAAAAAAAAD1yZXR1cm5zIHdoZXRoZXIgYG9wZXJhdG9yYCBtYXkgdHJhbnNmZXIgdGhlIHNoYXJlcyBvZiBgb3duZXJgAAAAAAAAE2lzX2FwcHJvdmVkX2Zvcl9hbGwAAAAAAgAAAAAAAAAFb3duZXIAAAAAAAATAAAAAAAAAAhvcGVyYXRvcgAAABMAAAABAAAAAQ==",
+        "AAAAAAAAADtyZXR1cm5zIHRoZSBpbmNlbnRpdmUgd2l0aCBpZCBgaWRgLCBpZiBpdCB3YXMgbm90IHJlY2xhaW1lZAAAAAANZ2V0X2luY2VudGl2ZQAAAAAAAAEAAAAAAAAAAmlkAAAAAAAEAAAAAQAAA+gAAAfQAAAACUluY2VudGl2ZQAAAA==",
+        "AAAAAAAAADlyZXR1cm5zIHRoZSBpZHMgb2YgdGhlIGluY2VudGl2ZXMgdGhhdCB3ZXJlIG5vdCByZWNsYWltZWQAAAAAAAAOZ2V0X2luY2VudGl2ZXMAAAAAAAAAAAABAAAD6gAAAAQ=",
+        "AAAAAAAAAGhyZXR1cm5zIHdoYXQgYGNsYWltX3Jld2FyZHNgIHdvdWxkIHNlbmQgZm9yIHRoZSBwb3NpdGlvbiBub3csIHBlciBpbmNlbnRpdmU6IHZlY1soaW5jZW50aXZlX2lkLCBhbW91bnQpXQAAABNnZXRfcGVuZGluZ19yZXdhcmRzAAAAAAIAAAAAAAAABW93bmVyAAAAAAAAEwAAAAAAAAALcG9zaXRpb25faWQAAAAABQAAAAEAAAPqAAAD7QAAAAIAAAAEAAAACw==",
+        "AAAAAAAAADdyZXR1cm5zIHRoZSBsaW1pdCBvcmRlciBmb3IgYSBnaXZlbiBvcmRlciBpZCBmb3IgYSB1c2VyAAAAAA9nZXRfbGltaXRfb3JkZXIAAAAAAgAAAAAAAAAFb3duZXIAAAAAAAATAAAAAAAAAAhvcmRlcl9pZAAAAAUAAAABAAAD6AAAB9AAAAAKTGltaXRPcmRlcgAA",
+        "AAAAAAAAACpyZXR1cm5zIHRoZSB1bmZpbGxlZCBsaW1pdCBvcmRlcnMgaW4gYSBiaW4AAAAAAA1nZXRfb3JkZXJfYmluAAAAAAAAAQAAAAAAAAAGYmluX2lkAAAAAAAFAAAAAQAAA+gAAAfQAAAACE9yZGVyQmlu",
+        "AAAAAAAAAWNyZXR1cm5zIHRoZSBodW1hbiBwcmljZSBpbiBiaW4gYGJpbl9pZGA6IHRoZSBhbW91bnQgb2Ygd2hvbGUgeCB0b2tlbnMgdGhhdCAxIHdob2xlIHkgdG9rZW4gaXMgd29ydGgsIGluIDEyOC4xMjggZml4ZWQgcG9pbnQuCgpDb250cmFyeSB0byBgZ2V0X3ByaWNlX2Zyb21faWRgLCB0aGlzIHRha2VzIHRoZSBkZWNpbWFscyBvZiB0aGUgdG9rZW5zIGludG8gYWNjb3VudC4KRmFpbHMgd2l0aCBgRXJyUHJpY2VPdXRPZlJhbmdlYCBpZiB0aGUgaHVtYW4gcHJpY2UgZG9lcyBub3QgZml0IGluIDI1NiBiaXRzLCB3aGljaCBoYXBwZW5zIGluIHRoZSB0b3AgYmlucyB3aGVuIHkgaGFzIG1hbnkgbW9yZSBkZWNpbWFscyB0aGFuIHguAAAAABdnZXRfaHVtYW5fcHJpY2VfZnJvbV9pZAAAAAABAAAAAAAAAAZiaW5faWQAAAAAAAUAAAABAAAADA==",
+        "AAAAAAAAAQNyZXR1cm5zIHRoZSBpZCBvZiB0aGUgYmluIGZvciBhIGh1bWFuIHByaWNlOiB0aGUgYW1vdW50IG9mIHdob2xlIHggdG9rZW5zIHRoYXQgMSB3aG9sZSB5IHRva2VuIGlzIHdvcnRoLCBpbiAxMjguMTI4IGZpeGVkIHBvaW50LgoKUHJpY2VzIGJldHdlZW4gdHdvIGJpbnMgYXJlIHJvdW5kZWQgZG93biB0byB0aGUgbG93ZXIgYmluLiBVc2UgYHByaWNlOjppZF9mcm9tX2h1bWFuX3ByaWNlYCB0byBwaWNrIHRoZSBhY3RpdmUgYmluIG9mIGEgbmV3IHBvb2wuAAAAABdnZXRfaWRfZnJvbV9odW1hbl9wcmljZQAAAAABAAAAAAAAAAtodW1hbl9wcmljZQAAAAAMAAAAAQAAAAU=",
+        "AAAAAAAAALFyZXR1cm5zIHRoZSBsb3dlc3QgYW5kIGhpZ2hlc3QgYmluIGlkIHRoYXQgYXJlIHN1cHBvcnRlZCBmb3IgdGhlIGJpbiBzdGVwIG9mIHRoZSBwb29sLgoKTGlxdWlkaXR5IGFuZCBsaW1pdCBvcmRlcnMgY2FuIG9ubHkgYmUgcGxhY2VkIGluIHRoZXNlIGJpbnMsIHNlZSBgcHJpY2U6OmdldF9tYXhfYmluX2lkYC4AAAAAAAASZ2V0X3N1cHBvcnRlZF9iaW5zAAAAAAAAAAAAAQAAA+0AAAACAAAABQAAAAU=",
+        "AAAAAAAAAIdyZXR1cm5zIHRoZSBhbW91bnRzIG9mIHRoZSB0b2tlbnMgdGhhdCB0aGUgcG9vbCBvd2VzIHRvIExQcyBhbmQgbGltaXQgb3JkZXJzLCBzZWUgYHNraW1gIGFuZCBgc3luY2AgZm9yIHRva2VucyB0aGF0IGFyZSBub3QgcGFydCBvZiBpdC4AAAAADGdldF9yZXNlcnZlcwAAAAAAAAABAAAH0AAAAAhSZXNlcnZlcw==",
+        "AAAAAAAAAIByZXR1cm5zIHRoZSBzdW1zIG9mIHRoZSByZXNlcnZlcyBhbmQgc2hhcmVzIG9mIGFsbCBiaW5zLiBUaGVzZSBhcmUgYWxzbyBwYXJ0IG9mIGBnZXRfcmVzZXJ2ZXNgLCB3aGljaCBpbmNsdWRlcyB0aGUgbGltaXQgb3JkZXJzLgAAAA5nZXRfYmluX3RvdGFscwAAAAAAAAAAAAEAAAfQAAAACUJpblRvdGFscwAAAA==",
         "AAAAAAAAAAAAAAAKZ2V0X2NvbmZpZwAAAAAAAAAAAAEAAAfQAAAABkNvbmZpZwAA",
-        "AAAAAgAAAAAAAAAAAAAAB0RhdGFLZXkAAAAABAAAAAAAAAAAAAAABkNvbmZpZwAAAAAAAQAAAAAAAAAGQmluVmVjAAAAAAABAAAABQAAAAEAAAAAAAAAC0JpblNoYXJlVmVjAAAAAAEAAAAFAAAAAQAAAAAAAAAIUG9zaXRpb24AAAACAAAAEwAAAAU=",
+        "AAAAAgAAAAAAAAAAAAAAB0RhdGFLZXkAAAAAEwAAAAAAAAAAAAAABkNvbmZpZwAAAAAAAQAAAAAAAAAGQmluVmVjAAAAAAABAAAABQAAAAEAAAAAAAAAC0JpblNoYXJlVmVjAAAAAAEAAAAFAAAAAQAAAAAAAAAIUG9zaXRpb24AAAACAAAAEwAAAAUAAAABAAAAAAAAAAlPcmRlckJpbnMAAAAAAAABAAAABQAAAAAAAAAAAAAACk9yZGVyRXBvY2gAAAAAAAEAAAAAAAAADEZpbGxlZE9yZGVycwAAAAEAAAAEAAAAAQAAAAAAAAAKTGltaXRPcmRlcgAAAAAAAgAAABMAAAAFAAAAAAAAAAAAAAAIUmVzZXJ2ZXMAAAAAAAAAAAAAAAlCaW5Ub3RhbHMAAAAAAAAAAAAAAAAAAApJbmNlbnRpdmVzAAAAAAAAAAAAAAAAAA5JbmNlbnRpdmVDb3VudAAAAAAAAQAAAAAAAAAJSW5jZW50aXZlAAAAAAAAAQAAAAQAAAABAAAAAAAAAA5SZXdhcmRQZXJTaGFyZQAAAAAAAgAAAAQAAAAFAAAAAQAAAAAAAAAMUmV3YXJkVmVjSWRzAAAAAQAAAAQAAAABAAAAAAAAAA9Qb3NpdGlvblJld2FyZHMAAAAAAgAAABMAAAAFAAAAAQAAAAAAAAALUG9zaXRpb25JZHMAAAAAAQAAABMAAAABAAAAAAAAAAhPcGVyYXRvcgAAAAIAAAATAAAAEwAAAAEAAAAAAAAACVZlY0JpdG1hcAAAAAAAAAMAAAfQAAAACFZlY0luZGV4AAAABAAAAAQ=",
+        "AAAAAwAAADBUaGUgYml0bWFwcyBvZiB2ZWNzIGluIHN0b3JhZ2UsIHNlZSBgYml0bWFwLnJzYC4AAAAAAAAACFZlY0luZGV4AAAAAgAAAB1UaGUgdmVjcyB0aGF0IGhhdmUgbGlxdWlkaXR5LgAAAAAAAARCaW5zAAAAAAAAACBUaGUgdmVjcyB0aGF0IGhhdmUgbGltaXQgb3JkZXJzLgAAAAZPcmRlcnMAAAAAAAE=",
         "AAAAAQAAABVzdG9yZWQgYXMgdmVjW3NoYXJlc10AAAAAAAAAAAAACUJpblNoYXJlcwAAAAAAAAIAAAAAAAAABmJpbl9pZAAAAAAABQAAAAAAAAAGc2hhcmVzAAAAAAAL",
+        "AAAAAQAAAEZBIGJpbiB3aXRoIGl0cyB0b3RhbCBzaGFyZXMgYW5kIHByaWNlLCBhcyByZXR1cm5lZCBieSB0aGUgYmluIGdldHRlcnMuAAAAAAAAAAAAB0JpbkluZm8AAAAABQAAAAAAAAAGYmluX2lkAAAAAAAFAAAAK3RoZSBwcmljZSBvZiB5IGluIHgsIGluIDEyOC4xMjggZml4ZWQgcG9pbnQAAAAABXByaWNlAAAAAAAADAAAAAAAAAAJcmVzZXJ2ZV94AAAAAAAACwAAAAAAAAAJcmVzZXJ2ZV95AAAAAAAACwAAAAAAAAAGc2hhcmVzAAAAAAAL",
         "AAAAAQAAAHdzdG9yZWQgYXMgdmVjW3Jlc2VydmVfeHxyZXNlcnZlX3ldIGlmIG5vdCBhY3RpdmUgYmluCm90aGVyd2lzZSBpZiBhY3RpdmVfYmluLCBpdCBpcyBzdG9yZWQgYXMgdmVjW3Jlc2VydmVfeCwgcmVzZXJ2ZV95XQAAAAAAAAAAA0JpbgAAAAADAAAAAAAAAAZiaW5faWQAAAAAAAUAAAAAAAAACXJlc2VydmVfeAAAAAAAAAsAAAAAAAAACXJlc2VydmVfeQAAAAAAAAs=",
         "AAAAAQAAARtiaW5faWRfb3Jfb2Zmc2V0IGlzIGVpdGhlciB0aGUgYmluIGlkIG9yIHRoZSBvZmZzZXQgZnJvbSB0aGUgY3VycmVudCBhY3RpdmUgYmluCmFtb3VudCBzcGVjaWZpZXMgdGhlIHRva2VuIGFtb3VudCBvciB0aGUgYW1vdW50IG9mIHNoYXJlcywgZGVwZW5kaW5nIG9uIHRoZSB2YWx1ZSBvZiBgaXNfcmVtb3ZlYApJIGRpZG4ndCBsaWtlIGhvdyB0aGUgRGVwb3NpdEFyZ3Mgc2hvd2VkIHVwIGluIHN0ZWxsYXIgZXhwZXJ0LCBzbyBJIGNoYW5nZWQgaXQgZnJvbSBhIHN1bSB0eXBlIHRvIGEgc3RydWN0AAAAAAAAAAALRGVwb3NpdEFyZ3MAAAAAAwAAAAAAAAAGYW1vdW50AAAAAAALAAAAAAAAABBiaW5faWRfb3Jfb2Zmc2V0AAAABQAAAAAAAAAJaXNfcmVtb3ZlAAAAAAAAAQ==",
-        "AAAAAQAAAAAAAAAAAAAABkNvbmZpZwAAAAAABQAAAAAAAAAKYWN0aXZlX2JpbgAAAAAABQAAAAAAAAAIYmluX3N0ZXAAAAAEAAAAAAAAAANmZWUAAAAABAAAAAAAAAAHdG9rZW5feAAAAAATAAAAAAAAAAd0b2tlbl95AAAAABM=",
+        "AAAAAwAAAGVIb3cgbGlxdWlkaXR5IGlzIHNwcmVhZCBvdmVyIHRoZSBiaW5zIGFyb3VuZCB0aGUgYWN0aXZlIGJpbiwgZm9yIGV4YW1wbGUgd2hlbiByZWJhbGFuY2luZyBhIHBvc2l0aW9uLgAAAAAAAAAAAAAOTGlxdWlkaXR5U2hhcGUAAAAAAAMAAAAdVGhlIHNhbWUgd2VpZ2h0IGluIGV2ZXJ5IGJpbi4AAAAAAAAEU3BvdAAAAAAAAABKTW9zdCBvZiB0aGUgbGlxdWlkaXR5IGFyb3VuZCB0aGUgYWN0aXZlIGJpbiwgZGVjcmVhc2luZyB0b3dhcmRzIHRoZSBlZGdlcy4AAAAAAAVDdXJ2ZQAAAAAAAAEAAABGTW9zdCBvZiB0aGUgbGlxdWlkaXR5IGF0IHRoZSBlZGdlcywgZGVjcmVhc2luZyB0b3dhcmRzIHRoZSBhY3RpdmUgYmluLgAAAAAABkJpZEFzawAAAAAAAg==",
+        "AAAAAQAAAAAAAAAAAAAABkNvbmZpZwAAAAAACwAAAAAAAAAKYWN0aXZlX2JpbgAAAAAABQAAABJtYXkgYWRkIGluY2VudGl2ZXMAAAAAAAVhZG1pbgAAAAAAABMAAAAAAAAACGJpbl9zdGVwAAAABAAAAEV0aGUgZGVjaW1hbHMgb2YgdG9rZW4geCwgcmVhZCBmcm9tIHRoZSB0b2tlbiB3aGVuIHRoZSBwb29sIGlzIGNyZWF0ZWQAAAAAAAAKZGVjaW1hbHNfeAAAAAAABAAAAEV0aGUgZGVjaW1hbHMgb2YgdG9rZW4geSwgcmVhZCBmcm9tIHRoZSB0b2tlbiB3aGVuIHRoZSBwb29sIGlzIGNyZWF0ZWQAAAAAAAAKZGVjaW1hbHNfeQAAAAAABAAAAAAAAAADZmVlAAAAAAQAAAAhZmVlIGNoYXJnZWQgb24gZmxhc2ggbG9hbnMgaW4gYnBzAAAAAAAADmZsYXNoX2xvYW5fZmVlAAAAAAAEAAAAAAAAAAd0b2tlbl94AAAAABMAAAAAAAAAB3Rva2VuX3kAAAAAEwAAAFV0aGUgVFRMIGluIGxlZGdlcnMgdGhhdCBlbnRyaWVzIGFyZSBleHRlbmRlZCB0bywgY2FwcGVkIHRvIHRoZSBtYXggVFRMIG9mIHRoZSBuZXR3b3JrAAAAAAAADXR0bF9leHRlbmRfdG8AAAAAAAAEAAAAXGVudHJpZXMgd2l0aCBhIFRUTCBiZWxvdyB0aGlzIGFtb3VudCBvZiBsZWRnZXJzIGFyZSBleHRlbmRlZCB3aGVuIHRoZXkgYXJlIHdyaXR0ZW4gb3IgYnVtcGVkAAAADXR0bF90aHJlc2hvbGQAAAAAAAAE",
         "AAAAAQAAAAAAAAAAAAAACFBvc2l0aW9uAAAAAQAAAAAAAAAKYmluX3NoYXJlcwAAAAAD6gAAB9AAAAAJQmluU2hhcmVzAAAA",
-        "AAAABAAAAAAAAAAAAAAABUVycm9yAAAAAAAACQAAAAAAAAALRXJyTmVnYXRpdmUAAAAAAgAAAAAAAAANRXJyTWF0aEFwcHJveAAAAAAAABIAAAAAAAAADkVyckFkZE92ZXJmbG93AAAAAAAeAAAAAAAAAA9FcnJTdWJVbmRlcmZsb3cAAAAAHwAAAAAAAAAORXJyRGl2SW50ZXJuYWwAAAAAACAAAAAAAAAADkVyck11bE92ZXJmbG93AAAAAAAhAAAAAAAAABFFcnJDUG93QmFzZVRvb0xvdwAAAAAAACIAAAAAAAAAEkVyckNQb3dCYXNlVG9vSGlnaAAAAAAAIwAAAAAAAAARRXJyTmVnYXRpdmVPclplcm8AAAAAAAAl" ]),
+        "AAAAAQAAANpUaGUgbGltaXQgb3JkZXJzIGluIGEgYmluLgoKQWxsIG9yZGVycyBpbiBhIGJpbiBzZWxsIHRoZSBzYW1lIHRva2VuLiB4IGlmIHRoZSBiaW4gaXMgbGVmdCBvZiB0aGUgYWN0aXZlIGJpbiBhbmQgeSBpZiBpdCBpcyByaWdodCBvZiBpdC4KVGhlIG9yZGVycyBwbGFjZWQgaW4gYSBiaW4gYXJlIGZpbGxlZCB0b2dldGhlciwgYXMgb25lIGJhdGNoIHdpdGggaXRzIG93biBgZXBvY2hgLgAAAAAAAAAAAAhPcmRlckJpbgAAAAYAAAAAAAAABmJpbl9pZAAAAAAABQAAAAAAAAAFZXBvY2gAAAAAAAAEAAAAQXRoZSBhbW91bnQgb2YgdGhlIG90aGVyIHRva2VuIHJlY2VpdmVkIGZvciB0aGUgcGFydCB0aGF0IHdhcyBzb2xkAAAAAAAACHByb2NlZWRzAAAACwAAAC50aGUgcGFydCBvZiBgdG90YWxgIHRoYXQgaGFzIG5vdCBiZWVuIHNvbGQgeWV0AAAAAAAJcmVtYWluaW5nAAAAAAAACwAAAAAAAAAGc2VsbF94AAAAAAABAAAAIHN1bSBvZiB0aGUgYW1vdW50cyBvZiB0aGUgb3JkZXJzAAAABXRvdGFsAAAAAAAACw==",
+        "AAAAAQAAADtXaGF0IGlzIGxlZnQgdG8gY2xhaW0gb2YgYSBiYXRjaCBvZiBvcmRlcnMgdGhhdCB3YXMgZmlsbGVkLgAAAAAAAAAADEZpbGxlZE9yZGVycwAAAAIAAAAAAAAACHByb2NlZWRzAAAACwAAAAAAAAAFdG90YWwAAAAAAAAL",
+        "AAAAAQAAAAAAAAAAAAAACkxpbWl0T3JkZXIAAAAAAAQAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAAGYmluX2lkAAAAAAAFAAAAAAAAAAVlcG9jaAAAAAAAAAQAAAAAAAAABnNlbGxfeAAAAAAAAQ==",
+        "AAAAAQAAAS9UaGUgYW1vdW50cyBvZiB0aGUgdG9rZW5zIHRoYXQgdGhlIHBvb2wgb3dlcyB0byBMUHMgYW5kIGxpbWl0IG9yZGVycy4KClRoaXMgaXMgdGhlIHN1bSBvZiB0aGUgYmluIHJlc2VydmVzIGFuZCBvZiB0aGUgdG9rZW5zIGluIGxpbWl0IG9yZGVycywgaW5jbHVkaW5nIHRoZSBwcm9jZWVkcyB0aGF0IGhhdmUgbm90IGJlZW4gY2xhaW1lZCB5ZXQuClRva2VucyB0aGF0IGFyZSBzZW50IHRvIHRoZSBwb29sIHdpdGhvdXQgZ29pbmcgdGhyb3VnaCB0aGUgcG9vbCBhcmUgbm90IHBhcnQgb2YgaXQsIHNlZSBgc2tpbWAgYW5kIGBzeW5jYC4AAAAAAAAAAAhSZXNlcnZlcwAAAAIAAAAAAAAACXJlc2VydmVfeAAAAAAAAAsAAAAAAAAACXJlc2VydmVfeQAAAAAAAAs=",
+        "AAAAAQAAALBUaGUgc3VtcyBvZiB0aGUgcmVzZXJ2ZXMgYW5kIHNoYXJlcyBvZiBhbGwgYmlucy4KClRoZSBzdW0gb2YgdGhlIHNoYXJlcyBvZiBkaWZmZXJlbnQgYmlucyBkb2VzIG5vdCBtZWFuIG11Y2ggYnkgaXRzZWxmLCBpdCBpcyBrZXB0IHRvIGNoZWNrIHRoYXQgc2hhcmVzIGFyZSBub3QgY3JlYXRlZCBvciBsb3N0LgAAAAAAAAAJQmluVG90YWxzAAAAAAAAAwAAAAAAAAAPdG90YWxfcmVzZXJ2ZV94AAAAAAsAAAAAAAAAD3RvdGFsX3Jlc2VydmVfeQAAAAALAAAAAAAAAAx0b3RhbF9zaGFyZXMAAAAL",
+        "AAAAAgAAACtUaGUgYmlucyB0aGF0IGFuIGluY2VudGl2ZSBwYXlzIHJld2FyZHMgdG8uAAAAAAAAAAALUmV3YXJkUmFuZ2UAAAAAAgAAAAEAAABkdGhlIGJpbnMgd2l0aGluIHRoaXMgbWFueSBiaW5zIG9mIHRoZSBhY3RpdmUgYmluLCB3aGVyZXZlciB0aGUgYWN0aXZlIGJpbiBpcyB3aGVuIHRoZSByZXdhcmRzIGFjY3J1ZQAAAAZBY3RpdmUAAAAAAAEAAAAEAAAAAQAAADd0aGUgYmlucyBmcm9tIHRoZSBmaXJzdCB0byB0aGUgc2Vjb25kIGJpbiBpZCwgaW5jbHVzaXZlAAAAAARCaW5zAAAAAgAAAAUAAAAF",
+        "AAAAAQAAAUVSZXdhcmRzIG9mIGB0b2tlbmAgdGhhdCBhcmUgcGFpZCB0byB0aGUgTFBzIG9mIHRoZSBiaW5zIGluIGByYW5nZWAsIGZyb20gYHN0YXJ0YCB0byBgZW5kYC4KCmBhbW91bnRgIGlzIHBhaWQgYXQgdGhlIHNhbWUgcmF0ZSBvdmVyIHRoZSB3aG9sZSB0aW1lLCBhbmQgZGl2aWRlZCBlcXVhbGx5IG92ZXIgdGhlIGJpbnMgaW4gdGhlIHJhbmdlIHRoYXQgaGF2ZSBzaGFyZXMuClJld2FyZHMgZm9yIGEgdGltZSB3aXRob3V0IHNoYXJlcyBpbiB0aGUgcmFuZ2UgYXJlIG5vdCBwYWlkLCBhbmQgZ28gYmFjayB0byB0aGUgbWFuYWdlciB3aXRoIGByZWNsYWltX2luY2VudGl2ZWAuAAAAAAAAAAAAAAlJbmNlbnRpdmUAAAAAAAAIAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAsdGhlIHBhcnQgb2YgYGFtb3VudGAgdGhhdCB3YXMgY2xhaW1lZCBieSBMUHMAAAAHY2xhaW1lZAAAAAALAAAAEGxlZGdlciB0aW1lc3RhbXAAAAADZW5kAAAAAAYAAAA+dGhlIHJld2FyZHMgdW50aWwgdGhpcyBsZWRnZXIgdGltZXN0YW1wIHdlcmUgYWRkZWQgdG8gdGhlIGJpbnMAAAAAAAtsYXN0X3VwZGF0ZQAAAAAGAAAAAAAAAAdtYW5hZ2VyAAAAABMAAAAAAAAABXJhbmdlAAAAAAAH0AAAAAtSZXdhcmRSYW5nZQAAAAAQbGVkZ2VyIHRpbWVzdGFtcAAAAAVzdGFydAAAAAAAAAYAAAAAAAAABXRva2VuAAAAAAAAEw==",
+        "AAAAAQAAABpUaGUgcmV3YXJkcyBvZiBhIHBvc2l0aW9uLgAAAAAAAAAAAA9Qb3NpdGlvblJld2FyZHMAAAAAAgAAAGJwZXIgaW5jZW50aXZlLCB0aGUgcmV3YXJkIHBlciBzaGFyZSBvZiB0aGUgYmlucyBvZiB0aGUgcG9zaXRpb24gd2hlbiBpdHMgcmV3YXJkcyB3ZXJlIGxhc3Qgc2V0dGxlZAAAAAAAC2NoZWNrcG9pbnRzAAAAA+wAAAAEAAAD7AAAAAUAAAALAAAAQHBlciBpbmNlbnRpdmUsIHRoZSByZXdhcmRzIHRoYXQgd2VyZSBzZXR0bGVkIGJ1dCBub3QgY2xhaW1lZCB5ZXQAAAAHcGVuZGluZwAAAAPsAAAABAAAAAs=",
+        "AAAABAAAAAAAAAAAAAAABUVycm9yAAAAAAAADgAAAAAAAAALRXJyTmVnYXRpdmUAAAAAAgAAAAAAAAANRXJyTWF0aEFwcHJveAAAAAAAABIAAAAAAAAADkVyckFkZE92ZXJmbG93AAAAAAAeAAAAAAAAAA9FcnJTdWJVbmRlcmZsb3cAAAAAHwAAAAAAAAAORXJyRGl2SW50ZXJuYWwAAAAAACAAAAAAAAAADkVyck11bE92ZXJmbG93AAAAAAAhAAAAAAAAABFFcnJDUG93QmFzZVRvb0xvdwAAAAAAACIAAAAAAAAAEkVyckNQb3dCYXNlVG9vSGlnaAAAAAAAIwAAAAAAAAARRXJyTmVnYXRpdmVPclplcm8AAAAAAAAlAAAAAAAAABJFcnJCaW5JZE91dE9mUmFuZ2UAAAAAACgAAAAAAAAAEkVyclByaWNlT3V0T2ZSYW5nZQAAAAAAKQAAAAAAAAARRXJySW52YWxpZEJpblN0ZXAAAAAAAAAqAAAAAAAAABlFcnJUcmFuc2ZlckFtb3VudE1pc21hdGNoAAAAAAAAKwAAAAAAAAAPRXJySW52YWxpZFdpZHRoAAAAACw=",
+        "AAAAAQAAAIRUaGUgY2hhbmdlIG9mIG9uZSBiaW4gaW4gYSBgbGlxdWlkaXR5YCBldmVudC4KUG9zaXRpdmUgYW1vdW50cyBhbmQgc2hhcmVzIHdlcmUgYWRkZWQgdG8gdGhlIGJpbiwgbmVnYXRpdmUgb25lcyB3ZXJlIHJlbW92ZWQgZnJvbSBpdC4AAAAAAAAACUJpbkNoYW5nZQAAAAAAAAQAAAAAAAAACGFtb3VudF94AAAACwAAAAAAAAAIYW1vdW50X3kAAAALAAAAAAAAAAZiaW5faWQAAAAAAAUAAAAAAAAABnNoYXJlcwAAAAAACw==",
+        "AAAAAQAAAD1UaGUgcGFydCBvZiBhIHN3YXAgdGhhdCB3ZW50IHRocm91Z2ggdGhlIHJlc2VydmVzIG9mIG9uZSBiaW4uAAAAAAAAAAAAAAdCaW5Td2FwAAAAAAMAAAA6dGhlIGlucHV0IHRoYXQgd2FzIGFkZGVkIHRvIHRoZSByZXNlcnZlLCBpbmNsdWRpbmcgdGhlIGZlZQAAAAAACWFtb3VudF9pbgAAAAAAAAsAAAAqdGhlIG91dHB1dCB0aGF0IHdhcyB0YWtlbiBmcm9tIHRoZSByZXNlcnZlAAAAAAAKYW1vdW50X291dAAAAAAACwAAAAAAAAAGYmluX2lkAAAAAAAF",
+        "AAAAAQAAAAAAAAAAAAAACVN3YXBFdmVudAAAAAAAAAUAAAAddGhlIGFjdGl2ZSBiaW4gYWZ0ZXIgdGhlIHN3YXAAAAAAAAAKYWN0aXZlX2JpbgAAAAAABQAAAAAAAAAJYW1vdW50X2luAAAAAAAACwAAAAAAAAAKYW1vdW50X291dAAAAAAACwAAAJF0aGUgYmlucyB0aGF0IHdlcmUgc3dhcHBlZCBhZ2FpbnN0LCBpbiB0aGUgb3JkZXIgb2YgdGhlIHN3YXAuClRoZSBpbnB1dCBhbmQgb3V0cHV0IHRoYXQgYXJlIG5vdCBpbiB0aGVzZSBiaW5zIHdlbnQgdG8gYW5kIGNhbWUgZnJvbSBsaW1pdCBvcmRlcnMuAAAAAAAABGJpbnMAAAPqAAAH0AAAAAdCaW5Td2FwAAAAAAAAAAAHaXNfeF9pbgAAAAAB" ]),
       options
     )
   }
   public readonly fromJSON = {
     modify_liquidity: this.txFromJSON<readonly [i128, i128]>,
+        modify_liquidity_to: this.txFromJSON<readonly [i128, i128]>,
+        remove_position_pct: this.txFromJSON<readonly [i128, i128]>,
+        rebalance_position: this.txFromJSON<readonly [i128, i128]>,
+        batch_transfer: this.txFromJSON<null>,
+        batch_transfer_from: this.txFromJSON<null>,
+        set_approval_for_all: this.txFromJSON<null>,
         swap_exact_amount_in: this.txFromJSON<i128>,
+        swap_exact_amount_in_to: this.txFromJSON<i128>,
+        quote_exact_amount_in: this.txFromJSON<i128>,
+        flash_swap: this.txFromJSON<i128>,
+        flash_loan: this.txFromJSON<i128>,
+        place_limit_order: this.txFromJSON<null>,
+        cancel_limit_order: this.txFromJSON<readonly [i128, i128]>,
+        claim_limit_order: this.txFromJSON<i128>,
+        add_incentive: this.txFromJSON<u32>,
+        claim_rewards: this.txFromJSON<i128>,
+        reclaim_incentive: this.txFromJSON<i128>,
+        skim: this.txFromJSON<readonly [i128, i128]>,
+        sync: this.txFromJSON<readonly [i128, i128]>,
+        bump: this.txFromJSON<null>,
+        get_bin: this.txFromJSON<Bin>,
+        get_bins_range: this.txFromJSON<Array<BinInfo>>,
+        get_active_bin_reserves: this.txFromJSON<BinInfo>,
+        get_price_from_id: this.txFromJSON<u256>,
+        get_id_from_price: this.txFromJSON<i32>,
         get_bin_vec: this.txFromJSON<Array<Bin>>,
         get_shares_vec: this.txFromJSON<Array<BinShares>>,
         get_position: this.txFromJSON<Option<Position>>,
+        get_position_ids: this.txFromJSON<Array<i32>>,
+        balance_of: this.txFromJSON<i128>,
+        balance_of_batch: this.txFromJSON<Array<i128>>,
+        is_approved_for_all: this.txFromJSON<boolean>,
+        get_incentive: this.txFromJSON<Option<Incentive>>,
+        get_incentives: this.txFromJSON<Array<u32>>,
+        get_pending_rewards: this.txFromJSON<Array<readonly [u32, i128]>>,
+        get_limit_order: this.txFromJSON<Option<LimitOrder>>,
+        get_order_bin: this.txFromJSON<Option<OrderBin>>,
+        get_human_price_from_id: this.txFromJSON<u256>,
+        get_id_from_human_price: this.txFromJSON<i32>,
+        get_supported_bins: this.txFromJSON<readonly [i32, i32]>,
+        get_reserves: this.txFromJSON<Reserves>,
+        get_bin_totals: this.txFromJSON<BinTotals>,
         get_config: this.txFromJSON<Config>
   }
 }
\ No newline at end of file
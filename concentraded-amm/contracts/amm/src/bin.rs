@@ -1,6 +1,10 @@
-use crate::constants::BIN_VEC_SIZE;
-use crate::storage::{get_vec_id_for_bin, Bin, BinShares, Position};
-use soroban_sdk::{Env, TryFromVal, Val, Vec};
+use crate::bitmap::{has_vec, next_vec};
+use crate::constants::{BIN_VEC_SIZE, MAX_LOSS_VECS};
+use crate::price::{price_from_bin, price_to_host};
+use crate::events::BinChange;
+use crate::storage::{add_to_bin_totals, get_bin_vec_or_default, get_shares_vec_or_default, get_vec_id_for_bin, store_bin_vec, Bin, BinInfo, BinShares, Config, Position, VecIndex};
+use soroban_fixed_point_math::SorobanFixedPoint;
+use soroban_sdk::{vec, Address, Env, TryFromVal, Val, Vec};
 
 pub fn is_bin_in_vec(bin_id: i32, vec_id: i32) -> bool {
     get_vec_id_for_bin(bin_id) == vec_id
@@ -15,7 +19,7 @@ pub fn default_bin_vec(env: &Env, vec_id: i32) -> Vec<Bin>
         array[i as usize].bin_id = bin_id_at_zero + i;
     }
     
-    Vec::from_array(&env, array)
+    Vec::from_array(env, array)
 }
 
 pub fn default_shares_vec(env: &Env, vec_id: i32) -> Vec<BinShares>
@@ -27,13 +31,11 @@ pub fn default_shares_vec(env: &Env, vec_id: i32) -> Vec<BinShares>
         array[i as usize].bin_id = bin_id_at_zero + i;
     }
 
-    Vec::from_array(&env, array)
+    Vec::from_array(env, array)
 }
 
 pub fn get_bin_position_in_vec(bin_id: i32) -> u32 {
-    let r = bin_id % BIN_VEC_SIZE;
-
-    (if r < 0 { r + BIN_VEC_SIZE } else { r }) as u32
+    bin_id.rem_euclid(BIN_VEC_SIZE) as u32
 }
 
 pub fn get_bin_from_vec<T>(vec: &Vec<T>, bin_id: i32) -> T
@@ -52,34 +54,44 @@ where
     vec.set(get_bin_position_in_vec(bin_id), bin)
 }
 
+pub fn bin_info(env: &Env, bin_step: u32, bin: &Bin, bin_shares: &BinShares) -> BinInfo {
+    BinInfo {
+        bin_id: bin.bin_id,
+        reserve_x: bin.reserve_x,
+        reserve_y: bin.reserve_y,
+        shares: bin_shares.shares,
+        price: price_to_host(env, price_from_bin(bin_step, bin.bin_id)),
+    }
+}
+
 /// We check if all the elements have no deposits
 pub fn is_bin_vec_empty(vec: &Vec<Bin>) -> bool {
     vec.iter()
         .all(|bin| bin.reserve_x == 0 && bin.reserve_y == 0)
 }
+
+/// We check if all the elements have no shares
+pub fn is_shares_vec_empty(vec: &Vec<BinShares>) -> bool {
+    vec.iter()
+        .all(|bin_shares| bin_shares.shares == 0)
+}
 pub fn get_shares_from_position(position: &Position, bin_id: i32) -> BinShares {
-    let res = position
+    position
         .bin_shares
         .iter().find(|shares| shares.bin_id == bin_id)
-        .unwrap_or(BinShares { bin_id, shares: 0 });
-
-    res
+        .unwrap_or(BinShares { bin_id, shares: 0 })
 }
 
 pub fn store_shares_in_position(position: &mut Position, bin_shares: BinShares) {
     let res = position.bin_shares
         .iter().position(|shares| shares.bin_id >= bin_shares.bin_id);
 
-    let ele = if res.is_some() {
-        Some(position.bin_shares.get_unchecked(res.unwrap() as u32))
-    }else {None};
-
-    if ele.is_some(){
-        let unwrapped = ele.unwrap();
-        if unwrapped.bin_id == bin_shares.bin_id {
-            position.bin_shares.set(res.unwrap() as u32, bin_shares);
+    if let Some(index) = res {
+        let ele = position.bin_shares.get_unchecked(index as u32);
+        if ele.bin_id == bin_shares.bin_id {
+            position.bin_shares.set(index as u32, bin_shares);
         }else{
-            position.bin_shares.insert(res.unwrap() as u32, bin_shares);
+            position.bin_shares.insert(index as u32, bin_shares);
         }
     } else {
         position.bin_shares.push_back(bin_shares);
@@ -91,7 +103,134 @@ pub fn delete_shares_in_position(position: &mut Position, bin_shares: BinShares)
         .bin_shares
         .iter().position(|shares| shares.bin_id == bin_shares.bin_id);
 
-    if res.is_some() {
-        position.bin_shares.remove(res.unwrap() as u32);
+    if let Some(index) = res {
+        position.bin_shares.remove(index as u32);
+    }
+}
+/// Panics if the active bin has no shares, as what is added to it would go to the next LP of the bin.
+pub fn assert_active_bin_has_shares(env: &Env, config: &Config) {
+    let shares = get_bin_from_vec(&get_shares_vec_or_default(env, get_vec_id_for_bin(config.active_bin)), config.active_bin).shares;
+    assert!(shares > 0, "No liquidity in active bin");
+}
+
+/// Adds `amount` of `token` to the reserves of the active bin, so it goes to the LPs of that bin.
+///
+/// Used for the flash loan fees and for tokens that are added with `sync`.
+pub fn add_to_active_bin(env: &Env, config: &Config, token: &Address, amount: i128) {
+    let vec_id = get_vec_id_for_bin(config.active_bin);
+    let mut bin_vec = get_bin_vec_or_default(env, vec_id, config.active_bin);
+    let mut bin = get_bin_from_vec(&bin_vec, config.active_bin);
+
+    if *token == config.token_x {
+        bin.reserve_x += amount;
+        add_to_bin_totals(env, amount, 0, 0);
+    } else {
+        bin.reserve_y += amount;
+        add_to_bin_totals(env, 0, amount, 0);
+    }
+
+    store_bin_in_vec(&mut bin_vec, config.active_bin, bin);
+    store_bin_vec(env, vec_id, &bin_vec, config.active_bin);
+}
+
+/// Takes `loss_x` and `loss_y` from the reserves of the bins in the `MAX_LOSS_VECS` vecs with liquidity that are nearest to the active bin,
+/// pro rata to what each bin holds.
+///
+/// Used by `sync` when the balance of the pool dropped below its reserves, for example after a token rebased down.
+/// Rounds down per bin and takes the rest from the bin that holds the most, so the bins lose exactly the loss.
+/// Fails if that would empty a bin, so a bin with shares always keeps its reserves. Returns the changes of the bins, with negative amounts.
+pub fn take_from_bins(env: &Env, config: &Config, loss_x: i128, loss_y: i128) -> Vec<BinChange> {
+    let bin_vecs = nearest_bin_vecs(env, config, MAX_LOSS_VECS);
+    let (mut base_x, mut base_y) = (0, 0);
+    let (mut largest_x, mut largest_y) = (Bin::default(), Bin::default());
+    for (_, bin_vec) in bin_vecs.iter() {
+        for bin in bin_vec.iter() {
+            base_x += bin.reserve_x;
+            base_y += bin.reserve_y;
+            if bin.reserve_x > largest_x.reserve_x {
+                largest_x = bin;
+            }
+            if bin.reserve_y > largest_y.reserve_y {
+                largest_y = bin;
+            }
+        }
+    }
+    assert!((loss_x == 0 || loss_x < base_x) && (loss_y == 0 || loss_y < base_y), "Shortfall exceeds the liquidity");
+
+    // what rounding down leaves of the loss
+    let (mut rest_x, mut rest_y) = (loss_x, loss_y);
+    for (_, bin_vec) in bin_vecs.iter() {
+        for bin in bin_vec.iter() {
+            rest_x -= share_of_loss(env, bin.reserve_x, loss_x, base_x);
+            rest_y -= share_of_loss(env, bin.reserve_y, loss_y, base_y);
+        }
+    }
+
+    let mut changes = vec![env];
+    for (vec_id, mut bin_vec) in bin_vecs.iter() {
+        for i in 0..bin_vec.len() {
+            let mut bin = bin_vec.get_unchecked(i);
+            let mut x = share_of_loss(env, bin.reserve_x, loss_x, base_x);
+            let mut y = share_of_loss(env, bin.reserve_y, loss_y, base_y);
+            if bin.reserve_x > 0 && bin.bin_id == largest_x.bin_id {
+                x += rest_x;
+            }
+            if bin.reserve_y > 0 && bin.bin_id == largest_y.bin_id {
+                y += rest_y;
+            }
+            if x == 0 && y == 0 {
+                continue;
+            }
+            assert!((x == 0 || x < bin.reserve_x) && (y == 0 || y < bin.reserve_y), "Shortfall exceeds the liquidity");
+
+            bin.reserve_x -= x;
+            bin.reserve_y -= y;
+            changes.push_back(BinChange { bin_id: bin.bin_id, amount_x: -x, amount_y: -y, shares: 0 });
+            bin_vec.set(i, bin);
+        }
+
+        store_bin_vec(env, vec_id, &bin_vec, config.active_bin);
     }
-}
\ No newline at end of file
+
+    add_to_bin_totals(env, -loss_x, -loss_y, 0);
+    changes
+}
+
+/// returns up to `count` vecs with liquidity with their ids, the vec of the active bin first and then the nearest vec on either side
+fn nearest_bin_vecs(env: &Env, config: &Config, count: u32) -> Vec<(i32, Vec<Bin>)> {
+    let active_vec_id = get_vec_id_for_bin(config.active_bin);
+    let mut vec_ids = vec![env];
+    if has_vec(env, VecIndex::Bins, active_vec_id) {
+        vec_ids.push_back(active_vec_id);
+    }
+
+    let mut below = next_vec(env, VecIndex::Bins, active_vec_id, -1);
+    let mut above = next_vec(env, VecIndex::Bins, active_vec_id, 1);
+    while vec_ids.len() < count {
+        let vec_id = match (below, above) {
+            (Some(b), a) if a.is_none_or(|a| active_vec_id - b <= a - active_vec_id) => {
+                below = next_vec(env, VecIndex::Bins, b, -1);
+                b
+            }
+            (_, Some(a)) => {
+                above = next_vec(env, VecIndex::Bins, a, 1);
+                a
+            }
+            _ => break,
+        };
+        vec_ids.push_back(vec_id);
+    }
+
+    let mut bin_vecs = vec![env];
+    for vec_id in vec_ids.iter() {
+        bin_vecs.push_back((vec_id, get_bin_vec_or_default(env, vec_id, config.active_bin)));
+    }
+    bin_vecs
+}
+
+fn share_of_loss(env: &Env, reserve: i128, loss: i128, total: i128) -> i128 {
+    if reserve == 0 || loss <= 0 {
+        return 0;
+    }
+    reserve.fixed_mul_floor(env, &loss, &total)
+}
@@ -0,0 +1,108 @@
+use crate::storage::{set_persistent, DataKey, VecIndex};
+use soroban_sdk::Env;
+
+// The vecs in a bitmap are kept in two levels of 128 bit words in persistent storage.
+// A bit of the top level is set when the word of the lower level with that index has any bit set,
+// so the next vec in a bitmap is found with at most a few reads, however far it is.
+
+const WORD_BITS: u32 = 128;
+const LEAF: u32 = 0;
+const TOP: u32 = 1;
+/// Vec ids are shifted by this to get their bit. The vecs of the supported bins of every bin step are within it, see `price::get_max_bin_id`.
+pub const VEC_ID_OFFSET: i32 = 1 << 14;
+const TOP_WORDS: u32 = (2 * VEC_ID_OFFSET) as u32 / WORD_BITS / WORD_BITS;
+
+/// returns the keys of the words that hold the bit of `vec_id` in the bitmap `index`, they may not exist
+pub fn bitmap_keys(index: VecIndex, vec_id: i32) -> [DataKey; 2] {
+    let word = bit_of(vec_id) / WORD_BITS;
+    [DataKey::VecBitmap(index, LEAF, word), DataKey::VecBitmap(index, TOP, word / WORD_BITS)]
+}
+
+pub fn has_vec(env: &Env, index: VecIndex, vec_id: i32) -> bool {
+    let bit = bit_of(vec_id);
+    get_word(env, index, LEAF, bit / WORD_BITS) & (1 << (bit % WORD_BITS)) != 0
+}
+
+/// Sets or clears the bit of `vec_id` in the bitmap `index`. Words without bits are removed instead of stored.
+pub fn set_vec(env: &Env, index: VecIndex, vec_id: i32, is_set: bool) {
+    let bit = bit_of(vec_id);
+    let word = bit / WORD_BITS;
+    let bits = get_word(env, index, LEAF, word);
+    let new_bits = if is_set { bits | (1 << (bit % WORD_BITS)) } else { bits & !(1 << (bit % WORD_BITS)) };
+    if new_bits == bits {
+        return;
+    }
+    store_word(env, index, LEAF, word, new_bits);
+
+    if (bits == 0) != (new_bits == 0) {
+        let top_bits = get_word(env, index, TOP, word / WORD_BITS);
+        let top_bit = 1 << (word % WORD_BITS);
+        store_word(env, index, TOP, word / WORD_BITS, if new_bits != 0 { top_bits | top_bit } else { top_bits & !top_bit });
+    }
+}
+
+/// returns the first vec after `vec_id` in the direction of `step` that is set in the bitmap `index`
+pub fn next_vec(env: &Env, index: VecIndex, vec_id: i32, step: i32) -> Option<i32> {
+    let bit = bit_of(vec_id);
+    let word = bit / WORD_BITS;
+
+    let bits = beyond(get_word(env, index, LEAF, word), bit % WORD_BITS, step);
+    if bits != 0 {
+        return Some(vec_of(word * WORD_BITS + nearest(bits, step)));
+    }
+
+    // the next word with bits, from the top level
+    let mut top_word = word / WORD_BITS;
+    let mut top_bits = beyond(get_word(env, index, TOP, top_word), word % WORD_BITS, step);
+    while top_bits == 0 {
+        top_word = top_word.checked_add_signed(step)?;
+        if top_word >= TOP_WORDS {
+            return None;
+        }
+        top_bits = get_word(env, index, TOP, top_word);
+    }
+
+    let word = top_word * WORD_BITS + nearest(top_bits, step);
+    let bits = get_word(env, index, LEAF, word);
+    Some(vec_of(word * WORD_BITS + nearest(bits, step)))
+}
+
+fn bit_of(vec_id: i32) -> u32 {
+    assert!((-VEC_ID_OFFSET..VEC_ID_OFFSET).contains(&vec_id), "Vec id out of range");
+    (vec_id + VEC_ID_OFFSET) as u32
+}
+
+fn vec_of(bit: u32) -> i32 {
+    bit as i32 - VEC_ID_OFFSET
+}
+
+/// the bits of `bits` after bit `bit` in the direction of `step`
+fn beyond(bits: u128, bit: u32, step: i32) -> u128 {
+    if step > 0 {
+        bits & (u128::MAX << bit << 1)
+    } else {
+        bits & ((1u128 << bit) - 1)
+    }
+}
+
+/// the lowest set bit for a positive `step`, the highest otherwise. `bits` must not be 0
+fn nearest(bits: u128, step: i32) -> u32 {
+    if step > 0 {
+        bits.trailing_zeros()
+    } else {
+        WORD_BITS - 1 - bits.leading_zeros()
+    }
+}
+
+fn get_word(env: &Env, index: VecIndex, level: u32, word: u32) -> u128 {
+    env.storage().persistent().get(&DataKey::VecBitmap(index, level, word)).unwrap_or(0)
+}
+
+fn store_word(env: &Env, index: VecIndex, level: u32, word: u32, bits: u128) {
+    let key = DataKey::VecBitmap(index, level, word);
+    if bits == 0 {
+        env.storage().persistent().remove(&key);
+    } else {
+        set_persistent(env, &key, &bits);
+    }
+}
@@ -1 +1,19 @@
-pub const BIN_VEC_SIZE: i32 = 75i32;
\ No newline at end of file
+pub use amm_math::constants::MAX_BPS;
+
+pub const BIN_VEC_SIZE: i32 = 75i32;
+/// the maximum amount of bins that can be read with `get_bins_range`
+pub const MAX_BINS_IN_RANGE: i32 = 4 * BIN_VEC_SIZE;
+/// the most bins on each side of the active bin that `rebalance_position` spreads a position over
+pub const MAX_WIDTH: u32 = 50;
+/// the most vecs with liquidity, nearest to the active bin, that `sync` takes a shortfall from, so its cost is bounded
+pub const MAX_LOSS_VECS: u32 = 8;
+/// the reward per share of a bin is scaled by this, so that bins with many shares still earn rewards
+pub const REWARD_SCALE: i128 = 1_000_000_000_000_000_000;
+/// the most incentives that a pool has at once. Every swap and change of liquidity updates all of them
+pub const MAX_INCENTIVES: u32 = 5;
+/// the most bins on each side of the active bin that an incentive pays. A fixed range has at most as many bins as that
+pub const MAX_REWARD_WIDTH: u32 = 50;
+/// the longest time in seconds from now that an incentive can end, so `end` stays far from the max timestamp
+pub const MAX_INCENTIVE_DURATION: u64 = 365 * 24 * 60 * 60;
+/// the time in seconds after the end of an incentive that LPs have to claim, before the manager can reclaim what is left
+pub const REWARD_CLAIM_PERIOD: u64 = 30 * 24 * 60 * 60;
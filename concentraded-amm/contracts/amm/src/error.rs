@@ -3,6 +3,7 @@ use soroban_sdk::contracterror;
 #[contracterror]
 #[derive(Copy, Clone, Debug, PartialEq)]
 #[repr(u32)]
+#[allow(clippy::enum_variant_names)]
 pub enum Error {
     ErrNegative = 2,
     ErrMathApprox = 18,
@@ -13,4 +14,9 @@ pub enum Error {
     ErrCPowBaseTooLow = 34,
     ErrCPowBaseTooHigh = 35,
     ErrNegativeOrZero = 37,
+    ErrBinIdOutOfRange = 40,
+    ErrPriceOutOfRange = 41,
+    ErrInvalidBinStep = 42,
+    ErrTransferAmountMismatch = 43,
+    ErrInvalidWidth = 44,
 }
\ No newline at end of file
@@ -0,0 +1,93 @@
+use crate::storage::{BinShares, Config, Incentive};
+use soroban_sdk::{contracttype, symbol_short, Address, Env, Symbol, Vec};
+
+/// The change of one bin in a `liquidity` event.
+/// Positive amounts and shares were added to the bin, negative ones were removed from it.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct BinChange {
+    pub bin_id: i32,
+    pub amount_x: i128,
+    pub amount_y: i128,
+    pub shares: i128,
+}
+
+/// The part of a swap that went through the reserves of one bin.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct BinSwap {
+    pub bin_id: i32,
+    /// the input that was added to the reserve, including the fee
+    pub amount_in: i128,
+    /// the output that was taken from the reserve
+    pub amount_out: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct SwapEvent {
+    pub is_x_in: bool,
+    pub amount_in: i128,
+    pub amount_out: i128,
+    /// the active bin after the swap
+    pub active_bin: i32,
+    /// the bins that were swapped against, in the order of the swap.
+    /// The input and output that are not in these bins went to and came from limit orders.
+    pub bins: Vec<BinSwap>,
+}
+
+/// topics `["created"]`, data the config of the pool
+pub fn created(env: &Env, config: &Config) {
+    env.events().publish((symbol_short!("created"),), config.clone());
+}
+
+/// topics `["liquidity", owner, position_id]`, data the changes of the bins of the position
+pub fn liquidity(env: &Env, owner: &Address, position_id: i32, changes: Vec<BinChange>) {
+    env.events().publish((symbol_short!("liquidity"), owner.clone(), position_id), changes);
+}
+
+/// topics `["batch_transfer", from, to, position_id]`, data the shares that moved from the position of `from` to the position of `to`.
+/// Not named `transfer`, so it can't be taken for a transfer of a token.
+pub fn batch_transfer(env: &Env, from: &Address, to: &Address, position_id: i32, shares: Vec<BinShares>) {
+    env.events().publish((Symbol::new(env, "batch_transfer"), from.clone(), to.clone(), position_id), shares);
+}
+
+/// topics `["approval", owner, operator]`, data whether `operator` may transfer all shares of `owner`
+pub fn approval(env: &Env, owner: &Address, operator: &Address, approved: bool) {
+    env.events().publish((symbol_short!("approval"), owner.clone(), operator.clone()), approved);
+}
+
+/// topics `["swap", from]`, data a `SwapEvent`
+pub fn swap(env: &Env, from: &Address, swap: SwapEvent) {
+    env.events().publish((symbol_short!("swap"), from.clone()), swap);
+}
+
+/// topics `["flashloan", receiver, token]`, data `(amount, fee, bin_id)`. The fee was added to the bin.
+pub fn flash_loan(env: &Env, receiver: &Address, token: &Address, amount: i128, fee: i128, bin_id: i32) {
+    env.events().publish((symbol_short!("flashloan"), receiver.clone(), token.clone()), (amount, fee, bin_id));
+}
+
+/// topics `["sync"]`, data `(amount_x, amount_y, bin_id)`. The amounts were added to the bin.
+pub fn sync(env: &Env, amount_x: i128, amount_y: i128, bin_id: i32) {
+    env.events().publish((symbol_short!("sync"),), (amount_x, amount_y, bin_id));
+}
+
+/// topics `["loss"]`, data the changes of the bins that the balance shortfall was taken from, with negative amounts
+pub fn loss(env: &Env, changes: Vec<BinChange>) {
+    env.events().publish((symbol_short!("loss"),), changes);
+}
+
+/// topics `["incentive", id]`, data the `Incentive` that was added
+pub fn incentive(env: &Env, id: u32, incentive: &Incentive) {
+    env.events().publish((symbol_short!("incentive"), id), incentive.clone());
+}
+
+/// topics `["rewards", owner, position_id]`, data the rewards that were claimed: `(incentive_id, amount)`
+pub fn rewards(env: &Env, owner: &Address, position_id: i32, incentive_id: u32, amount: i128) {
+    env.events().publish((symbol_short!("rewards"), owner.clone(), position_id), (incentive_id, amount));
+}
+
+/// topics `["reclaim", id]`, data the amount that went back to the manager of the incentive
+pub fn reclaim(env: &Env, id: u32, amount: i128) {
+    env.events().publish((symbol_short!("reclaim"), id), amount);
+}
@@ -0,0 +1,27 @@
+use crate::constants::MAX_BPS;
+use crate::storage::Config;
+use soroban_fixed_point_math::SorobanFixedPoint;
+use soroban_sdk::{contractclient, Address, Bytes, Env};
+
+/// The interface that a contract must implement to receive a flash loan.
+///
+/// `on_flash_loan` is called after `amount` of `token` is transferred to the receiver.
+/// Before returning, the receiver must transfer `amount + fee` of `token` back to the pool.
+#[contractclient(name = "FlashLoanReceiverClient")]
+pub trait FlashLoanReceiver {
+    fn on_flash_loan(env: Env, token: Address, amount: i128, fee: i128, data: Bytes);
+}
+
+/// The interface that a contract must implement to receive the output of a flash swap.
+///
+/// `on_flash_swap` is called after `amount_out` of `out_token` is transferred to the receiver.
+/// Before returning, the receiver must transfer `amount_in` of `in_token` to the pool.
+#[contractclient(name = "FlashSwapReceiverClient")]
+pub trait FlashSwapReceiver {
+    fn on_flash_swap(env: Env, in_token: Address, amount_in: i128, out_token: Address, amount_out: i128, data: Bytes);
+}
+
+/// The fee for borrowing `amount`, rounded up.
+pub fn flash_loan_fee(env: &Env, config: &Config, amount: i128) -> i128 {
+    amount.fixed_mul_ceil(env, &(config.flash_loan_fee as i128), &(MAX_BPS as i128))
+}
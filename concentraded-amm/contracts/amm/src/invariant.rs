@@ -0,0 +1,47 @@
+use crate::bitmap::{has_vec, next_vec, VEC_ID_OFFSET};
+use crate::storage::{get_bin_totals, get_bin_vec_or_default, get_config, get_reserves, get_shares_vec_or_default, VecIndex};
+use crate::token::balance;
+use soroban_sdk::Env;
+
+/// Checks that the bin totals match the bins and that the pool holds enough tokens:
+/// the sum of the bin reserves == the bin totals <= the reserves <= the token balances.
+///
+/// Reading every vec with liquidity is expensive, so this only runs with debug assertions, which are enabled in tests
+/// and in the `release-with-logs` profile.
+pub fn assert_invariants(env: &Env) {
+    if !cfg!(debug_assertions) {
+        return;
+    }
+
+    let config = get_config(env);
+    let totals = get_bin_totals(env);
+
+    let mut sum_x = 0;
+    let mut sum_y = 0;
+    let mut sum_shares = 0;
+
+    // bins with shares always have reserves, so the vecs with liquidity also have all of the shares
+    let first_vec_id = -VEC_ID_OFFSET;
+    let mut next = if has_vec(env, VecIndex::Bins, first_vec_id) { Some(first_vec_id) } else { next_vec(env, VecIndex::Bins, first_vec_id, 1) };
+    while let Some(vec_id) = next {
+        for bin in get_bin_vec_or_default(env, vec_id, config.active_bin).iter() {
+            sum_x += bin.reserve_x;
+            sum_y += bin.reserve_y;
+        }
+        for bin_shares in get_shares_vec_or_default(env, vec_id).iter() {
+            sum_shares += bin_shares.shares;
+        }
+        next = next_vec(env, VecIndex::Bins, vec_id, 1);
+    }
+
+    assert_eq!(sum_x, totals.total_reserve_x, "Bin totals of x don't match the bins");
+    assert_eq!(sum_y, totals.total_reserve_y, "Bin totals of y don't match the bins");
+    assert_eq!(sum_shares, totals.total_shares, "Bin totals of shares don't match the bins");
+
+    let reserves = get_reserves(env);
+    assert!(totals.total_reserve_x <= reserves.reserve_x && totals.total_reserve_y <= reserves.reserve_y, "Bin totals exceed the reserves");
+
+    let pool = env.current_contract_address();
+    assert!(reserves.reserve_x <= balance(env, config.token_x, pool.clone()), "Reserves of x exceed the balance");
+    assert!(reserves.reserve_y <= balance(env, config.token_y, pool), "Reserves of y exceed the balance");
+}
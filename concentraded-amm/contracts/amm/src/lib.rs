@@ -1,22 +1,42 @@
 #![no_std]
-
-use crate::bin::{delete_shares_in_position, get_bin_from_vec, get_bin_position_in_vec, get_shares_from_position, is_bin_in_vec, store_bin_in_vec, store_shares_in_position};
-use crate::math::{downscale_ceil, downscale_floor, upscale, BONE, FEE_SCALAR};
-use crate::price::price_from_bin_and_token;
-use crate::shares::calculate_shares_to_mint;
-use crate::storage::{get_bin_vec_or_default, get_config, get_position, get_position_or_default, get_shares_vec_or_default, get_vec_id_for_bin, store_bin_vec, store_config, store_position, store_shares_vec, Bin, BinShares, Config, DataKey, DepositArgs, Position};
-use crate::token::transfer;
+// contract entrypoints take their arguments flat, and the generated client repeats them.
+#![allow(clippy::too_many_arguments)]
+
+use crate::constants::{MAX_BINS_IN_RANGE, MAX_BPS, MAX_WIDTH, MAX_INCENTIVE_DURATION, MAX_INCENTIVES, REWARD_CLAIM_PERIOD};
+use crate::bin::{add_to_active_bin, assert_active_bin_has_shares, bin_info, get_bin_from_vec, get_shares_from_position, take_from_bins};
+use crate::error::Error;
+use crate::flash::{flash_loan_fee, FlashLoanReceiverClient, FlashSwapReceiverClient};
+use crate::liquidity::{modify_position, settle_amounts, shape_liquidity, transfer_shares};
+use crate::invariant::assert_invariants;
+use crate::order::order_share;
+use crate::price::{check_bin_id, check_bin_step, check_price, get_amount_out, get_max_bin_id, id_from_human_price, id_from_price, price_from_bin, price_from_bin_and_token, price_from_host, price_to_host, price_to_human};
+use crate::rewards::{check_reward_range, claim_rewards, pending_rewards};
+use crate::swap::{quote_swap_bins, swap_bins, try_quote_swap_bins};
+use crate::bitmap::bitmap_keys;
+use crate::storage::{add_to_reserves, delete_filled_orders, delete_incentive, get_incentive, get_incentives, get_reward_vec_ids, next_incentive_id, store_incentive, store_incentives, Incentive, RewardRange, get_bin_totals, delete_limit_order, delete_position, get_bin_vec_or_default, get_config, get_filled_orders, get_limit_order, get_order_bins, get_position, get_position_ids, get_position_or_default, is_operator, store_operator, get_reserves, get_shares_vec_or_default, get_vec_id_for_bin, extend_instance_ttl, extend_persistent_ttl, next_order_epoch, store_config, store_filled_orders, store_limit_order, store_order_bins, store_position, Bin, BinInfo, BinShares, Config, DataKey, DepositArgs, LimitOrder, LiquidityShape, OrderBin, Position, Reserves, BinTotals, VecIndex};
+use crate::token::{balance, decimals, excess_balances, transfer, transfer_in, transfer_in_exact, transfer_out};
 use soroban_fixed_point_math::SorobanFixedPoint;
-use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, Vec, I256};
+use soroban_sdk::{assert_with_error, contract, vec, Address, Bytes, Env, Vec, U256};
 
 #[contract]
 pub struct Contract;
 
 
-#[contractimpl]
+#[cfg_attr(not(all(feature = "library", target_family = "wasm")), soroban_sdk::contractimpl)]
 impl Contract {
+    /// Creates the pool. `decimals_x` and `decimals_y` of `conf` are ignored, they are read from the tokens.
     pub fn __constructor(env: Env, conf: Config) {
-        env.storage().instance().set(&DataKey::Config, &conf);
+        assert!(conf.fee < MAX_BPS, "Invalid fee");
+        assert!(conf.flash_loan_fee <= MAX_BPS, "Invalid flash loan fee");
+        check_bin_step(&env, conf.bin_step);
+        check_bin_id(&env, conf.bin_step, conf.active_bin);
+
+        let mut conf = conf;
+        conf.decimals_x = decimals(&env, conf.token_x.clone());
+        conf.decimals_y = decimals(&env, conf.token_y.clone());
+
+        store_config(&env, &conf);
+        events::created(&env, &conf);
     }
 
     // pub fn upgrade(env: Env, wasm_hash: BytesN<32>){
@@ -34,14 +54,16 @@ impl Contract {
     /// returns a pair with the amounts deposited removed or added: (x_token_amount, y_token_amount)
     /// a positive number means that we deposited that amount and a negative number means that we withdrew that amount.
     pub fn modify_liquidity(env: Env, from: Address, position_id: i32, args: Vec<DepositArgs>, offset_from_active: bool) -> (i128, i128) {
+        Self::modify_liquidity_to(env, from.clone(), from, position_id, args, offset_from_active)
+    }
+
+    /// Like `modify_liquidity`, but the withdrawn amounts are sent to `to`. Deposits are still paid by `from`.
+    pub fn modify_liquidity_to(env: Env, from: Address, to: Address, position_id: i32, args: Vec<DepositArgs>, offset_from_active: bool) -> (i128, i128) {
         from.require_auth();
-        assert!(args.len() > 0);
+        assert!(!args.is_empty());
 
         let config = get_config(&env);
 
-        let mut x_amount_delta = 0;
-        let mut y_amount_delta = 0;
-
         let bin_offset = if offset_from_active {
             config.active_bin
         } else {
@@ -49,177 +71,187 @@ impl Contract {
         };
 
         let mut position = get_position_or_default(&env, DataKey::Position(from.clone(), position_id));
-        
-        let starting_bin_id = args.first_unchecked().bin_id_or_offset + bin_offset;
-            // match args.first_unchecked() {
-            // ActionArgs::Deposit(v) => v.bin_id_or_offset + bin_offset,
-            // ActionArgs::Remove(v) => v.bin_id_or_offset + bin_offset,
-        // };
-
-        let mut cur_vec_id = get_vec_id_for_bin(starting_bin_id);
-        let mut cur_bin_vec: Vec<Bin> = get_bin_vec_or_default(&env, cur_vec_id, config.active_bin);
-        let mut cur_shares_vec: Vec<BinShares> = get_shares_vec_or_default(&env, cur_vec_id);
-
-        let bone_i256 = I256::from_i128(&env, BONE);
-
-        for i in 0..args.len() {
-            let modify_arg = &args.get(i).unwrap();
-            
-            let bin_id = modify_arg.bin_id_or_offset + bin_offset;
-                // match modify_arg {
-                // ActionArgs::Deposit(v) => v.bin_id_or_offset,
-                // ActionArgs::Remove(v) => v.bin_id_or_offset,
-            // } + bin_offset;
-
-            // if the vec does not have the elements,
-            if !is_bin_in_vec(bin_id, cur_vec_id) {
-                store_bin_vec(&env,cur_vec_id, &cur_bin_vec);
-                store_shares_vec(&env, cur_vec_id, &cur_shares_vec);
-
-                cur_vec_id = get_vec_id_for_bin(bin_id);
-                cur_bin_vec = get_bin_vec_or_default(&env, cur_vec_id, config.active_bin);
-                cur_shares_vec = get_shares_vec_or_default(&env, cur_vec_id);
+
+        let (x_amount_delta, y_amount_delta) = modify_position(&env, &config, &from, position_id, &mut position, &args, bin_offset);
+
+        store_position(&env, DataKey::Position(from.clone(), position_id), &position);
+        extend_instance_ttl(&env, &config);
+
+        settle_amounts(&env, &config, &from, &to, x_amount_delta, y_amount_delta);
+
+        assert_invariants(&env);
+
+        (x_amount_delta, y_amount_delta)
+    }
+
+    /// Allows `owner` to withdraw a percentage of every bin in a position.
+    /// `bps` is the percentage of the shares to burn in basis points, so `10_000` withdraws everything.
+    ///
+    /// The shares to burn are rounded down per bin.
+    /// When all of the shares are burned, the position is deleted.
+    ///
+    /// returns a pair with the amounts withdrawn: (x_token_amount, y_token_amount)
+    pub fn remove_position_pct(env: Env, owner: Address, position_id: i32, bps: u32, min_x: i128, min_y: i128) -> (i128, i128) {
+        owner.require_auth();
+        assert!(bps > 0 && bps <= MAX_BPS, "Invalid bps");
+
+        let config = get_config(&env);
+        let key = DataKey::Position(owner.clone(), position_id);
+        let mut position = get_position(&env, key.clone()).expect("Position does not exist");
+
+        let mut args = vec![&env];
+        for bin_shares in position.bin_shares.iter() {
+            let shares_to_burn = bin_shares.shares.fixed_mul_floor(&env, &(bps as i128), &(MAX_BPS as i128));
+
+            if shares_to_burn > 0 {
+                args.push_back(DepositArgs { is_remove: true, bin_id_or_offset: bin_shares.bin_id, amount: shares_to_burn });
             }
+        }
+        assert!(!args.is_empty(), "Nothing to withdraw");
+
+        let (x_amount_delta, y_amount_delta) = modify_position(&env, &config, &owner, position_id, &mut position, &args, 0);
+
+        assert!(-x_amount_delta >= min_x, "Insufficient x amount");
+        assert!(-y_amount_delta >= min_y, "Insufficient y amount");
+
+        if position.bin_shares.is_empty() {
+            delete_position(&env, key);
+        } else {
+            store_position(&env, key, &position);
+        }
 
-            let mut bin = get_bin_from_vec(&cur_bin_vec, bin_id);
-            let mut bin_shares = get_bin_from_vec(&cur_shares_vec, bin_id);
-            let mut user_shares = get_shares_from_position(&position, bin_id);
-
-            match modify_arg.is_remove {
-                false => {
-                    assert!(modify_arg.amount > 0);
-                    let (amount_x, amount_y) = if bin_id == config.active_bin {
-                        let total_reserve = bin.reserve_x + bin.reserve_y;
-
-                        if total_reserve == 0 {
-                            let half_amount = modify_arg.amount / 2;
-                            (half_amount as i128, (modify_arg.amount - half_amount) as i128) // handle odd amounts
-                        }else{
-                            let x = downscale_floor(
-                                &env,
-                                &upscale(&env, modify_arg.amount, BONE)
-                                    .fixed_mul_floor(&env, &upscale(&env, bin.reserve_x, BONE), &upscale(&env, total_reserve, BONE)),
-                                BONE,
-                            );
-                            (x, (modify_arg.amount - x))
-                        }
-                    } else if bin_id < config.active_bin {
-                        (modify_arg.amount as i128, 0i128)
-                    } else if bin_id > config.active_bin {
-                        (0i128, modify_arg.amount as i128)
-                    }else {
-                        panic!("Invalid bin id. my logic is wrong.");
-                    };
-
-                    let shares_to_mint = calculate_shares_to_mint(
-                        &env,
-                        &bin_shares,
-                        &bin,
-                        modify_arg.amount,
-                    );
-
-                    x_amount_delta += amount_x;
-                    y_amount_delta += amount_y;
-
-                    bin.reserve_x += amount_x;
-                    bin.reserve_y += amount_y;
-
-                    bin_shares.shares += shares_to_mint;
-                    user_shares.shares += shares_to_mint;
-                    
-                    store_shares_in_position(&mut position, user_shares);
-                    store_bin_in_vec(&mut cur_bin_vec, bin_id, bin);
-                    store_bin_in_vec(&mut cur_shares_vec, bin_id, bin_shares);
+        settle_amounts(&env, &config, &owner, &owner, x_amount_delta, y_amount_delta);
+
+        assert_invariants(&env);
+
+        (-x_amount_delta, -y_amount_delta)
+    }
+
+    /// Allows `owner` to move all the liquidity of a position to the bins around the current active bin.
+    ///
+    /// All shares of the position are withdrawn and redeposited in the bins `[active_bin - width, active_bin + width]`, spread according to `shape`.
+    /// Fails with `ErrInvalidWidth` if `width` is more than `MAX_WIDTH`. Bins of the range outside of the supported bins are skipped.
+    /// If `swap_imbalance` is set, the withdrawn tokens are first swapped through this pool, so that the value of x and y is about equal.
+    /// The swap may move the active bin, the liquidity is centered on the active bin after the swap.
+    /// The swap is skipped when the other liquidity of the pool can't take it, for example when the position is the only liquidity.
+    ///
+    /// Tokens that could not be redeposited, because of rounding or the distribution of the active bin, are sent back to `owner`.
+    ///
+    /// `min_x` and `min_y` are the minimum amounts that must be redeposited.
+    ///
+    /// returns a pair with the amounts redeposited: (x_token_amount, y_token_amount)
+    pub fn rebalance_position(env: Env, owner: Address, position_id: i32, width: u32, shape: LiquidityShape, swap_imbalance: bool, min_x: i128, min_y: i128) -> (i128, i128) {
+        owner.require_auth();
+        assert_with_error!(&env, width <= MAX_WIDTH, Error::ErrInvalidWidth);
+
+        let mut config = get_config(&env);
+        let key = DataKey::Position(owner.clone(), position_id);
+        let mut position = get_position(&env, key.clone()).expect("Position does not exist");
+        assert!(!position.bin_shares.is_empty(), "Nothing to rebalance");
+
+        let mut remove_args = vec![&env];
+        for bin_shares in position.bin_shares.iter() {
+            remove_args.push_back(DepositArgs { is_remove: true, bin_id_or_offset: bin_shares.bin_id, amount: bin_shares.shares });
+        }
+
+        let (x_removed, y_removed) = modify_position(&env, &config, &owner, position_id, &mut position, &remove_args, 0);
+        let mut amount_x = -x_removed;
+        let mut amount_y = -y_removed;
+
+        if swap_imbalance {
+            let price = price_from_bin(config.bin_step, config.active_bin);
+            // value of the x amount in y
+            let x_value = i128::try_from(get_amount_out(price, amount_x as u128, true)).ok();
+            assert_with_error!(&env, x_value.is_some(), Error::ErrMulOverflow);
+            let x_value = x_value.unwrap();
+
+            if x_value > amount_y {
+                // swap half of the excess value
+                let x_in = amount_x.fixed_mul_floor(&env, &((x_value - amount_y) / 2), &x_value);
+                let token_x = config.token_x.clone();
+                if x_in > 0 && try_quote_swap_bins(&env, &config, x_in, &token_x).is_some_and(|out| out > 0) {
+                    amount_y += swap_bins(&env, &mut config, &owner, x_in, &token_x);
+                    amount_x -= x_in;
                 }
-                true => {
-                    assert!(modify_arg.amount > 0);
-                    assert!(user_shares.shares >= modify_arg.amount);
-
-                    let removal_ratio = upscale(&env, modify_arg.amount, BONE)
-                        .fixed_div_floor(&env, &upscale(&env, bin_shares.shares, BONE), &bone_i256);
-
-                    let amount_x_to_remove = downscale_floor(
-                        &env,
-                        &removal_ratio.fixed_mul_floor(
-                            &env,
-                            &upscale(&env, bin.reserve_x, BONE),
-                            &bone_i256,
-                        ),
-                        BONE,
-                    );
-                    
-                    let amount_y_to_remove = downscale_floor(
-                        &env,
-                        &removal_ratio.fixed_mul_floor(
-                            &env,
-                            &upscale(&env, bin.reserve_y, BONE),
-                            &bone_i256,
-                        ),
-                        BONE,
-                    );
-                    
-                    x_amount_delta -= amount_x_to_remove;
-                    y_amount_delta -= amount_y_to_remove;
-                    
-                    bin.reserve_x -= amount_x_to_remove;
-                    bin.reserve_y -= amount_y_to_remove;
-                    
-                    bin_shares.shares -= modify_arg.amount;
-                    user_shares.shares -= modify_arg.amount;
-
-
-                    if user_shares.shares == 0 {
-                        delete_shares_in_position(&mut position, user_shares);
-                    }else{
-                        store_shares_in_position(&mut position, user_shares);
-                    }
-
-                    store_bin_in_vec(&mut cur_bin_vec, bin_id, bin);
-                    store_bin_in_vec(&mut cur_shares_vec, bin_id, bin_shares);
+            } else {
+                let y_in = (amount_y - x_value) / 2;
+                let token_y = config.token_y.clone();
+                if y_in > 0 && try_quote_swap_bins(&env, &config, y_in, &token_y).is_some_and(|out| out > 0) {
+                    amount_x += swap_bins(&env, &mut config, &owner, y_in, &token_y);
+                    amount_y -= y_in;
                 }
-            };
+            }
+
+            store_config(&env, &config);
         }
-        store_bin_vec(&env, cur_vec_id, &cur_bin_vec);
-        store_shares_vec(&env, cur_vec_id, &cur_shares_vec);
-        store_position(&env, DataKey::Position(from.clone(), position_id), &position);
-        
-        let (x_to, x_from) = if x_amount_delta > 0 {
-            (env.current_contract_address(), from.clone())
-        }else{
-            (from.clone(), env.current_contract_address())
-        };
-        
-        let (y_to, y_from) = if y_amount_delta > 0 {
-            (env.current_contract_address(), from.clone())
-        }else{
-            (from.clone(), env.current_contract_address())
+
+        let active_bin = get_bin_from_vec(
+            &get_bin_vec_or_default(&env, get_vec_id_for_bin(config.active_bin), config.active_bin),
+            config.active_bin,
+        );
+        let deposit_args = shape_liquidity(&env, &config, shape, width, amount_x, amount_y, &active_bin);
+
+        let (x_deposited, y_deposited) = if deposit_args.is_empty() {
+            (0, 0)
+        } else {
+            modify_position(&env, &config, &owner, position_id, &mut position, &deposit_args, config.active_bin)
         };
-        
-        if x_amount_delta != 0 {
-            transfer(
-                &env,
-                config.token_x.clone(),
-                x_from,
-                x_to,
-                x_amount_delta.abs(),
-            )
-        }
-        
-        if y_amount_delta != 0 {
-            transfer(
-                &env,
-                config.token_y.clone(),
-                y_from,
-                y_to,
-                y_amount_delta.abs(),
-            )
+
+        assert!(x_deposited >= min_x, "Insufficient x amount");
+        assert!(y_deposited >= min_y, "Insufficient y amount");
+
+        if position.bin_shares.is_empty() {
+            delete_position(&env, key);
+        } else {
+            store_position(&env, key, &position);
         }
-        
 
-        (x_amount_delta, y_amount_delta)
+        // whatever we could not deposit goes back to the owner
+        settle_amounts(&env, &config, &owner, &owner, x_deposited - amount_x, y_deposited - amount_y);
+
+        assert_invariants(&env);
+
+        (x_deposited, y_deposited)
+    }
+
+    /// Moves `shares` of the position `position_id` of `from` to the position with the same id of `to`, which is created if not exists.
+    ///
+    /// The shares of each bin work like a multi token, where the bin id is the token id. Only the owner of the shares moves, the bins stay the same.
+    /// The rewards that the shares earned until now stay with `from`.
+    pub fn batch_transfer(env: Env, from: Address, to: Address, position_id: i32, shares: Vec<BinShares>) {
+        from.require_auth();
+
+        let config = get_config(&env);
+        transfer_shares(&env, &config, &from, &to, position_id, &shares);
+        extend_instance_ttl(&env, &config);
+    }
+
+    /// Like `batch_transfer`, but authorized by `operator`, which must be approved by `from` with `set_approval_for_all`.
+    pub fn batch_transfer_from(env: Env, operator: Address, from: Address, to: Address, position_id: i32, shares: Vec<BinShares>) {
+        operator.require_auth();
+        assert!(is_operator(&env, &from, &operator), "Not approved");
+
+        let config = get_config(&env);
+        transfer_shares(&env, &config, &from, &to, position_id, &shares);
+        extend_instance_ttl(&env, &config);
+    }
+
+    /// Allows or disallows `operator` to transfer all shares of all positions of `owner`.
+    pub fn set_approval_for_all(env: Env, owner: Address, operator: Address, approved: bool) {
+        owner.require_auth();
+        assert!(owner != operator, "Cannot approve self");
+
+        store_operator(&env, &owner, &operator, approved);
+        extend_instance_ttl(&env, &get_config(&env));
+        events::approval(&env, &owner, &operator, approved);
     }
 
     pub fn swap_exact_amount_in(env: Env, from: Address, amount_in: i128, min_amount_out: i128, in_token: Address) -> i128 {
+        Self::swap_exact_amount_in_to(env, from.clone(), from, amount_in, min_amount_out, in_token)
+    }
+
+    /// Like `swap_exact_amount_in`, but the output is sent to `to`, for example to pay someone in the other token.
+    pub fn swap_exact_amount_in_to(env: Env, from: Address, to: Address, amount_in: i128, min_amount_out: i128, in_token: Address) -> i128 {
         from.require_auth();
         let config = get_config(&env);
 
@@ -227,14 +259,9 @@ impl Contract {
         assert!(min_amount_out >= 0);
         assert!(in_token == config.token_x || in_token == config.token_y);
 
-        transfer(
-            &env,
-            in_token.clone(),
-            from.clone(),
-            env.current_contract_address(),
-            amount_in,
-        );
-
+        // tokens that charge a fee on transfer are swapped for what the pool received
+        let amount_in = transfer_in(&env, &config, in_token.clone(), from.clone(), amount_in);
+        assert!(amount_in > 0, "Nothing received");
 
         let out_token = if in_token == config.token_x {
             config.token_y.clone()
@@ -242,165 +269,545 @@ impl Contract {
             config.token_x.clone()
         };
 
-        let is_x_in = in_token == config.token_x;
+        let mut config = config.clone();
+        let downscaled_out = swap_bins(&env, &mut config, &from, amount_in, &in_token);
+        store_config(&env, &config);
 
-        let bone_i256 = I256::from_i128(&env, BONE);
-        let zero_i256 = I256::from_i128(&env, 0);
+        assert!(downscaled_out >= min_amount_out, "Insufficient output amount");
 
-        let upscaled_fee = upscale(&env, config.fee as i128, FEE_SCALAR);
+        transfer_out(&env, &config, out_token.clone(), to, downscaled_out);
 
-        let mut cur_bin_id = config.active_bin;
-        let mut cur_vec_id = get_vec_id_for_bin(cur_bin_id);
-        let mut cur_bin_vec: Vec<Bin> = get_bin_vec_or_default(&env, cur_vec_id, config.active_bin);
+        assert_invariants(&env);
 
-        let amount_in_scaled = upscale(&env, amount_in, BONE);
-        let mut amount_in_remaining = amount_in_scaled.clone();
-        let mut amount_out = I256::from_i128(&env, 0);
+        downscaled_out
+    }
 
-        loop {
+    /// returns what `swap_exact_amount_in` would send for `amount_in` of `in_token`, without changing the pool.
+    ///
+    /// Panics with "Not enough liquidity" like the swap. A fee on the transfer of `in_token` is not taken into account.
+    pub fn quote_exact_amount_in(env: Env, amount_in: i128, in_token: Address) -> i128 {
+        let config = get_config(&env);
 
-            //TODO: handle when next vec has no liquidity
+        assert!(amount_in > 0);
+        assert!(in_token == config.token_x || in_token == config.token_y);
 
-            // reverse ranges are hard
-            let len = cur_bin_vec.len() as i32;
-            let loop_params = if is_x_in {
-                (get_bin_position_in_vec(cur_bin_id) as i32, 1)
-            } else {
-                (get_bin_position_in_vec(cur_bin_id) as i32, -1)
-            };
-
-            // here we loop over the bins of the vec
-            let mut i = loop_params.0;
-            loop {
-                if i >= len || i < 0 {
-                    break;
-                }
+        quote_swap_bins(&env, &config, amount_in, &in_token)
+    }
 
-                let mut bin = cur_bin_vec.get(i as u32).unwrap();
+    /// Swaps `amount_in` of `in_token` like `swap_exact_amount_in`, but the output is sent to `receiver` before the input is paid.
+    ///
+    /// After the output is transferred, `on_flash_swap` of the `FlashSwapReceiver` interface is invoked on `receiver` with `data`.
+    /// Before the callback returns, `receiver` must transfer `amount_in` of `in_token` to the pool, for example with the proceeds of
+    /// selling the output somewhere else.
+    ///
+    /// returns the amount of the other token sent to `receiver`
+    pub fn flash_swap(env: Env, receiver: Address, amount_in: i128, min_amount_out: i128, in_token: Address, data: Bytes) -> i128 {
+        receiver.require_auth();
+        let mut config = get_config(&env);
 
+        assert!(amount_in > 0);
+        assert!(min_amount_out >= 0);
+        assert!(in_token == config.token_x || in_token == config.token_y);
 
-                // here we check that there is liq for the swap
-                if is_x_in {
-                    if bin.reserve_y == 0 {
-                        i += loop_params.1;
-                        continue;
-                    }
-                }else {
-                    if bin.reserve_x == 0 {
-                        i += loop_params.1;
-                        continue;
-                    }
-                }
+        let out_token = if in_token == config.token_x {
+            config.token_y.clone()
+        } else {
+            config.token_x.clone()
+        };
+
+        let balance_before = balance(&env, in_token.clone(), env.current_contract_address());
+
+        let downscaled_out = swap_bins(&env, &mut config, &receiver, amount_in, &in_token);
+        store_config(&env, &config);
+
+        assert!(downscaled_out >= min_amount_out, "Insufficient output amount");
+
+        transfer_out(&env, &config, out_token.clone(), receiver.clone(), downscaled_out);
+
+        FlashSwapReceiverClient::new(&env, &receiver).on_flash_swap(&in_token, &amount_in, &out_token, &downscaled_out, &data);
+
+        let balance_after = balance(&env, in_token.clone(), env.current_contract_address());
+        assert!(balance_after >= balance_before + amount_in, "Flash swap not paid");
+        // anything that was paid on top of `amount_in` can be skimmed
+        add_to_reserves(&env, &config, &in_token, amount_in);
+
+        assert_invariants(&env);
+
+        downscaled_out
+    }
+
+    /// Lends `amount` of `token` to `receiver` for the duration of this call.
+    ///
+    /// The tokens are transferred to `receiver` and `on_flash_loan` of the `FlashLoanReceiver` interface is invoked with `data`.
+    /// Before the callback returns, `receiver` must transfer the amount plus the fee back to the pool.
+    /// The fee is `flash_loan_fee` bps of `amount`, rounded up, and is added to the reserves of the active bin.
+    /// So a loan with a fee needs liquidity in the active bin.
+    ///
+    /// The host does not allow a contract to be re-entered, so the callback can't call back into the pool
+    /// (e.g. `swap_exact_amount_in` or `modify_liquidity`) while the loan is outstanding.
+    ///
+    /// returns the fee that was paid
+    pub fn flash_loan(env: Env, receiver: Address, token: Address, amount: i128, data: Bytes) -> i128 {
+        receiver.require_auth();
+        assert!(amount > 0);
+
+        let config = get_config(&env);
+        assert!(token == config.token_x || token == config.token_y);
+
+        let fee = flash_loan_fee(&env, &config, amount);
+        if fee > 0 {
+            assert_active_bin_has_shares(&env, &config);
+        }
+        let balance_before = balance(&env, token.clone(), env.current_contract_address());
+
+        transfer(&env, token.clone(), env.current_contract_address(), receiver.clone(), amount);
+
+        FlashLoanReceiverClient::new(&env, &receiver).on_flash_loan(&token, &amount, &fee, &data);
+
+        let balance_after = balance(&env, token.clone(), env.current_contract_address());
+        assert!(balance_after >= balance_before + fee, "Flash loan not repaid");
+
+        if fee > 0 {
+            add_to_active_bin(&env, &config, &token, fee);
+            add_to_reserves(&env, &config, &token, fee);
+        }
+        events::flash_loan(&env, &receiver, &token, amount, fee, config.active_bin);
+
+        assert_invariants(&env);
+
+        fee
+    }
+
+    /// Allows `owner` to place a limit order of `amount` in `bin_id`.
+    ///
+    /// If the bin is left of the active bin, the order sells x for y, otherwise it sells y for x.
+    /// Orders are tracked separately from the liquidity of the LPs. Swaps use the orders after the liquidity of the bin,
+    /// and once all orders in the bin are sold, they can be claimed with `claim_limit_order`.
+    /// Contrary to liquidity, the proceeds of a filled order can't be swapped back.
+    ///
+    /// Orders can't be placed in the active bin, or in a bin where the orders are partially filled.
+    pub fn place_limit_order(env: Env, owner: Address, order_id: i32, bin_id: i32, amount: i128) {
+        owner.require_auth();
+        assert!(amount > 0);
+
+        let config = get_config(&env);
+        check_bin_id(&env, config.bin_step, bin_id);
+        assert!(bin_id != config.active_bin, "Can't place an order in the active bin");
+
+        let key = DataKey::LimitOrder(owner.clone(), order_id);
+        assert!(get_limit_order(&env, key.clone()).is_none(), "Order already exists");
+
+        let vec_id = get_vec_id_for_bin(bin_id);
+        let mut order_bins = get_order_bins(&env, vec_id);
+        let mut order_bin = order_bins.get(bin_id).unwrap_or_else(|| OrderBin {
+            bin_id,
+            epoch: next_order_epoch(&env),
+            sell_x: bin_id < config.active_bin,
+            total: 0,
+            remaining: 0,
+            proceeds: 0,
+        });
+        assert!(order_bin.proceeds == 0, "Orders in bin are partially filled");
+
+        order_bin.total += amount;
+        order_bin.remaining += amount;
+
+        store_limit_order(&env, key, &LimitOrder {
+            bin_id,
+            epoch: order_bin.epoch,
+            sell_x: order_bin.sell_x,
+            amount,
+        });
+
+        let token = if order_bin.sell_x { config.token_x.clone() } else { config.token_y.clone() };
+
+        order_bins.set(bin_id, order_bin);
+        store_order_bins(&env, vec_id, &order_bins);
+
+        transfer_in_exact(&env, &config, token, owner, amount);
+
+        assert_invariants(&env);
+    }
+
+    /// Allows `owner` to cancel an order that is not filled yet.
+    ///
+    /// If the order is partially filled, `owner` receives their part of what is left and of what was received.
+    ///
+    /// returns a pair with the amounts sent to `owner`: (x_token_amount, y_token_amount)
+    pub fn cancel_limit_order(env: Env, owner: Address, order_id: i32) -> (i128, i128) {
+        owner.require_auth();
+
+        let config = get_config(&env);
+        let key = DataKey::LimitOrder(owner.clone(), order_id);
+        let order = get_limit_order(&env, key.clone()).expect("Order does not exist");
+
+        let vec_id = get_vec_id_for_bin(order.bin_id);
+        let mut order_bins = get_order_bins(&env, vec_id);
+        let mut order_bin = order_bins
+            .get(order.bin_id)
+            .filter(|order_bin| order_bin.epoch == order.epoch)
+            .expect("Order is filled");
+
+        let remaining = order_share(&env, order.amount, order_bin.total, order_bin.remaining);
+        let proceeds = order_share(&env, order.amount, order_bin.total, order_bin.proceeds);
+
+        order_bin.total -= order.amount;
+        order_bin.remaining -= remaining;
+        order_bin.proceeds -= proceeds;
 
+        if order_bin.total == 0 {
+            order_bins.remove(order.bin_id);
+        } else {
+            order_bins.set(order.bin_id, order_bin);
+        }
+        store_order_bins(&env, vec_id, &order_bins);
+        delete_limit_order(&env, key);
 
-                cur_bin_id = bin.bin_id;
+        let (amount_x, amount_y) = if order.sell_x { (remaining, proceeds) } else { (proceeds, remaining) };
 
-                let price_with_fee = {
-                    let _price = price_from_bin_and_token(&env, &config, bin.bin_id, in_token.clone());
+        if amount_x > 0 {
+            transfer_out(&env, &config, config.token_x.clone(), owner.clone(), amount_x);
+        }
+        if amount_y > 0 {
+            transfer_out(&env, &config, config.token_y.clone(), owner, amount_y);
+        }
 
-                    if config.fee == 0 {
-                        _price
-                    } else {
-                        // price = price - (price * fee_pct / bone))
-                        _price.sub(&_price.fixed_mul_ceil(&env, &upscaled_fee, &bone_i256))
-                    }
-                };
+        assert_invariants(&env);
 
+        (amount_x, amount_y)
+    }
 
-                let potential_amount_out =
-                    price_with_fee.fixed_mul_floor(&env, &amount_in_remaining, &bone_i256);
+    /// Allows `owner` to claim the proceeds of a filled order.
+    ///
+    /// returns the amount of the bought token sent to `owner`
+    pub fn claim_limit_order(env: Env, owner: Address, order_id: i32) -> i128 {
+        owner.require_auth();
 
-                // TODO: reduce code duplication
+        let config = get_config(&env);
+        let key = DataKey::LimitOrder(owner.clone(), order_id);
+        let order = get_limit_order(&env, key.clone()).expect("Order does not exist");
+        let mut filled_orders = get_filled_orders(&env, order.epoch).expect("Order is not filled");
 
-                // here we swap using the current bin
-                if is_x_in {
-                    let scaled_reserve = upscale(&env, bin.reserve_y, BONE);
+        let proceeds = order_share(&env, order.amount, filled_orders.total, filled_orders.proceeds);
 
-                    // we can finish the trade
-                    if potential_amount_out <= scaled_reserve {
-                        amount_out = amount_out.add(&potential_amount_out);
+        filled_orders.total -= order.amount;
+        filled_orders.proceeds -= proceeds;
 
-                        bin.reserve_x = bin.reserve_x + downscale_floor(&env, &amount_in_remaining, BONE);
-                        bin.reserve_y =
-                            downscale_ceil(&env, &scaled_reserve.sub(&potential_amount_out), BONE);
+        if filled_orders.total == 0 {
+            delete_filled_orders(&env, order.epoch);
+        } else {
+            store_filled_orders(&env, order.epoch, &filled_orders);
+        }
+        delete_limit_order(&env, key);
 
-                        amount_in_remaining = zero_i256.clone();
+        let token = if order.sell_x { config.token_y.clone() } else { config.token_x.clone() };
+        if proceeds > 0 {
+            transfer_out(&env, &config, token, owner, proceeds);
+        }
 
-                        cur_bin_vec.set(i as u32, bin);
-                        break;
-                    } else {
-                        amount_out = amount_out.add(&scaled_reserve);
+        assert_invariants(&env);
 
-                        let amount_in_consumed = scaled_reserve
-                            .fixed_div_floor(&env, &potential_amount_out, &bone_i256)
-                            .fixed_mul_ceil(&env, &amount_in_remaining, &bone_i256);
+        proceeds
+    }
 
-                        amount_in_remaining = amount_in_remaining.sub(&amount_in_consumed);
+    /// Adds an incentive that pays `amount` of `token` to the LPs of the bins in `range`, at the same rate from `start` to `end`.
+    ///
+    /// Only the admin of the pool can add incentives, `manager` pays them and can reclaim what is left.
+    ///
+    /// `start` and `end` are ledger timestamps, a `start` in the past starts now, and `end` is at most `MAX_INCENTIVE_DURATION` from now.
+    /// The rewards of every moment are divided equally over the bins in the range that have shares, and within a bin by shares.
+    /// LPs claim them per position and incentive with `claim_rewards`. The tokens of the pool can't be rewards, as they would be taken by `skim`.
+    ///
+    /// The pool has at most `MAX_INCENTIVES` incentives, an incentive counts until it is reclaimed with `reclaim_incentive`.
+    ///
+    /// returns the id of the incentive
+    pub fn add_incentive(env: Env, manager: Address, token: Address, amount: i128, start: u64, end: u64, range: RewardRange) -> u32 {
+        let config = get_config(&env);
+        manager.require_auth();
+        if manager != config.admin {
+            config.admin.require_auth();
+        }
+        assert!(amount > 0);
+        assert!(token != config.token_x && token != config.token_y, "Pool tokens can't be rewards");
+        check_reward_range(&env, &config, &range);
+
+        let now = env.ledger().timestamp();
+        let start = start.max(now);
+        assert!(start < end && end <= now + MAX_INCENTIVE_DURATION, "Invalid incentive time");
+
+        let mut incentives = get_incentives(&env);
+        assert!(incentives.len() < MAX_INCENTIVES, "Too many incentives");
+
+        // tokens that charge a fee on transfer pay out what the pool received
+        let pool = env.current_contract_address();
+        let balance_before = balance(&env, token.clone(), pool.clone());
+        transfer(&env, token.clone(), manager.clone(), pool.clone(), amount);
+        let amount = balance(&env, token.clone(), pool) - balance_before;
+        assert!(amount > 0, "Nothing received");
+
+        let id = next_incentive_id(&env);
+        let incentive = Incentive {
+            manager,
+            token,
+            amount,
+            start,
+            end,
+            range,
+            last_update: start,
+            claimed: 0,
+        };
+        store_incentive(&env, id, &incentive);
+        incentives.push_back(id);
+        store_incentives(&env, &incentives);
+        extend_instance_ttl(&env, &config);
 
-                        bin.reserve_x = bin.reserve_x + downscale_ceil(&env, &amount_in_consumed, BONE);
-                        bin.reserve_y = 0;
+        events::incentive(&env, id, &incentive);
 
-                        cur_bin_vec.set(i as u32, bin);
-                    }
-                } else {
-                    let scaled_reserve = upscale(&env, bin.reserve_x, BONE);
+        id
+    }
 
-                    // we can finish the trade
-                    if potential_amount_out <= scaled_reserve {
-                        amount_out = amount_out.add(&potential_amount_out);
+    /// Allows `owner` to claim the rewards that position `position_id` earned from incentive `incentive_id`.
+    ///
+    /// Every incentive is claimed on its own, so a reward token that can't be transferred doesn't block the others.
+    /// Rewards stay claimable after the shares are removed, until `REWARD_CLAIM_PERIOD` after the end of their incentive.
+    ///
+    /// returns the amount sent to `owner`
+    pub fn claim_rewards(env: Env, owner: Address, position_id: i32, incentive_id: u32) -> i128 {
+        owner.require_auth();
 
-                        bin.reserve_y = bin.reserve_y + downscale_floor(&env, &amount_in_remaining, BONE);
-                        bin.reserve_x =
-                            downscale_ceil(&env, &scaled_reserve.sub(&potential_amount_out), BONE);
+        let config = get_config(&env);
+        let position = get_position_or_default(&env, DataKey::Position(owner.clone(), position_id));
+        let claimed = claim_rewards(&env, &config, &owner, position_id, &position, incentive_id);
 
-                        amount_in_remaining = zero_i256.clone();
+        events::rewards(&env, &owner, position_id, incentive_id, claimed);
 
-                        cur_bin_vec.set(i as u32, bin);
-                        break;
-                    } else {
-                        amount_out = amount_out.add(&scaled_reserve);
+        claimed
+    }
+
+    /// Allows the manager of incentive `id` to take back what was not claimed, once `REWARD_CLAIM_PERIOD` passed after its end.
+    ///
+    /// This includes the rewards of the times that no bin in the range had shares.
+    /// The incentive is removed, so its rewards that were not claimed can't be claimed anymore.
+    ///
+    /// returns the amount sent to the manager
+    pub fn reclaim_incentive(env: Env, id: u32) -> i128 {
+        let incentive = get_incentive(&env, id).expect("Incentive does not exist");
+        incentive.manager.require_auth();
+        assert!(env.ledger().timestamp() >= incentive.end + REWARD_CLAIM_PERIOD, "Claim period is not over");
+
+        let mut incentives = get_incentives(&env);
+        incentives.remove(incentives.first_index_of(id).unwrap());
+        store_incentives(&env, &incentives);
+        delete_incentive(&env, id);
+
+        let amount = incentive.amount - incentive.claimed;
+        if amount > 0 {
+            transfer(&env, incentive.token, env.current_contract_address(), incentive.manager, amount);
+        }
 
-                        let amount_in_consumed = scaled_reserve
-                            .fixed_div_floor(&env, &potential_amount_out, &bone_i256)
-                            .fixed_mul_ceil(&env, &amount_in_remaining, &bone_i256);
+        events::reclaim(&env, id, amount);
 
-                        amount_in_remaining = amount_in_remaining.sub(&amount_in_consumed);
+        amount
+    }
+
+    /// Sends the tokens that the pool holds on top of its reserves to `to`.
+    ///
+    /// These are tokens that were transferred to the pool directly, or that were added by a token that rebases up.
+    /// Anyone can call this, so tokens that are sent to the pool by accident can be taken by anyone.
+    ///
+    /// returns a pair with the amounts sent: (x_token_amount, y_token_amount)
+    pub fn skim(env: Env, to: Address) -> (i128, i128) {
+        let config = get_config(&env);
+        let (excess_x, excess_y) = excess_balances(&env, &config);
+
+        // the excess is not part of the reserves, so it is transferred without going through `transfer_out`
+        if excess_x > 0 {
+            transfer(&env, config.token_x.clone(), env.current_contract_address(), to.clone(), excess_x);
+        }
+        if excess_y > 0 {
+            transfer(&env, config.token_y.clone(), env.current_contract_address(), to, excess_y);
+        }
 
-                        bin.reserve_y = bin.reserve_y + downscale_ceil(&env, &amount_in_consumed, BONE);
-                        bin.reserve_x = 0;
+        assert_invariants(&env);
 
-                        cur_bin_vec.set(i as u32, bin);
-                    }
+        (excess_x.max(0), excess_y.max(0))
+    }
+
+    /// Adds the tokens that the pool holds on top of its reserves to the reserves of the active bin, so they go to the LPs of that bin.
+    ///
+    /// Like `skim`, this is for tokens that were transferred directly or that rebased up. If a token rebased down or was clawed back,
+    /// the balance is below the reserves. The reserves are then lowered to the balance and the shortfall is taken pro rata from the bins nearest to the active bin, see `take_from_bins`.
+    /// Fails if those bins can't cover the shortfall, the tokens of the limit orders are never taken.
+    ///
+    /// returns a pair with the amounts added: (x_token_amount, y_token_amount)
+    pub fn sync(env: Env) -> (i128, i128) {
+        let config = get_config(&env);
+        let (excess_x, excess_y) = excess_balances(&env, &config);
+
+        if excess_x < 0 || excess_y < 0 {
+            let changes = take_from_bins(&env, &config, -excess_x.min(0), -excess_y.min(0));
+            for (token, excess) in [(&config.token_x, excess_x), (&config.token_y, excess_y)] {
+                if excess < 0 {
+                    add_to_reserves(&env, &config, token, excess);
                 }
-                i += loop_params.1;
             }
+            events::loss(&env, changes);
+        }
+
+        if excess_x > 0 || excess_y > 0 {
+            assert_active_bin_has_shares(&env, &config);
+        }
+
+        for (token, excess) in [(&config.token_x, excess_x), (&config.token_y, excess_y)] {
+            if excess > 0 {
+                add_to_active_bin(&env, &config, token, excess);
+                add_to_reserves(&env, &config, token, excess);
+            }
+        }
+        if excess_x > 0 || excess_y > 0 {
+            events::sync(&env, excess_x.max(0), excess_y.max(0), config.active_bin);
+        }
+
+        assert_invariants(&env);
+
+        (excess_x.max(0), excess_y.max(0))
+    }
+
+    /// Extends the TTL of the bins in `bins` with their limit orders, the positions in `positions` with their rewards and the position ids of their owners,
+    /// the limit orders in `orders` with their filled orders, the approvals of the (owner, operator) pairs in `operators`, the incentives and of the contract instance.
+    ///
+    /// Bins, positions, orders and approvals are also extended when they are written, this allows anyone to keep idle pools, positions, open orders
+    /// and approvals from being archived.
+    /// Incentives are only written until their end, so they need to be bumped until they are reclaimed.
+    /// Entries that don't exist are skipped. The thresholds come from `ttl_threshold` and `ttl_extend_to` in the config.
+    pub fn bump(env: Env, bins: Vec<i32>, positions: Vec<(Address, i32)>, orders: Vec<(Address, i32)>, operators: Vec<(Address, Address)>) {
+        let config = get_config(&env);
+        extend_instance_ttl(&env, &config);
 
-            store_bin_vec(&env, cur_vec_id, &cur_bin_vec);
+        let mut vec_ids: Vec<i32> = vec![&env];
+        for bin_id in bins.iter() {
+            let vec_id = get_vec_id_for_bin(bin_id);
+            if !vec_ids.contains(vec_id) {
+                vec_ids.push_back(vec_id);
+            }
+        }
 
-            if amount_in_remaining == zero_i256{ break; }
+        for vec_id in vec_ids.iter() {
+            let [bin_word, bin_top_word] = bitmap_keys(VecIndex::Bins, vec_id);
+            let [order_word, order_top_word] = bitmap_keys(VecIndex::Orders, vec_id);
+            for key in [DataKey::BinVec(vec_id), DataKey::BinShareVec(vec_id), DataKey::OrderBins(vec_id), bin_word, bin_top_word, order_word, order_top_word] {
+                if env.storage().persistent().has(&key) {
+                    extend_persistent_ttl(&env, &config, &key);
+                }
+            }
+        }
 
-            cur_vec_id += loop_params.1;
-            cur_bin_id += loop_params.1;
-            cur_bin_vec = get_bin_vec_or_default(&env, cur_vec_id, config.active_bin);
+        for (owner, position_id) in positions.iter() {
+            for key in [DataKey::Position(owner.clone(), position_id), DataKey::PositionRewards(owner.clone(), position_id), DataKey::PositionIds(owner)] {
+                if env.storage().persistent().has(&key) {
+                    extend_persistent_ttl(&env, &config, &key);
+                }
+            }
         }
 
-        let mut config = config.clone();
-        config.active_bin = cur_bin_id;
-        store_config(&env, &config);
+        for (owner, order_id) in orders.iter() {
+            let key = DataKey::LimitOrder(owner, order_id);
+            let Some(order) = get_limit_order(&env, key.clone()) else { continue };
+            extend_persistent_ttl(&env, &config, &key);
+
+            let key = DataKey::FilledOrders(order.epoch);
+            if env.storage().persistent().has(&key) {
+                extend_persistent_ttl(&env, &config, &key);
+            }
+        }
 
-        let downscaled_out = downscale_floor(&env, &amount_out, BONE);
+        for (owner, operator) in operators.iter() {
+            let key = DataKey::Operator(owner, operator);
+            if env.storage().persistent().has(&key) {
+                extend_persistent_ttl(&env, &config, &key);
+            }
+        }
 
-        assert!(downscaled_out >= min_amount_out, "Insufficient output amount");
+        for id in get_incentives(&env).iter() {
+            extend_persistent_ttl(&env, &config, &DataKey::Incentive(id));
 
-        transfer(
+            let key = DataKey::RewardVecIds(id);
+            if env.storage().persistent().has(&key) {
+                extend_persistent_ttl(&env, &config, &key);
+                for vec_id in get_reward_vec_ids(&env, id).iter() {
+                    extend_persistent_ttl(&env, &config, &DataKey::RewardPerShare(id, vec_id));
+                }
+            }
+        }
+    }
+
+    /// returns the bin with id `bin_id`
+    pub fn get_bin(env: Env, bin_id: i32) -> Bin {
+        let active_bin = get_config(&env).active_bin;
+        get_bin_from_vec(&get_bin_vec_or_default(&env, get_vec_id_for_bin(bin_id), active_bin), bin_id)
+    }
+
+    /// returns the bins from `from_bin` up to and including `to_bin`, with their total shares and price.
+    ///
+    /// The range may span multiple vecs, but can't be larger than `MAX_BINS_IN_RANGE` bins and must be within the supported bins.
+    pub fn get_bins_range(env: Env, from_bin: i32, to_bin: i32) -> Vec<BinInfo> {
+        assert!(from_bin <= to_bin, "Invalid range");
+        assert!(to_bin - from_bin < MAX_BINS_IN_RANGE, "Range too large");
+
+        let config = get_config(&env);
+        check_bin_id(&env, config.bin_step, from_bin);
+        check_bin_id(&env, config.bin_step, to_bin);
+
+        let mut bins = vec![&env];
+
+        for vec_id in get_vec_id_for_bin(from_bin)..=get_vec_id_for_bin(to_bin) {
+            let bin_vec = get_bin_vec_or_default(&env, vec_id, config.active_bin);
+            let shares_vec = get_shares_vec_or_default(&env, vec_id);
+
+            for (bin, bin_shares) in bin_vec.iter().zip(shares_vec.iter()) {
+                if bin.bin_id >= from_bin && bin.bin_id <= to_bin {
+                    bins.push_back(bin_info(&env, config.bin_step, &bin, &bin_shares));
+                }
+            }
+        }
+
+        bins
+    }
+
+    /// returns the active bin with its total shares and price
+    pub fn get_active_bin_reserves(env: Env) -> BinInfo {
+        let config = get_config(&env);
+        let vec_id = get_vec_id_for_bin(config.active_bin);
+
+        bin_info(
             &env,
-            out_token.clone(),
-            env.current_contract_address(),
-            from.clone(),
-            downscaled_out,
-        );
+            config.bin_step,
+            &get_bin_from_vec(&get_bin_vec_or_default(&env, vec_id, config.active_bin), config.active_bin),
+            &get_bin_from_vec(&get_shares_vec_or_default(&env, vec_id), config.active_bin),
+        )
+    }
 
-        downscaled_out
+    /// returns the amount of the other token that you get for 1 `in_token` in bin `bin_id`, before fees and in 128.128 fixed point
+    ///
+    /// Fails with `ErrBinIdOutOfRange` if the bin is outside of the supported bins.
+    pub fn get_price_from_id(env: Env, bin_id: i32, in_token: Address) -> U256 {
+        let config = get_config(&env);
+        assert!(in_token == config.token_x || in_token == config.token_y);
+        check_bin_id(&env, config.bin_step, bin_id);
+
+        price_to_host(&env, price_from_bin_and_token(&config, bin_id, in_token))
+    }
+
+    /// returns the id of the bin for `price`, the price of y in x in 128.128 fixed point.
+    ///
+    /// Prices between two bins are rounded down to the lower bin, see `price::id_from_price`.
+    /// Fails with `ErrPriceOutOfRange` if the price is outside of the supported bins.
+    pub fn get_id_from_price(env: Env, price: U256) -> i32 {
+        let bin_step = get_config(&env).bin_step;
+        let price = price_from_host(&price);
+        check_price(&env, bin_step, price);
+
+        id_from_price(bin_step, price)
     }
 
     /// Bins are grouped together in a `BinVec` of size `BIN_VEC_SIZE`
@@ -429,17 +836,119 @@ impl Contract {
         get_position(&env, DataKey::Position(from.clone(), position_id))
     }
 
+    /// returns the ids of the positions of `owner`
+    pub fn get_position_ids(env: Env, owner: Address) -> Vec<i32> {
+        get_position_ids(&env, &owner)
+    }
+
+    /// returns the shares of `owner` in the bin `bin_id`, summed over all positions of `owner`
+    pub fn balance_of(env: Env, owner: Address, bin_id: i32) -> i128 {
+        get_position_ids(&env, &owner).iter()
+            .filter_map(|position_id| get_position(&env, DataKey::Position(owner.clone(), position_id)))
+            .map(|position| get_shares_from_position(&position, bin_id).shares)
+            .sum()
+    }
+
+    /// returns `balance_of` for each pair of `owners` and `bin_ids`, which must have the same length
+    pub fn balance_of_batch(env: Env, owners: Vec<Address>, bin_ids: Vec<i32>) -> Vec<i128> {
+        assert!(owners.len() == bin_ids.len(), "Length mismatch");
+
+        let mut balances = vec![&env];
+        for (owner, bin_id) in owners.iter().zip(bin_ids.iter()) {
+            balances.push_back(Self::balance_of(env.clone(), owner, bin_id));
+        }
+        balances
+    }
+
+    /// returns whether `operator` may transfer the shares of `owner`
+    pub fn is_approved_for_all(env: Env, owner: Address, operator: Address) -> bool {
+        is_operator(&env, &owner, &operator)
+    }
+
+    /// returns the incentive with id `id`, if it was not reclaimed
+    pub fn get_incentive(env: Env, id: u32) -> Option<Incentive> {
+        get_incentive(&env, id)
+    }
+
+    /// returns the ids of the incentives that were not reclaimed
+    pub fn get_incentives(env: Env) -> Vec<u32> {
+        get_incentives(&env)
+    }
+
+    /// returns what `claim_rewards` would send for the position now, per incentive: vec[(incentive_id, amount)]
+    pub fn get_pending_rewards(env: Env, owner: Address, position_id: i32) -> Vec<(u32, i128)> {
+        let config = get_config(&env);
+        let position = get_position_or_default(&env, DataKey::Position(owner.clone(), position_id));
+        pending_rewards(&env, &config, &owner, position_id, &position)
+    }
+
+    /// returns the limit order for a given order id for a user
+    pub fn get_limit_order(env: Env, owner: Address, order_id: i32) -> Option<LimitOrder> {
+        get_limit_order(&env, DataKey::LimitOrder(owner, order_id))
+    }
+
+    /// returns the unfilled limit orders in a bin
+    pub fn get_order_bin(env: Env, bin_id: i32) -> Option<OrderBin> {
+        get_order_bins(&env, get_vec_id_for_bin(bin_id)).get(bin_id)
+    }
+
+    /// returns the human price in bin `bin_id`: the amount of whole x tokens that 1 whole y token is worth, in 128.128 fixed point.
+    ///
+    /// Contrary to `get_price_from_id`, this takes the decimals of the tokens into account.
+    /// Fails with `ErrPriceOutOfRange` if the human price does not fit in 256 bits, which happens in the top bins when y has many more decimals than x.
+    pub fn get_human_price_from_id(env: Env, bin_id: i32) -> U256 {
+        let config = get_config(&env);
+        check_bin_id(&env, config.bin_step, bin_id);
+
+        let human_price = price_to_human(price_from_bin(config.bin_step, bin_id), config.decimals_x, config.decimals_y);
+        assert_with_error!(&env, human_price.is_some(), Error::ErrPriceOutOfRange);
+        price_to_host(&env, human_price.unwrap())
+    }
+
+    /// returns the id of the bin for a human price: the amount of whole x tokens that 1 whole y token is worth, in 128.128 fixed point.
+    ///
+    /// Prices between two bins are rounded down to the lower bin. Use `price::id_from_human_price` to pick the active bin of a new pool.
+    pub fn get_id_from_human_price(env: Env, human_price: U256) -> i32 {
+        let config = get_config(&env);
+
+        id_from_human_price(&env, config.bin_step, config.decimals_x, config.decimals_y, price_from_host(&human_price))
+    }
+
+    /// returns the lowest and highest bin id that are supported for the bin step of the pool.
+    ///
+    /// Liquidity and limit orders can only be placed in these bins, see `price::get_max_bin_id`.
+    pub fn get_supported_bins(env: Env) -> (i32, i32) {
+        let max_bin_id = get_max_bin_id(get_config(&env).bin_step);
+        (-max_bin_id, max_bin_id)
+    }
+
+    /// returns the amounts of the tokens that the pool owes to LPs and limit orders, see `skim` and `sync` for tokens that are not part of it.
+    pub fn get_reserves(env: Env) -> Reserves {
+        get_reserves(&env)
+    }
+
+    /// returns the sums of the reserves and shares of all bins. These are also part of `get_reserves`, which includes the limit orders.
+    pub fn get_bin_totals(env: Env) -> BinTotals {
+        get_bin_totals(&env)
+    }
+
     pub fn get_config(env: Env) -> Config {
         get_config(&env)
     }
 }
 
 mod test;
-mod storage;
-mod constants;
-mod math;
+pub mod storage;
+pub mod constants;
 mod error;
 mod bin;
-mod shares;
 mod token;
-mod price;
+pub mod price;
+mod liquidity;
+mod swap;
+mod order;
+pub mod flash;
+pub mod events;
+mod invariant;
+mod bitmap;
+mod rewards;
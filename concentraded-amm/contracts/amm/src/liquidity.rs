@@ -0,0 +1,217 @@
+use crate::bin::{delete_shares_in_position, get_bin_from_vec, get_shares_from_position, is_bin_in_vec, store_bin_in_vec, store_shares_in_position};
+use crate::error::Error;
+use crate::events::{self, BinChange};
+use crate::price::get_max_bin_id;
+use crate::rewards::{checkpoint_position, settle_position};
+use crate::storage::{add_to_bin_totals, delete_position, get_position, get_position_or_default, store_position, DataKey, get_bin_vec_or_default, get_shares_vec_or_default, get_vec_id_for_bin, store_bin_vec, store_shares_vec, Bin, BinShares, Config, DepositArgs, LiquidityShape, Position};
+use crate::token::{transfer_in_exact, transfer_out};
+use soroban_sdk::{assert_with_error, vec, Address, Env, Vec};
+use amm_math::shares::{active_bin_deposit, amounts_to_remove, shares_to_mint, Distribution};
+
+/// Adds or removes liquidity in the bins specified by `args` and credits or debits the shares to `position`,
+/// the position `position_id` of `owner`. `bin_offset` is added to the `bin_id_or_offset` of each arg.
+///
+/// NOTE: `args` must be ordered by bin_id ascending order
+///
+/// The bin vecs and the rewards of the position are stored and a `liquidity` event is published, but the position is not stored.
+/// That is up to the caller.
+///
+/// returns the token amounts that need to be settled: (x_token_amount, y_token_amount)
+/// a positive number means that the amount needs to be deposited and a negative number means that it needs to be withdrawn.
+pub fn modify_position(env: &Env, config: &Config, owner: &Address, position_id: i32, position: &mut Position, args: &Vec<DepositArgs>, bin_offset: i32) -> (i128, i128) {
+    let mut x_amount_delta = 0;
+    let mut y_amount_delta = 0;
+    let mut shares_delta = 0;
+    let mut changes = vec![env];
+    let settled = settle_position(env, config, owner, position_id, position);
+
+    let starting_bin_id = args.first_unchecked().bin_id_or_offset + bin_offset;
+        // match args.first_unchecked() {
+        // ActionArgs::Deposit(v) => v.bin_id_or_offset + bin_offset,
+        // ActionArgs::Remove(v) => v.bin_id_or_offset + bin_offset,
+    // };
+
+    let mut cur_vec_id = get_vec_id_for_bin(starting_bin_id);
+    let mut cur_bin_vec: Vec<Bin> = get_bin_vec_or_default(env, cur_vec_id, config.active_bin);
+    let mut cur_shares_vec: Vec<BinShares> = get_shares_vec_or_default(env, cur_vec_id);
+
+    let max_bin_id = get_max_bin_id(config.bin_step);
+
+    for i in 0..args.len() {
+        let modify_arg = &args.get(i).unwrap();
+        
+        let bin_id = modify_arg.bin_id_or_offset + bin_offset;
+        assert_with_error!(env, bin_id.abs() <= max_bin_id, Error::ErrBinIdOutOfRange);
+            // match modify_arg {
+            // ActionArgs::Deposit(v) => v.bin_id_or_offset,
+            // ActionArgs::Remove(v) => v.bin_id_or_offset,
+        // } + bin_offset;
+
+        // if the vec does not have the elements,
+        if !is_bin_in_vec(bin_id, cur_vec_id) {
+            store_bin_vec(env, cur_vec_id, &cur_bin_vec, config.active_bin);
+            store_shares_vec(env, cur_vec_id, &cur_shares_vec);
+
+            cur_vec_id = get_vec_id_for_bin(bin_id);
+            cur_bin_vec = get_bin_vec_or_default(env, cur_vec_id, config.active_bin);
+            cur_shares_vec = get_shares_vec_or_default(env, cur_vec_id);
+        }
+
+        let mut bin = get_bin_from_vec(&cur_bin_vec, bin_id);
+        let mut bin_shares = get_bin_from_vec(&cur_shares_vec, bin_id);
+        let mut user_shares = get_shares_from_position(position, bin_id);
+
+        match modify_arg.is_remove {
+            false => {
+                assert!(modify_arg.amount > 0);
+                let (amount_x, amount_y) = if bin_id == config.active_bin {
+                    active_bin_deposit(modify_arg.amount, bin.reserve_x, bin.reserve_y)
+                } else if bin_id < config.active_bin {
+                    (modify_arg.amount, 0i128)
+                } else {
+                    (0i128, modify_arg.amount)
+                };
+
+                let shares_to_mint = shares_to_mint(modify_arg.amount, bin_shares.shares, bin.reserve_x + bin.reserve_y);
+
+                x_amount_delta += amount_x;
+                y_amount_delta += amount_y;
+
+                bin.reserve_x += amount_x;
+                bin.reserve_y += amount_y;
+
+                bin_shares.shares += shares_to_mint;
+                user_shares.shares += shares_to_mint;
+                shares_delta += shares_to_mint;
+                
+                store_shares_in_position(position, user_shares);
+                store_bin_in_vec(&mut cur_bin_vec, bin_id, bin);
+                store_bin_in_vec(&mut cur_shares_vec, bin_id, bin_shares);
+                changes.push_back(BinChange { bin_id, amount_x, amount_y, shares: shares_to_mint });
+            }
+            true => {
+                assert!(modify_arg.amount > 0);
+                assert!(user_shares.shares >= modify_arg.amount);
+
+                let (amount_x_to_remove, amount_y_to_remove) =
+                    amounts_to_remove(modify_arg.amount, bin_shares.shares, bin.reserve_x, bin.reserve_y);
+
+                x_amount_delta -= amount_x_to_remove;
+                y_amount_delta -= amount_y_to_remove;
+                
+                bin.reserve_x -= amount_x_to_remove;
+                bin.reserve_y -= amount_y_to_remove;
+                
+                bin_shares.shares -= modify_arg.amount;
+                user_shares.shares -= modify_arg.amount;
+                shares_delta -= modify_arg.amount;
+
+
+                if user_shares.shares == 0 {
+                    delete_shares_in_position(position, user_shares);
+                }else{
+                    store_shares_in_position(position, user_shares);
+                }
+
+                store_bin_in_vec(&mut cur_bin_vec, bin_id, bin);
+                store_bin_in_vec(&mut cur_shares_vec, bin_id, bin_shares);
+                changes.push_back(BinChange {
+                    bin_id,
+                    amount_x: -amount_x_to_remove,
+                    amount_y: -amount_y_to_remove,
+                    shares: -modify_arg.amount,
+                });
+            }
+        };
+    }
+    store_bin_vec(env, cur_vec_id, &cur_bin_vec, config.active_bin);
+    store_shares_vec(env, cur_vec_id, &cur_shares_vec);
+    add_to_bin_totals(env, x_amount_delta, y_amount_delta, shares_delta);
+    checkpoint_position(env, owner, position_id, position, settled);
+    events::liquidity(env, owner, position_id, changes);
+
+    (x_amount_delta, y_amount_delta)
+}
+
+/// Transfers the token amounts returned by `modify_position`: deposits from `from` to the contract and withdrawals from the contract to `to`.
+///
+/// Deposits are credited to the bins before they are transferred, so they must be received in full.
+pub fn settle_amounts(env: &Env, config: &Config, from: &Address, to: &Address, x_amount_delta: i128, y_amount_delta: i128) {
+    for (token, delta) in [(&config.token_x, x_amount_delta), (&config.token_y, y_amount_delta)] {
+        if delta > 0 {
+            transfer_in_exact(env, config, token.clone(), from.clone(), delta);
+        } else if delta < 0 {
+            transfer_out(env, config, token.clone(), to.clone(), -delta);
+        }
+    }
+}
+
+/// Moves `shares` from the position `position_id` of `from` to the position with the same id of `to`.
+/// The bins and their reserves do not change, only who owns the shares.
+///
+/// The rewards of both positions are settled before the shares move. Positions without shares are deleted.
+pub fn transfer_shares(env: &Env, config: &Config, from: &Address, to: &Address, position_id: i32, shares: &Vec<BinShares>) {
+    assert!(from != to, "Cannot transfer to self");
+    assert!(!shares.is_empty(), "Nothing to transfer");
+
+    let from_key = DataKey::Position(from.clone(), position_id);
+    let to_key = DataKey::Position(to.clone(), position_id);
+    let mut from_position = get_position(env, from_key.clone()).expect("Position does not exist");
+    let mut to_position = get_position_or_default(env, to_key.clone());
+
+    let from_settled = settle_position(env, config, from, position_id, &from_position);
+    let to_settled = settle_position(env, config, to, position_id, &to_position);
+
+    for bin_shares in shares.iter() {
+        assert!(bin_shares.shares > 0, "Invalid shares");
+
+        let mut from_shares = get_shares_from_position(&from_position, bin_shares.bin_id);
+        assert!(from_shares.shares >= bin_shares.shares, "Insufficient shares");
+        from_shares.shares -= bin_shares.shares;
+        if from_shares.shares == 0 {
+            delete_shares_in_position(&mut from_position, from_shares);
+        } else {
+            store_shares_in_position(&mut from_position, from_shares);
+        }
+
+        let mut to_shares = get_shares_from_position(&to_position, bin_shares.bin_id);
+        to_shares.shares += bin_shares.shares;
+        store_shares_in_position(&mut to_position, to_shares);
+    }
+
+    checkpoint_position(env, from, position_id, &from_position, from_settled);
+    checkpoint_position(env, to, position_id, &to_position, to_settled);
+
+    if from_position.bin_shares.is_empty() {
+        delete_position(env, from_key);
+    } else {
+        store_position(env, from_key, &from_position);
+    }
+    store_position(env, to_key, &to_position);
+
+    events::batch_transfer(env, from, to, position_id, shares.clone());
+}
+
+/// Creates the `DepositArgs` to spread `amount_x` and `amount_y` over the bins in `[-width, width]`.
+/// The `bin_id_or_offset` of the args are offsets from the active bin.
+///
+/// `amount_x` is divided over the active bin and the bins to the left of it, and `amount_y` over the active bin and the bins to the right of it.
+/// Deposits in the active bin follow the current distribution of the bin, so only the part of the x and y amounts that matches it is deposited there.
+///
+/// The amounts are rounded down, so the sum of the args may be less than the amounts passed in.
+/// Bins outside of the supported bins are skipped, so their part of the amounts is not in the args either.
+pub fn shape_liquidity(env: &Env, config: &Config, shape: LiquidityShape, width: u32, amount_x: i128, amount_y: i128, active_bin: &Bin) -> Vec<DepositArgs> {
+    let distribution = Distribution::new(shape.into(), width);
+    let max_bin_id = get_max_bin_id(config.bin_step);
+    let mut args = vec![env];
+
+    for offset in distribution.offsets().filter(|offset| (active_bin.bin_id + offset).abs() <= max_bin_id) {
+        let amount = distribution.amount(offset, amount_x, amount_y, active_bin.reserve_x, active_bin.reserve_y);
+
+        if amount > 0 {
+            args.push_back(DepositArgs { is_remove: false, bin_id_or_offset: offset, amount });
+        }
+    }
+
+    args
+}
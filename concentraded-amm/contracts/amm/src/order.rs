@@ -0,0 +1,27 @@
+use crate::bitmap::has_vec;
+use crate::storage::{store_filled_orders, FilledOrders, OrderBin, VecIndex};
+use soroban_fixed_point_math::SorobanFixedPoint;
+use soroban_sdk::Env;
+
+pub fn has_orders_in_vec(env: &Env, vec_id: i32) -> bool {
+    has_vec(env, VecIndex::Orders, vec_id)
+}
+
+/// Called when all orders of `order_bin` are sold. The proceeds can now be claimed by the order owners.
+pub fn fill_order_bin(env: &Env, order_bin: &OrderBin) {
+    store_filled_orders(
+        env,
+        order_bin.epoch,
+        &FilledOrders {
+            total: order_bin.total,
+            proceeds: order_bin.proceeds,
+        },
+    );
+}
+
+/// The part of `value` that belongs to an order of `amount`, out of orders with a sum of `total`.
+///
+/// Rounds down. `total` and `value` should be lowered after every payout, so the last order gets what is left.
+pub fn order_share(env: &Env, amount: i128, total: i128, value: i128) -> i128 {
+    value.fixed_mul_floor(env, &amount, &total)
+}
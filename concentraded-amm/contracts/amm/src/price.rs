@@ -1,29 +1,67 @@
-use soroban_fixed_point_math::SorobanFixedPoint;
-use soroban_sdk::{Address, Env, I256};
-use crate::math::{c_pow, BONE};
+use ethnum::U256;
+use soroban_sdk::{assert_with_error, Address, Bytes, Env};
+use crate::constants::MAX_BPS;
+use crate::error::Error;
 use crate::storage::Config;
 
-pub fn price_from_bin_and_token(e: &Env, config: &Config, id: i32, token: Address) -> I256 {
+// the price math lives in the `amm-math` crate, so that off-chain code gives the same results
+pub use amm_math::price::{
+    get_amount_in, get_amount_out, get_base, get_max_bin_id, id_from_price, pow, price_from_bin, price_from_human,
+    price_to_human, SCALE, SCALE_OFFSET,
+};
+
+/// Fails with `ErrBinIdOutOfRange` if `id` is not supported for `bin_step`
+pub fn check_bin_id(env: &Env, bin_step: u32, id: i32) {
+    assert_with_error!(env, id.abs() <= get_max_bin_id(bin_step), Error::ErrBinIdOutOfRange);
+}
+
+/// Fails with `ErrInvalidBinStep` if `bin_step` is not in `[1, 10_000]`
+pub fn check_bin_step(env: &Env, bin_step: u32) {
+    assert_with_error!(env, bin_step > 0 && bin_step <= MAX_BPS, Error::ErrInvalidBinStep);
+}
+
+/// Fails with `ErrPriceOutOfRange` if `price` is not between the prices of the lowest and highest supported bins
+pub fn check_price(env: &Env, bin_step: u32, price: U256) {
+    let max_bin_id = get_max_bin_id(bin_step);
+    assert_with_error!(
+        env,
+        price >= price_from_bin(bin_step, -max_bin_id) && price < price_from_bin(bin_step, max_bin_id + 1),
+        Error::ErrPriceOutOfRange
+    );
+}
+
+/// returns the amount of the other token that you get for 1 `token` in bin `id`, in 128.128 fixed point
+pub fn price_from_bin_and_token(config: &Config, id: i32, token: Address) -> U256 {
+    let price = price_from_bin(config.bin_step, id);
+
     if token == config.token_y {
-        price_from_bin(e, config.bin_step, id, false)
+        price
     } else {
-        let bone_i256 = I256::from_i128(e, BONE);
-        bone_i256.fixed_div_floor(
-            &e,
-            &price_from_bin(e, config.bin_step, id, true),
-            &bone_i256,
-        )
+        U256::MAX / price
     }
 }
 
-pub fn price_from_bin(e: &Env, bin_step: u32, id: i32, round_up: bool) -> I256 {
-    // return (1 + binStep / 10_000) ** (binId - 100_000)
-    let base = I256::from_i128(
-        e,
-        BONE + id.signum() as i128 * (bin_step as i128 * BONE / 10_000),
-    );
-    let exp = I256::from_i128(e, BONE * id.abs() as i128);
-    let r = c_pow(e, &base, &exp, round_up);
+/// returns the id of the bin for a human price, the amount of whole x tokens that 1 whole y token is worth in 128.128 fixed point.
+///
+/// This can be used to pick the `active_bin` when creating a pool. Rounds down like `id_from_price`.
+/// Fails with `ErrPriceOutOfRange` if the price is outside of the supported bins.
+pub fn id_from_human_price(env: &Env, bin_step: u32, decimals_x: u32, decimals_y: u32, human_price: U256) -> i32 {
+    let price = price_from_human(human_price, decimals_x, decimals_y);
+    assert_with_error!(env, price.is_some(), Error::ErrPriceOutOfRange);
+    let price = price.unwrap();
+    check_price(env, bin_step, price);
+
+    id_from_price(bin_step, price)
+}
 
-    r
-}
\ No newline at end of file
+/// Converts a price to the `U256` host type, to return it from the contract.
+pub fn price_to_host(env: &Env, price: U256) -> soroban_sdk::U256 {
+    soroban_sdk::U256::from_be_bytes(env, &Bytes::from_array(env, &price.to_be_bytes()))
+}
+
+/// Converts a price from the `U256` host type, when it is passed to the contract.
+pub fn price_from_host(price: &soroban_sdk::U256) -> U256 {
+    let mut bytes = [0u8; 32];
+    price.to_be_bytes().copy_into_slice(&mut bytes);
+    U256::from_be_bytes(bytes)
+}
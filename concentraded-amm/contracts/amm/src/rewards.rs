@@ -0,0 +1,245 @@
+use crate::constants::{MAX_REWARD_WIDTH, REWARD_SCALE};
+use crate::price::check_bin_id;
+use crate::token::transfer;
+use crate::storage::{get_incentive, get_incentives, get_position_rewards, get_reward_per_share, get_shares_vec, get_vec_id_for_bin, store_incentive, store_position_rewards, store_reward_per_share, BinShares, Config, DataKey, Incentive, Position, PositionRewards, RewardRange};
+use soroban_fixed_point_math::SorobanFixedPoint;
+use soroban_sdk::{vec, Address, Env, Map, Vec};
+
+/// The incentives that were not reclaimed, with the reward per share of the bin vecs that accrued: (id, incentive, reward per share by vec id).
+/// The reward per share of the other bin vecs is read from storage.
+pub type Incentives = Vec<(u32, Incentive, Map<i32, Map<i32, i128>>)>;
+
+/// The rewards of a position that were settled before its shares change, see `settle_position`.
+pub struct Settled {
+    rewards: PositionRewards,
+    incentives: Incentives,
+}
+
+/// returns the part of the amount of `incentive` that is paid out until `timestamp`
+fn emitted(env: &Env, incentive: &Incentive, timestamp: u64) -> i128 {
+    let elapsed = timestamp.clamp(incentive.start, incentive.end) - incentive.start;
+    incentive.amount.fixed_mul_floor(env, &(elapsed as i128), &((incentive.end - incentive.start) as i128))
+}
+
+/// Fails if `range` has more bins than an incentive can pay, or bins that the pool does not support.
+pub fn check_reward_range(env: &Env, config: &Config, range: &RewardRange) {
+    match range {
+        RewardRange::Active(width) => assert!(*width <= MAX_REWARD_WIDTH, "Invalid reward range"),
+        RewardRange::Bins(from_bin, to_bin) => {
+            check_bin_id(env, config.bin_step, *from_bin);
+            check_bin_id(env, config.bin_step, *to_bin);
+            assert!(from_bin <= to_bin && (to_bin - from_bin) as u32 <= 2 * MAX_REWARD_WIDTH, "Invalid reward range");
+        }
+    }
+}
+
+/// returns the first and last bin that `range` pays, with `active_bin` as the active bin
+pub fn range_bins(range: &RewardRange, active_bin: i32) -> (i32, i32) {
+    match range {
+        RewardRange::Active(width) => (active_bin - *width as i32, active_bin + *width as i32),
+        RewardRange::Bins(from_bin, to_bin) => (*from_bin, *to_bin),
+    }
+}
+
+/// returns the bins in `[from_bin, to_bin]` that have shares
+fn bins_with_shares(env: &Env, from_bin: i32, to_bin: i32) -> Vec<BinShares> {
+    let mut bins = vec![env];
+
+    for vec_id in get_vec_id_for_bin(from_bin)..=get_vec_id_for_bin(to_bin) {
+        let Some(shares_vec) = get_shares_vec(env, vec_id) else { continue };
+
+        for bin in shares_vec.iter() {
+            if bin.shares > 0 && bin.bin_id >= from_bin && bin.bin_id <= to_bin {
+                bins.push_back(bin);
+            }
+        }
+    }
+
+    bins
+}
+
+/// Adds the rewards of `incentive` from its `last_update` until `now` to the reward per share of the bins in its range.
+///
+/// The rewards are divided equally over the bins with shares, and within a bin by shares.
+/// If no bin in the range has shares, the rewards are not paid.
+///
+/// returns whether `incentive` changed
+fn accrue(env: &Env, config: &Config, id: u32, incentive: &mut Incentive, accrued: &mut Map<i32, Map<i32, i128>>, now: u64) -> bool {
+    let until = now.min(incentive.end);
+    if until <= incentive.last_update {
+        return false;
+    }
+
+    let reward = emitted(env, incentive, until) - emitted(env, incentive, incentive.last_update);
+    incentive.last_update = until;
+
+    let (from_bin, to_bin) = range_bins(&incentive.range, config.active_bin);
+    let bins = bins_with_shares(env, from_bin, to_bin);
+    if bins.is_empty() {
+        return true;
+    }
+
+    let part = reward / bins.len() as i128;
+    for bin in bins.iter() {
+        let added = part.fixed_mul_floor(env, &REWARD_SCALE, &bin.shares);
+        let vec_id = get_vec_id_for_bin(bin.bin_id);
+        let mut reward_per_share = accrued.get(vec_id).unwrap_or_else(|| get_reward_per_share(env, id, vec_id));
+        reward_per_share.set(bin.bin_id, reward_per_share.get(bin.bin_id).unwrap_or(0) + added);
+        accrued.set(vec_id, reward_per_share);
+    }
+
+    true
+}
+
+/// returns the incentives that were not reclaimed, accrued until now. The incentives that changed are stored if `store` is true.
+fn accrue_incentives(env: &Env, config: &Config, store: bool) -> Incentives {
+    let now = env.ledger().timestamp();
+    let mut incentives = vec![env];
+
+    for id in get_incentives(env).iter() {
+        let mut incentive = get_incentive(env, id).unwrap();
+        let mut accrued = Map::new(env);
+
+        if accrue(env, config, id, &mut incentive, &mut accrued, now) && store {
+            store_incentive(env, id, &incentive);
+            for (vec_id, reward_per_share) in accrued.iter() {
+                store_reward_per_share(env, id, vec_id, &reward_per_share);
+            }
+        }
+
+        incentives.push_back((id, incentive, accrued));
+    }
+
+    incentives
+}
+
+/// Adds the rewards until now to the bins. Must be called before the shares of a bin or the active bin change,
+/// so the rewards until then go to the bins and shares that earned them.
+pub fn update_rewards(env: &Env, config: &Config) -> Incentives {
+    accrue_incentives(env, config, true)
+}
+
+/// returns the reward per share of `bin_id` for incentive `id`, from `accrued` if its bin vec accrued and otherwise from storage.
+/// A bin vec that is read from storage is added to `accrued`, so it is read once.
+fn bin_reward_per_share(env: &Env, id: u32, accrued: &mut Map<i32, Map<i32, i128>>, bin_id: i32) -> i128 {
+    let vec_id = get_vec_id_for_bin(bin_id);
+    let reward_per_share = accrued.get(vec_id).unwrap_or_else(|| {
+        let reward_per_share = get_reward_per_share(env, id, vec_id);
+        accrued.set(vec_id, reward_per_share.clone());
+        reward_per_share
+    });
+    reward_per_share.get(bin_id).unwrap_or(0)
+}
+
+/// Adds what the shares of `position` earned since its checkpoints to the pending rewards.
+///
+/// An incentive without a checkpoint for a bin was added after the position was last settled,
+/// and its reward per share started at 0, so 0 is used as the checkpoint.
+fn settle(env: &Env, rewards: &mut PositionRewards, position: &Position, incentives: &Incentives) {
+    for (id, _, mut accrued) in incentives.iter() {
+        let checkpoints = rewards.checkpoints.get(id).unwrap_or_else(|| Map::new(env));
+
+        let mut earned = 0;
+        for bin in position.bin_shares.iter() {
+            let delta = bin_reward_per_share(env, id, &mut accrued, bin.bin_id) - checkpoints.get(bin.bin_id).unwrap_or(0);
+            if delta > 0 {
+                earned += bin.shares.fixed_mul_floor(env, &delta, &REWARD_SCALE);
+            }
+        }
+
+        if earned > 0 {
+            rewards.pending.set(id, rewards.pending.get(id).unwrap_or(0) + earned);
+        }
+    }
+}
+
+/// Sets the checkpoints to the current reward per share of the bins of `position`,
+/// and drops the checkpoints and pending rewards of incentives that were reclaimed.
+fn checkpoint(env: &Env, rewards: &mut PositionRewards, position: &Position, incentives: &Incentives) {
+    let mut checkpoints = Map::new(env);
+    let mut pending = Map::new(env);
+
+    for (id, _, mut accrued) in incentives.iter() {
+        let mut bins = Map::new(env);
+        for bin in position.bin_shares.iter() {
+            let bin_reward_per_share = bin_reward_per_share(env, id, &mut accrued, bin.bin_id);
+            if bin_reward_per_share > 0 {
+                bins.set(bin.bin_id, bin_reward_per_share);
+            }
+        }
+
+        if !bins.is_empty() {
+            checkpoints.set(id, bins);
+        }
+        if let Some(amount) = rewards.pending.get(id) {
+            pending.set(id, amount);
+        }
+    }
+
+    rewards.checkpoints = checkpoints;
+    rewards.pending = pending;
+}
+
+/// Updates the rewards and settles what `position` earned until now. Must be called before the shares of the position change,
+/// and followed by `checkpoint_position` after the change.
+///
+/// returns `None` if the pool has no incentives
+pub fn settle_position(env: &Env, config: &Config, owner: &Address, position_id: i32, position: &Position) -> Option<Settled> {
+    let incentives = update_rewards(env, config);
+    if incentives.is_empty() {
+        return None;
+    }
+
+    let mut rewards = get_position_rewards(env, DataKey::PositionRewards(owner.clone(), position_id));
+    settle(env, &mut rewards, position, &incentives);
+
+    Some(Settled { rewards, incentives })
+}
+
+/// Stores the rewards settled by `settle_position`, with checkpoints for the shares that `position` has now.
+pub fn checkpoint_position(env: &Env, owner: &Address, position_id: i32, position: &Position, settled: Option<Settled>) {
+    let Some(Settled { mut rewards, incentives }) = settled else { return };
+
+    checkpoint(env, &mut rewards, position, &incentives);
+    store_position_rewards(env, DataKey::PositionRewards(owner.clone(), position_id), &rewards);
+}
+
+/// returns the rewards of `position` that can be claimed now, per incentive: (incentive id, amount). Nothing is stored.
+pub fn pending_rewards(env: &Env, config: &Config, owner: &Address, position_id: i32, position: &Position) -> Vec<(u32, i128)> {
+    let incentives = accrue_incentives(env, config, false);
+    let mut rewards = get_position_rewards(env, DataKey::PositionRewards(owner.clone(), position_id));
+    settle(env, &mut rewards, position, &incentives);
+    checkpoint(env, &mut rewards, position, &incentives);
+
+    let mut pending = vec![env];
+    for (id, amount) in rewards.pending.iter() {
+        if amount > 0 {
+            pending.push_back((id, amount));
+        }
+    }
+    pending
+}
+
+/// Settles the rewards of `position` and sends its pending rewards of incentive `incentive_id` to `owner`.
+/// The rewards of the other incentives stay pending, so a reward token that fails its transfer only blocks its own rewards.
+///
+/// returns the amount sent
+pub fn claim_rewards(env: &Env, config: &Config, owner: &Address, position_id: i32, position: &Position, incentive_id: u32) -> i128 {
+    let Some(mut settled) = settle_position(env, config, owner, position_id, position) else { return 0 };
+
+    // pending rewards of incentives that were reclaimed are not in `incentives`, and are dropped by the checkpoint
+    let mut amount = 0;
+    if let Some((id, mut incentive, _)) = settled.incentives.iter().find(|(id, _, _)| *id == incentive_id) {
+        amount = settled.rewards.pending.get(id).unwrap_or(0);
+        if amount > 0 {
+            incentive.claimed += amount;
+            store_incentive(env, id, &incentive);
+            transfer(env, incentive.token, env.current_contract_address(), owner.clone(), amount);
+        }
+        settled.rewards.pending.remove(id);
+    }
+
+    checkpoint_position(env, owner, position_id, position, Some(settled));
+
+    amount
+}
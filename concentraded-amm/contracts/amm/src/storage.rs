@@ -1,6 +1,8 @@
-use crate::bin::{default_bin_vec, default_shares_vec};
+use crate::bin::{default_bin_vec, default_shares_vec, is_bin_vec_empty, is_shares_vec_empty};
+use crate::bitmap::set_vec;
 use crate::constants::BIN_VEC_SIZE;
-use soroban_sdk::{contracttype, vec, Address, Env, TryIntoVal, Val, Vec};
+use amm_math::shares::Shape;
+use soroban_sdk::{contracttype, vec, Address, Env, IntoVal, Map, TryIntoVal, Val, Vec, U256};
 
 #[derive(Clone)]
 #[contracttype]
@@ -9,6 +11,32 @@ pub enum DataKey {
     BinVec(i32),
     BinShareVec(i32),
     Position(Address, i32),
+    OrderBins(i32),
+    OrderEpoch,
+    FilledOrders(u32),
+    LimitOrder(Address, i32),
+    Reserves,
+    BinTotals,
+    Incentives,
+    IncentiveCount,
+    Incentive(u32),
+    RewardPerShare(u32, i32),
+    RewardVecIds(u32),
+    PositionRewards(Address, i32),
+    PositionIds(Address),
+    Operator(Address, Address),
+    VecBitmap(VecIndex, u32, u32),
+}
+
+/// The bitmaps of vecs in storage, see `bitmap.rs`.
+#[contracttype]
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[repr(u32)]
+pub enum VecIndex {
+    /// The vecs that have liquidity.
+    Bins = 0,
+    /// The vecs that have limit orders.
+    Orders = 1,
 }
 
 /// stored as vec[shares]
@@ -19,6 +47,18 @@ pub struct BinShares {
     pub shares: i128,
 }
 
+/// A bin with its total shares and price, as returned by the bin getters.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct BinInfo {
+    pub bin_id: i32,
+    pub reserve_x: i128,
+    pub reserve_y: i128,
+    pub shares: i128,
+    /// the price of y in x, in 128.128 fixed point
+    pub price: U256,
+}
+
 /// stored as vec[reserve_x|reserve_y] if not active bin
 /// otherwise if active_bin, it is stored as vec[reserve_x, reserve_y]
 #[contracttype]
@@ -40,19 +80,15 @@ pub struct Bin {
 pub fn get_bin_vec(env: &Env, vec_id: i32, active_bin: i32) -> Option<Vec<Bin>> {
     let raw: Option<Vec<Vec<i128>>> = env.storage().persistent().get(&DataKey::BinVec(vec_id));
 
-    if raw.is_none() {
-        return None;
-    }
-
-    let raw_vec = raw.unwrap();
+    let raw_vec = raw?;
     let mut bin_array = [Bin::default(); BIN_VEC_SIZE as usize];
 
     for i in 0..raw_vec.len() {
-        let mut raw_bin: Vec<i128> = raw_vec.get_unchecked(i);
+        let raw_bin: Vec<i128> = raw_vec.get_unchecked(i);
 
         let bin_id: i32 = get_first_bin_id_in_vec(vec_id) + i as i32;
 
-        let reserve_x = if bin_id < active_bin || bin_id == active_bin{
+        let reserve_x = if bin_id <= active_bin{
             raw_bin.get_unchecked(0).try_into_val(env).unwrap()
         } else {
             0
@@ -61,7 +97,7 @@ pub fn get_bin_vec(env: &Env, vec_id: i32, active_bin: i32) -> Option<Vec<Bin>>
         let reserve_y = if bin_id > active_bin {
             raw_bin.get_unchecked(0).try_into_val(env).unwrap()
         } else if bin_id == active_bin {
-            raw_bin.get_unchecked(1).try_into_val(env).unwrap()
+            raw_bin.get(1).unwrap_or(0)
         }else {
             0
         };
@@ -69,17 +105,13 @@ pub fn get_bin_vec(env: &Env, vec_id: i32, active_bin: i32) -> Option<Vec<Bin>>
         bin_array[i as usize] = Bin{bin_id, reserve_x, reserve_y};
     }
 
-    Some(Vec::from_array(&env, bin_array))
+    Some(Vec::from_array(env, bin_array))
 }
 
 pub fn get_shares_vec(env: &Env, vec_id: i32) -> Option<Vec<BinShares>> {
     let raw: Option<Vec<i128>> = env.storage().persistent().get(&DataKey::BinShareVec(vec_id));
 
-    if raw.is_none() {
-        return None;
-    }
-
-    let raw_vec = raw.unwrap();
+    let raw_vec = raw?;
     let mut bin_array = [BinShares::default(); BIN_VEC_SIZE as usize];
 
     for i in 0..raw_vec.len() {
@@ -90,9 +122,7 @@ pub fn get_shares_vec(env: &Env, vec_id: i32) -> Option<Vec<BinShares>> {
         bin_array[i as usize] = BinShares{bin_id, shares};
     }
 
-    // dbg!(&bin_array);
-
-    Some(Vec::from_array(&env, bin_array))
+    Some(Vec::from_array(env, bin_array))
 }
 
 pub fn get_bin_vec_or_default(env: &Env, vec_id: i32, active_bin: i32) -> Vec<Bin> {
@@ -104,40 +134,54 @@ pub fn get_shares_vec_or_default(env: &Env, vec_id: i32) -> Vec<BinShares> {
         .unwrap_or_else(|| default_shares_vec(env, vec_id))
 }
 
-pub fn bin_to_vec(env: &Env, bin: &Bin) -> Vec<i128>{
-    if bin.reserve_x > 0 && bin.reserve_y > 0 {
-        Vec::from_array(&env, [bin.reserve_x, bin.reserve_y])
+/// The active bin is always stored with both reserves, even if one of them is 0.
+/// Otherwise, we can't tell which reserve is stored when reading it back.
+pub fn bin_to_vec(env: &Env, bin: &Bin, active_bin: i32) -> Vec<i128>{
+    if bin.bin_id == active_bin || (bin.reserve_x > 0 && bin.reserve_y > 0) {
+        Vec::from_array(env, [bin.reserve_x, bin.reserve_y])
     }else{
         let reserve = if bin.reserve_x > 0 {bin.reserve_x} else {bin.reserve_y};
-        Vec::from_array(&env, [reserve])
+        Vec::from_array(env, [reserve])
     }
 }
 
-pub fn store_bin_vec(env: &Env, vec_id: i32, vec: &Vec<Bin>) {
+/// Empty vecs are removed instead of stored, reading them gives the default vec again.
+///
+/// Also keeps track of which vecs have liquidity in the `VecIndex::Bins` bitmap, so that swaps can skip the empty vecs.
+pub fn store_bin_vec(env: &Env, vec_id: i32, vec: &Vec<Bin>, active_bin: i32) {
+    let is_empty = is_bin_vec_empty(vec);
+    set_vec(env, VecIndex::Bins, vec_id, !is_empty);
+
+    if is_empty {
+        env.storage().persistent().remove(&DataKey::BinVec(vec_id));
+        return;
+    }
+
     let mut array = [Val::default(); BIN_VEC_SIZE as usize];
 
-    for i in 0..BIN_VEC_SIZE as usize{
-        let bin_val = bin_to_vec(env, &vec.get_unchecked(i as u32));
+    for (i, val) in array.iter_mut().enumerate() {
+        let bin_val = bin_to_vec(env, &vec.get_unchecked(i as u32), active_bin);
 
-        array[i] = bin_val.to_val(); // not actually sure if this is better :shrug:
+        *val = bin_val.to_val(); // not actually sure if this is better :shrug:
     }
 
-    env.storage()
-        .persistent()
-        .set(&DataKey::BinVec(vec_id), &Vec::from_array(env, array))
+    set_persistent(env, &DataKey::BinVec(vec_id), &Vec::from_array(env, array))
 }
 
+/// Empty vecs are removed instead of stored, reading them gives the default vec again.
 pub fn store_shares_vec(env: &Env, vec_id: i32, vec: &Vec<BinShares>) {
-    let mut array = [0i128; BIN_VEC_SIZE as usize];
+    if is_shares_vec_empty(vec) {
+        env.storage().persistent().remove(&DataKey::BinShareVec(vec_id));
+        return;
+    }
 
-    for i in 0..BIN_VEC_SIZE as usize{
+    let mut array = [0i128; BIN_VEC_SIZE as usize];
 
-        array[i] = vec.get_unchecked(i as u32).shares;
+    for (i, shares) in array.iter_mut().enumerate() {
+        *shares = vec.get_unchecked(i as u32).shares;
     }
 
-    env.storage()
-        .persistent()
-        .set(&DataKey::BinShareVec(vec_id), &Vec::from_array(env, array))
+    set_persistent(env, &DataKey::BinShareVec(vec_id), &Vec::from_array(env, array))
 }
 
 /// bin_id_or_offset is either the bin id or the offset from the current active bin
@@ -152,6 +196,29 @@ pub struct DepositArgs {
 }
 
 
+/// How liquidity is spread over the bins around the active bin, for example when rebalancing a position.
+#[contracttype]
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[repr(u32)]
+pub enum LiquidityShape {
+    /// The same weight in every bin.
+    Spot = 0,
+    /// Most of the liquidity around the active bin, decreasing towards the edges.
+    Curve = 1,
+    /// Most of the liquidity at the edges, decreasing towards the active bin.
+    BidAsk = 2,
+}
+
+impl From<LiquidityShape> for Shape {
+    fn from(shape: LiquidityShape) -> Self {
+        match shape {
+            LiquidityShape::Spot => Shape::Spot,
+            LiquidityShape::Curve => Shape::Curve,
+            LiquidityShape::BidAsk => Shape::BidAsk,
+        }
+    }
+}
+
 // #[contracttype]
 // #[derive(Clone)]
 // pub struct RemoveArgs {
@@ -167,6 +234,18 @@ pub struct Config {
     pub bin_step: u32,
     pub active_bin: i32,
     pub fee: u32,
+    /// fee charged on flash loans in bps
+    pub flash_loan_fee: u32,
+    /// entries with a TTL below this amount of ledgers are extended when they are written or bumped
+    pub ttl_threshold: u32,
+    /// the TTL in ledgers that entries are extended to, capped to the max TTL of the network
+    pub ttl_extend_to: u32,
+    /// the decimals of token x, read from the token when the pool is created
+    pub decimals_x: u32,
+    /// the decimals of token y, read from the token when the pool is created
+    pub decimals_y: u32,
+    /// may add incentives
+    pub admin: Address,
     // pub protocol_fee: u32,
     // pub fee_recipient: Address,
 }
@@ -177,6 +256,31 @@ pub fn get_config(env: &Env) -> Config {
 
 pub fn store_config(env: &Env, config: &Config) {
     env.storage().instance().set(&DataKey::Config, config);
+    extend_instance_ttl(env, config);
+}
+
+/// returns the (threshold, extend_to) pair to use with `extend_ttl`
+fn ttl_bounds(env: &Env, config: &Config) -> (u32, u32) {
+    let extend_to = config.ttl_extend_to.min(env.storage().max_ttl());
+    (config.ttl_threshold.min(extend_to), extend_to)
+}
+
+/// Extends the TTL of the instance, which also keeps the config and the contract code alive.
+pub fn extend_instance_ttl(env: &Env, config: &Config) {
+    let (threshold, extend_to) = ttl_bounds(env, config);
+    env.storage().instance().extend_ttl(threshold, extend_to);
+}
+
+/// Extends the TTL of a persistent entry. The entry must exist.
+pub fn extend_persistent_ttl(env: &Env, config: &Config, key: &DataKey) {
+    let (threshold, extend_to) = ttl_bounds(env, config);
+    env.storage().persistent().extend_ttl(key, threshold, extend_to);
+}
+
+/// Writes a persistent entry and extends its TTL, so the entries that are used don't get archived.
+pub fn set_persistent<V: IntoVal<Env, Val>>(env: &Env, key: &DataKey, value: &V) {
+    env.storage().persistent().set(key, value);
+    extend_persistent_ttl(env, &get_config(env), key);
 }
 
 #[contracttype]
@@ -195,19 +299,334 @@ pub fn get_position_or_default(env: &Env, key: DataKey) -> Position {
     })
 }
 
+/// Also adds the id of a new position to the position ids of its owner.
 pub fn store_position(env: &Env, key: DataKey, position: &Position) {
-    env.storage().persistent().set(&key, position);
+    if let DataKey::Position(owner, position_id) = key.clone() {
+        if !env.storage().persistent().has(&key) {
+            let mut position_ids = get_position_ids(env, &owner);
+            position_ids.push_back(position_id);
+            store_position_ids(env, &owner, &position_ids);
+        }
+    }
+
+    set_persistent(env, &key, position);
+}
+
+/// Also removes the id of the position from the position ids of its owner.
+pub fn delete_position(env: &Env, key: DataKey) {
+    if let DataKey::Position(owner, position_id) = key.clone() {
+        let mut position_ids = get_position_ids(env, &owner);
+        if let Some(index) = position_ids.first_index_of(position_id) {
+            position_ids.remove(index);
+            store_position_ids(env, &owner, &position_ids);
+        }
+    }
+
+    env.storage().persistent().remove(&key);
+}
+
+/// The ids of the positions of `owner`, in the order they were created.
+pub fn get_position_ids(env: &Env, owner: &Address) -> Vec<i32> {
+    env.storage().persistent().get(&DataKey::PositionIds(owner.clone())).unwrap_or_else(|| vec![env])
+}
+
+/// An owner without positions is removed instead of stored.
+fn store_position_ids(env: &Env, owner: &Address, position_ids: &Vec<i32>) {
+    let key = DataKey::PositionIds(owner.clone());
+
+    if position_ids.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        set_persistent(env, &key, position_ids);
+    }
+}
+
+pub fn is_operator(env: &Env, owner: &Address, operator: &Address) -> bool {
+    env.storage().persistent().get(&DataKey::Operator(owner.clone(), operator.clone())).unwrap_or(false)
+}
+
+/// Operators that are not approved anymore are removed instead of stored.
+pub fn store_operator(env: &Env, owner: &Address, operator: &Address, approved: bool) {
+    let key = DataKey::Operator(owner.clone(), operator.clone());
+
+    if approved {
+        set_persistent(env, &key, &true);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
 }
 
 /// We store bins in lists of size `BIN_VEC_SIZE`
 /// This function finds the id of the list for a given `bin_id`
+///
+/// Rounds down, so the vec with id `-1` holds the bins `-BIN_VEC_SIZE` to `-1`.
 pub fn get_vec_id_for_bin(bin_id: i32) -> i32 {
-    if bin_id < 0 {
-        return bin_id / BIN_VEC_SIZE - 1;
-    }
-    bin_id / BIN_VEC_SIZE
+    bin_id.div_euclid(BIN_VEC_SIZE)
 }
 
 pub fn get_first_bin_id_in_vec(vec_id: i32) -> i32 {
     vec_id * BIN_VEC_SIZE
-}
\ No newline at end of file
+}
+
+/// The limit orders in a bin.
+///
+/// All orders in a bin sell the same token. x if the bin is left of the active bin and y if it is right of it.
+/// The orders placed in a bin are filled together, as one batch with its own `epoch`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct OrderBin {
+    pub bin_id: i32,
+    pub epoch: u32,
+    pub sell_x: bool,
+    /// sum of the amounts of the orders
+    pub total: i128,
+    /// the part of `total` that has not been sold yet
+    pub remaining: i128,
+    /// the amount of the other token received for the part that was sold
+    pub proceeds: i128,
+}
+
+/// What is left to claim of a batch of orders that was filled.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct FilledOrders {
+    pub total: i128,
+    pub proceeds: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct LimitOrder {
+    pub bin_id: i32,
+    pub epoch: u32,
+    pub sell_x: bool,
+    pub amount: i128,
+}
+
+/// The order bins are grouped by the same vec id as the bins.
+pub fn get_order_bins(env: &Env, vec_id: i32) -> Map<i32, OrderBin> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::OrderBins(vec_id))
+        .unwrap_or_else(|| Map::new(env))
+}
+
+/// Also keeps track of which vecs have orders in a bitmap, so that swaps don't need to read the order bins of every vec.
+pub fn store_order_bins(env: &Env, vec_id: i32, order_bins: &Map<i32, OrderBin>) {
+    if order_bins.is_empty() {
+        env.storage().persistent().remove(&DataKey::OrderBins(vec_id));
+    } else {
+        set_persistent(env, &DataKey::OrderBins(vec_id), order_bins);
+    }
+    set_vec(env, VecIndex::Orders, vec_id, !order_bins.is_empty());
+}
+
+/// The amounts of the tokens that the pool owes to LPs and limit orders.
+///
+/// This is the sum of the bin reserves and of the tokens in limit orders, including the proceeds that have not been claimed yet.
+/// Tokens that are sent to the pool without going through the pool are not part of it, see `skim` and `sync`.
+#[contracttype]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Reserves {
+    pub reserve_x: i128,
+    pub reserve_y: i128,
+}
+
+pub fn get_reserves(env: &Env) -> Reserves {
+    env.storage()
+        .instance()
+        .get(&DataKey::Reserves)
+        .unwrap_or_default()
+}
+
+/// Adds `delta` of `token` to the reserves, a negative `delta` removes it.
+pub fn add_to_reserves(env: &Env, config: &Config, token: &Address, delta: i128) {
+    let mut reserves = get_reserves(env);
+
+    if *token == config.token_x {
+        reserves.reserve_x += delta;
+    } else {
+        reserves.reserve_y += delta;
+    }
+
+    env.storage().instance().set(&DataKey::Reserves, &reserves);
+}
+
+/// The sums of the reserves and shares of all bins.
+///
+/// The sum of the shares of different bins does not mean much by itself, it is kept to check that shares are not created or lost.
+#[contracttype]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct BinTotals {
+    pub total_reserve_x: i128,
+    pub total_reserve_y: i128,
+    pub total_shares: i128,
+}
+
+pub fn get_bin_totals(env: &Env) -> BinTotals {
+    env.storage()
+        .instance()
+        .get(&DataKey::BinTotals)
+        .unwrap_or_default()
+}
+
+/// Adds the changes in the reserves and shares of the bins to the totals. Must be called for every change to the bins.
+pub fn add_to_bin_totals(env: &Env, x_delta: i128, y_delta: i128, shares_delta: i128) {
+    if x_delta == 0 && y_delta == 0 && shares_delta == 0 {
+        return;
+    }
+
+    let mut totals = get_bin_totals(env);
+    totals.total_reserve_x += x_delta;
+    totals.total_reserve_y += y_delta;
+    totals.total_shares += shares_delta;
+
+    env.storage().instance().set(&DataKey::BinTotals, &totals);
+}
+
+/// Every batch of orders gets a new epoch, so filled batches never share an epoch.
+pub fn next_order_epoch(env: &Env) -> u32 {
+    let epoch: u32 = env.storage().instance().get(&DataKey::OrderEpoch).unwrap_or(0);
+    env.storage().instance().set(&DataKey::OrderEpoch, &(epoch + 1));
+    epoch
+}
+
+pub fn get_filled_orders(env: &Env, epoch: u32) -> Option<FilledOrders> {
+    env.storage().persistent().get(&DataKey::FilledOrders(epoch))
+}
+
+pub fn store_filled_orders(env: &Env, epoch: u32, filled_orders: &FilledOrders) {
+    set_persistent(env, &DataKey::FilledOrders(epoch), filled_orders);
+}
+
+pub fn delete_filled_orders(env: &Env, epoch: u32) {
+    env.storage().persistent().remove(&DataKey::FilledOrders(epoch));
+}
+
+pub fn get_limit_order(env: &Env, key: DataKey) -> Option<LimitOrder> {
+    env.storage().persistent().get(&key)
+}
+
+pub fn store_limit_order(env: &Env, key: DataKey, order: &LimitOrder) {
+    set_persistent(env, &key, order);
+}
+
+pub fn delete_limit_order(env: &Env, key: DataKey) {
+    env.storage().persistent().remove(&key);
+}
+
+/// The bins that an incentive pays rewards to.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum RewardRange {
+    /// the bins within this many bins of the active bin, wherever the active bin is when the rewards accrue
+    Active(u32),
+    /// the bins from the first to the second bin id, inclusive
+    Bins(i32, i32),
+}
+
+/// Rewards of `token` that are paid to the LPs of the bins in `range`, from `start` to `end`.
+///
+/// `amount` is paid at the same rate over the whole time, and divided equally over the bins in the range that have shares.
+/// Rewards for a time without shares in the range are not paid, and go back to the manager with `reclaim_incentive`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Incentive {
+    pub manager: Address,
+    pub token: Address,
+    pub amount: i128,
+    /// ledger timestamp
+    pub start: u64,
+    /// ledger timestamp
+    pub end: u64,
+    pub range: RewardRange,
+    /// the rewards until this ledger timestamp were added to the bins
+    pub last_update: u64,
+    /// the part of `amount` that was claimed by LPs
+    pub claimed: i128,
+}
+
+/// The rewards of a position.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PositionRewards {
+    /// per incentive, the reward per share of the bins of the position when its rewards were last settled
+    pub checkpoints: Map<u32, Map<i32, i128>>,
+    /// per incentive, the rewards that were settled but not claimed yet
+    pub pending: Map<u32, i128>,
+}
+
+/// The ids of the incentives that were not reclaimed, in the order they were added.
+pub fn get_incentives(env: &Env) -> Vec<u32> {
+    env.storage()
+        .instance()
+        .get(&DataKey::Incentives)
+        .unwrap_or_else(|| vec![env])
+}
+
+pub fn store_incentives(env: &Env, incentives: &Vec<u32>) {
+    env.storage().instance().set(&DataKey::Incentives, incentives);
+}
+
+pub fn next_incentive_id(env: &Env) -> u32 {
+    let id: u32 = env.storage().instance().get(&DataKey::IncentiveCount).unwrap_or(0);
+    env.storage().instance().set(&DataKey::IncentiveCount, &(id + 1));
+    id
+}
+
+pub fn get_incentive(env: &Env, id: u32) -> Option<Incentive> {
+    env.storage().persistent().get(&DataKey::Incentive(id))
+}
+
+pub fn store_incentive(env: &Env, id: u32, incentive: &Incentive) {
+    set_persistent(env, &DataKey::Incentive(id), incentive);
+}
+
+/// The rewards that one share of a bin earned since the incentive started, scaled by `REWARD_SCALE`, for the bins of bin vec `vec_id`.
+pub fn get_reward_per_share(env: &Env, id: u32, vec_id: i32) -> Map<i32, i128> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::RewardPerShare(id, vec_id))
+        .unwrap_or_else(|| Map::new(env))
+}
+
+pub fn store_reward_per_share(env: &Env, id: u32, vec_id: i32, reward_per_share: &Map<i32, i128>) {
+    let mut vec_ids = get_reward_vec_ids(env, id);
+    if let Err(index) = vec_ids.binary_search(vec_id) {
+        vec_ids.insert(index, vec_id);
+        set_persistent(env, &DataKey::RewardVecIds(id), &vec_ids);
+    }
+    set_persistent(env, &DataKey::RewardPerShare(id, vec_id), reward_per_share);
+}
+
+/// The ids of the bin vecs that have a reward per share for the incentive, in ascending order.
+pub fn get_reward_vec_ids(env: &Env, id: u32) -> Vec<i32> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::RewardVecIds(id))
+        .unwrap_or_else(|| vec![env])
+}
+
+pub fn delete_incentive(env: &Env, id: u32) {
+    for vec_id in get_reward_vec_ids(env, id).iter() {
+        env.storage().persistent().remove(&DataKey::RewardPerShare(id, vec_id));
+    }
+    env.storage().persistent().remove(&DataKey::RewardVecIds(id));
+    env.storage().persistent().remove(&DataKey::Incentive(id));
+}
+
+pub fn get_position_rewards(env: &Env, key: DataKey) -> PositionRewards {
+    env.storage().persistent().get(&key).unwrap_or_else(|| PositionRewards {
+        checkpoints: Map::new(env),
+        pending: Map::new(env),
+    })
+}
+
+/// Rewards without checkpoints or pending rewards are removed instead of stored.
+pub fn store_position_rewards(env: &Env, key: DataKey, rewards: &PositionRewards) {
+    if rewards.checkpoints.is_empty() && rewards.pending.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        set_persistent(env, &key, rewards);
+    }
+}
@@ -0,0 +1,191 @@
+use crate::bin::{get_bin_from_vec, is_bin_in_vec, store_bin_in_vec};
+use crate::constants::BIN_VEC_SIZE;
+use crate::events::{self, BinSwap, SwapEvent};
+use crate::order::{fill_order_bin, has_orders_in_vec};
+use crate::rewards::update_rewards;
+use crate::storage::{add_to_bin_totals, get_bin_vec_or_default, get_first_bin_id_in_vec, get_order_bins, get_vec_id_for_bin, store_bin_vec, store_order_bins, Bin, Config, OrderBin, VecIndex};
+use crate::bitmap::next_vec;
+use amm_math::swap::{BinWalk, ReserveSwap};
+use soroban_sdk::{Address, Env, Map, Vec};
+
+/// returns the id of the first vec after `vec_id`, in the direction of `step`, that has liquidity or limit orders.
+fn next_vec_id(env: &Env, vec_id: i32, step: i32) -> Option<i32> {
+    let next_bin_vec = next_vec(env, VecIndex::Bins, vec_id, step);
+    let next_order_vec = next_vec(env, VecIndex::Orders, vec_id, step);
+
+    match (next_bin_vec, next_order_vec) {
+        (Some(a), Some(b)) => Some(if (a - b) * step < 0 { a } else { b }),
+        (a, b) => a.or(b),
+    }
+}
+
+/// Walks over the bins in storage for `amm_math::swap::swap_bins`.
+///
+/// The vec of the current bin and its orders are kept in memory, and stored when the walk moves to another vec or finishes.
+/// With `dry_run` nothing is stored, for quotes.
+struct StorageBins<'a> {
+    env: &'a Env,
+    dry_run: bool,
+    active_bin: i32,
+    vec_id: i32,
+    bin_vec: Vec<Bin>,
+    order_bins: Option<Map<i32, OrderBin>>,
+    orders_changed: bool,
+    /// the reserve swaps, for the event
+    swaps: Vec<BinSwap>,
+}
+
+impl<'a> StorageBins<'a> {
+    fn new(env: &'a Env, active_bin: i32, dry_run: bool) -> Self {
+        let vec_id = get_vec_id_for_bin(active_bin);
+        let mut bins = StorageBins {
+            env,
+            dry_run,
+            active_bin,
+            vec_id,
+            bin_vec: get_bin_vec_or_default(env, vec_id, active_bin),
+            order_bins: None,
+            orders_changed: false,
+            swaps: Vec::new(env),
+        };
+        bins.load_orders();
+        bins
+    }
+
+    fn load_orders(&mut self) {
+        self.order_bins = if has_orders_in_vec(self.env, self.vec_id) {
+            Some(get_order_bins(self.env, self.vec_id))
+        } else {
+            None
+        };
+        self.orders_changed = false;
+    }
+
+    /// Stores the current vec. `active_bin` is needed to store the bins, see `bin_to_vec`.
+    fn store(&mut self, active_bin: i32) {
+        if self.dry_run {
+            return;
+        }
+        if self.orders_changed {
+            store_order_bins(self.env, self.vec_id, self.order_bins.as_ref().unwrap());
+        }
+        store_bin_vec(self.env, self.vec_id, &self.bin_vec, active_bin);
+    }
+
+    /// orders that sell the token that we are buying
+    fn order_bin(&self, bin_id: i32, is_x_in: bool) -> Option<OrderBin> {
+        self.order_bins
+            .as_ref()
+            .and_then(|bins| bins.get(bin_id))
+            .filter(|order_bin| order_bin.sell_x != is_x_in)
+    }
+}
+
+impl BinWalk for StorageBins<'_> {
+    fn liquidity_out(&mut self, bin_id: i32, is_x_in: bool) -> (i128, i128) {
+        let bin = get_bin_from_vec(&self.bin_vec, bin_id);
+        let reserve_out = if is_x_in { bin.reserve_y } else { bin.reserve_x };
+        let orders_out = self.order_bin(bin_id, is_x_in).map(|order_bin| order_bin.remaining).unwrap_or(0);
+
+        (reserve_out, orders_out)
+    }
+
+    fn swap_reserve(&mut self, bin_id: i32, is_x_in: bool, swapped: ReserveSwap) {
+        let mut bin = get_bin_from_vec(&self.bin_vec, bin_id);
+
+        if is_x_in {
+            bin.reserve_x += swapped.amount_in;
+            bin.reserve_y -= swapped.amount_out;
+        } else {
+            bin.reserve_y += swapped.amount_in;
+            bin.reserve_x -= swapped.amount_out;
+        }
+
+        store_bin_in_vec(&mut self.bin_vec, bin_id, bin);
+        self.swaps.push_back(BinSwap { bin_id, amount_in: swapped.amount_in, amount_out: swapped.amount_out });
+    }
+
+    fn swap_orders(&mut self, bin_id: i32, is_x_in: bool, swapped: ReserveSwap) {
+        let mut order_bin = self.order_bin(bin_id, is_x_in).unwrap();
+        order_bin.remaining -= swapped.amount_out;
+        order_bin.proceeds += swapped.amount_in;
+
+        let bins = self.order_bins.as_mut().unwrap();
+        if order_bin.remaining == 0 {
+            if !self.dry_run {
+                fill_order_bin(self.env, &order_bin);
+            }
+            bins.remove(bin_id);
+        } else {
+            bins.set(bin_id, order_bin);
+        }
+        self.orders_changed = true;
+    }
+
+    fn next_bin(&mut self, bin_id: i32, step: i32) -> Option<i32> {
+        if is_bin_in_vec(bin_id + step, self.vec_id) {
+            return Some(bin_id + step);
+        }
+
+        // the active bin will end up in one of the next vecs
+        self.store(get_first_bin_id_in_vec(self.vec_id + step));
+
+        self.vec_id = next_vec_id(self.env, self.vec_id, step)?;
+        self.bin_vec = get_bin_vec_or_default(self.env, self.vec_id, self.active_bin);
+        self.load_orders();
+
+        // we start at the edge of the vec
+        let first_bin_id = get_first_bin_id_in_vec(self.vec_id);
+        Some(if step > 0 { first_bin_id } else { first_bin_id + BIN_VEC_SIZE - 1 })
+    }
+}
+
+/// Swaps `amount_in` of `in_token` against the bins, starting at the active bin, see `amm_math::swap::swap_bins`.
+///
+/// The bin vecs are stored and `config.active_bin` is moved to the last bin that was swapped against,
+/// but the config itself is not stored. That is up to the caller. No tokens are transferred.
+/// A `swap` event is published with `from` as the trader.
+///
+/// returns the amount of the other token that the swap produced.
+pub fn swap_bins(env: &Env, config: &mut Config, from: &Address, amount_in: i128, in_token: &Address) -> i128 {
+    let is_x_in = *in_token == config.token_x;
+    update_rewards(env, config);
+
+    let mut bins = StorageBins::new(env, config.active_bin, false);
+    let result = amm_math::swap::swap_bins(&mut bins, config.bin_step, config.fee, config.active_bin, amount_in, is_x_in)
+        .expect("Not enough liquidity");
+
+    // the last bin we swapped against will be the active bin
+    bins.store(result.active_bin);
+    config.active_bin = result.active_bin;
+
+    if is_x_in {
+        add_to_bin_totals(env, result.bins_in, -result.bins_out, 0);
+    } else {
+        add_to_bin_totals(env, -result.bins_out, result.bins_in, 0);
+    }
+
+    events::swap(env, from, SwapEvent {
+        is_x_in,
+        amount_in,
+        amount_out: result.amount_out,
+        active_bin: result.active_bin,
+        bins: bins.swaps,
+    });
+
+    result.amount_out
+}
+
+/// returns what `swap_bins` would produce for `amount_in` of `in_token`, without storing anything.
+pub fn quote_swap_bins(env: &Env, config: &Config, amount_in: i128, in_token: &Address) -> i128 {
+    try_quote_swap_bins(env, config, amount_in, in_token).expect("Not enough liquidity")
+}
+
+/// Like `quote_swap_bins`, but returns `None` if there is not enough liquidity.
+pub fn try_quote_swap_bins(env: &Env, config: &Config, amount_in: i128, in_token: &Address) -> Option<i128> {
+    let is_x_in = *in_token == config.token_x;
+
+    let mut bins = StorageBins::new(env, config.active_bin, true);
+    amm_math::swap::swap_bins(&mut bins, config.bin_step, config.fee, config.active_bin, amount_in, is_x_in)
+        .map(|result| result.amount_out)
+}
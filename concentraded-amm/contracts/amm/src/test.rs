@@ -1,12 +1,22 @@
 #![cfg(test)]
+// amounts are written as `whole_fraction` with 7 decimals, like the stellar assets.
+#![allow(clippy::inconsistent_digit_grouping)]
 extern crate std;
 use super::*;
-use soroban_sdk::{vec, Env,};
-use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{contract, contractimpl, symbol_short, vec, Bytes, Env, IntoVal, Symbol};
+use soroban_sdk::testutils::storage::{Instance as _, Persistent as _};
+use soroban_sdk::testutils::{Address as _, Events as _, IssuerFlags, Ledger as _};
 use soroban_sdk::token::{StellarAssetClient, TokenClient};
-use crate::bin::get_shares_from_position;
-use crate::constants::BIN_VEC_SIZE;
-use crate::storage::DepositArgs;
+use crate::error;
+use crate::bitmap::{bitmap_keys, has_vec, next_vec, set_vec, VEC_ID_OFFSET};
+use crate::bin::{default_bin_vec, default_shares_vec, get_shares_from_position};
+use crate::constants::{BIN_VEC_SIZE, MAX_BPS, MAX_LOSS_VECS, MAX_WIDTH};
+use crate::storage::{BinTotals, DepositArgs, RewardRange, VecIndex};
+use amm_math::pool::PoolState;
+
+// a week and a month in ledgers of 5 seconds
+const TTL_THRESHOLD: u32 = 7 * 17_280;
+const TTL_EXTEND_TO: u32 = 30 * 17_280;
 
 #[test]
 fn test_get_shares_from_position() {
@@ -44,7 +54,7 @@ fn things_work() {
     token_a_admin_client.mint(&user_1, &100000_000_000_0);
     token_b_admin_client.mint(&user_1, &100000_000_000_0);
 
-    let contract_id = env.register(Contract, (Config{ token_x: token_a.address(), token_y: token_b.address(), bin_step: 10, active_bin: 0, fee: 0},));
+    let contract_id = env.register(Contract, (Config{ token_x: token_a.address(), token_y: token_b.address(), bin_step: 10, active_bin: 0, fee: 0, flash_loan_fee: 0, ttl_threshold: TTL_THRESHOLD, ttl_extend_to: TTL_EXTEND_TO, decimals_x: 0, decimals_y: 0, admin: user_1.clone()},));
     let client = ContractClient::new(&env, &contract_id);
     
     // let modify_args = vec![&env,
@@ -116,7 +126,7 @@ fn deposit_multiple_times() {
     token_a_client.mint(&user_1, &100000_000_000_0);
     token_b_client.mint(&user_1, &100000_000_000_0);
 
-    let contract_id = env.register(Contract, (Config{ token_x: token_a.address(), token_y: token_b.address(), bin_step: 10, active_bin: 0, fee: 0},));
+    let contract_id = env.register(Contract, (Config{ token_x: token_a.address(), token_y: token_b.address(), bin_step: 10, active_bin: 0, fee: 0, flash_loan_fee: 0, ttl_threshold: TTL_THRESHOLD, ttl_extend_to: TTL_EXTEND_TO, decimals_x: 0, decimals_y: 0, admin: user_1.clone()},));
     let client = ContractClient::new(&env, &contract_id);
 
 
@@ -125,14 +135,14 @@ fn deposit_multiple_times() {
                            DepositArgs{is_remove: false, bin_id_or_offset: 1, amount: 10_000_000_0},
     ];
 
-    let amounts = client.modify_liquidity(&user_1, &0,  &modify_args, &false);
+    let _amounts = client.modify_liquidity(&user_1, &0,  &modify_args, &false);
 
     let modify_args = vec![&env,
                            DepositArgs{is_remove: false, bin_id_or_offset: 0, amount: 10_000_000_0},
                            DepositArgs{is_remove: false, bin_id_or_offset: 1, amount: 10_000_000_0},
     ];
 
-    let amounts = client.modify_liquidity(&user_1, &0,  &modify_args, &false);
+    let _amounts = client.modify_liquidity(&user_1, &0,  &modify_args, &false);
 
     let position  = client.get_position(&user_1, &0).unwrap();
 
@@ -167,7 +177,7 @@ fn deposit_and_remove_in_same_call() {
     token_a_client.mint(&user_1, &100000_000_000_0);
     token_b_client.mint(&user_1, &100000_000_000_0);
 
-    let contract_id = env.register(Contract, (Config{ token_x: token_a.address(), token_y: token_b.address(), bin_step: 10, active_bin: 0, fee: 0},));
+    let contract_id = env.register(Contract, (Config{ token_x: token_a.address(), token_y: token_b.address(), bin_step: 10, active_bin: 0, fee: 0, flash_loan_fee: 0, ttl_threshold: TTL_THRESHOLD, ttl_extend_to: TTL_EXTEND_TO, decimals_x: 0, decimals_y: 0, admin: user_1.clone()},));
     let client = ContractClient::new(&env, &contract_id);
 
 
@@ -211,4 +221,1647 @@ fn deposit_and_remove_in_same_call() {
 
     assert_eq!(token_x_balance_before - token_x_balance_after, -5_000_000_0);
     assert_eq!(token_y_balance_before - token_y_balance_after, 5_000_000_0);
-}
\ No newline at end of file
+}
+
+fn create_pool<'a>(env: &Env, user: &Address) -> (ContractClient<'a>, TokenClient<'a>, TokenClient<'a>) {
+    create_pool_with(env, user, |_| {})
+}
+
+/// creates a pool with the default config after `modify` is applied to it
+fn create_pool_with<'a>(env: &Env, user: &Address, modify: impl FnOnce(&mut Config)) -> (ContractClient<'a>, TokenClient<'a>, TokenClient<'a>) {
+    let token_a = env.register_stellar_asset_contract_v2(user.clone());
+    let token_b = env.register_stellar_asset_contract_v2(user.clone());
+
+    StellarAssetClient::new(env, &token_a.address()).mint(user, &100000_000_000_0);
+    StellarAssetClient::new(env, &token_b.address()).mint(user, &100000_000_000_0);
+
+    let mut config = Config{ token_x: token_a.address(), token_y: token_b.address(), bin_step: 10, active_bin: 0, fee: 0, flash_loan_fee: 0, ttl_threshold: TTL_THRESHOLD, ttl_extend_to: TTL_EXTEND_TO, decimals_x: 0, decimals_y: 0, admin: user.clone()};
+    modify(&mut config);
+
+    let contract_id = env.register(Contract, (config,));
+
+    (
+        ContractClient::new(env, &contract_id),
+        TokenClient::new(env, &token_a.address()),
+        TokenClient::new(env, &token_b.address()),
+    )
+}
+
+#[test]
+fn remove_position_pct() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let user_1 = Address::generate(&env);
+    let (client, token_x, token_y) = create_pool(&env, &user_1);
+
+    let modify_args = vec![&env,
+                           DepositArgs{is_remove: false, bin_id_or_offset: -1, amount: 10_000_000_0},
+                           DepositArgs{is_remove: false, bin_id_or_offset: 0, amount: 10_000_000_0},
+                           DepositArgs{is_remove: false, bin_id_or_offset: 1, amount: 10_000_000_0},
+    ];
+    client.modify_liquidity(&user_1, &0,  &modify_args, &false);
+
+    let token_x_balance_before = token_x.balance(&user_1);
+    let token_y_balance_before = token_y.balance(&user_1);
+
+    let amounts = client.remove_position_pct(&user_1, &0, &2_500, &0, &0);
+    // bin -1 (10, 0) bin 0 (5, 5) bin 1 (0, 10)
+    assert_eq!(amounts, (3_750_000_0, 3_750_000_0));
+    assert_eq!(token_x.balance(&user_1) - token_x_balance_before, 3_750_000_0);
+    assert_eq!(token_y.balance(&user_1) - token_y_balance_before, 3_750_000_0);
+
+    let expected_position = Position{
+        bin_shares: vec![&env, BinShares{bin_id: -1, shares: 7_500_000_0}, BinShares{bin_id: 0, shares: 7_500_000_0}, BinShares{bin_id: 1, shares: 7_500_000_0}],
+    };
+    assert_eq!(expected_position, client.get_position(&user_1, &0).unwrap());
+
+    let amounts = client.remove_position_pct(&user_1, &0, &10_000, &11_250_000_0, &11_250_000_0);
+    assert_eq!(amounts, (11_250_000_0, 11_250_000_0));
+
+    // the position is closed when everything is withdrawn
+    assert_eq!(None, client.get_position(&user_1, &0));
+}
+
+#[test]
+fn output_to_recipient() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let user_1 = Address::generate(&env);
+    let merchant = Address::generate(&env);
+    let (client, token_x, token_y) = create_pool(&env, &user_1);
+
+    let modify_args = vec![&env,
+                           DepositArgs{is_remove: false, bin_id_or_offset: 0, amount: 10_000_000_0},
+                           DepositArgs{is_remove: false, bin_id_or_offset: 1, amount: 10_000_000_0},
+    ];
+    // deposits are always paid by `from`
+    client.modify_liquidity_to(&user_1, &merchant, &0, &modify_args, &false);
+    assert_eq!(0, token_x.balance(&merchant) + token_y.balance(&merchant));
+
+    // pay the merchant in y with x
+    let token_x_balance_before = token_x.balance(&user_1);
+    let token_y_balance_before = token_y.balance(&user_1);
+    let amount_out = client.swap_exact_amount_in_to(&user_1, &merchant, &1_000_000_0, &1_000_000_0, &token_x.address);
+    assert_eq!(1_000_000_0, amount_out);
+    assert_eq!(amount_out, token_y.balance(&merchant));
+    assert_eq!(token_x_balance_before - 1_000_000_0, token_x.balance(&user_1));
+    assert_eq!(token_y_balance_before, token_y.balance(&user_1));
+
+    // the position stays with user_1, the withdrawal goes to the merchant
+    let remove_args = vec![&env, DepositArgs{is_remove: true, bin_id_or_offset: 1, amount: 10_000_000_0}];
+    let (x_delta, y_delta) = client.modify_liquidity_to(&user_1, &merchant, &0, &remove_args, &false);
+    assert_eq!((0, -10_000_000_0), (x_delta, y_delta));
+    assert_eq!(amount_out + 10_000_000_0, token_y.balance(&merchant));
+    assert_eq!(token_y_balance_before, token_y.balance(&user_1));
+    assert_eq!(Position{bin_shares: vec![&env, BinShares{bin_id: 0, shares: 10_000_000_0}]}, client.get_position(&user_1, &0).unwrap());
+    assert_eq!(None, client.get_position(&merchant, &0));
+}
+
+#[test]
+#[should_panic(expected = "Insufficient y amount")]
+fn remove_position_pct_min_amount() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let user_1 = Address::generate(&env);
+    let (client, _, _) = create_pool(&env, &user_1);
+
+    let modify_args = vec![&env,
+                           DepositArgs{is_remove: false, bin_id_or_offset: 0, amount: 10_000_000_0},
+    ];
+    client.modify_liquidity(&user_1, &0,  &modify_args, &false);
+
+    client.remove_position_pct(&user_1, &0, &10_000, &0, &5_000_000_1);
+}
+
+#[test]
+fn liquidity_and_swap_events() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let user_1 = Address::generate(&env);
+    let (client, token_x, _) = create_pool(&env, &user_1);
+
+    let modify_args = vec![&env,
+                           DepositArgs{is_remove: false, bin_id_or_offset: 0, amount: 10_000_000_0},
+                           DepositArgs{is_remove: false, bin_id_or_offset: 1, amount: 10_000_000_0},
+    ];
+    client.modify_liquidity(&user_1, &3, &modify_args, &false);
+
+    let changes = vec![&env,
+                       events::BinChange{bin_id: 0, amount_x: 5_000_000_0, amount_y: 5_000_000_0, shares: 10_000_000_0},
+                       events::BinChange{bin_id: 1, amount_x: 0, amount_y: 10_000_000_0, shares: 10_000_000_0},
+    ];
+    // the last event of the pool, the transfers of the tokens come after it
+    let last_event = || env.events().all().iter().filter(|(contract, _, _)| *contract == client.address).last().unwrap();
+    let (_, topics, data) = last_event();
+    assert_eq!(topics, (symbol_short!("liquidity"), user_1.clone(), 3).into_val(&env));
+    assert_eq!(changes, data.into_val(&env));
+
+    // crosses the active bin into bin 1
+    client.swap_exact_amount_in(&user_1, &6_000_000_0, &0, &token_x.address);
+    let (_, topics, data) = last_event();
+    assert_eq!(topics, (symbol_short!("swap"), user_1.clone()).into_val(&env));
+
+    let swap: events::SwapEvent = data.into_val(&env);
+    assert_eq!((swap.is_x_in, swap.amount_in, swap.active_bin), (true, 6_000_000_0, 1));
+    assert_eq!(swap.bins.iter().map(|bin| bin.bin_id).collect::<std::vec::Vec<_>>(), [0, 1]);
+    assert_eq!(swap.bins.iter().map(|bin| bin.amount_in).sum::<i128>(), swap.amount_in);
+    assert_eq!(swap.bins.iter().map(|bin| bin.amount_out).sum::<i128>(), swap.amount_out);
+}
+
+#[test]
+fn empty_active_bin_can_be_read() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let user_1 = Address::generate(&env);
+    let (client, _, _) = create_pool(&env, &user_1);
+
+    let modify_args = vec![&env,
+                           DepositArgs{is_remove: false, bin_id_or_offset: 1, amount: 10_000_000_0},
+    ];
+    client.modify_liquidity(&user_1, &0,  &modify_args, &false);
+
+    // the active bin has no reserves, but we still need to be able to read it
+    let bin_vec = client.get_bin_vec(&0);
+    assert_eq!(Bin{bin_id: 0, reserve_x: 0, reserve_y: 0}, bin_vec.get(0).unwrap());
+    assert_eq!(Bin{bin_id: 1, reserve_x: 0, reserve_y: 10_000_000_0}, bin_vec.get(1).unwrap());
+}
+
+#[test]
+fn rebalance_position() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let user_1 = Address::generate(&env);
+    let (client, _, _) = create_pool(&env, &user_1);
+
+    let modify_args = vec![&env,
+                           DepositArgs{is_remove: false, bin_id_or_offset: -1, amount: 10_000_000_0},
+                           DepositArgs{is_remove: false, bin_id_or_offset: 0, amount: 10_000_000_0},
+                           DepositArgs{is_remove: false, bin_id_or_offset: 1, amount: 10_000_000_0},
+    ];
+    client.modify_liquidity(&user_1, &0,  &modify_args, &false);
+
+    // we withdraw (15, 15) and spread it evenly over 5 bins
+    let amounts = client.rebalance_position(&user_1, &0, &2, &LiquidityShape::Spot, &false, &0, &0);
+    assert_eq!(amounts, (15_000_000_0, 15_000_000_0));
+
+    let expected_position = Position{
+        bin_shares: vec![&env,
+                         BinShares{bin_id: -2, shares: 5_000_000_0},
+                         BinShares{bin_id: -1, shares: 5_000_000_0},
+                         BinShares{bin_id: 0, shares: 10_000_000_0},
+                         BinShares{bin_id: 1, shares: 5_000_000_0},
+                         BinShares{bin_id: 2, shares: 5_000_000_0},
+        ],
+    };
+    assert_eq!(expected_position, client.get_position(&user_1, &0).unwrap());
+
+    let bin_vec = client.get_bin_vec(&-1);
+    assert_eq!(Bin{bin_id: -2, reserve_x: 5_000_000_0, reserve_y: 0}, bin_vec.get(BIN_VEC_SIZE as u32 - 2).unwrap());
+    assert_eq!(Bin{bin_id: -1, reserve_x: 5_000_000_0, reserve_y: 0}, bin_vec.get(BIN_VEC_SIZE as u32 - 1).unwrap());
+}
+
+#[test]
+fn rebalance_position_width() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let user_1 = Address::generate(&env);
+    let max_bin_id = get_max_bin_id(10);
+    let (client, _, _) = create_pool_with(&env, &user_1, |config| config.active_bin = max_bin_id - 1);
+
+    let modify_args = vec![&env, DepositArgs{is_remove: false, bin_id_or_offset: 0, amount: 10_000_000_0}];
+    client.modify_liquidity(&user_1, &0, &modify_args, &true);
+
+    let invalid_width = Some(Ok(error::Error::ErrInvalidWidth.into()));
+    assert_eq!(invalid_width, client.try_rebalance_position(&user_1, &0, &(MAX_WIDTH + 1), &LiquidityShape::Spot, &false, &0, &0).err());
+    assert_eq!(invalid_width, client.try_rebalance_position(&user_1, &0, &u32::MAX, &LiquidityShape::Spot, &false, &0, &0).err());
+
+    // the bins above the max bin are skipped, the rest of y goes back to the owner
+    client.rebalance_position(&user_1, &0, &3, &LiquidityShape::Spot, &false, &0, &0);
+    let bin_ids: std::vec::Vec<i32> = client.get_position(&user_1, &0).unwrap().bin_shares.iter().map(|shares| shares.bin_id).collect();
+    assert_eq!(((max_bin_id - 4)..=max_bin_id).collect::<std::vec::Vec<i32>>(), bin_ids);
+}
+
+#[test]
+fn rebalance_position_with_swap() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let user_1 = Address::generate(&env);
+    let (client, token_x, token_y) = create_pool(&env, &user_1);
+
+    // other liquidity to swap against
+    let modify_args = vec![&env,
+                           DepositArgs{is_remove: false, bin_id_or_offset: 0, amount: 90_000_000_0},
+    ];
+    client.modify_liquidity(&user_1, &1,  &modify_args, &false);
+
+    let modify_args = vec![&env,
+                           DepositArgs{is_remove: false, bin_id_or_offset: 0, amount: 10_000_000_0},
+                           DepositArgs{is_remove: false, bin_id_or_offset: 1, amount: 10_000_000_0},
+    ];
+    client.modify_liquidity(&user_1, &0,  &modify_args, &false);
+
+    let token_x_balance_before = token_x.balance(&user_1);
+    let token_y_balance_before = token_y.balance(&user_1);
+
+    // we withdraw (5, 15) and swap 5 y for 5 x at a price of 1.
+    // the active bin then has (40, 50), so we can deposit all of the y, but not all the x.
+    let amounts = client.rebalance_position(&user_1, &0, &0, &LiquidityShape::Spot, &true, &0, &10_000_000_0);
+    assert_eq!(amounts, (8_000_000_0, 10_000_000_0));
+    assert_eq!(0, client.get_config().active_bin);
+
+    assert_eq!(token_x.balance(&user_1) - token_x_balance_before, 2_000_000_0);
+    assert_eq!(token_y.balance(&user_1), token_y_balance_before);
+
+    let position = client.get_position(&user_1, &0).unwrap();
+    assert_eq!(1, position.bin_shares.len());
+    assert_eq!(0, position.bin_shares.get(0).unwrap().bin_id);
+}
+
+#[test]
+fn rebalance_position_skips_swap_without_other_liquidity() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let user_1 = Address::generate(&env);
+    let (client, _, _) = create_pool(&env, &user_1);
+
+    let modify_args = vec![&env,
+                           DepositArgs{is_remove: false, bin_id_or_offset: 0, amount: 10_000_000_0},
+                           DepositArgs{is_remove: false, bin_id_or_offset: 1, amount: 10_000_000_0},
+    ];
+    client.modify_liquidity(&user_1, &0,  &modify_args, &false);
+
+    // the position is all the liquidity of the pool, so the excess y can't be swapped
+    let (amount_x, amount_y) = client.rebalance_position(&user_1, &0, &1, &LiquidityShape::Spot, &true, &0, &0);
+    assert!(amount_x > 0 && amount_y > 0);
+    assert_eq!(0, client.get_config().active_bin);
+}
+
+#[test]
+fn vec_bitmap() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let user_1 = Address::generate(&env);
+    let (client, _, _) = create_pool(&env, &user_1);
+
+    env.as_contract(&client.address, || {
+        let vec_ids = [-VEC_ID_OFFSET, -200, 5, 6, 300, VEC_ID_OFFSET - 1];
+        for vec_id in vec_ids {
+            set_vec(&env, VecIndex::Orders, vec_id, true);
+        }
+        assert!(has_vec(&env, VecIndex::Orders, 6));
+        assert!(!has_vec(&env, VecIndex::Orders, 7));
+
+        // the next vec in the same word, in another word and in another top word
+        assert_eq!(Some(6), next_vec(&env, VecIndex::Orders, 5, 1));
+        assert_eq!(Some(5), next_vec(&env, VecIndex::Orders, 6, -1));
+        assert_eq!(Some(300), next_vec(&env, VecIndex::Orders, 6, 1));
+        assert_eq!(Some(-200), next_vec(&env, VecIndex::Orders, 0, -1));
+        assert_eq!(Some(VEC_ID_OFFSET - 1), next_vec(&env, VecIndex::Orders, 300, 1));
+        assert_eq!(Some(-VEC_ID_OFFSET), next_vec(&env, VecIndex::Orders, -200, -1));
+        assert_eq!(None, next_vec(&env, VecIndex::Orders, VEC_ID_OFFSET - 1, 1));
+        assert_eq!(None, next_vec(&env, VecIndex::Orders, -VEC_ID_OFFSET, -1));
+
+        // words without bits are removed
+        set_vec(&env, VecIndex::Orders, 300, false);
+        assert_eq!(Some(VEC_ID_OFFSET - 1), next_vec(&env, VecIndex::Orders, 6, 1));
+        let [word, top_word] = bitmap_keys(VecIndex::Orders, 300);
+        assert!(!env.storage().persistent().has(&word));
+        assert!(env.storage().persistent().has(&top_word));
+    });
+}
+
+#[test]
+fn limit_order_is_filled_when_bin_is_crossed() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let user_1 = Address::generate(&env);
+    let (client, token_x, token_y) = create_pool(&env, &user_1);
+
+    let modify_args = vec![&env,
+                           DepositArgs{is_remove: false, bin_id_or_offset: 0, amount: 10_000_000_0},
+                           DepositArgs{is_remove: false, bin_id_or_offset: 1, amount: 10_000_000_0},
+                           DepositArgs{is_remove: false, bin_id_or_offset: 3, amount: 10_000_000_0},
+    ];
+    client.modify_liquidity(&user_1, &0,  &modify_args, &false);
+
+    // sell 10 y at the price of bin 2
+    client.place_limit_order(&user_1, &0, &2, &10_000_000_0);
+    assert_eq!(10_000_000_0, client.get_order_bin(&2).unwrap().remaining);
+
+    // not filled yet
+    assert!(client.try_claim_limit_order(&user_1, &0).is_err());
+
+    // crosses bin 0, 1 and 2 and ends in bin 3
+    client.swap_exact_amount_in(&user_1, &26_000_000_0, &0, &token_x.address);
+    assert_eq!(3, client.get_config().active_bin);
+    assert_eq!(None, client.get_order_bin(&2));
+
+    // swapping back does not touch the filled order
+    client.swap_exact_amount_in(&user_1, &15_000_000_0, &0, &token_y.address);
+    assert_eq!(0, client.get_config().active_bin);
+    assert_eq!(Bin{bin_id: 2, reserve_x: 0, reserve_y: 0}, client.get_bin_vec(&0).get(2).unwrap());
+
+    let token_x_balance_before = token_x.balance(&user_1);
+    let proceeds = client.claim_limit_order(&user_1, &0);
+
+    // 10 * 1.001^2
+    assert_eq!(10_020_010_0, proceeds);
+    assert_eq!(token_x.balance(&user_1) - token_x_balance_before, proceeds);
+    assert_eq!(None, client.get_limit_order(&user_1, &0));
+}
+
+#[test]
+fn quote_matches_swap() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let user_1 = Address::generate(&env);
+    let (client, token_x, token_y) = create_pool_with(&env, &user_1, |config| config.fee = 30);
+
+    // the last bin is in the next vec
+    let modify_args = vec![&env,
+                           DepositArgs{is_remove: false, bin_id_or_offset: 0, amount: 10_000_000_0},
+                           DepositArgs{is_remove: false, bin_id_or_offset: 1, amount: 10_000_000_0},
+                           DepositArgs{is_remove: false, bin_id_or_offset: BIN_VEC_SIZE, amount: 10_000_000_0},
+    ];
+    client.modify_liquidity(&user_1, &0,  &modify_args, &false);
+    client.place_limit_order(&user_1, &0, &2, &10_000_000_0);
+
+    let quote = client.quote_exact_amount_in(&26_000_000_0, &token_x.address);
+    // nothing changed
+    assert_eq!(0, client.get_config().active_bin);
+    assert_eq!(10_000_000_0, client.get_order_bin(&2).unwrap().remaining);
+    assert!(client.try_claim_limit_order(&user_1, &0).is_err());
+
+    assert_eq!(quote, client.swap_exact_amount_in(&user_1, &26_000_000_0, &0, &token_x.address));
+    assert_eq!(BIN_VEC_SIZE, client.get_config().active_bin);
+
+    let quote = client.quote_exact_amount_in(&5_000_000_0, &token_y.address);
+    assert_eq!(quote, client.swap_exact_amount_in(&user_1, &5_000_000_0, &0, &token_y.address));
+
+    assert!(client.try_quote_exact_amount_in(&100_000_000_0, &token_x.address).is_err());
+}
+
+#[test]
+fn cancel_partially_filled_limit_order() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let user_1 = Address::generate(&env);
+    let (client, token_x, _) = create_pool(&env, &user_1);
+
+    let modify_args = vec![&env,
+                           DepositArgs{is_remove: false, bin_id_or_offset: 0, amount: 10_000_000_0},
+                           DepositArgs{is_remove: false, bin_id_or_offset: 2, amount: 10_000_000_0},
+    ];
+    client.modify_liquidity(&user_1, &0,  &modify_args, &false);
+
+    client.place_limit_order(&user_1, &0, &1, &10_000_000_0);
+
+    // 5 x is swapped in bin 0, the rest buys 3 y of the order
+    client.swap_exact_amount_in(&user_1, &8_003_000_0, &0, &token_x.address);
+    assert_eq!(1, client.get_config().active_bin);
+
+    let order_bin = client.get_order_bin(&1).unwrap();
+    assert_eq!(order_bin.remaining, 7_000_000_0);
+    assert_eq!(order_bin.proceeds, 3_003_000_0);
+
+    // we can't add to the orders until they are filled or cancelled
+    assert!(client.try_place_limit_order(&user_1, &1, &1, &10_000_000_0).is_err());
+
+    let amounts = client.cancel_limit_order(&user_1, &0);
+    assert_eq!(amounts, (3_003_000_0, 7_000_000_0));
+    assert_eq!(None, client.get_order_bin(&1));
+}
+
+#[test]
+#[should_panic(expected = "Can't place an order in the active bin")]
+fn limit_order_in_active_bin() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let user_1 = Address::generate(&env);
+    let (client, _, _) = create_pool(&env, &user_1);
+
+    client.place_limit_order(&user_1, &0, &0, &10_000_000_0);
+}
+
+const POOL: Symbol = symbol_short!("POOL");
+
+/// Borrows or flash swaps from the pool and does what the first byte of `data` says:
+/// 0 pays what is owed, 1 pays less than what is owed and 2 tries to swap on the pool.
+#[contract]
+struct FlashReceiverContract;
+
+#[contractimpl]
+impl FlashReceiverContract {
+    pub fn __constructor(env: Env, pool: Address) {
+        env.storage().instance().set(&POOL, &pool);
+    }
+
+    pub fn on_flash_loan(env: Env, token: Address, amount: i128, fee: i128, data: Bytes) {
+        let pool: Address = env.storage().instance().get(&POOL).unwrap();
+        let token_client = TokenClient::new(&env, &token);
+
+        match data.get(0).unwrap() {
+            0 => token_client.transfer(&env.current_contract_address(), &pool, &(amount + fee)),
+            1 => token_client.transfer(&env.current_contract_address(), &pool, &amount),
+            _ => {
+                ContractClient::new(&env, &pool).swap_exact_amount_in(&env.current_contract_address(), &amount, &0, &token);
+            }
+        }
+    }
+
+    pub fn on_flash_swap(env: Env, in_token: Address, amount_in: i128, _out_token: Address, _amount_out: i128, data: Bytes) {
+        let pool: Address = env.storage().instance().get(&POOL).unwrap();
+        let amount = if data.get(0).unwrap() == 0 { amount_in } else { amount_in - 1 };
+
+        TokenClient::new(&env, &in_token).transfer(&env.current_contract_address(), &pool, &amount);
+    }
+}
+
+fn create_flash_receiver(env: &Env, user: &Address, client: &ContractClient, token_x: &TokenClient) -> Address {
+    let receiver = env.register(FlashReceiverContract, (client.address.clone(),));
+    // pays for the fees and flash swaps
+    token_x.transfer(user, &receiver, &1_000_000_0);
+
+    let modify_args = vec![env,
+                           DepositArgs{is_remove: false, bin_id_or_offset: 0, amount: 10_000_000_0},
+    ];
+    client.modify_liquidity(user, &0,  &modify_args, &false);
+
+    receiver
+}
+
+#[test]
+fn flash_loan_fee_goes_to_lps() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let user_1 = Address::generate(&env);
+    // 0.3% fee
+    let (client, token_x, _) = create_pool_with(&env, &user_1, |config| config.flash_loan_fee = 30);
+    let receiver = create_flash_receiver(&env, &user_1, &client, &token_x);
+
+    let fee = client.flash_loan(&receiver, &token_x.address, &5_000_000_0, &Bytes::from_array(&env, &[0]));
+    assert_eq!(150_000, fee);
+    assert_eq!(1_000_000_0 - fee, token_x.balance(&receiver));
+    assert_eq!(Bin{bin_id: 0, reserve_x: 5_015_000_0, reserve_y: 5_000_000_0}, client.get_bin_vec(&0).get(0).unwrap());
+
+    let amounts = client.remove_position_pct(&user_1, &0, &10_000, &0, &0);
+    assert_eq!(amounts, (5_015_000_0, 5_000_000_0));
+}
+
+#[test]
+#[should_panic(expected = "Flash loan not repaid")]
+fn flash_loan_without_fee() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let user_1 = Address::generate(&env);
+    let (client, token_x, _) = create_pool_with(&env, &user_1, |config| config.flash_loan_fee = 30);
+    let receiver = create_flash_receiver(&env, &user_1, &client, &token_x);
+
+    client.flash_loan(&receiver, &token_x.address, &5_000_000_0, &Bytes::from_array(&env, &[1]));
+}
+
+#[test]
+fn flash_loan_receiver_cant_reenter() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let user_1 = Address::generate(&env);
+    let (client, token_x, _) = create_pool_with(&env, &user_1, |config| config.flash_loan_fee = 30);
+    let receiver = create_flash_receiver(&env, &user_1, &client, &token_x);
+
+    assert!(client.try_flash_loan(&receiver, &token_x.address, &5_000_000_0, &Bytes::from_array(&env, &[2])).is_err());
+    assert_eq!(Bin{bin_id: 0, reserve_x: 5_000_000_0, reserve_y: 5_000_000_0}, client.get_bin_vec(&0).get(0).unwrap());
+}
+
+#[test]
+#[should_panic(expected = "No liquidity in active bin")]
+fn flash_loan_fee_needs_active_liquidity() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let user_1 = Address::generate(&env);
+    let (client, _, token_y) = create_pool_with(&env, &user_1, |config| config.flash_loan_fee = 30);
+    let receiver = env.register(FlashReceiverContract, (client.address.clone(),));
+    token_y.transfer(&user_1, &receiver, &1_000_000_0);
+
+    // only y in bin 1, the fee would go to the next LP of the empty active bin
+    client.modify_liquidity(&user_1, &0, &vec![&env, DepositArgs{is_remove: false, bin_id_or_offset: 1, amount: 10_000_000_0}], &false);
+    client.flash_loan(&receiver, &token_y.address, &5_000_000_0, &Bytes::from_array(&env, &[0]));
+}
+
+#[test]
+#[should_panic(expected = "Invalid flash loan fee")]
+fn invalid_flash_loan_fee() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let user_1 = Address::generate(&env);
+    create_pool_with(&env, &user_1, |config| config.flash_loan_fee = MAX_BPS + 1);
+}
+
+#[test]
+fn flash_swap() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let user_1 = Address::generate(&env);
+    let (client, token_x, token_y) = create_pool(&env, &user_1);
+    let receiver = create_flash_receiver(&env, &user_1, &client, &token_x);
+
+    let amount_out = client.flash_swap(&receiver, &1_000_000_0, &0, &token_x.address, &Bytes::from_array(&env, &[0]));
+    assert_eq!(1_000_000_0, amount_out);
+    assert_eq!(0, token_x.balance(&receiver));
+    assert_eq!(1_000_000_0, token_y.balance(&receiver));
+    assert_eq!(Bin{bin_id: 0, reserve_x: 6_000_000_0, reserve_y: 4_000_000_0}, client.get_bin_vec(&0).get(0).unwrap());
+}
+
+#[test]
+#[should_panic(expected = "Flash swap not paid")]
+fn flash_swap_underpaid() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let user_1 = Address::generate(&env);
+    let (client, token_x, _) = create_pool(&env, &user_1);
+    let receiver = create_flash_receiver(&env, &user_1, &client, &token_x);
+
+    client.flash_swap(&receiver, &1_000_000_0, &0, &token_x.address, &Bytes::from_array(&env, &[1]));
+}
+
+#[test]
+fn entries_are_extended_and_bumped() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let user_1 = Address::generate(&env);
+    let operator = Address::generate(&env);
+    let (client, _, _) = create_pool(&env, &user_1);
+
+    let modify_args = vec![&env,
+                           DepositArgs{is_remove: false, bin_id_or_offset: 0, amount: 10_000_000_0},
+    ];
+    client.modify_liquidity(&user_1, &0,  &modify_args, &false);
+    client.place_limit_order(&user_1, &0, &2, &1_000_000_0);
+    client.set_approval_for_all(&user_1, &operator, &true);
+
+    let ttls = |env: &Env| env.as_contract(&client.address, || [
+        env.storage().instance().get_ttl(),
+        env.storage().persistent().get_ttl(&DataKey::BinVec(0)),
+        env.storage().persistent().get_ttl(&DataKey::BinShareVec(0)),
+        env.storage().persistent().get_ttl(&DataKey::Position(user_1.clone(), 0)),
+        env.storage().persistent().get_ttl(&DataKey::OrderBins(0)),
+        env.storage().persistent().get_ttl(&DataKey::LimitOrder(user_1.clone(), 0)),
+        env.storage().persistent().get_ttl(&DataKey::Operator(user_1.clone(), operator.clone())),
+    ]);
+    assert_eq!([TTL_EXTEND_TO; 7], ttls(&env));
+
+    // above the threshold nothing is extended
+    env.ledger().with_mut(|ledger| ledger.sequence_number += TTL_EXTEND_TO - TTL_THRESHOLD - 1);
+    client.bump(&vec![&env, 0], &vec![&env, (user_1.clone(), 0)], &vec![&env, (user_1.clone(), 0)], &vec![&env, (user_1.clone(), operator.clone())]);
+    assert_eq!([TTL_THRESHOLD + 1; 7], ttls(&env));
+
+    env.ledger().with_mut(|ledger| ledger.sequence_number += 2);
+    // the bins, the position, the order and the approval that don't exist are skipped
+    client.bump(&vec![&env, 0, 1, 1000], &vec![&env, (user_1.clone(), 0), (user_1.clone(), 1)], &vec![&env, (user_1.clone(), 0), (user_1.clone(), 1)],
+                &vec![&env, (user_1.clone(), operator.clone()), (operator.clone(), user_1.clone())]);
+    assert_eq!([TTL_EXTEND_TO; 7], ttls(&env));
+}
+
+#[test]
+fn empty_vecs_are_removed() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let user_1 = Address::generate(&env);
+    let (client, _, _) = create_pool(&env, &user_1);
+
+    let modify_args = vec![&env,
+                           DepositArgs{is_remove: false, bin_id_or_offset: 0, amount: 10_000_000_0},
+                           DepositArgs{is_remove: false, bin_id_or_offset: BIN_VEC_SIZE, amount: 10_000_000_0},
+    ];
+    client.modify_liquidity(&user_1, &0,  &modify_args, &false);
+
+    let remove_args = vec![&env,
+                           DepositArgs{is_remove: true, bin_id_or_offset: BIN_VEC_SIZE, amount: 10_000_000_0},
+    ];
+    client.modify_liquidity(&user_1, &0,  &remove_args, &false);
+
+    let has = |key: DataKey| env.as_contract(&client.address, || env.storage().persistent().has(&key));
+    assert!(has(DataKey::BinVec(0)) && has(DataKey::BinShareVec(0)));
+    assert!(!has(DataKey::BinVec(1)) && !has(DataKey::BinShareVec(1)));
+
+    // missing vecs are read as the default vecs
+    assert_eq!(client.get_bin_vec(&1), default_bin_vec(&env, 1));
+    assert_eq!(client.get_shares_vec(&1), default_shares_vec(&env, 1));
+
+    // and can be deposited in again
+    client.modify_liquidity(&user_1, &0,  &modify_args.slice(1..), &false);
+    assert_eq!(BinShares{bin_id: BIN_VEC_SIZE, shares: 10_000_000_0}, client.get_shares_vec(&1).get(0).unwrap());
+}
+
+#[test]
+fn bin_getters() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let user_1 = Address::generate(&env);
+    let (client, _, _) = create_pool(&env, &user_1);
+
+    let modify_args = vec![&env,
+                           DepositArgs{is_remove: false, bin_id_or_offset: -1, amount: 10_000_000_0},
+                           DepositArgs{is_remove: false, bin_id_or_offset: 0, amount: 10_000_000_0},
+                           DepositArgs{is_remove: false, bin_id_or_offset: 1, amount: 10_000_000_0},
+                           DepositArgs{is_remove: false, bin_id_or_offset: BIN_VEC_SIZE, amount: 10_000_000_0},
+    ];
+    client.modify_liquidity(&user_1, &0,  &modify_args, &false);
+
+    assert_eq!(Bin{bin_id: -1, reserve_x: 10_000_000_0, reserve_y: 0}, client.get_bin(&-1));
+    assert_eq!(Bin{bin_id: 1, reserve_x: 0, reserve_y: 10_000_000_0}, client.get_bin(&1));
+
+    let bins = client.get_bins_range(&-1, &BIN_VEC_SIZE);
+    assert_eq!(BIN_VEC_SIZE as u32 + 2, bins.len());
+    assert_eq!(
+        BinInfo{bin_id: -1, reserve_x: 10_000_000_0, reserve_y: 0, shares: 10_000_000_0, price: price::price_to_host(&env, price::price_from_bin(10, -1))},
+        bins.get(0).unwrap()
+    );
+    assert_eq!(
+        BinInfo{bin_id: 1, reserve_x: 0, reserve_y: 10_000_000_0, shares: 10_000_000_0, price: price::price_to_host(&env, price::get_base(10))},
+        bins.get(2).unwrap()
+    );
+    assert_eq!(BIN_VEC_SIZE, bins.last().unwrap().bin_id);
+    assert_eq!(10_000_000_0, bins.last().unwrap().reserve_y);
+
+    assert_eq!(
+        BinInfo{bin_id: 0, reserve_x: 5_000_000_0, reserve_y: 5_000_000_0, shares: 10_000_000_0, price: price::price_to_host(&env, price::SCALE)},
+        client.get_active_bin_reserves()
+    );
+
+    assert!(client.try_get_bins_range(&1, &0).is_err());
+    assert!(client.try_get_bins_range(&0, &MAX_BINS_IN_RANGE).is_err());
+}
+
+/// returns a 128.128 price as a float
+fn price_to_f64(price: ethnum::U256) -> f64 {
+    price.as_f64() / 2f64.powi(128)
+}
+
+#[test]
+fn price_from_bin_is_exact() {
+    assert_eq!(price::SCALE, price::price_from_bin(10, 0));
+    assert_eq!(price::get_base(10), price::price_from_bin(10, 1));
+
+    for (bin_step, id) in [(10, 1000), (10, -1000), (1, 54321), (100, -2500)] {
+        let expected = (id as f64 * (bin_step as f64 / 10_000f64).ln_1p()).exp();
+        let relative_error = (price_to_f64(price::price_from_bin(bin_step, id)) - expected).abs() / expected;
+        assert!(relative_error < 1e-12);
+    }
+
+    // the prices of the bins left of 0 are the inverse of the bins right of it
+    let product = (price::price_from_bin(10, 500) >> 64u32) * (price::price_from_bin(10, -500) >> 64u32);
+    assert!(product.abs_diff(price::SCALE) < ethnum::U256::from(1u128 << 72));
+}
+
+#[test]
+fn id_from_price_is_inverse_of_price_from_bin() {
+    for (bin_step, max_id) in [(1, 30000), (10, 30000), (100, 5000)] {
+        for id in (-max_id..max_id).step_by(97) {
+            let price = price::price_from_bin(bin_step, id);
+            assert_eq!(id, price::id_from_price(bin_step, price));
+
+            // prices between two bins are rounded down
+            let next_price = price::price_from_bin(bin_step, id + 1);
+            assert_eq!(id, price::id_from_price(bin_step, next_price - 1));
+        }
+    }
+}
+
+#[test]
+fn price_getters() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let user_1 = Address::generate(&env);
+    let (client, token_x, token_y) = create_pool(&env, &user_1);
+
+    let base = price::get_base(10);
+    // y in x
+    assert_eq!(price::price_to_host(&env, base), client.get_price_from_id(&1, &token_y.address));
+    // x in y, 1 / 1.001
+    assert_eq!(price::price_to_host(&env, ethnum::U256::MAX / base), client.get_price_from_id(&1, &token_x.address));
+
+    assert_eq!(1, client.get_id_from_price(&price::price_to_host(&env, base)));
+    assert_eq!(0, client.get_id_from_price(&price::price_to_host(&env, base - 1)));
+    assert_eq!(-1, client.get_id_from_price(&price::price_to_host(&env, price::SCALE - 1)));
+    assert!(client.try_get_id_from_price(&price::price_to_host(&env, ethnum::U256::ZERO)).is_err());
+}
+
+#[test]
+fn supported_bin_range() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let user_1 = Address::generate(&env);
+    let (client, token_x, token_y) = create_pool(&env, &user_1);
+
+    // 96 / log2(1.001) = 66575.39
+    let max_bin_id = 66575;
+    assert_eq!((-max_bin_id, max_bin_id), client.get_supported_bins());
+    assert_eq!(6687, price::get_max_bin_id(100));
+    assert_eq!(665454, price::get_max_bin_id(1));
+
+    // the prices at the edges are within 2^-96 and 2^96 and still precise
+    let max_price = ethnum::U256::ONE << 224u32;
+    assert!(price::price_from_bin(10, max_bin_id) <= max_price && price::price_from_bin(10, max_bin_id + 1) > max_price);
+    assert!(price::price_from_bin(10, -max_bin_id) >= ethnum::U256::ONE << 32u32);
+    for id in [-max_bin_id, max_bin_id] {
+        let expected = (id as f64 * 0.001f64.ln_1p()).exp();
+        let relative_error = (price_to_f64(price::price_from_bin(10, id)) - expected).abs() / expected;
+        assert!(relative_error < 1e-9);
+
+        let price = price::price_to_host(&env, price::price_from_bin(10, id));
+        assert_eq!(id, client.get_id_from_price(&price));
+    }
+
+    let out_of_range = Some(Ok(error::Error::ErrBinIdOutOfRange.into()));
+    assert_eq!(out_of_range, client.try_get_price_from_id(&(max_bin_id + 1), &token_y.address).err());
+    assert_eq!(out_of_range, client.try_get_price_from_id(&(-max_bin_id - 1), &token_x.address).err());
+    assert_eq!(out_of_range, client.try_get_bins_range(&(max_bin_id - 1), &(max_bin_id + 1)).err());
+    assert_eq!(out_of_range, client.try_place_limit_order(&user_1, &0, &(max_bin_id + 1), &10_000_000_0).err());
+
+    let modify_args = vec![&env,
+                           DepositArgs{is_remove: false, bin_id_or_offset: -max_bin_id - 1, amount: 10_000_000_0},
+    ];
+    assert_eq!(out_of_range, client.try_modify_liquidity(&user_1, &0,  &modify_args, &false).err());
+
+    let price_out_of_range = Some(Ok(error::Error::ErrPriceOutOfRange.into()));
+    assert_eq!(price_out_of_range, client.try_get_id_from_price(&price::price_to_host(&env, price::price_from_bin(10, max_bin_id + 1))).err());
+    assert_eq!(price_out_of_range, client.try_get_id_from_price(&price::price_to_host(&env, ethnum::U256::ONE)).err());
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #40)")]
+fn active_bin_out_of_range() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let user_1 = Address::generate(&env);
+    create_pool_with(&env, &user_1, |config| config.active_bin = 66576);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #42)")]
+fn invalid_bin_step() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let user_1 = Address::generate(&env);
+    create_pool_with(&env, &user_1, |config| config.bin_step = 0);
+}
+
+#[test]
+fn swap_at_highest_supported_price() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let user_1 = Address::generate(&env);
+    let max_bin_id = price::get_max_bin_id(10);
+    // 1 y is worth about 2^96 x
+    let (client, token_x, token_y) = create_pool_with(&env, &user_1, |config| config.active_bin = max_bin_id - 1);
+    StellarAssetClient::new(&env, &token_x.address).mint(&user_1, &(1i128 << 110));
+
+    let modify_args = vec![&env,
+                           DepositArgs{is_remove: false, bin_id_or_offset: max_bin_id, amount: 1000},
+    ];
+    client.modify_liquidity(&user_1, &0,  &modify_args, &false);
+
+    let price = price::price_from_bin(10, max_bin_id);
+    let amount_in = price::get_amount_in(price, 1000, true).as_i128();
+    assert_eq!(1000, client.swap_exact_amount_in(&user_1, &amount_in, &0, &token_x.address));
+    assert_eq!(max_bin_id, client.get_config().active_bin);
+
+    // swapping back gives the input back, minus rounding
+    let amount_out = client.swap_exact_amount_in(&user_1, &1000, &0, &token_y.address);
+    assert!(amount_in - amount_out <= 1);
+}
+
+#[test]
+#[should_panic(expected = "Not enough liquidity")]
+fn swap_without_enough_liquidity() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let user_1 = Address::generate(&env);
+    let (client, token_x, _) = create_pool(&env, &user_1);
+
+    let modify_args = vec![&env,
+                           DepositArgs{is_remove: false, bin_id_or_offset: 0, amount: 10_000_000_0},
+    ];
+    client.modify_liquidity(&user_1, &0,  &modify_args, &false);
+
+    // there is only 5 y, the empty vecs after it are skipped
+    client.swap_exact_amount_in(&user_1, &10_000_000_0, &0, &token_x.address);
+}
+
+/// a token with 18 decimals that only implements `decimals`, the pool only reads the decimals when it is created.
+#[contract]
+struct DecimalsTokenContract;
+
+#[contractimpl]
+impl DecimalsTokenContract {
+    pub fn decimals() -> u32 {
+        18
+    }
+}
+
+#[test]
+fn decimals_are_read_from_tokens() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let user_1 = Address::generate(&env);
+    let token_y = env.register(DecimalsTokenContract, ());
+    // the decimals that are passed are ignored
+    let (client, _, _) = create_pool_with(&env, &user_1, |config| {
+        config.token_y = token_y;
+        config.decimals_x = 3;
+    });
+
+    let config = client.get_config();
+    assert_eq!(7, config.decimals_x);
+    assert_eq!(18, config.decimals_y);
+}
+
+#[test]
+fn human_price_helpers() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let user_1 = Address::generate(&env);
+    let token_y = env.register(DecimalsTokenContract, ());
+    let (client, _, _) = create_pool_with(&env, &user_1, |config| config.token_y = token_y);
+
+    // 1 raw y is worth 1e-11 raw x, so 1 whole y (1e18 raw) is worth 1e7 raw x, which is 1 whole x.
+    let price = price::price_from_bin(10, 1);
+    let human_price = price::price_to_human(price, 7, 18).unwrap();
+    assert_eq!(price * ethnum::U256::from(100_000_000_000u64), human_price);
+    assert_eq!(price::price_to_host(&env, human_price), client.get_human_price_from_id(&1));
+    assert_eq!(Some(price), price::price_from_human(human_price, 7, 18));
+    assert_eq!(1, client.get_id_from_human_price(&price::price_to_host(&env, human_price)));
+
+    // a human price of 1 is about 1e-11 raw x per raw y, which is bin ln(1e-11) / ln(1.001) = -25341.1, rounded down
+    let active_bin = price::id_from_human_price(&env, 10, 7, 18, price::SCALE);
+    assert_eq!(-25342, active_bin);
+    assert_eq!(active_bin, client.get_id_from_human_price(&price::price_to_host(&env, price::SCALE)));
+
+    // 2000 whole x per whole y with equal decimals
+    let active_bin = price::id_from_human_price(&env, 10, 7, 7, price::SCALE * 2000);
+    assert!(price::price_from_bin(10, active_bin) <= price::SCALE * 2000);
+    assert!(price::price_from_bin(10, active_bin + 1) > price::SCALE * 2000);
+
+    assert_eq!(None, price::price_from_human(ethnum::U256::MAX, 18, 7));
+    let price_out_of_range = Some(Ok(error::Error::ErrPriceOutOfRange.into()));
+    assert_eq!(price_out_of_range, client.try_get_id_from_human_price(&price::price_to_host(&env, ethnum::U256::ONE)).err());
+
+    // with 11 more decimals for y, the human prices of the top bins don't fit in 256 bits
+    let max_bin_id = price::get_max_bin_id(10);
+    assert_eq!(None, price::price_to_human(price::price_from_bin(10, max_bin_id), 7, 18));
+    assert_eq!(price_out_of_range, client.try_get_human_price_from_id(&max_bin_id).err());
+    assert!(client.try_get_human_price_from_id(&-max_bin_id).is_ok());
+}
+
+/// a token that burns 1% of every transfer, like tokens that charge a fee on transfer.
+///
+/// In its own module, because the generated functions would clash with the ones of `DecimalsTokenContract`.
+mod fee_token {
+    use soroban_sdk::{contract, contractimpl, Address, Env};
+
+    #[contract]
+    pub struct FeeTokenContract;
+
+    #[contractimpl]
+    impl FeeTokenContract {
+        pub fn mint(env: Env, to: Address, amount: i128) {
+            let balance = Self::balance(env.clone(), to.clone());
+            env.storage().persistent().set(&to, &(balance + amount));
+        }
+
+        pub fn balance(env: Env, id: Address) -> i128 {
+            env.storage().persistent().get(&id).unwrap_or(0)
+        }
+
+        pub fn transfer(env: Env, from: Address, to: Address, amount: i128) {
+            from.require_auth();
+            let from_balance = Self::balance(env.clone(), from.clone());
+            assert!(from_balance >= amount);
+            env.storage().persistent().set(&from, &(from_balance - amount));
+            Self::mint(env, to, amount - amount / 100);
+        }
+
+        pub fn decimals() -> u32 {
+            7
+        }
+    }
+}
+
+#[test]
+fn reserves_skim_and_sync() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let user_1 = Address::generate(&env);
+    let user_2 = Address::generate(&env);
+    let (client, token_x, token_y) = create_pool(&env, &user_1);
+
+    let modify_args = vec![&env,
+                           DepositArgs{is_remove: false, bin_id_or_offset: -1, amount: 10_000_000_0},
+                           DepositArgs{is_remove: false, bin_id_or_offset: 0, amount: 10_000_000_0},
+                           DepositArgs{is_remove: false, bin_id_or_offset: 1, amount: 10_000_000_0},
+    ];
+    client.modify_liquidity(&user_1, &0, &modify_args, &false);
+    client.swap_exact_amount_in(&user_1, &1_000_000_0, &0, &token_x.address);
+
+    let reserves = client.get_reserves();
+    assert_eq!(token_x.balance(&client.address), reserves.reserve_x);
+    assert_eq!(token_y.balance(&client.address), reserves.reserve_y);
+    assert_eq!((0, 0), client.skim(&user_2));
+
+    // tokens sent directly are not part of the reserves
+    token_x.transfer(&user_1, &client.address, &5_000_000_0);
+    token_y.transfer(&user_1, &client.address, &1_000_000_0);
+    assert_eq!(reserves, client.get_reserves());
+
+    assert_eq!((5_000_000_0, 1_000_000_0), client.skim(&user_2));
+    assert_eq!(5_000_000_0, token_x.balance(&user_2));
+    assert_eq!(1_000_000_0, token_y.balance(&user_2));
+    assert_eq!(reserves, client.get_reserves());
+
+    // sync gives the excess to the LPs of the active bin
+    let active_bin = client.get_active_bin_reserves();
+    token_y.transfer(&user_1, &client.address, &1_000_000_0);
+    assert_eq!((0, 1_000_000_0), client.sync());
+    assert_eq!(active_bin.reserve_y + 1_000_000_0, client.get_active_bin_reserves().reserve_y);
+    assert_eq!(reserves.reserve_y + 1_000_000_0, client.get_reserves().reserve_y);
+    assert_eq!((0, 0), client.skim(&user_2));
+
+    client.remove_position_pct(&user_1, &0, &10_000, &0, &0);
+    let reserves = client.get_reserves();
+    assert_eq!(token_x.balance(&client.address), reserves.reserve_x);
+    assert_eq!(token_y.balance(&client.address), reserves.reserve_y);
+}
+
+#[test]
+fn sync_takes_shortfall_from_bins() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let user_1 = Address::generate(&env);
+    // the flag is read when the balance of the pool is created, so it is set before the pool is created
+    let token = env.register_stellar_asset_contract_v2(user_1.clone());
+    token.issuer().set_flag(IssuerFlags::ClawbackEnabledFlag);
+    StellarAssetClient::new(&env, &token.address()).mint(&user_1, &100_000_000_0);
+    let (client, _, token_y) = create_pool_with(&env, &user_1, |config| config.token_x = token.address());
+
+    let modify_args = vec![&env,
+                           DepositArgs{is_remove: false, bin_id_or_offset: -1, amount: 10_000_000_0},
+                           DepositArgs{is_remove: false, bin_id_or_offset: 0, amount: 10_000_000_0},
+                           DepositArgs{is_remove: false, bin_id_or_offset: 1, amount: 10_000_000_0},
+    ];
+    client.modify_liquidity(&user_1, &0, &modify_args, &false);
+    let reserves = client.get_reserves();
+    let token_x = TokenClient::new(&env, &token.address());
+
+    StellarAssetClient::new(&env, &token_x.address).clawback(&client.address, &1_000_000_0);
+    assert_eq!((0, 0), client.sync());
+
+    let event = env.events().all().last().unwrap();
+    assert_eq!(vec![&env, symbol_short!("loss").into_val(&env)], event.1);
+    let changes: Vec<events::BinChange> = event.2.into_val(&env);
+    // rounded down per bin with the rest taken from the largest bin, so the bins lose exactly the shortfall
+    let taken = -changes.iter().map(|change| change.amount_x).sum::<i128>();
+    assert_eq!(1_000_000_0, taken);
+
+    assert_eq!(reserves.reserve_x - 1_000_000_0, client.get_reserves().reserve_x);
+    assert_eq!(token_x.balance(&client.address), client.get_reserves().reserve_x);
+    assert_eq!(token_y.balance(&client.address), client.get_reserves().reserve_y);
+
+    client.remove_position_pct(&user_1, &0, &10_000, &0, &0);
+    assert_eq!(token_x.balance(&client.address), client.get_reserves().reserve_x);
+}
+
+#[test]
+fn sync_keeps_dust_bins() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let user_1 = Address::generate(&env);
+    let token = env.register_stellar_asset_contract_v2(user_1.clone());
+    token.issuer().set_flag(IssuerFlags::ClawbackEnabledFlag);
+    StellarAssetClient::new(&env, &token.address()).mint(&user_1, &100_000_000_0);
+    let (client, _, _) = create_pool_with(&env, &user_1, |config| config.token_x = token.address());
+    let token_admin = StellarAssetClient::new(&env, &token.address());
+
+    let modify_args = vec![&env,
+                           DepositArgs{is_remove: false, bin_id_or_offset: -2, amount: 1},
+                           DepositArgs{is_remove: false, bin_id_or_offset: -1, amount: 10_000_000_0},
+    ];
+    client.modify_liquidity(&user_1, &0, &modify_args, &false);
+    let dust_bin_id = client.get_config().active_bin - 2;
+
+    // the dust bin rounds down to nothing, the large bin takes the whole shortfall
+    token_admin.clawback(&client.address, &5_000_000_0);
+    client.sync();
+    assert_eq!(1, client.get_bin(&dust_bin_id).reserve_x);
+    assert_eq!(10_000_000_0 - 5_000_000_0, client.get_bin(&(dust_bin_id + 1)).reserve_x);
+
+    // the dust bin still has its shares and reserves, so it takes deposits
+    let modify_args = vec![&env, DepositArgs{is_remove: false, bin_id_or_offset: -2, amount: 1_000_0}];
+    client.modify_liquidity(&user_1, &0, &modify_args, &false);
+    assert_eq!(1 + 1_000_0, client.get_bin(&dust_bin_id).reserve_x);
+
+    // a shortfall that would empty the bins is rejected
+    token_admin.clawback(&client.address, &client.get_reserves().reserve_x);
+    let res = client.try_sync();
+    assert!(res.is_err());
+    assert_eq!(1 + 1_000_0, client.get_bin(&dust_bin_id).reserve_x);
+}
+
+#[test]
+fn sync_takes_shortfall_from_nearest_vecs() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let user_1 = Address::generate(&env);
+    let token = env.register_stellar_asset_contract_v2(user_1.clone());
+    token.issuer().set_flag(IssuerFlags::ClawbackEnabledFlag);
+    StellarAssetClient::new(&env, &token.address()).mint(&user_1, &100_000_000_0);
+    let (client, _, _) = create_pool_with(&env, &user_1, |config| config.token_x = token.address());
+
+    // a bin in each of more vecs than the shortfall is taken from, below the active bin
+    let vec_count = MAX_LOSS_VECS as i32 + 2;
+    let mut modify_args = vec![&env];
+    for i in (1..=vec_count).rev() {
+        modify_args.push_back(DepositArgs{is_remove: false, bin_id_or_offset: -i * BIN_VEC_SIZE, amount: 1_000_000_0});
+    }
+    client.modify_liquidity(&user_1, &0, &modify_args, &false);
+    let active_bin = client.get_config().active_bin;
+
+    StellarAssetClient::new(&env, &token.address()).clawback(&client.address, &1_000_000_0);
+    client.sync();
+
+    // only the bins of the nearest vecs lost, pro rata
+    for i in 1..=vec_count {
+        let bin = client.get_bin(&(active_bin - i * BIN_VEC_SIZE));
+        if i <= MAX_LOSS_VECS as i32 {
+            assert!(bin.reserve_x < 1_000_000_0);
+        } else {
+            assert_eq!(1_000_000_0, bin.reserve_x);
+        }
+    }
+    let token_x = TokenClient::new(&env, &token.address());
+    assert_eq!(token_x.balance(&client.address), client.get_reserves().reserve_x);
+}
+
+#[test]
+fn fee_on_transfer_token() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let user_1 = Address::generate(&env);
+    let token_x = env.register(fee_token::FeeTokenContract, ());
+    let token_x_client = fee_token::FeeTokenContractClient::new(&env, &token_x);
+    token_x_client.mint(&user_1, &100_000_000_0);
+    let (client, _, token_y) = create_pool_with(&env, &user_1, |config| config.token_x = token_x.clone());
+
+    // the bins are credited before the transfer, so deposits must arrive in full
+    let modify_args = vec![&env,
+                           DepositArgs{is_remove: false, bin_id_or_offset: -1, amount: 10_000_000_0},
+    ];
+    let transfer_mismatch = Some(Ok(error::Error::ErrTransferAmountMismatch.into()));
+    assert_eq!(transfer_mismatch, client.try_modify_liquidity(&user_1, &0, &modify_args, &false).err());
+
+    // only y is deposited
+    let modify_args = vec![&env,
+                           DepositArgs{is_remove: false, bin_id_or_offset: 1, amount: 10_000_000_0},
+    ];
+    client.modify_liquidity(&user_1, &0, &modify_args, &false);
+
+    // the swap uses the 99% that was received, 990 x at a price of 1.001 is 989.0109 y
+    let amount_out = client.swap_exact_amount_in(&user_1, &1_000_000_0, &0, &token_x);
+    assert_eq!(989_010_9, amount_out);
+    assert_eq!(990_000_0, client.get_reserves().reserve_x);
+    assert_eq!(990_000_0, token_x_client.balance(&client.address));
+    assert_eq!(10_000_000_0 - amount_out, token_y.balance(&client.address));
+}
+
+#[test]
+fn bin_totals() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let user_1 = Address::generate(&env);
+    let (client, token_x, token_y) = create_pool_with(&env, &user_1, |config| config.fee = 10);
+
+    let modify_args = vec![&env,
+                           DepositArgs{is_remove: false, bin_id_or_offset: -1, amount: 10_000_000_0},
+                           DepositArgs{is_remove: false, bin_id_or_offset: 0, amount: 10_000_000_0},
+                           DepositArgs{is_remove: false, bin_id_or_offset: 80, amount: 10_000_000_0},
+    ];
+    client.modify_liquidity(&user_1, &0, &modify_args, &false);
+    client.place_limit_order(&user_1, &0, &2, &1_000_000_0);
+
+    let totals = client.get_bin_totals();
+    assert_eq!(15_000_000_0, totals.total_reserve_x);
+    assert_eq!(15_000_000_0, totals.total_reserve_y);
+    // the limit order is only part of the reserves
+    assert_eq!(16_000_000_0, client.get_reserves().reserve_y);
+
+    // crosses the limit order and ends in the next vec
+    let amount_out = client.swap_exact_amount_in(&user_1, &10_000_000_0, &0, &token_x.address);
+    let totals = client.get_bin_totals();
+    let reserves = client.get_reserves();
+    assert_eq!(16_000_000_0 - amount_out, reserves.reserve_y);
+    assert_eq!(totals.total_reserve_y, reserves.reserve_y);
+    // the proceeds of the filled order are not claimed yet
+    assert!(totals.total_reserve_x < reserves.reserve_x);
+    assert_eq!(token_x.balance(&client.address), reserves.reserve_x);
+    assert_eq!(token_y.balance(&client.address), reserves.reserve_y);
+
+    client.remove_position_pct(&user_1, &0, &10_000, &0, &0);
+    assert_eq!(BinTotals::default(), client.get_bin_totals());
+}
+
+#[test]
+#[should_panic(expected = "Bin totals of x don't match the bins")]
+fn invariants_are_checked() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let user_1 = Address::generate(&env);
+    let (client, token_x, _) = create_pool(&env, &user_1);
+
+    let modify_args = vec![&env,
+                           DepositArgs{is_remove: false, bin_id_or_offset: 0, amount: 10_000_000_0},
+    ];
+    client.modify_liquidity(&user_1, &0, &modify_args, &false);
+
+    env.as_contract(&client.address, || storage::add_to_bin_totals(&env, 1, 0, 0));
+    client.swap_exact_amount_in(&user_1, &1_000_000_0, &0, &token_x.address);
+}
+
+#[test]
+fn vec_ids_of_negative_bins() {
+    for (bin_id, vec_id, position) in [(-151, -3, 74), (-150, -2, 0), (-76, -2, 74), (-75, -1, 0), (-1, -1, 74), (0, 0, 0), (74, 0, 74), (75, 1, 0)] {
+        assert_eq!(vec_id, storage::get_vec_id_for_bin(bin_id));
+        assert_eq!(position, bin::get_bin_position_in_vec(bin_id));
+        assert_eq!(vec_id * BIN_VEC_SIZE + position as i32, bin_id);
+    }
+}
+
+/// a reward token with `amount` minted to a new manager: (manager, token)
+fn create_reward_token<'a>(env: &Env, amount: i128) -> (Address, TokenClient<'a>) {
+    let manager = Address::generate(env);
+    let token = env.register_stellar_asset_contract_v2(manager.clone()).address();
+    StellarAssetClient::new(env, &token).mint(&manager, &amount);
+    (manager, TokenClient::new(env, &token))
+}
+
+#[test]
+fn incentive_rewards_follow_shares() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|ledger| ledger.timestamp = 1000);
+
+    let user_1 = Address::generate(&env);
+    let user_2 = Address::generate(&env);
+    let (client, _, token_y) = create_pool(&env, &user_1);
+    token_y.transfer(&user_1, &user_2, &10_000_000_0);
+    let (manager, reward) = create_reward_token(&env, 1_000_000_0);
+
+    let modify_args = vec![&env,
+                           DepositArgs{is_remove: false, bin_id_or_offset: 0, amount: 10_000_000_0},
+                           DepositArgs{is_remove: false, bin_id_or_offset: 1, amount: 10_000_000_0},
+    ];
+    client.modify_liquidity(&user_1, &0, &modify_args, &false);
+
+    let id = client.add_incentive(&manager, &reward.address, &1_000_000_0, &1000, &2000, &RewardRange::Bins(0, 1));
+    assert_eq!(vec![&env, id], client.get_incentives());
+    assert_eq!(0, reward.balance(&manager));
+
+    // half of the time, each bin gets half of it
+    env.ledger().with_mut(|ledger| ledger.timestamp = 1500);
+    assert_eq!(vec![&env, (id, 500_000_0)], client.get_pending_rewards(&user_1, &0));
+
+    // user_2 gets half of bin 1 from now on
+    client.modify_liquidity(&user_2, &0, &vec![&env, DepositArgs{is_remove: false, bin_id_or_offset: 1, amount: 10_000_000_0}], &false);
+
+    // rewards stay claimable after the position is removed, and stop at the end
+    env.ledger().with_mut(|ledger| ledger.timestamp = 2500);
+    client.remove_position_pct(&user_1, &0, &MAX_BPS, &0, &0);
+    assert_eq!(875_000_0, client.claim_rewards(&user_1, &0, &id));
+    assert_eq!(875_000_0, reward.balance(&user_1));
+    assert_eq!(0, client.claim_rewards(&user_1, &0, &id));
+    assert_eq!(vec![&env, (id, 125_000_0)], client.get_pending_rewards(&user_2, &0));
+
+    assert!(client.try_reclaim_incentive(&id).is_err());
+
+    // what user_2 did not claim goes back to the manager
+    env.ledger().with_mut(|ledger| ledger.timestamp = 2000 + constants::REWARD_CLAIM_PERIOD);
+    assert_eq!(125_000_0, client.reclaim_incentive(&id));
+    assert_eq!(125_000_0, reward.balance(&manager));
+    assert_eq!(None, client.get_incentive(&id));
+    assert_eq!(vec![&env], client.get_pending_rewards(&user_2, &0));
+    assert_eq!(0, client.claim_rewards(&user_2, &0, &id));
+    assert_eq!(0, reward.balance(&client.address));
+}
+
+#[test]
+fn incentive_rewards_follow_active_bin() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|ledger| ledger.timestamp = 1000);
+
+    let user_1 = Address::generate(&env);
+    let user_2 = Address::generate(&env);
+    let (client, token_x, token_y) = create_pool(&env, &user_1);
+    token_y.transfer(&user_1, &user_2, &10_000_000_0);
+    let (manager, reward) = create_reward_token(&env, 1_000_000_0);
+
+    let modify_args = vec![&env,
+                           DepositArgs{is_remove: false, bin_id_or_offset: -1, amount: 10_000_000_0},
+                           DepositArgs{is_remove: false, bin_id_or_offset: 0, amount: 10_000_000_0},
+    ];
+    client.modify_liquidity(&user_1, &0, &modify_args, &false);
+    client.modify_liquidity(&user_2, &0, &vec![&env, DepositArgs{is_remove: false, bin_id_or_offset: 1, amount: 10_000_000_0}], &false);
+
+    // only the active bin
+    let id = client.add_incentive(&manager, &reward.address, &1_000_000_0, &0, &2000, &RewardRange::Active(0));
+    assert_eq!(1000, client.get_incentive(&id).unwrap().start);
+
+    // y in moves the active bin down to -1
+    env.ledger().with_mut(|ledger| ledger.timestamp = 1500);
+    client.swap_exact_amount_in(&user_1, &6_000_000_0, &0, &token_y.address);
+    assert_eq!(-1, client.get_config().active_bin);
+
+    env.ledger().with_mut(|ledger| ledger.timestamp = 2000);
+    assert_eq!(vec![&env, (id, 1_000_000_0)], client.get_pending_rewards(&user_1, &0));
+    assert_eq!(vec![&env], client.get_pending_rewards(&user_2, &0));
+    assert_eq!(0, reward.balance(&user_2));
+
+    // the pool tokens are not touched
+    assert_eq!((0, 0), client.skim(&user_2));
+    assert_eq!(0, token_x.balance(&user_2));
+}
+
+#[test]
+fn incentives_are_claimed_separately() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|ledger| ledger.timestamp = 1000);
+
+    let user_1 = Address::generate(&env);
+    let (client, _, _) = create_pool(&env, &user_1);
+    let (manager, reward_a) = create_reward_token(&env, 1_000_000_0);
+    // the issuer of reward b can deauthorize the pool, so its transfers fail
+    let reward_b = env.register_stellar_asset_contract_v2(manager.clone());
+    reward_b.issuer().set_flag(IssuerFlags::RevocableFlag);
+    StellarAssetClient::new(&env, &reward_b.address()).mint(&manager, &1_000_000_0);
+
+    let modify_args = vec![&env,
+                           DepositArgs{is_remove: false, bin_id_or_offset: -1, amount: 10_000_000_0},
+                           DepositArgs{is_remove: false, bin_id_or_offset: 0, amount: 10_000_000_0},
+    ];
+    client.modify_liquidity(&user_1, &0, &modify_args, &false);
+
+    // the admin of the pool authorizes the incentives
+    let id_a = client.add_incentive(&manager, &reward_a.address, &1_000_000_0, &1000, &2000, &RewardRange::Bins(-1, 0));
+    assert!(env.auths().iter().any(|(address, _)| *address == user_1));
+    let id_b = client.add_incentive(&manager, &reward_b.address(), &1_000_000_0, &1000, &2000, &RewardRange::Bins(-1, 0));
+
+    env.ledger().with_mut(|ledger| ledger.timestamp = 2000);
+    StellarAssetClient::new(&env, &reward_b.address()).set_authorized(&client.address, &false);
+    assert!(client.try_claim_rewards(&user_1, &0, &id_b).is_err());
+    assert_eq!(1_000_000_0, client.claim_rewards(&user_1, &0, &id_a));
+    assert_eq!(vec![&env, (id_b, 1_000_000_0)], client.get_pending_rewards(&user_1, &0));
+
+    // the reward per share is stored per bin vec
+    env.as_contract(&client.address, || {
+        let vec_ids = vec![&env, storage::get_vec_id_for_bin(-1), storage::get_vec_id_for_bin(0)];
+        assert_eq!(Some(vec_ids.clone()), env.storage().persistent().get(&DataKey::RewardVecIds(id_a)));
+        for vec_id in vec_ids.iter() {
+            assert!(env.storage().persistent().has(&DataKey::RewardPerShare(id_a, vec_id)));
+        }
+    });
+
+    StellarAssetClient::new(&env, &reward_b.address()).set_authorized(&client.address, &true);
+    assert_eq!(1_000_000_0, client.claim_rewards(&user_1, &0, &id_b));
+}
+
+#[test]
+fn invalid_incentives() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let user_1 = Address::generate(&env);
+    let (client, token_x, _) = create_pool(&env, &user_1);
+    let (manager, reward) = create_reward_token(&env, 1_000_000_0);
+    let range = RewardRange::Active(constants::MAX_REWARD_WIDTH);
+
+    assert!(client.try_add_incentive(&user_1, &token_x.address, &1_000_0, &0, &100, &range).is_err());
+    assert!(client.try_add_incentive(&manager, &reward.address, &1_000_0, &100, &100, &range).is_err());
+    assert!(client.try_add_incentive(&manager, &reward.address, &1_000_0, &0, &(constants::MAX_INCENTIVE_DURATION + 1), &range).is_err());
+    assert!(client.try_add_incentive(&manager, &reward.address, &1_000_0, &0, &u64::MAX, &range).is_err());
+    assert!(client.try_add_incentive(&manager, &reward.address, &1_000_0, &0, &100, &RewardRange::Active(constants::MAX_REWARD_WIDTH + 1)).is_err());
+    assert!(client.try_add_incentive(&manager, &reward.address, &1_000_0, &0, &100, &RewardRange::Bins(1, 0)).is_err());
+    assert!(client.try_add_incentive(&manager, &reward.address, &1_000_0, &0, &100, &RewardRange::Bins(0, 2 * constants::MAX_REWARD_WIDTH as i32 + 1)).is_err());
+
+    for _ in 0..constants::MAX_INCENTIVES {
+        client.add_incentive(&manager, &reward.address, &1_000_0, &0, &100, &range);
+    }
+    assert!(client.try_add_incentive(&manager, &reward.address, &1_000_0, &0, &100, &range).is_err());
+}
+
+#[test]
+fn bin_shares_are_a_multi_token() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|ledger| ledger.timestamp = 1000);
+
+    let user_1 = Address::generate(&env);
+    let user_2 = Address::generate(&env);
+    let operator = Address::generate(&env);
+    let (client, _, _) = create_pool(&env, &user_1);
+    let (manager, reward) = create_reward_token(&env, 1_000_000_0);
+
+    let modify_args = vec![&env,
+                           DepositArgs{is_remove: false, bin_id_or_offset: 0, amount: 10_000_000_0},
+                           DepositArgs{is_remove: false, bin_id_or_offset: 1, amount: 10_000_000_0},
+    ];
+    client.modify_liquidity(&user_1, &0, &modify_args, &false);
+    client.modify_liquidity(&user_1, &1, &vec![&env, DepositArgs{is_remove: false, bin_id_or_offset: 1, amount: 10_000_000_0}], &false);
+
+    // the balance of a bin is the sum over the positions
+    assert_eq!(vec![&env, 0, 1], client.get_position_ids(&user_1));
+    assert_eq!(20_000_000_0, client.balance_of(&user_1, &1));
+    assert_eq!(vec![&env, 10_000_000_0, 20_000_000_0, 0], client.balance_of_batch(&vec![&env, user_1.clone(), user_1.clone(), user_2.clone()], &vec![&env, 0, 1, 1]));
+    assert!(client.try_balance_of_batch(&vec![&env, user_1.clone()], &vec![&env, 0, 1]).is_err());
+
+    let id = client.add_incentive(&manager, &reward.address, &1_000_000_0, &1000, &2000, &RewardRange::Bins(0, 1));
+    env.ledger().with_mut(|ledger| ledger.timestamp = 1500);
+
+    let shares = vec![&env, BinShares{bin_id: 1, shares: 5_000_000_0}];
+    client.batch_transfer(&user_1, &user_2, &0, &shares);
+    let (_, topics, data) = env.events().all().iter().filter(|(contract, _, _)| *contract == client.address).last().unwrap();
+    assert_eq!(topics, (Symbol::new(&env, "batch_transfer"), user_1.clone(), user_2.clone(), 0).into_val(&env));
+    assert_eq!(shares, data.into_val(&env));
+
+    assert_eq!(15_000_000_0, client.balance_of(&user_1, &1));
+    assert_eq!(shares, client.get_position(&user_2, &0).unwrap().bin_shares);
+    assert_eq!(vec![&env, 0], client.get_position_ids(&user_2));
+    assert!(client.try_batch_transfer(&user_2, &user_1, &0, &vec![&env, BinShares{bin_id: 1, shares: 5_000_000_1}]).is_err());
+    assert!(client.try_batch_transfer(&user_2, &user_2, &0, &shares).is_err());
+    assert!(client.try_batch_transfer(&user_2, &user_1, &1, &shares).is_err());
+
+    // the rewards until the transfer stay with user_1, the shares earn for user_2 from then on
+    env.ledger().with_mut(|ledger| ledger.timestamp = 2000);
+    assert_eq!(vec![&env, (id, 500_000_0 + 125_000_0 + 62_500_0)], client.get_pending_rewards(&user_1, &0));
+    assert_eq!(vec![&env, (id, 62_500_0)], client.get_pending_rewards(&user_2, &0));
+
+    let rest = vec![&env, BinShares{bin_id: 0, shares: 10_000_000_0}, BinShares{bin_id: 1, shares: 5_000_000_0}];
+    assert!(client.try_batch_transfer_from(&operator, &user_1, &user_2, &0, &rest).is_err());
+    client.set_approval_for_all(&user_1, &operator, &true);
+    assert!(client.is_approved_for_all(&user_1, &operator));
+    assert!(!client.is_approved_for_all(&user_2, &operator));
+
+    // moving all shares deletes the position, the position api is a view over the same shares
+    client.batch_transfer_from(&operator, &user_1, &user_2, &0, &rest);
+    assert_eq!(None, client.get_position(&user_1, &0));
+    assert_eq!(vec![&env, 1], client.get_position_ids(&user_1));
+    assert_eq!(10_000_000_0, client.balance_of(&user_1, &1));
+    assert_eq!(vec![&env, BinShares{bin_id: 0, shares: 10_000_000_0}, BinShares{bin_id: 1, shares: 10_000_000_0}], client.get_position(&user_2, &0).unwrap().bin_shares);
+    assert_eq!(687_500_0, client.claim_rewards(&user_1, &0, &id));
+    assert_eq!(687_500_0, reward.balance(&user_1));
+
+    let (amount_x, amount_y) = client.remove_position_pct(&user_2, &0, &MAX_BPS, &0, &0);
+    assert!(amount_x > 0 && amount_y > 0);
+    assert_eq!(vec![&env], client.get_position_ids(&user_2));
+
+    client.set_approval_for_all(&user_1, &operator, &false);
+    assert!(!client.is_approved_for_all(&user_1, &operator));
+    assert!(client.try_batch_transfer_from(&operator, &user_1, &user_2, &1, &shares).is_err());
+}
+
+/// The actions of the property tests. Bins are relative to the active bin and users are indices in the list of LPs.
+#[derive(Clone, Debug)]
+enum Action {
+    Deposit { user: usize, offset: i32, width: i32, amount: i128 },
+    Remove { user: usize, bps: u32 },
+    Swap { user: usize, x_in: bool, amount: i128 },
+}
+
+const USERS: usize = 3;
+
+fn action_strategy() -> impl proptest::strategy::Strategy<Value = Action> {
+    use proptest::prelude::*;
+
+    prop_oneof![
+        (0..USERS, -12..12i32, 1..8i32, 1_000i128..10_000_000_0).prop_map(|(user, offset, width, amount)| Action::Deposit { user, offset, width, amount }),
+        (0..USERS, 1..=MAX_BPS).prop_map(|(user, bps)| Action::Remove { user, bps }),
+        (0..USERS, any::<bool>(), 1i128..20_000_000_0).prop_map(|(user, x_in, amount)| Action::Swap { user, x_in, amount }),
+    ]
+}
+
+/// Running sums of what went in and out of the pool, per token.
+#[derive(Default)]
+struct Flows {
+    deposited: [i128; 2],
+    withdrawn: [i128; 2],
+    swapped_in: [i128; 2],
+    swapped_out: [i128; 2],
+}
+
+/// A pool with an active bin next to the edge of a vec, a few funded LPs and a swapper.
+fn create_fuzz_pool<'a>(env: &Env, active_bin: i32, fee: u32) -> (ContractClient<'a>, [TokenClient<'a>; 2], std::vec::Vec<Address>) {
+    let admin = Address::generate(env);
+    let (client, token_x, token_y) = create_pool_with(env, &admin, |config| {
+        config.active_bin = active_bin;
+        config.fee = fee;
+    });
+
+    let users: std::vec::Vec<Address> = (0..USERS).map(|_| Address::generate(env)).collect();
+    for user in users.iter() {
+        token_x.transfer(&admin, user, &10000_000_000_0);
+        token_y.transfer(&admin, user, &10000_000_000_0);
+    }
+
+    (client, [token_x, token_y], users)
+}
+
+/// Loads the bins and orders of the vecs around the active bin into a `PoolState`, like a bot would do with `get_bin_vec`.
+fn load_pool_state(client: &ContractClient) -> PoolState {
+    let config = client.get_config();
+    let mut pool = PoolState::new(config.bin_step, config.fee, config.active_bin);
+
+    let active_vec_id = storage::get_vec_id_for_bin(config.active_bin);
+    for vec_id in active_vec_id - 3..=active_vec_id + 3 {
+        for bin in client.get_bin_vec(&vec_id).iter() {
+            pool.load_bin(bin.bin_id, bin.reserve_x, bin.reserve_y);
+            if let Some(order_bin) = client.get_order_bin(&bin.bin_id) {
+                pool.load_orders(bin.bin_id, order_bin.sell_x, order_bin.remaining, order_bin.proceeds);
+            }
+        }
+        for bin_shares in client.get_shares_vec(&vec_id).iter() {
+            pool.load_shares(bin_shares.bin_id, bin_shares.shares);
+        }
+    }
+
+    pool
+}
+
+/// Applies `action` and checks the invariants that hold for a single call.
+///
+/// Deposits and removals must succeed. Swaps may fail when there is not enough liquidity, those are rolled back and ignored.
+fn apply_action(env: &Env, client: &ContractClient, tokens: &[TokenClient; 2], users: &[Address], flows: &mut Flows, action: &Action) {
+    match *action {
+        Action::Deposit { user, offset, width, amount } => {
+            let mut args = vec![env];
+            for i in 0..width {
+                args.push_back(DepositArgs { is_remove: false, bin_id_or_offset: offset + i, amount });
+            }
+
+            let (x, y) = client.modify_liquidity(&users[user], &0, &args, &true);
+            assert!(x >= 0 && y >= 0);
+            assert_eq!(amount * width as i128, x + y);
+            flows.deposited[0] += x;
+            flows.deposited[1] += y;
+        }
+        Action::Remove { user, bps } => {
+            // removing fails if the position is empty or if no shares are left after rounding down
+            let Some(position) = client.get_position(&users[user], &0) else { return };
+            if position.bin_shares.iter().all(|bin_shares| bin_shares.shares * bps as i128 / MAX_BPS as i128 == 0) {
+                return;
+            }
+
+            let (x, y) = client.remove_position_pct(&users[user], &0, &bps, &0, &0);
+            flows.withdrawn[0] += x;
+            flows.withdrawn[1] += y;
+        }
+        Action::Swap { user, x_in, amount } => {
+            let (in_index, out_index) = if x_in { (0, 1) } else { (1, 0) };
+            let config = client.get_config();
+            let best_price = price::price_from_bin(config.bin_step, config.active_bin);
+
+            // the off-chain simulation predicts the swap, also when it fails
+            let quote = load_pool_state(client).quote_exact_amount_in(amount, x_in);
+
+            match client.try_swap_exact_amount_in(&users[user], &amount, &0, &tokens[in_index].address) {
+                Ok(Ok(amount_out)) => {
+                    // no value is created: the whole swap is at most as good as the price of the active bin before the swap, without fees
+                    assert!(ethnum::U256::from(amount_out as u128) <= price::get_amount_out(best_price, amount as u128, x_in));
+                    flows.swapped_in[in_index] += amount;
+                    flows.swapped_out[out_index] += amount_out;
+
+                    let quote = quote.unwrap();
+                    assert_eq!(quote.amount_out, amount_out);
+                    assert_eq!(quote.active_bin, client.get_config().active_bin);
+                }
+                _ => assert!(quote.is_err()),
+            }
+        }
+    }
+
+    // the pool holds what went in minus what went out, and that covers the reserves of the bins
+    let totals = client.get_bin_totals();
+    for (i, total) in [totals.total_reserve_x, totals.total_reserve_y].into_iter().enumerate() {
+        let balance = tokens[i].balance(&client.address);
+        assert_eq!(flows.deposited[i] + flows.swapped_in[i] - flows.withdrawn[i] - flows.swapped_out[i], balance);
+        assert!(total <= balance);
+    }
+}
+
+proptest::proptest! {
+    #![proptest_config(proptest::prelude::ProptestConfig::with_cases(24))]
+
+    #[test]
+    fn fuzz_liquidity_and_swaps(
+        active_bin in proptest::sample::select(std::vec![-151, -150, -76, -75, -74, -1, 0, 74, 75]),
+        fee in proptest::sample::select(std::vec![0u32, 10, 100]),
+        actions in proptest::collection::vec(action_strategy(), 1..16),
+    ) {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (client, tokens, users) = create_fuzz_pool(&env, active_bin, fee);
+
+        let mut flows = Flows::default();
+        for action in actions.iter() {
+            apply_action(&env, &client, &tokens, &users, &mut flows, action);
+        }
+
+        // every LP can withdraw everything, so the balance covers all claims
+        for user in users.iter() {
+            if client.get_position(user, &0).is_some() {
+                let (x, y) = client.remove_position_pct(user, &0, &MAX_BPS, &0, &0);
+                flows.withdrawn[0] += x;
+                flows.withdrawn[1] += y;
+            }
+        }
+        proptest::prop_assert_eq!(BinTotals::default(), client.get_bin_totals());
+
+        // LPs get at most what they deposited plus what the swaps left in the pool, which includes the fees
+        for (i, token) in tokens.iter().enumerate() {
+            let dust = flows.deposited[i] + flows.swapped_in[i] - flows.swapped_out[i] - flows.withdrawn[i];
+            proptest::prop_assert!(dust >= 0);
+            proptest::prop_assert_eq!(dust, token.balance(&client.address));
+        }
+
+        // without swaps, every token that comes out was deposited
+        let swapped = actions.iter().any(|action| matches!(action, Action::Swap { .. }));
+        if !swapped {
+            proptest::prop_assert!(flows.withdrawn[0] <= flows.deposited[0]);
+            proptest::prop_assert!(flows.withdrawn[1] <= flows.deposited[1]);
+        }
+    }
+
+    #[test]
+    fn fuzz_round_trip_loses(
+        active_bin in proptest::sample::select(std::vec![-76, -75, -1, 0, 74]),
+        fee in proptest::sample::select(std::vec![0u32, 10, 100]),
+        deposits in proptest::collection::vec((-12..12i32, 1_000i128..10_000_000_0), 1..6),
+        x_in in proptest::prelude::any::<bool>(),
+        amount in 1i128..20_000_000_0,
+    ) {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (client, tokens, users) = create_fuzz_pool(&env, active_bin, fee);
+
+        let mut flows = Flows::default();
+        for (offset, amount) in deposits {
+            apply_action(&env, &client, &tokens, &users, &mut flows, &Action::Deposit { user: 0, offset, width: 1, amount });
+        }
+
+        let (token_in, token_out) = if x_in { (&tokens[0], &tokens[1]) } else { (&tokens[1], &tokens[0]) };
+        let swapper = &users[1];
+
+        if let Ok(Ok(amount_out)) = client.try_swap_exact_amount_in(swapper, &amount, &0, &token_in.address) {
+            if amount_out > 0 {
+                if let Ok(Ok(amount_back)) = client.try_swap_exact_amount_in(swapper, &amount_out, &0, &token_out.address) {
+                    proptest::prop_assert!(amount_back <= amount);
+                }
+            }
+        }
+    }
+}
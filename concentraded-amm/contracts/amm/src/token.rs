@@ -1,6 +1,59 @@
-use soroban_sdk::{Address, Env};
+use soroban_sdk::{assert_with_error, Address, Env};
+use crate::error::Error;
+use crate::storage::{add_to_reserves, get_reserves, Config};
 use soroban_sdk::token::TokenClient;
 
 pub fn transfer(e: &Env, token: Address, from: Address, recipient: Address, amount: i128) {
     TokenClient::new(e, &token).transfer(&from, &recipient, &amount);
-}
\ No newline at end of file
+}
+pub fn balance(e: &Env, token: Address, id: Address) -> i128 {
+    TokenClient::new(e, &token).balance(&id)
+}
+
+pub fn decimals(e: &Env, token: Address) -> u32 {
+    TokenClient::new(e, &token).decimals()
+}
+
+/// Transfers `amount` of `token` from `from` to the pool and adds what was received to the reserves.
+///
+/// The amount received is measured with the balance of the pool, so tokens that charge a fee on transfer are not over credited.
+///
+/// returns the amount that was received
+pub fn transfer_in(e: &Env, config: &Config, token: Address, from: Address, amount: i128) -> i128 {
+    let pool = e.current_contract_address();
+    let balance_before = balance(e, token.clone(), pool.clone());
+
+    transfer(e, token.clone(), from, pool.clone(), amount);
+
+    let received = balance(e, token.clone(), pool) - balance_before;
+    add_to_reserves(e, config, &token, received);
+
+    received
+}
+
+/// Like `transfer_in`, but fails with `ErrTransferAmountMismatch` if the pool did not receive exactly `amount`.
+///
+/// Used when `amount` was already credited, for example to a bin.
+pub fn transfer_in_exact(e: &Env, config: &Config, token: Address, from: Address, amount: i128) {
+    let received = transfer_in(e, config, token, from, amount);
+    assert_with_error!(e, received == amount, Error::ErrTransferAmountMismatch);
+}
+
+/// Transfers `amount` of `token` from the pool to `to` and removes it from the reserves.
+pub fn transfer_out(e: &Env, config: &Config, token: Address, to: Address, amount: i128) {
+    add_to_reserves(e, config, &token, -amount);
+    transfer(e, token, e.current_contract_address(), to, amount);
+}
+
+/// returns the balances of the pool minus its reserves: (x_token_amount, y_token_amount)
+///
+/// Negative if a token rebased down.
+pub fn excess_balances(e: &Env, config: &Config) -> (i128, i128) {
+    let pool = e.current_contract_address();
+    let reserves = get_reserves(e);
+
+    (
+        balance(e, config.token_x.clone(), pool.clone()) - reserves.reserve_x,
+        balance(e, config.token_y.clone(), pool) - reserves.reserve_y,
+    )
+}
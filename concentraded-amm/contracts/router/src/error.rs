@@ -0,0 +1,22 @@
+use soroban_sdk::contracterror;
+
+/// The failures of the transfers of the router, from the errors of the stellar asset contract.
+#[contracterror]
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[repr(u32)]
+pub enum Error {
+    /// `from` does not have the input
+    InsufficientBalance = 1,
+    /// `from` is an account without a trustline for the input token
+    SenderTrustlineMissing = 2,
+    /// the issuer of the input token deauthorized `from`
+    SenderDeauthorized = 3,
+    /// `to` is an account without a trustline for the output token
+    RecipientTrustlineMissing = 4,
+    /// `to` is an account that does not exist
+    RecipientAccountMissing = 5,
+    /// the issuer of the output token deauthorized `to`
+    RecipientDeauthorized = 6,
+    /// the token failed the transfer for another reason
+    TransferFailed = 7,
+}
@@ -0,0 +1,197 @@
+#![no_std]
+// contract entrypoints take their arguments flat, and the generated client repeats them.
+#![allow(clippy::too_many_arguments)]
+
+use crate::token::{authorize_transfer, native_token, transfer_from_sender, transfer_to_recipient};
+use soroban_fixed_point_math::SorobanFixedPoint;
+use soroban_sdk::token::TokenClient;
+use soroban_sdk::{contract, contractclient, contractimpl, Address, Env, Vec};
+
+/// The most pools that a swap can be split over.
+pub const MAX_POOLS: u32 = 10;
+/// The most parts that `quote_split` divides the input in. Every part costs a quote.
+pub const MAX_STEPS: u32 = 50;
+
+/// The functions of the `amm` pools that the router uses.
+#[contractclient(name = "PoolClient")]
+pub trait Pool {
+    fn swap_exact_amount_in(env: Env, from: Address, amount_in: i128, min_amount_out: i128, in_token: Address) -> i128;
+    fn quote_exact_amount_in(env: Env, amount_in: i128, in_token: Address) -> i128;
+}
+
+#[contract]
+pub struct Router;
+
+/// returns the quote of `pool` for `amount_in`, or `None` if the pool does not have enough liquidity.
+fn quote(env: &Env, pool: &Address, amount_in: i128, in_token: &Address) -> Option<i128> {
+    match PoolClient::new(env, pool).try_quote_exact_amount_in(&amount_in, in_token) {
+        Ok(Ok(amount_out)) => Some(amount_out),
+        _ => None,
+    }
+}
+
+fn check_pools(pools: &Vec<Address>) {
+    assert!(!pools.is_empty() && pools.len() <= MAX_POOLS, "Invalid number of pools");
+    for (i, pool) in pools.iter().enumerate() {
+        assert!(pools.iter().skip(i + 1).all(|other| other != pool), "Duplicate pool");
+    }
+}
+
+#[contractimpl]
+impl Router {
+    /// Splits `amount_in` of `in_token` over `pools` of the same pair, for the most output.
+    ///
+    /// The input is divided in `steps` equal parts, and every part goes to the pool where it adds the most output,
+    /// from the quotes of the pools. More steps get closer to the best split, but cost more quotes.
+    /// Pools that can't swap a part, because they don't have enough liquidity, are skipped.
+    ///
+    /// returns the amount for every pool, in the order of `pools`, and the quoted output of the split
+    pub fn quote_split(env: Env, pools: Vec<Address>, amount_in: i128, in_token: Address, steps: u32) -> (Vec<i128>, i128) {
+        check_pools(&pools);
+        assert!(amount_in > 0);
+        assert!(steps > 0 && steps <= MAX_STEPS, "Invalid number of steps");
+
+        let mut amounts = Vec::new(&env);
+        let mut amounts_out = Vec::new(&env);
+        // the last quote of every pool: (amount_in, amount_out), the quote of the next part is often the same
+        let mut quotes: Vec<(i128, Option<i128>)> = Vec::new(&env);
+        for _ in pools.iter() {
+            amounts.push_back(0);
+            amounts_out.push_back(0);
+            quotes.push_back((0, Some(0)));
+        }
+
+        let steps = steps as i128;
+        for step in 0..steps {
+            // the first parts get the remainder
+            let part = amount_in / steps + if step < amount_in % steps { 1 } else { 0 };
+            if part == 0 {
+                break;
+            }
+
+            // (index of the pool, amount, amount out)
+            let mut best: Option<(u32, i128, i128)> = None;
+            for (i, pool) in pools.iter().enumerate() {
+                let i = i as u32;
+                let amount = amounts.get_unchecked(i) + part;
+
+                let (quoted, mut amount_out) = quotes.get_unchecked(i);
+                if quoted != amount {
+                    amount_out = quote(&env, &pool, amount, &in_token);
+                    quotes.set(i, (amount, amount_out));
+                }
+
+                let Some(amount_out) = amount_out else { continue };
+                let added = amount_out - amounts_out.get_unchecked(i);
+                if best.is_none_or(|(best_i, _, best_out)| added > best_out - amounts_out.get_unchecked(best_i)) {
+                    best = Some((i, amount, amount_out));
+                }
+            }
+
+            let (i, amount, amount_out) = best.expect("Not enough liquidity");
+            amounts.set(i, amount);
+            amounts_out.set(i, amount_out);
+        }
+
+        let total_out = amounts_out.iter().sum();
+        (amounts, total_out)
+    }
+
+    /// The address of the stellar asset contract of native XLM, for pools with XLM as one of the tokens.
+    pub fn native_token(env: Env) -> Address {
+        native_token(&env)
+    }
+
+    /// Swaps `amounts[i]` of `in_token` in `pools[i]` with `swap_exact_amount_in`, for `out_token` that is sent to `to`.
+    ///
+    /// The input is transferred from `from` to the router, which swaps it and sends the output to `to`.
+    /// If less than the input arrives, because `in_token` has a fee on transfer, every pool swaps its part of what arrived.
+    /// Instead of a minimum per pool, the output of all swaps together must be at least `min_amount_out`.
+    /// The output is what the router received of `out_token`, a pool that pays another token fails the swap.
+    ///
+    /// Transfers that the stellar asset contract refuses fail with an `Error`, for example when `to` has no trustline for `out_token`.
+    ///
+    /// returns the output of all swaps together
+    pub fn swap_split(env: Env, from: Address, to: Address, pools: Vec<Address>, amounts: Vec<i128>, in_token: Address, out_token: Address, min_amount_out: i128) -> i128 {
+        from.require_auth();
+        check_pools(&pools);
+        assert!(pools.len() == amounts.len(), "Pools and amounts differ in length");
+        assert!(in_token != out_token);
+        assert!(min_amount_out >= 0);
+
+        let mut amount_in = 0;
+        for amount in amounts.iter() {
+            assert!(amount >= 0);
+            amount_in += amount;
+        }
+        assert!(amount_in > 0);
+
+        let router = env.current_contract_address();
+        let input = TokenClient::new(&env, &in_token);
+        let input_before = input.balance(&router);
+        transfer_from_sender(&env, &in_token, &from, amount_in);
+        // less than `amount_in` arrives for tokens with a fee on transfer, every pool gets its part of what arrived
+        let mut received_left = input.balance(&router) - input_before;
+        let mut amount_in_left = amount_in;
+
+        let out = TokenClient::new(&env, &out_token);
+        let mut amount_out = 0;
+
+        for (pool, amount) in pools.iter().zip(amounts.iter()) {
+            if amount > 0 {
+                // the last pool gets the rest, so nothing is left in the router
+                let amount_received = if amount == amount_in_left { received_left } else { received_left.fixed_mul_floor(&env, &amount, &amount_in_left) };
+                received_left -= amount_received;
+                amount_in_left -= amount;
+                let amount = amount_received;
+
+                let balance_before = out.balance(&router);
+                authorize_transfer(&env, &in_token, &pool, amount);
+                let sent = PoolClient::new(&env, &pool).swap_exact_amount_in(&router, &amount, &0, &in_token);
+
+                // what we received can be less than what the pool sent for tokens with a fee on transfer
+                let received = out.balance(&router) - balance_before;
+                assert!(received > 0 || sent == 0, "Pool of another pair");
+                amount_out += received;
+            }
+        }
+
+        assert!(amount_out >= min_amount_out, "Insufficient output amount");
+        transfer_to_recipient(&env, &out_token, &to, amount_out);
+
+        amount_out
+    }
+
+    /// Swaps native XLM for `out_token` like `swap_split`, in pools with the stellar asset contract of XLM as one of the tokens.
+    ///
+    /// The XLM is taken from the balance of `from`, for an account that is its lumens.
+    ///
+    /// returns the output of all swaps together
+    pub fn swap_xlm_in(env: Env, from: Address, to: Address, pools: Vec<Address>, amounts: Vec<i128>, out_token: Address, min_amount_out: i128) -> i128 {
+        let native = native_token(&env);
+        Self::swap_split(env, from, to, pools, amounts, native, out_token, min_amount_out)
+    }
+
+    /// Swaps `in_token` for native XLM like `swap_split`, in pools with the stellar asset contract of XLM as one of the tokens.
+    ///
+    /// The XLM goes to the balance of `to`, for an account that is its lumens. An account that doesn't exist fails with `RecipientAccountMissing`.
+    ///
+    /// returns the output of all swaps together
+    pub fn swap_xlm_out(env: Env, from: Address, to: Address, pools: Vec<Address>, amounts: Vec<i128>, in_token: Address, min_amount_out: i128) -> i128 {
+        let native = native_token(&env);
+        Self::swap_split(env, from, to, pools, amounts, in_token, native, min_amount_out)
+    }
+
+    /// Splits `amount_in` over `pools` like `quote_split` and swaps it like `swap_split`.
+    ///
+    /// returns the output of all swaps together
+    pub fn swap_best(env: Env, from: Address, to: Address, pools: Vec<Address>, amount_in: i128, in_token: Address, out_token: Address, min_amount_out: i128, steps: u32) -> i128 {
+        let (amounts, _) = Self::quote_split(env.clone(), pools.clone(), amount_in, in_token.clone(), steps);
+
+        Self::swap_split(env, from, to, pools, amounts, in_token, out_token, min_amount_out)
+    }
+}
+
+pub mod error;
+mod token;
+mod test;
@@ -0,0 +1,312 @@
+#![cfg(test)]
+// amounts are written as `whole_fraction` with 7 decimals, like the stellar assets.
+#![allow(clippy::inconsistent_digit_grouping)]
+extern crate std;
+use super::*;
+use amm::storage::{Config, DepositArgs};
+use amm::ContractClient as AmmClient;
+use soroban_sdk::testutils::{Address as _, IssuerFlags};
+use soroban_sdk::token::StellarAssetClient;
+use soroban_sdk::xdr::{AccountId, PublicKey, ScAddress, Uint256};
+use soroban_sdk::{contracttype, vec, Address, Bytes, Env, String, TryFromVal};
+
+struct Setup<'a> {
+    env: Env,
+    user: Address,
+    token_x: Address,
+    token_y: Address,
+    router: RouterClient<'a>,
+}
+
+fn setup<'a>() -> Setup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let user = Address::generate(&env);
+    let admin = Address::generate(&env);
+    let [token_x, token_y] = [(); 2].map(|_| {
+        let token = env.register_stellar_asset_contract_v2(admin.clone());
+        // so that the tests can deauthorize holders
+        token.issuer().set_flag(IssuerFlags::RevocableFlag);
+        token.address()
+    });
+    StellarAssetClient::new(&env, &token_x).mint(&user, &1_000_000_000_0);
+    StellarAssetClient::new(&env, &token_y).mint(&user, &1_000_000_000_0);
+
+    let router = RouterClient::new(&env, &env.register(Router, ()));
+    Setup { env, user, token_x, token_y, router }
+}
+
+/// a pool of `token_x` and `token_y` with `amount` of liquidity in each of the bins from -`width` to `width`
+fn create_pool<'a>(setup: &Setup, token_x: &Address, token_y: &Address, bin_step: u32, width: i32, amount: i128) -> AmmClient<'a> {
+    let config = Config {
+        token_x: token_x.clone(),
+        token_y: token_y.clone(),
+        bin_step,
+        active_bin: 0,
+        fee: 30,
+        flash_loan_fee: 0,
+        ttl_threshold: 100,
+        ttl_extend_to: 1000,
+        decimals_x: 0,
+        decimals_y: 0,
+        admin: setup.user.clone(),
+    };
+    let pool = AmmClient::new(&setup.env, &setup.env.register(amm::Contract, (config,)));
+
+    let mut args = vec![&setup.env];
+    for bin_id in -width..=width {
+        args.push_back(DepositArgs { is_remove: false, bin_id_or_offset: bin_id, amount });
+    }
+    if !args.is_empty() {
+        pool.modify_liquidity(&setup.user, &0, &args, &false);
+    }
+    pool
+}
+
+#[test]
+fn swap_split() {
+    let setup = setup();
+    let pool_a = create_pool(&setup, &setup.token_x, &setup.token_y, 10, 5, 10_000_000_0);
+    let pool_b = create_pool(&setup, &setup.token_x, &setup.token_y, 25, 5, 10_000_000_0);
+    let pools = vec![&setup.env, pool_a.address.clone(), pool_b.address.clone()];
+
+    let quote_a = pool_a.quote_exact_amount_in(&30_000_000_0, &setup.token_x);
+    let quote_b = pool_b.quote_exact_amount_in(&20_000_000_0, &setup.token_x);
+
+    // one bound for both swaps
+    let amounts = vec![&setup.env, 30_000_000_0, 20_000_000_0];
+    let result = setup.router.try_swap_split(&setup.user, &setup.user, &pools, &amounts, &setup.token_x, &setup.token_y, &(quote_a + quote_b + 1));
+    assert!(result.is_err());
+
+    let balance_before = TokenClient::new(&setup.env, &setup.token_y).balance(&setup.user);
+    let amount_out = setup.router.swap_split(&setup.user, &setup.user, &pools, &amounts, &setup.token_x, &setup.token_y, &(quote_a + quote_b));
+    assert_eq!(amount_out, quote_a + quote_b);
+    assert_eq!(TokenClient::new(&setup.env, &setup.token_y).balance(&setup.user) - balance_before, amount_out);
+
+    // pools without an amount are skipped
+    let amounts = vec![&setup.env, 0, 1_000_000_0];
+    let quote_b = pool_b.quote_exact_amount_in(&1_000_000_0, &setup.token_x);
+    assert_eq!(setup.router.swap_split(&setup.user, &setup.user, &pools, &amounts, &setup.token_x, &setup.token_y, &0), quote_b);
+
+    assert!(setup.router.try_swap_split(&setup.user, &setup.user, &pools, &vec![&setup.env, 1], &setup.token_x, &setup.token_y, &0).is_err());
+}
+
+#[test]
+#[should_panic(expected = "Pool of another pair")]
+fn pool_of_another_pair() {
+    let setup = setup();
+    let admin = Address::generate(&setup.env);
+    let token_z = setup.env.register_stellar_asset_contract_v2(admin).address();
+    StellarAssetClient::new(&setup.env, &token_z).mint(&setup.user, &1_000_000_000_0);
+
+    let pool_a = create_pool(&setup, &setup.token_x, &setup.token_y, 10, 5, 10_000_000_0);
+    let pool_z = create_pool(&setup, &setup.token_x, &token_z, 10, 5, 10_000_000_0);
+    let pools = vec![&setup.env, pool_a.address.clone(), pool_z.address.clone()];
+    let amounts = vec![&setup.env, 1_000_000_0, 1_000_000_0];
+
+    setup.router.swap_split(&setup.user, &setup.user, &pools, &amounts, &setup.token_x, &setup.token_y, &0);
+}
+
+#[test]
+fn output_goes_to_recipient() {
+    let setup = setup();
+    let pool_a = create_pool(&setup, &setup.token_x, &setup.token_y, 10, 5, 10_000_000_0);
+    let pools = vec![&setup.env, pool_a.address.clone()];
+    let to = Address::generate(&setup.env);
+
+    let quote = pool_a.quote_exact_amount_in(&1_000_000_0, &setup.token_x);
+    let amount_out = setup.router.swap_split(&setup.user, &to, &pools, &vec![&setup.env, 1_000_000_0], &setup.token_x, &setup.token_y, &quote);
+    assert_eq!(amount_out, quote);
+    assert_eq!(TokenClient::new(&setup.env, &setup.token_y).balance(&to), quote);
+    // nothing stays in the router
+    assert_eq!(TokenClient::new(&setup.env, &setup.token_x).balance(&setup.router.address), 0);
+    assert_eq!(TokenClient::new(&setup.env, &setup.token_y).balance(&setup.router.address), 0);
+}
+
+/// a token that keeps 1% of every transfer
+mod fee_token {
+    use soroban_sdk::{contract, contractimpl, Address, Env};
+
+    #[contract]
+    pub struct FeeTokenContract;
+
+    #[contractimpl]
+    impl FeeTokenContract {
+        pub fn mint(env: Env, to: Address, amount: i128) {
+            let balance = Self::balance(env.clone(), to.clone());
+            env.storage().persistent().set(&to, &(balance + amount));
+        }
+
+        pub fn balance(env: Env, id: Address) -> i128 {
+            env.storage().persistent().get(&id).unwrap_or(0)
+        }
+
+        pub fn transfer(env: Env, from: Address, to: Address, amount: i128) {
+            from.require_auth();
+            let from_balance = Self::balance(env.clone(), from.clone());
+            assert!(from_balance >= amount);
+            env.storage().persistent().set(&from, &(from_balance - amount));
+            Self::mint(env, to, amount - amount / 100);
+        }
+
+        pub fn decimals() -> u32 {
+            7
+        }
+    }
+}
+
+#[test]
+fn fee_on_transfer_input() {
+    let setup = setup();
+    let token_z = setup.env.register(fee_token::FeeTokenContract, ());
+    fee_token::FeeTokenContractClient::new(&setup.env, &token_z).mint(&setup.user, &1_000_000_000_0);
+
+    // deposits of the fee token don't arrive in full, so the pools only have y above the active bin
+    let pools = vec![&setup.env,
+        create_pool(&setup, &token_z, &setup.token_y, 10, -1, 0).address,
+        create_pool(&setup, &token_z, &setup.token_y, 25, -1, 0).address,
+    ];
+    for pool in pools.iter() {
+        let mut args = vec![&setup.env];
+        for bin_id in 1..=5 {
+            args.push_back(DepositArgs { is_remove: false, bin_id_or_offset: bin_id, amount: 10_000_000_0 });
+        }
+        AmmClient::new(&setup.env, &pool).modify_liquidity(&setup.user, &0, &args, &false);
+    }
+
+    // the router receives 99% of the input and splits that 3 to 1, and the pools receive 99% of their part
+    let amounts = vec![&setup.env, 3_000_000_0, 1_000_000_0];
+    let quote_a = AmmClient::new(&setup.env, &pools.get_unchecked(0)).quote_exact_amount_in(&2_940_300_0, &token_z);
+    let quote_b = AmmClient::new(&setup.env, &pools.get_unchecked(1)).quote_exact_amount_in(&980_100_0, &token_z);
+    let amount_out = setup.router.swap_split(&setup.user, &setup.user, &pools, &amounts, &token_z, &setup.token_y, &0);
+    assert_eq!(amount_out, quote_a + quote_b);
+    assert_eq!(fee_token::FeeTokenContractClient::new(&setup.env, &token_z).balance(&setup.router.address), 0);
+}
+
+/// an account that does not exist on the ledger
+fn account(env: &Env, byte: u8) -> Address {
+    let key = PublicKey::PublicKeyTypeEd25519(Uint256([byte; 32]));
+    Address::try_from_val(env, &ScAddress::Account(AccountId(key))).unwrap()
+}
+
+#[test]
+fn transfer_errors() {
+    let setup = setup();
+    let pool_a = create_pool(&setup, &setup.token_x, &setup.token_y, 10, 5, 10_000_000_0);
+    let pools = vec![&setup.env, pool_a.address.clone()];
+    let amounts = vec![&setup.env, 1_000_000_0];
+    let swap = |from: &Address, to: &Address| {
+        setup.router.try_swap_split(from, to, &pools, &amounts, &setup.token_x, &setup.token_y, &0).unwrap_err().unwrap()
+    };
+
+    assert_eq!(swap(&Address::generate(&setup.env), &setup.user), error::Error::InsufficientBalance.into());
+    assert_eq!(swap(&account(&setup.env, 1), &setup.user), error::Error::SenderTrustlineMissing.into());
+    assert_eq!(swap(&setup.user, &account(&setup.env, 2)), error::Error::RecipientTrustlineMissing.into());
+
+    StellarAssetClient::new(&setup.env, &setup.token_y).set_authorized(&setup.user, &false);
+    assert_eq!(swap(&setup.user, &setup.user), error::Error::RecipientDeauthorized.into());
+}
+
+#[test]
+fn native_token() {
+    let setup = setup();
+    let native = setup.env.deployer().with_stellar_asset(Bytes::from_array(&setup.env, &[0, 0, 0, 0])).deploy();
+    assert_eq!(setup.router.native_token(), native);
+    assert_eq!(TokenClient::new(&setup.env, &native).symbol(), String::from_str(&setup.env, "native"));
+}
+
+/// the keys and balances of the stellar asset contract, to give contracts native XLM, which can't be minted
+#[contracttype]
+enum SacKey {
+    Balance(Address),
+}
+
+#[contracttype]
+struct BalanceValue {
+    amount: i128,
+    authorized: bool,
+    clawback: bool,
+}
+
+#[test]
+fn swap_native_xlm() {
+    let setup = setup();
+    let native = setup.env.deployer().with_stellar_asset(Bytes::from_array(&setup.env, &[0, 0, 0, 0])).deploy();
+    setup.env.as_contract(&native, || {
+        let balance = BalanceValue { amount: 1_000_000_000_0, authorized: true, clawback: false };
+        setup.env.storage().persistent().set(&SacKey::Balance(setup.user.clone()), &balance);
+    });
+    let xlm = TokenClient::new(&setup.env, &native);
+    let token_y = TokenClient::new(&setup.env, &setup.token_y);
+
+    let pool = create_pool(&setup, &native, &setup.token_y, 10, 5, 10_000_000_0);
+    let pools = vec![&setup.env, pool.address.clone()];
+    let amounts = vec![&setup.env, 1_000_000_0];
+
+    let quote = pool.quote_exact_amount_in(&1_000_000_0, &native);
+    let xlm_before = xlm.balance(&setup.user);
+    let y_before = token_y.balance(&setup.user);
+    assert_eq!(setup.router.swap_xlm_in(&setup.user, &setup.user, &pools, &amounts, &setup.token_y, &quote), quote);
+    assert_eq!(xlm.balance(&setup.user), xlm_before - 1_000_000_0);
+    assert_eq!(token_y.balance(&setup.user), y_before + quote);
+
+    let quote = pool.quote_exact_amount_in(&1_000_000_0, &setup.token_y);
+    assert_eq!(setup.router.swap_xlm_out(&setup.user, &setup.user, &pools, &amounts, &setup.token_y, &quote), quote);
+    assert_eq!(xlm.balance(&setup.user), xlm_before - 1_000_000_0 + quote);
+
+    // XLM can't be sent to an account that doesn't exist
+    let missing = setup.router.try_swap_xlm_out(&setup.user, &account(&setup.env, 3), &pools, &amounts, &setup.token_y, &0);
+    assert_eq!(missing.unwrap_err().unwrap(), error::Error::RecipientAccountMissing.into());
+}
+
+#[test]
+fn best_split_beats_single_pools() {
+    let setup = setup();
+    let pool_a = create_pool(&setup, &setup.token_x, &setup.token_y, 10, 10, 10_000_000_0);
+    let pool_b = create_pool(&setup, &setup.token_x, &setup.token_y, 25, 10, 10_000_000_0);
+    // no liquidity, can't take any part
+    let pool_c = create_pool(&setup, &setup.token_x, &setup.token_y, 10, -1, 0);
+    let pools = vec![&setup.env, pool_a.address.clone(), pool_b.address.clone(), pool_c.address.clone()];
+
+    let amount_in = 80_000_000_0;
+    let (amounts, quoted) = setup.router.quote_split(&pools, &amount_in, &setup.token_y, &20);
+    assert_eq!(amounts.iter().sum::<i128>(), amount_in);
+    assert_eq!(amounts.get(2), Some(0));
+    assert!(amounts.get(0).unwrap() > 0 && amounts.get(1).unwrap() > 0);
+
+    assert!(quoted > pool_a.quote_exact_amount_in(&amount_in, &setup.token_y));
+    assert!(quoted > pool_b.quote_exact_amount_in(&amount_in, &setup.token_y));
+    assert_eq!(
+        quoted,
+        pool_a.quote_exact_amount_in(&amounts.get(0).unwrap(), &setup.token_y)
+            + pool_b.quote_exact_amount_in(&amounts.get(1).unwrap(), &setup.token_y)
+    );
+
+    assert!(setup.router.try_swap_best(&setup.user, &setup.user, &pools, &amount_in, &setup.token_y, &setup.token_x, &(quoted + 1), &20).is_err());
+    let amount_out = setup.router.swap_best(&setup.user, &setup.user, &pools, &amount_in, &setup.token_y, &setup.token_x, &quoted, &20);
+    assert_eq!(amount_out, quoted);
+    // y in moves both pools down
+    assert!(pool_a.get_config().active_bin < 0 && pool_b.get_config().active_bin < 0);
+}
+
+#[test]
+fn split_needs_liquidity_and_valid_pools() {
+    let setup = setup();
+    let pool_a = create_pool(&setup, &setup.token_x, &setup.token_y, 10, 2, 10_000_000_0);
+    let pools = vec![&setup.env, pool_a.address.clone()];
+
+    // more than the pool has
+    assert!(setup.router.try_quote_split(&pools, &100_000_000_0, &setup.token_x, &5).is_err());
+    // an amount smaller than the steps
+    let (amounts, _) = setup.router.quote_split(&pools, &3, &setup.token_x, &5);
+    assert_eq!(amounts, vec![&setup.env, 3]);
+
+    assert!(setup.router.try_quote_split(&pools, &1_000_000_0, &setup.token_x, &0).is_err());
+    assert!(setup.router.try_quote_split(&pools, &1_000_000_0, &setup.token_x, &(MAX_STEPS + 1)).is_err());
+    assert!(setup.router.try_quote_split(&vec![&setup.env], &1_000_000_0, &setup.token_x, &5).is_err());
+    let duplicates = vec![&setup.env, pool_a.address.clone(), pool_a.address.clone()];
+    assert!(setup.router.try_quote_split(&duplicates, &1_000_000_0, &setup.token_x, &5).is_err());
+    let amounts = vec![&setup.env, 1_000_000_0, 1_000_000_0];
+    assert!(setup.router.try_swap_split(&setup.user, &setup.user, &duplicates, &amounts, &setup.token_x, &setup.token_y, &0).is_err());
+}
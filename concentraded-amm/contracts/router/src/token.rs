@@ -0,0 +1,64 @@
+use soroban_sdk::auth::{ContractContext, InvokerContractAuthEntry, SubContractInvocation};
+use soroban_sdk::token::TokenClient;
+use soroban_sdk::{panic_with_error, vec, Address, Bytes, Env, IntoVal, Symbol};
+use crate::error::Error;
+
+// the error codes of the stellar asset contract
+const ACCOUNT_MISSING: u32 = 6;
+const BALANCE: u32 = 10;
+const BALANCE_DEAUTHORIZED: u32 = 11;
+const TRUSTLINE_MISSING: u32 = 13;
+
+/// The address of the stellar asset contract of native XLM on this network. Pools with XLM use this address as the token.
+pub fn native_token(env: &Env) -> Address {
+    // `Asset::Native` as xdr
+    env.deployer().with_stellar_asset(Bytes::from_array(env, &[0, 0, 0, 0])).deployed_address()
+}
+
+/// Transfers `amount` of `token`, and returns the error code of the token if it failed.
+fn try_transfer(env: &Env, token: &Address, from: &Address, to: &Address, amount: i128) -> Result<(), Option<u32>> {
+    match TokenClient::new(env, token).try_transfer(from, to, &amount) {
+        Ok(Ok(())) => Ok(()),
+        Err(Ok(error)) if error.is_type(soroban_sdk::xdr::ScErrorType::Contract) => Err(Some(error.get_code())),
+        _ => Err(None),
+    }
+}
+
+/// Transfers the input of a swap from `from` to the router.
+pub fn transfer_from_sender(env: &Env, token: &Address, from: &Address, amount: i128) {
+    let error = match try_transfer(env, token, from, &env.current_contract_address(), amount) {
+        Ok(()) => return,
+        Err(Some(BALANCE)) => Error::InsufficientBalance,
+        Err(Some(TRUSTLINE_MISSING)) => Error::SenderTrustlineMissing,
+        Err(Some(BALANCE_DEAUTHORIZED)) => Error::SenderDeauthorized,
+        Err(_) => Error::TransferFailed,
+    };
+    panic_with_error!(env, error);
+}
+
+/// Transfers the output of a swap from the router to `to`.
+pub fn transfer_to_recipient(env: &Env, token: &Address, to: &Address, amount: i128) {
+    let error = match try_transfer(env, token, &env.current_contract_address(), to, amount) {
+        Ok(()) => return,
+        Err(Some(TRUSTLINE_MISSING)) => Error::RecipientTrustlineMissing,
+        Err(Some(ACCOUNT_MISSING)) => Error::RecipientAccountMissing,
+        Err(Some(BALANCE_DEAUTHORIZED)) => Error::RecipientDeauthorized,
+        Err(_) => Error::TransferFailed,
+    };
+    panic_with_error!(env, error);
+}
+
+/// Authorizes the transfer of `amount` of `token` from the router to `pool`, that the pool makes in the call to it that follows.
+pub fn authorize_transfer(env: &Env, token: &Address, pool: &Address, amount: i128) {
+    env.authorize_as_current_contract(vec![
+        env,
+        InvokerContractAuthEntry::Contract(SubContractInvocation {
+            context: ContractContext {
+                contract: token.clone(),
+                fn_name: Symbol::new(env, "transfer"),
+                args: (env.current_contract_address(), pool.clone(), amount).into_val(env),
+            },
+            sub_invocations: vec![env],
+        }),
+    ]);
+}
@@ -0,0 +1,342 @@
+#![no_std]
+// contract entrypoints take their arguments flat, and the generated client repeats them.
+#![allow(clippy::too_many_arguments)]
+
+use crate::pool::{PoolClient, Rewards, POSITION_ID};
+use amm::constants::MAX_BINS_IN_RANGE;
+use amm::storage::{DepositArgs, LiquidityShape};
+use crate::storage::{extend_instance_ttl, get_config, get_total_supply, store_config, VaultConfig};
+use crate::token::{burn, mint};
+use amm_math::shares::{active_bin_deposit, amounts_to_remove, Distribution};
+use soroban_fixed_point_math::SorobanFixedPoint;
+use soroban_sdk::auth::{ContractContext, InvokerContractAuthEntry, SubContractInvocation};
+use soroban_sdk::token::TokenClient;
+use soroban_sdk::{contract, contractimpl, vec, Address, Env, IntoVal, String, Symbol};
+
+/// The most bins on each side of the active bin that the vault spreads its liquidity over.
+pub const MAX_WIDTH: u32 = 50;
+/// The shares of the first deposit that the vault keeps, so the value of a share can't be inflated from almost no shares.
+pub const MIN_SHARES: i128 = 1000;
+
+#[contract]
+pub struct Vault;
+
+/// returns the amounts that the vault would get for all the shares of its position: (x_token_amount, y_token_amount)
+fn position_amounts(env: &Env, pool: &PoolClient) -> (i128, i128) {
+    let Some(position) = pool.get_position(&env.current_contract_address(), &POSITION_ID) else { return (0, 0) };
+    let Some(last_bin) = position.bin_shares.last() else { return (0, 0) };
+
+    let mut amount_x = 0;
+    let mut amount_y = 0;
+    let mut bins = vec![env];
+    let mut first_bin = 0;
+
+    for bin_shares in position.bin_shares.iter() {
+        // the bins are read in ranges, that start at the first bin of the position that was not read yet
+        if bins.is_empty() || bin_shares.bin_id >= first_bin + bins.len() as i32 {
+            first_bin = bin_shares.bin_id;
+            bins = pool.get_bins_range(&first_bin, &(first_bin + MAX_BINS_IN_RANGE - 1).min(last_bin.bin_id));
+        }
+
+        let bin = bins.get_unchecked((bin_shares.bin_id - first_bin) as u32);
+        let (x, y) = amounts_to_remove(bin_shares.shares, bin.shares, bin.reserve_x, bin.reserve_y);
+        amount_x += x;
+        amount_y += y;
+    }
+
+    (amount_x, amount_y)
+}
+
+/// returns the tokens of the vault that are not in its position: (x_token_amount, y_token_amount)
+fn idle_amounts(env: &Env, config: &VaultConfig) -> (i128, i128) {
+    let vault = env.current_contract_address();
+    (
+        TokenClient::new(env, &config.token_x).balance(&vault),
+        TokenClient::new(env, &config.token_y).balance(&vault),
+    )
+}
+
+/// returns the tokens of the vault, in its position and idle: (x_token_amount, y_token_amount)
+fn total_amounts(env: &Env, config: &VaultConfig) -> (i128, i128) {
+    let (position_x, position_y) = position_amounts(env, &PoolClient::new(env, &config.pool));
+    let (idle_x, idle_y) = idle_amounts(env, config);
+    (position_x + idle_x, position_y + idle_y)
+}
+
+/// Authorizes the transfers of the tokens from the vault to `pool`, that the pool makes in the call to it that follows.
+fn authorize_transfers(env: &Env, pool: &Address, transfers: &[(&Address, i128)]) {
+    let mut entries = vec![env];
+
+    for &(token, amount) in transfers {
+        if amount > 0 {
+            entries.push_back(InvokerContractAuthEntry::Contract(SubContractInvocation {
+                context: ContractContext {
+                    contract: token.clone(),
+                    fn_name: Symbol::new(env, "transfer"),
+                    args: (env.current_contract_address(), pool.clone(), amount).into_val(env),
+                },
+                sub_invocations: vec![env],
+            }));
+        }
+    }
+
+    env.authorize_as_current_contract(entries);
+}
+
+#[contractimpl]
+impl Vault {
+    /// Creates a vault for `pool`. Its liquidity is spread over `width` bins on each side of the active bin with `shape`.
+    ///
+    /// `name` and `symbol` are the name and symbol of the vault shares.
+    pub fn __constructor(env: Env, pool: Address, strategist: Address, width: u32, shape: LiquidityShape, name: String, symbol: String) {
+        assert!(width <= MAX_WIDTH, "Invalid width");
+
+        let pool_config = PoolClient::new(&env, &pool).get_config();
+        store_config(&env, &VaultConfig {
+            pool,
+            token_x: pool_config.token_x,
+            token_y: pool_config.token_y,
+            strategist,
+            width,
+            shape,
+            name,
+            symbol,
+        });
+    }
+
+    /// Deposits `amount_x` and `amount_y` of `from` for vault shares.
+    ///
+    /// A deposit gets the part of the vault that it adds, so it has to be in the ratio of the tokens of the vault, in the position and idle.
+    /// Only the amounts in that ratio are taken, rounded up. The first deposit sets the ratio and gets the sum of the amounts as shares,
+    /// minus `MIN_SHARES` that the vault keeps. The sum only sets the scale of the shares, the price of the pool is never used,
+    /// so a price that was moved just before can't change the value of a share.
+    ///
+    /// The deposit stays idle in the vault until the strategist calls `compound`.
+    ///
+    /// returns the shares minted and the amounts taken: (shares, x_token_amount, y_token_amount)
+    pub fn deposit(env: Env, from: Address, amount_x: i128, amount_y: i128, min_shares: i128) -> (i128, i128, i128) {
+        from.require_auth();
+        assert!(amount_x >= 0 && amount_y >= 0);
+
+        let config = get_config(&env);
+        let vault = env.current_contract_address();
+        let supply = get_total_supply(&env);
+
+        let (shares, amount_x, amount_y) = if supply == 0 {
+            mint(&env, &vault, MIN_SHARES);
+            (amount_x + amount_y - MIN_SHARES, amount_x, amount_y)
+        } else {
+            let (total_x, total_y) = total_amounts(&env, &config);
+            assert!(total_x > 0 || total_y > 0, "Vault is empty");
+
+            let shares_x = if total_x > 0 { amount_x.fixed_mul_floor(&env, &supply, &total_x) } else { i128::MAX };
+            let shares_y = if total_y > 0 { amount_y.fixed_mul_floor(&env, &supply, &total_y) } else { i128::MAX };
+            let shares = shares_x.min(shares_y);
+
+            (shares, shares.fixed_mul_ceil(&env, &total_x, &supply), shares.fixed_mul_ceil(&env, &total_y, &supply))
+        };
+        assert!(shares > 0 && shares >= min_shares, "Insufficient shares");
+
+        for (token, amount) in [(&config.token_x, amount_x), (&config.token_y, amount_y)] {
+            if amount > 0 {
+                let token = TokenClient::new(&env, token);
+                let balance_before = token.balance(&vault);
+                token.transfer(&from, &vault, &amount);
+                assert!(token.balance(&vault) - balance_before == amount, "Transfer amount mismatch");
+            }
+        }
+
+        mint(&env, &from, shares);
+        extend_instance_ttl(&env);
+
+        (shares, amount_x, amount_y)
+    }
+
+    /// Burns `shares` of `from` for their part of the vault: the part of every bin of the position and of the idle tokens, rounded down.
+    ///
+    /// returns the amounts sent to `from`: (x_token_amount, y_token_amount)
+    pub fn withdraw(env: Env, from: Address, shares: i128, min_x: i128, min_y: i128) -> (i128, i128) {
+        from.require_auth();
+        assert!(shares > 0);
+
+        let config = get_config(&env);
+        let vault = env.current_contract_address();
+        let pool = PoolClient::new(&env, &config.pool);
+        let supply = get_total_supply(&env);
+        let (idle_x, idle_y) = idle_amounts(&env, &config);
+
+        burn(&env, &from, shares);
+
+        let mut args = vec![&env];
+        if let Some(position) = pool.get_position(&vault, &POSITION_ID) {
+            for bin_shares in position.bin_shares.iter() {
+                let amount = bin_shares.shares.fixed_mul_floor(&env, &shares, &supply);
+                if amount > 0 {
+                    args.push_back(DepositArgs { is_remove: true, bin_id_or_offset: bin_shares.bin_id, amount });
+                }
+            }
+        }
+
+        let (mut amount_x, mut amount_y) = if args.is_empty() {
+            (0, 0)
+        } else {
+            // the pool sends the withdrawn amounts to `from`
+            let (x_delta, y_delta) = pool.modify_liquidity_to(&vault, &from, &POSITION_ID, &args, &false);
+            (-x_delta, -y_delta)
+        };
+
+        for (token, idle, amount) in [(&config.token_x, idle_x, &mut amount_x), (&config.token_y, idle_y, &mut amount_y)] {
+            let idle_part = idle.fixed_mul_floor(&env, &shares, &supply);
+            if idle_part > 0 {
+                TokenClient::new(&env, token).transfer(&vault, &from, &idle_part);
+                *amount += idle_part;
+            }
+        }
+
+        assert!(amount_x >= min_x, "Insufficient x amount");
+        assert!(amount_y >= min_y, "Insufficient y amount");
+        extend_instance_ttl(&env);
+
+        (amount_x, amount_y)
+    }
+
+    /// Allows the strategist to deposit the idle tokens of the vault in its position, spread over `width` bins around the active bin with `shape`.
+    ///
+    /// The pool adds the swap fees to the bins, so the position earns fees on its fees without this. The idle tokens are the deposits
+    /// and what `rebalance` could not redeposit. Only the part that matches the distribution of the active bin is deposited there,
+    /// the rest stays idle.
+    ///
+    /// Only the strategist can call this, so the deposit can't be made at a price that was moved just before.
+    ///
+    /// returns the amounts deposited: (x_token_amount, y_token_amount)
+    pub fn compound(env: Env) -> (i128, i128) {
+        let config = get_config(&env);
+        config.strategist.require_auth();
+
+        let pool = PoolClient::new(&env, &config.pool);
+        let (idle_x, idle_y) = idle_amounts(&env, &config);
+        let active_bin = pool.get_active_bin_reserves();
+        let distribution = Distribution::new(config.shape.into(), config.width);
+
+        let mut args = vec![&env];
+        // the amounts that the pool will take, to authorize the transfers
+        let mut amount_x = 0;
+        let mut amount_y = 0;
+
+        for offset in distribution.offsets() {
+            let amount = distribution.amount(offset, idle_x, idle_y, active_bin.reserve_x, active_bin.reserve_y);
+            if amount == 0 {
+                continue;
+            }
+
+            let (x, y) = if offset == 0 {
+                active_bin_deposit(amount, active_bin.reserve_x, active_bin.reserve_y)
+            } else if offset < 0 {
+                (amount, 0)
+            } else {
+                (0, amount)
+            };
+            amount_x += x;
+            amount_y += y;
+            args.push_back(DepositArgs { is_remove: false, bin_id_or_offset: offset, amount });
+        }
+
+        if args.is_empty() {
+            return (0, 0);
+        }
+
+        authorize_transfers(&env, &config.pool, &[(&config.token_x, amount_x), (&config.token_y, amount_y)]);
+        let deposited = pool.modify_liquidity(&env.current_contract_address(), &POSITION_ID, &args, &true);
+        extend_instance_ttl(&env);
+
+        deposited
+    }
+
+    /// Allows the strategist to move the liquidity of the vault to the bins around the active bin, see `rebalance_position` of the pool.
+    ///
+    /// `width` and `shape` are also used by `compound` from now on. What the pool could not redeposit stays idle in the vault.
+    ///
+    /// returns a pair with the amounts redeposited: (x_token_amount, y_token_amount)
+    pub fn rebalance(env: Env, width: u32, shape: LiquidityShape, swap_imbalance: bool, min_x: i128, min_y: i128) -> (i128, i128) {
+        let mut config = get_config(&env);
+        config.strategist.require_auth();
+        assert!(width <= MAX_WIDTH, "Invalid width");
+
+        config.width = width;
+        config.shape = shape;
+        store_config(&env, &config);
+
+        let pool = PoolClient::new(&env, &config.pool);
+        if pool.get_position(&env.current_contract_address(), &POSITION_ID).is_none() {
+            return (0, 0);
+        }
+
+        pool.rebalance_position(&env.current_contract_address(), &POSITION_ID, &width, &shape, &swap_imbalance, &min_x, &min_y)
+    }
+
+    /// Allows the strategist to claim the rewards that the position of the vault earned from incentive `incentive_id` of the pool.
+    ///
+    /// `rewards` pays them out to an address, or compounds them: they are swapped for a token of the vault in another pool,
+    /// and the output stays idle until `compound` deposits it.
+    ///
+    /// returns the rewards claimed and what the swap paid out, 0 if they were paid out: (reward_amount, amount_out)
+    pub fn claim_rewards(env: Env, incentive_id: u32, rewards: Rewards) -> (i128, i128) {
+        let config = get_config(&env);
+        config.strategist.require_auth();
+
+        let vault = env.current_contract_address();
+        let pool = PoolClient::new(&env, &config.pool);
+        let token = pool.get_incentive(&incentive_id).expect("Incentive does not exist").token;
+        let amount = pool.claim_rewards(&vault, &POSITION_ID, &incentive_id);
+        if amount == 0 {
+            return (0, 0);
+        }
+
+        let amount_out = match rewards {
+            Rewards::PayOut(to) => {
+                TokenClient::new(&env, &token).transfer(&vault, &to, &amount);
+                0
+            }
+            Rewards::Compound(swap_pool, min_amount_out) => {
+                // the reward token is never a token of the vault, as the pool doesn't allow its tokens as rewards
+                let swap_config = PoolClient::new(&env, &swap_pool).get_config();
+                let pair = [swap_config.token_x, swap_config.token_y];
+                assert!(pair.contains(&token) && (pair.contains(&config.token_x) || pair.contains(&config.token_y)), "Pool of another pair");
+
+                authorize_transfers(&env, &swap_pool, &[(&token, amount)]);
+                PoolClient::new(&env, &swap_pool).swap_exact_amount_in(&vault, &amount, &min_amount_out, &token)
+            }
+        };
+        extend_instance_ttl(&env);
+
+        (amount, amount_out)
+    }
+
+    /// Allows the strategist to hand over the vault to `strategist`.
+    pub fn set_strategist(env: Env, strategist: Address) {
+        let mut config = get_config(&env);
+        config.strategist.require_auth();
+
+        config.strategist = strategist;
+        store_config(&env, &config);
+    }
+
+    /// returns the tokens of the vault, in its position and idle: (x_token_amount, y_token_amount)
+    pub fn get_total_amounts(env: Env) -> (i128, i128) {
+        total_amounts(&env, &get_config(&env))
+    }
+
+    /// returns the shares of the vault that exist
+    pub fn total_supply(env: Env) -> i128 {
+        get_total_supply(&env)
+    }
+
+    pub fn get_config(env: Env) -> VaultConfig {
+        get_config(&env)
+    }
+}
+
+pub mod pool;
+pub mod storage;
+mod token;
+mod test;
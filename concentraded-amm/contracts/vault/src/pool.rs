@@ -0,0 +1,31 @@
+use amm::storage::{BinInfo, Config, DepositArgs, Incentive, LiquidityShape, Position};
+use soroban_sdk::{contractclient, contracttype, Address, Env, Vec};
+
+/// The position id of the vault in the pool.
+pub const POSITION_ID: i32 = 0;
+
+/// Where `claim_rewards` of the vault puts the rewards.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum Rewards {
+    /// sends the rewards to the address
+    PayOut(Address),
+    /// swaps the rewards in the `amm` pool at the address, a pool of the reward token and a token of the vault, for at least the amount.
+    /// The output stays idle in the vault until `compound`.
+    Compound(Address, i128),
+}
+
+/// The functions of the `amm` pool that the vault uses.
+#[contractclient(name = "PoolClient")]
+pub trait Pool {
+    fn modify_liquidity(env: Env, from: Address, position_id: i32, args: Vec<DepositArgs>, offset_from_active: bool) -> (i128, i128);
+    fn modify_liquidity_to(env: Env, from: Address, to: Address, position_id: i32, args: Vec<DepositArgs>, offset_from_active: bool) -> (i128, i128);
+    fn rebalance_position(env: Env, owner: Address, position_id: i32, width: u32, shape: LiquidityShape, swap_imbalance: bool, min_x: i128, min_y: i128) -> (i128, i128);
+    fn get_position(env: Env, from: Address, position_id: i32) -> Option<Position>;
+    fn get_bins_range(env: Env, from_bin: i32, to_bin: i32) -> Vec<BinInfo>;
+    fn get_active_bin_reserves(env: Env) -> BinInfo;
+    fn get_config(env: Env) -> Config;
+    fn claim_rewards(env: Env, owner: Address, position_id: i32, incentive_id: u32) -> i128;
+    fn get_incentive(env: Env, id: u32) -> Option<Incentive>;
+    fn swap_exact_amount_in(env: Env, from: Address, amount_in: i128, min_amount_out: i128, in_token: Address) -> i128;
+}
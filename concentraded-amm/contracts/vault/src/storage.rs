@@ -0,0 +1,101 @@
+use amm::storage::LiquidityShape;
+use soroban_sdk::{contracttype, Address, Env, String};
+
+// a week and a month in ledgers of 5 seconds
+const TTL_THRESHOLD: u32 = 7 * 17_280;
+const TTL_EXTEND_TO: u32 = 30 * 17_280;
+
+#[derive(Clone)]
+#[contracttype]
+pub enum DataKey {
+    Config,
+    TotalSupply,
+    Balance(Address),
+    Allowance(Address, Address),
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct VaultConfig {
+    pub pool: Address,
+    pub token_x: Address,
+    pub token_y: Address,
+    /// the address that can rebalance and compound the position
+    pub strategist: Address,
+    /// the liquidity is spread over the bins in `[active_bin - width, active_bin + width]`
+    pub width: u32,
+    pub shape: LiquidityShape,
+    /// the name of the vault shares
+    pub name: String,
+    /// the symbol of the vault shares
+    pub symbol: String,
+}
+
+/// An allowance of the vault shares, see `approve`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Allowance {
+    pub amount: i128,
+    pub expiration_ledger: u32,
+}
+
+pub fn get_config(env: &Env) -> VaultConfig {
+    env.storage().instance().get(&DataKey::Config).unwrap()
+}
+
+pub fn store_config(env: &Env, config: &VaultConfig) {
+    env.storage().instance().set(&DataKey::Config, config);
+    extend_instance_ttl(env);
+}
+
+/// Extends the TTL of the instance, which also keeps the config and the contract code alive.
+pub fn extend_instance_ttl(env: &Env) {
+    env.storage().instance().extend_ttl(TTL_THRESHOLD, TTL_EXTEND_TO);
+}
+
+pub fn get_total_supply(env: &Env) -> i128 {
+    env.storage().instance().get(&DataKey::TotalSupply).unwrap_or(0)
+}
+
+pub fn store_total_supply(env: &Env, total_supply: i128) {
+    env.storage().instance().set(&DataKey::TotalSupply, &total_supply);
+}
+
+pub fn get_balance(env: &Env, id: &Address) -> i128 {
+    env.storage().persistent().get(&DataKey::Balance(id.clone())).unwrap_or(0)
+}
+
+/// Balances are removed instead of stored when they are 0.
+pub fn store_balance(env: &Env, id: &Address, balance: i128) {
+    let key = DataKey::Balance(id.clone());
+
+    if balance == 0 {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, &balance);
+        env.storage().persistent().extend_ttl(&key, TTL_THRESHOLD, TTL_EXTEND_TO);
+    }
+}
+
+/// An allowance that expired is returned as 0.
+pub fn get_allowance(env: &Env, from: &Address, spender: &Address) -> Allowance {
+    let allowance: Option<Allowance> = env.storage().temporary().get(&DataKey::Allowance(from.clone(), spender.clone()));
+
+    match allowance {
+        Some(allowance) if allowance.expiration_ledger >= env.ledger().sequence() => allowance,
+        _ => Allowance { amount: 0, expiration_ledger: 0 },
+    }
+}
+
+/// The allowance is stored as a temporary entry that lives until its expiration ledger, an allowance of 0 is removed.
+pub fn store_allowance(env: &Env, from: &Address, spender: &Address, allowance: &Allowance) {
+    let key = DataKey::Allowance(from.clone(), spender.clone());
+
+    if allowance.amount == 0 {
+        env.storage().temporary().remove(&key);
+    } else {
+        env.storage().temporary().set(&key, allowance);
+        let live_for = (allowance.expiration_ledger - env.ledger().sequence()).min(env.storage().max_ttl());
+        env.storage().temporary().extend_ttl(&key, live_for, live_for);
+    }
+}
@@ -0,0 +1,259 @@
+#![cfg(test)]
+// amounts are written as `whole_fraction` with 7 decimals, like the stellar assets.
+#![allow(clippy::inconsistent_digit_grouping)]
+extern crate std;
+use super::*;
+use amm::storage::{Config, RewardRange};
+use amm::ContractClient as AmmClient;
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::token::StellarAssetClient;
+
+struct Setup<'a> {
+    env: Env,
+    alice: Address,
+    bob: Address,
+    strategist: Address,
+    token_x: TokenClient<'a>,
+    token_y: TokenClient<'a>,
+    pool: AmmClient<'a>,
+    vault: VaultClient<'a>,
+}
+
+/// a pool with liquidity in the bins from -20 to 20, and a vault over 5 bins on each side of the active bin
+fn setup<'a>() -> Setup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let [alice, bob, strategist, lp] = [(); 4].map(|_| Address::generate(&env));
+    let [token_x, token_y] = [(); 2].map(|_| {
+        let token = env.register_stellar_asset_contract_v2(admin.clone()).address();
+        for user in [&alice, &bob, &lp] {
+            StellarAssetClient::new(&env, &token).mint(user, &1_000_000_000_0);
+        }
+        TokenClient::new(&env, &token)
+    });
+
+    let pool = create_pool(&env, &token_x.address, &token_y.address, &lp);
+
+    let vault_args = (pool.address.clone(), strategist.clone(), 5u32, LiquidityShape::Spot, String::from_str(&env, "Vault"), String::from_str(&env, "VLT"));
+    let vault = VaultClient::new(&env, &env.register(Vault, vault_args));
+
+    Setup { env, alice, bob, strategist, token_x, token_y, pool, vault }
+}
+
+/// a pool of `token_x` and `token_y` with liquidity of `lp` in the bins from -20 to 20. `lp` is also the admin of the pool.
+fn create_pool<'a>(env: &Env, token_x: &Address, token_y: &Address, lp: &Address) -> AmmClient<'a> {
+    let config = Config {
+        token_x: token_x.clone(),
+        token_y: token_y.clone(),
+        bin_step: 10,
+        active_bin: 0,
+        fee: 30,
+        flash_loan_fee: 0,
+        ttl_threshold: 100,
+        ttl_extend_to: 1000,
+        decimals_x: 0,
+        decimals_y: 0,
+        admin: lp.clone(),
+    };
+    let pool = AmmClient::new(env, &env.register(amm::Contract, (config,)));
+
+    let mut args = vec![env];
+    for bin_id in -20..=20 {
+        args.push_back(DepositArgs { is_remove: false, bin_id_or_offset: bin_id, amount: 10_000_000_0 });
+    }
+    pool.modify_liquidity(lp, &0, &args, &false);
+    pool
+}
+
+#[test]
+fn deposit_compound_and_withdraw() {
+    let setup = setup();
+    let vault = &setup.vault;
+
+    let (shares, amount_x, amount_y) = vault.deposit(&setup.alice, &100_000_0, &100_000_0, &0);
+    assert_eq!((200_000_0 - MIN_SHARES, 100_000_0, 100_000_0), (shares, amount_x, amount_y));
+    assert_eq!(200_000_0, vault.total_supply());
+    assert_eq!(MIN_SHARES, vault.balance(&vault.address));
+
+    // the deposit goes into the bins around the active bin
+    let (deposited_x, deposited_y) = vault.compound();
+    assert!(deposited_x > 99_000_0 && deposited_y > 99_000_0);
+    let position = setup.pool.get_position(&vault.address, &POSITION_ID).unwrap();
+    assert_eq!(11, position.bin_shares.len());
+    let (total_x, total_y) = vault.get_total_amounts();
+    assert!(100_000_0 - total_x <= 11 && 100_000_0 - total_y <= 11);
+
+    // only the part in the ratio of the vault is taken
+    let (bob_shares, bob_x, bob_y) = vault.deposit(&setup.bob, &50_000_0, &80_000_0, &0);
+    assert!((49_999_9..=50_000_0).contains(&bob_x));
+    assert!((49_999_9..=50_000_1).contains(&bob_y));
+    assert!((100_000_0..=100_001_0).contains(&bob_shares));
+    assert!(vault.try_deposit(&setup.bob, &1_000_0, &1_000_0, &(1_000_000_0)).is_err());
+
+    // fees of swaps back and forth go to the position
+    for _ in 0..5 {
+        setup.pool.swap_exact_amount_in(&setup.bob, &20_000_000_0, &0, &setup.token_x.address);
+        setup.pool.swap_exact_amount_in(&setup.bob, &20_000_000_0, &0, &setup.token_y.address);
+    }
+
+    let x_before = setup.token_x.balance(&setup.alice);
+    let y_before = setup.token_y.balance(&setup.alice);
+    let (alice_x, alice_y) = vault.withdraw(&setup.alice, &shares, &0, &0);
+    assert_eq!(alice_x, setup.token_x.balance(&setup.alice) - x_before);
+    assert_eq!(alice_y, setup.token_y.balance(&setup.alice) - y_before);
+    assert!(alice_x + alice_y > 200_000_0);
+    assert_eq!(0, vault.balance(&setup.alice));
+
+    assert!(vault.try_withdraw(&setup.bob, &(bob_shares + 1), &0, &0).is_err());
+    assert!(vault.try_withdraw(&setup.bob, &bob_shares, &100_000_0, &100_000_0).is_err());
+    vault.withdraw(&setup.bob, &bob_shares, &0, &0);
+
+    // what is left belongs to the shares that the vault keeps
+    assert_eq!(MIN_SHARES, vault.total_supply());
+    let (left_x, left_y) = vault.get_total_amounts();
+    assert!(left_x + left_y < 2 * MIN_SHARES);
+}
+
+#[test]
+fn rebalance_recenters_position() {
+    let setup = setup();
+    let vault = &setup.vault;
+
+    vault.deposit(&setup.alice, &100_000_0, &100_000_0, &0);
+    vault.compound();
+
+    // move the price out of the range of the vault
+    setup.pool.swap_exact_amount_in(&setup.bob, &100_000_000_0, &0, &setup.token_x.address);
+    let active_bin = setup.pool.get_config().active_bin;
+    assert!(active_bin > 5);
+
+    let (total_x, total_y) = vault.get_total_amounts();
+    let (x, y) = vault.rebalance(&2, &LiquidityShape::Curve, &false, &0, &0);
+    assert!(x <= total_x && y <= total_y);
+
+    let position = setup.pool.get_position(&vault.address, &POSITION_ID).unwrap();
+    assert!(position.bin_shares.iter().all(|bin| (bin.bin_id - active_bin).abs() <= 2));
+    assert_eq!((2, LiquidityShape::Curve), (vault.get_config().width, vault.get_config().shape));
+
+    // the value does not change, only what could not be redeposited is idle
+    let (after_x, after_y) = vault.get_total_amounts();
+    assert!(total_x - after_x <= 5 && total_y - after_y <= 5);
+
+    assert!(vault.try_rebalance(&(MAX_WIDTH + 1), &LiquidityShape::Spot, &false, &0, &0).is_err());
+}
+
+#[test]
+fn deposits_keep_the_value_of_a_share() {
+    let setup = setup();
+    let vault = &setup.vault;
+
+    // the first deposit sets the ratio, so a deposit of only y adds nothing to a vault of only x
+    vault.deposit(&setup.alice, &100_000_0, &0, &0);
+    assert!(vault.try_deposit(&setup.bob, &0, &100_000_0, &0).is_err());
+
+    // the price moves through the position, and the vault holds both tokens
+    vault.compound();
+    setup.pool.swap_exact_amount_in(&setup.bob, &30_000_000_0, &0, &setup.token_y.address);
+    let (total_x, total_y) = vault.get_total_amounts();
+    let supply = vault.total_supply();
+    assert!(total_x > 0 && total_y > 0);
+
+    // a deposit gets the part of the vault that it adds of both tokens, whatever the price of the pool is
+    let (shares, amount_x, amount_y) = vault.deposit(&setup.bob, &100_000_0, &100_000_0, &0);
+    assert!(amount_x <= 100_000_0 && amount_y <= 100_000_0);
+    assert!(amount_x == 100_000_0 || amount_y == 100_000_0);
+    assert!(shares * total_x <= amount_x * supply && shares * total_y <= amount_y * supply);
+
+    // so the amounts of a share don't go down
+    let (after_x, after_y) = vault.get_total_amounts();
+    let after_supply = vault.total_supply();
+    assert!(after_x * supply >= total_x * after_supply && after_y * supply >= total_y * after_supply);
+}
+
+#[test]
+fn claim_rewards_pays_out_or_compounds() {
+    let setup = setup();
+    let env = &setup.env;
+    let vault = &setup.vault;
+    env.ledger().with_mut(|ledger| ledger.timestamp = 1000);
+
+    vault.deposit(&setup.alice, &100_000_0, &100_000_0, &0);
+    vault.compound();
+
+    let manager = Address::generate(env);
+    let reward = TokenClient::new(env, &env.register_stellar_asset_contract_v2(manager.clone()).address());
+    StellarAssetClient::new(env, &reward.address).mint(&manager, &1_000_000_0);
+    StellarAssetClient::new(env, &reward.address).mint(&setup.bob, &1_000_000_000_0);
+    let id = setup.pool.add_incentive(&manager, &reward.address, &1_000_000_0, &1000, &2000, &RewardRange::Active(5));
+
+    env.ledger().with_mut(|ledger| ledger.timestamp = 1500);
+    let (paid, amount_out) = vault.claim_rewards(&id, &Rewards::PayOut(setup.strategist.clone()));
+    assert!(paid > 0 && amount_out == 0);
+    assert_eq!(paid, reward.balance(&setup.strategist));
+    assert_eq!((0, 0), vault.claim_rewards(&id, &Rewards::PayOut(setup.strategist.clone())));
+
+    // the rewards are swapped for x, which stays idle until it is compounded
+    env.ledger().with_mut(|ledger| ledger.timestamp = 2000);
+    let swap_pool = create_pool(env, &reward.address, &setup.token_x.address, &setup.bob);
+    let wrong_pool = Rewards::Compound(setup.pool.address.clone(), 0);
+    assert!(vault.try_claim_rewards(&id, &wrong_pool).is_err());
+
+    let idle_x = setup.token_x.balance(&vault.address);
+    let (compounded, amount_out) = vault.claim_rewards(&id, &Rewards::Compound(swap_pool.address.clone(), 1));
+    assert!(compounded > 0 && amount_out > 0);
+    assert_eq!(idle_x + amount_out, setup.token_x.balance(&vault.address));
+    assert_eq!(0, reward.balance(&vault.address));
+    assert!(vault.compound().0 > 0);
+}
+
+#[test]
+fn only_strategist_manages() {
+    let setup = setup();
+    let vault = &setup.vault;
+    vault.deposit(&setup.alice, &100_000_0, &100_000_0, &0);
+
+    setup.env.set_auths(&[]);
+    assert!(vault.try_compound().is_err());
+    assert!(vault.try_rebalance(&2, &LiquidityShape::Spot, &false, &0, &0).is_err());
+    assert!(vault.try_set_strategist(&setup.alice).is_err());
+    assert!(vault.try_claim_rewards(&0, &Rewards::PayOut(setup.alice.clone())).is_err());
+
+    setup.env.mock_all_auths();
+    vault.set_strategist(&setup.alice);
+    assert_eq!(setup.alice, vault.get_config().strategist);
+    assert_ne!(setup.strategist, vault.get_config().strategist);
+}
+
+#[test]
+fn shares_are_a_token() {
+    let setup = setup();
+    let vault = &setup.vault;
+    let (shares, _, _) = vault.deposit(&setup.alice, &100_000_0, &100_000_0, &0);
+
+    assert_eq!(7, vault.decimals());
+    assert_eq!(String::from_str(&setup.env, "Vault"), vault.name());
+    assert_eq!(String::from_str(&setup.env, "VLT"), vault.symbol());
+
+    vault.transfer(&setup.alice, &setup.bob, &1_000_0);
+    assert_eq!(shares - 1_000_0, vault.balance(&setup.alice));
+    assert_eq!(1_000_0, vault.balance(&setup.bob));
+    assert!(vault.try_transfer(&setup.bob, &setup.alice, &1_000_1).is_err());
+
+    let expiration = setup.env.ledger().sequence() + 100;
+    vault.approve(&setup.alice, &setup.strategist, &500_0, &expiration);
+    assert_eq!(500_0, vault.allowance(&setup.alice, &setup.strategist));
+    vault.transfer_from(&setup.strategist, &setup.alice, &setup.bob, &300_0);
+    assert_eq!(200_0, vault.allowance(&setup.alice, &setup.strategist));
+    assert!(vault.try_transfer_from(&setup.strategist, &setup.alice, &setup.bob, &300_0).is_err());
+    vault.burn_from(&setup.strategist, &setup.alice, &200_0);
+    assert_eq!(0, vault.allowance(&setup.alice, &setup.strategist));
+
+    // burned shares give their part to the other holders
+    let supply = vault.total_supply();
+    vault.burn(&setup.bob, &1_300_0);
+    assert_eq!(supply - 1_300_0, vault.total_supply());
+    let (amount_x, _) = vault.withdraw(&setup.alice, &vault.balance(&setup.alice), &0, &0);
+    assert!(amount_x > 100_000_0 - 1_500_0);
+}
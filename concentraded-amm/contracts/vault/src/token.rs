@@ -0,0 +1,105 @@
+use crate::storage::{extend_instance_ttl, get_allowance, get_balance, get_config, get_total_supply, store_allowance, store_balance, store_total_supply, Allowance};
+use crate::{Vault, VaultArgs, VaultClient};
+use soroban_sdk::token::TokenInterface;
+use soroban_sdk::{contractimpl, symbol_short, Address, Env, String};
+
+/// The vault shares have as many decimals as the stellar assets.
+pub const DECIMALS: u32 = 7;
+
+/// Mints `amount` vault shares to `to`.
+pub fn mint(env: &Env, to: &Address, amount: i128) {
+    store_balance(env, to, get_balance(env, to) + amount);
+    store_total_supply(env, get_total_supply(env) + amount);
+    env.events().publish((symbol_short!("mint"), to.clone()), amount);
+}
+
+/// Burns `amount` vault shares of `from`, the caller checks the authorization.
+pub fn burn(env: &Env, from: &Address, amount: i128) {
+    spend_balance(env, from, amount);
+    store_total_supply(env, get_total_supply(env) - amount);
+    env.events().publish((symbol_short!("burn"), from.clone()), amount);
+}
+
+fn spend_balance(env: &Env, from: &Address, amount: i128) {
+    assert!(amount >= 0, "Negative amount");
+    let balance = get_balance(env, from);
+    assert!(balance >= amount, "Insufficient balance");
+    store_balance(env, from, balance - amount);
+}
+
+fn spend_allowance(env: &Env, from: &Address, spender: &Address, amount: i128) {
+    let mut allowance = get_allowance(env, from, spender);
+    assert!(allowance.amount >= amount, "Insufficient allowance");
+
+    if amount > 0 {
+        allowance.amount -= amount;
+        store_allowance(env, from, spender, &allowance);
+    }
+}
+
+fn move_balance(env: &Env, from: &Address, to: &Address, amount: i128) {
+    spend_balance(env, from, amount);
+    store_balance(env, to, get_balance(env, to) + amount);
+    env.events().publish((symbol_short!("transfer"), from.clone(), to.clone()), amount);
+}
+
+/// The vault shares, a token like the stellar assets without an admin.
+#[contractimpl]
+impl TokenInterface for Vault {
+    fn allowance(env: Env, from: Address, spender: Address) -> i128 {
+        get_allowance(&env, &from, &spender).amount
+    }
+
+    fn approve(env: Env, from: Address, spender: Address, amount: i128, expiration_ledger: u32) {
+        from.require_auth();
+        assert!(amount >= 0, "Negative amount");
+        assert!(amount == 0 || expiration_ledger >= env.ledger().sequence(), "Invalid expiration ledger");
+
+        store_allowance(&env, &from, &spender, &Allowance { amount, expiration_ledger });
+        extend_instance_ttl(&env);
+        env.events().publish((symbol_short!("approve"), from, spender), (amount, expiration_ledger));
+    }
+
+    fn balance(env: Env, id: Address) -> i128 {
+        get_balance(&env, &id)
+    }
+
+    fn transfer(env: Env, from: Address, to: Address, amount: i128) {
+        from.require_auth();
+        move_balance(&env, &from, &to, amount);
+        extend_instance_ttl(&env);
+    }
+
+    fn transfer_from(env: Env, spender: Address, from: Address, to: Address, amount: i128) {
+        spender.require_auth();
+        spend_allowance(&env, &from, &spender, amount);
+        move_balance(&env, &from, &to, amount);
+        extend_instance_ttl(&env);
+    }
+
+    /// Burning shares without withdrawing gives their part of the vault to the other holders.
+    fn burn(env: Env, from: Address, amount: i128) {
+        from.require_auth();
+        burn(&env, &from, amount);
+        extend_instance_ttl(&env);
+    }
+
+    fn burn_from(env: Env, spender: Address, from: Address, amount: i128) {
+        spender.require_auth();
+        spend_allowance(&env, &from, &spender, amount);
+        burn(&env, &from, amount);
+        extend_instance_ttl(&env);
+    }
+
+    fn decimals(_env: Env) -> u32 {
+        DECIMALS
+    }
+
+    fn name(env: Env) -> String {
+        get_config(&env).name
+    }
+
+    fn symbol(env: Env) -> String {
+        get_config(&env).symbol
+    }
+}
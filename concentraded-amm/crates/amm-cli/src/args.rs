@@ -0,0 +1,56 @@
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+/// The options of a command: `--name value` pairs and `--name` switches. The last value of a repeated option is used.
+///
+/// Options are taken as the command reads them, `finish` fails for the options that were not used.
+#[derive(Debug, Default)]
+pub struct Flags {
+    values: BTreeMap<String, Vec<String>>,
+}
+
+impl Flags {
+    pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Self, String> {
+        let mut flags = Flags::default();
+        let mut args = args.into_iter().peekable();
+
+        while let Some(arg) = args.next() {
+            let name = arg
+                .strip_prefix("--")
+                .ok_or_else(|| format!("unexpected argument {arg}"))?
+                .to_string();
+            // a switch is followed by another option or by nothing
+            let value = match args.peek() {
+                Some(next) if !next.starts_with("--") => args.next().unwrap(),
+                _ => String::new(),
+            };
+
+            flags.values.entry(name).or_default().push(value);
+        }
+
+        Ok(flags)
+    }
+
+    pub fn switch(&mut self, name: &str) -> bool {
+        self.values.remove(name).is_some()
+    }
+
+    pub fn get<T: FromStr>(&mut self, name: &str) -> Result<Option<T>, String> {
+        match self.values.remove(name).and_then(|values| values.into_iter().last()) {
+            Some(value) => value.parse().map(Some).map_err(|_| format!("invalid value for --{name}: {value}")),
+            None => Ok(None),
+        }
+    }
+
+    pub fn require<T: FromStr>(&mut self, name: &str) -> Result<T, String> {
+        self.get(name)?.ok_or_else(|| format!("missing --{name}"))
+    }
+
+    /// Fails if there are options that the command does not know.
+    pub fn finish(self) -> Result<(), String> {
+        match self.values.keys().next() {
+            Some(name) => Err(format!("unknown option --{name}")),
+            None => Ok(()),
+        }
+    }
+}
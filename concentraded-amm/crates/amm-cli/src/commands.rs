@@ -0,0 +1,727 @@
+use std::path::PathBuf;
+use amm::storage::{Config, DepositArgs, LiquidityShape, Position};
+use amm::Contract;
+use amm_math::price::{get_max_bin_id, id_from_price, price_from_bin, price_from_human, price_to_human};
+use amm_math::shares::{amounts_to_remove, Distribution};
+use serde_json::json;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::xdr::{Hash, ScAddress, ScVal, ToXdr};
+use soroban_sdk::{vec, Address, BytesN, Env, TryFromVal, Vec};
+use crate::args::Flags;
+use crate::ledger::{address_string, has_contract, parse_address, Ledger};
+use crate::output::Table;
+use crate::tx::{TxBuilder, TxSource};
+use crate::units::{format_amount, format_price, parse_amount, parse_price};
+
+/// a week and a month in ledgers, like `deploy.ts`
+const TTL_THRESHOLD: u32 = 7 * 17280;
+const TTL_EXTEND_TO: u32 = 30 * 17280;
+
+/// Where a command that changes a pool goes.
+pub enum Target {
+    /// The command runs against a ledger snapshot, which is then written to `out`.
+    Snapshot { env: Env, contract: Option<Address>, out: PathBuf },
+    /// The command is printed as an unsigned transaction.
+    Tx { builder: TxBuilder, contract: Option<Address> },
+}
+
+impl Target {
+    /// `--snapshot` runs against a snapshot, otherwise `--source` and `--sequence` build a transaction.
+    pub fn from_flags(flags: &mut Flags) -> Result<Self, String> {
+        let contract: Option<String> = flags.get("contract")?;
+
+        if let Some(path) = flags.get::<PathBuf>("snapshot")? {
+            let env = Ledger::read_env(&path)?;
+            let contract = contract.map(|contract| parse_address(&env, &contract)).transpose()?;
+            let out = flags.get("out")?.unwrap_or(path);
+
+            Ok(Target::Snapshot { env, contract, out })
+        } else {
+            let env = Env::default();
+            let source = TxSource {
+                account: parse_address(&env, &flags.require::<String>("source")?)?,
+                sequence: flags.require("sequence")?,
+                fee: flags.get("fee")?.unwrap_or(100),
+            };
+            let contract = contract.map(|contract| parse_address(&env, &contract)).transpose()?;
+
+            Ok(Target::Tx { builder: TxBuilder { env, source }, contract })
+        }
+    }
+
+    fn env(&self) -> &Env {
+        match self {
+            Target::Snapshot { env, .. } => env,
+            Target::Tx { builder, .. } => &builder.env,
+        }
+    }
+
+    /// returns the pool in the snapshot
+    fn ledger(&self) -> Result<Ledger, String> {
+        match self {
+            Target::Snapshot { env, contract: Some(contract), .. } => Ok(Ledger::new(env.clone(), contract.clone())),
+            Target::Snapshot { .. } => Err("missing --contract".into()),
+            Target::Tx { .. } => Err("this only works with --snapshot".into()),
+        }
+    }
+
+    /// Runs `call` against the snapshot and saves it, or prints the transaction that invokes `function` with `args`.
+    fn invoke<T: std::fmt::Debug>(
+        &self,
+        function: &str,
+        args: impl soroban_sdk::IntoVal<Env, Vec<soroban_sdk::Val>>,
+        call: impl FnOnce(Env) -> T,
+    ) -> Result<(), String> {
+        match self {
+            Target::Snapshot { out, .. } => {
+                let ledger = self.ledger()?;
+                let result = ledger.call(call)?;
+                ledger.save(out)?;
+                println!("{function}: {result:?}");
+            }
+            Target::Tx { builder, contract } => {
+                let contract = contract.as_ref().ok_or("missing --contract")?;
+                println!("{}", builder.invoke(contract, function, args)?);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// What a command needs to know about the pool: from the snapshot, or from the options when a transaction is built offline.
+struct PoolInfo {
+    token_x: Option<Address>,
+    token_y: Option<Address>,
+    bin_step: Option<u32>,
+    active_bin: Option<i32>,
+    decimals_x: u32,
+    decimals_y: u32,
+    active_reserve_x: i128,
+    active_reserve_y: i128,
+}
+
+impl PoolInfo {
+    fn new(target: &Target, flags: &mut Flags) -> Result<Self, String> {
+        if let Ok(ledger) = target.ledger() {
+            let config = ledger.config()?;
+            let active = ledger.call(Contract::get_active_bin_reserves)?;
+            return Ok(PoolInfo {
+                token_x: Some(config.token_x),
+                token_y: Some(config.token_y),
+                bin_step: Some(config.bin_step),
+                active_bin: Some(config.active_bin),
+                decimals_x: config.decimals_x,
+                decimals_y: config.decimals_y,
+                active_reserve_x: active.reserve_x,
+                active_reserve_y: active.reserve_y,
+            });
+        }
+
+        let env = target.env();
+        Ok(PoolInfo {
+            token_x: flags.get::<String>("token-x")?.map(|token| parse_address(env, &token)).transpose()?,
+            token_y: flags.get::<String>("token-y")?.map(|token| parse_address(env, &token)).transpose()?,
+            bin_step: flags.get("bin-step")?,
+            active_bin: flags.get("active-bin")?,
+            // stellar assets have 7 decimals
+            decimals_x: flags.get("decimals-x")?.unwrap_or(7),
+            decimals_y: flags.get("decimals-y")?.unwrap_or(7),
+            // without a snapshot the active bin is treated as empty, the contract splits the deposit by its actual reserves
+            active_reserve_x: 0,
+            active_reserve_y: 0,
+        })
+    }
+
+    /// `x`, `y` or the address of one of the tokens. returns (token, is_x)
+    fn token(&self, env: &Env, token: &str) -> Result<(Address, bool), String> {
+        let missing = |name: &str| format!("the token {name} of the pool is not known, use --token-{name} or the address");
+
+        match token {
+            "x" => Ok((self.token_x.clone().ok_or_else(|| missing("x"))?, true)),
+            "y" => Ok((self.token_y.clone().ok_or_else(|| missing("y"))?, false)),
+            _ => {
+                let address = parse_address(env, token)?;
+                match (&self.token_x, &self.token_y) {
+                    (Some(x), _) if *x == address => Ok((address, true)),
+                    (_, Some(y)) if *y == address => Ok((address, false)),
+                    _ => Err(format!("{token} is not a token of the pool")),
+                }
+            }
+        }
+    }
+
+    fn bin_from_price(&self, price: &str) -> Result<i32, String> {
+        let bin_step = self.bin_step.ok_or("the bin step of the pool is not known, use --bin-step")?;
+        bin_from_human_price(bin_step, self.decimals_x, self.decimals_y, price)
+    }
+}
+
+/// returns the bin of a human price, the amount of whole x tokens that 1 whole y token is worth. Rounds down like the contract.
+pub fn bin_from_human_price(bin_step: u32, decimals_x: u32, decimals_y: u32, price: &str) -> Result<i32, String> {
+    let max_bin_id = get_max_bin_id(bin_step);
+    let raw_price = price_from_human(parse_price(price)?, decimals_x, decimals_y).ok_or_else(|| format!("price {price} is too large"))?;
+
+    if raw_price < price_from_bin(bin_step, -max_bin_id) || raw_price >= price_from_bin(bin_step, max_bin_id + 1) {
+        return Err(format!("price {price} is outside of the supported bins"));
+    }
+
+    Ok(id_from_price(bin_step, raw_price))
+}
+
+/// returns the human price of bin `bin_id` to show, or "too large" if it does not fit in 256 bits
+pub fn human_price(bin_step: u32, decimals_x: u32, decimals_y: u32, bin_id: i32) -> String {
+    price_to_human(price_from_bin(bin_step, bin_id), decimals_x, decimals_y).map_or_else(|| "too large".to_string(), |price| format_price(price, 8))
+}
+
+pub fn parse_shape(shape: &str) -> Result<LiquidityShape, String> {
+    match shape {
+        "spot" => Ok(LiquidityShape::Spot),
+        "curve" => Ok(LiquidityShape::Curve),
+        "bid-ask" => Ok(LiquidityShape::BidAsk),
+        _ => Err(format!("unknown shape {shape}, expected spot, curve or bid-ask")),
+    }
+}
+
+/// Spreads `amount_x` and `amount_y` over a range of bins, like `shape_liquidity` of the contract.
+///
+/// With `width` the args are offsets from the active bin, so the range moves with the price until the transaction lands.
+/// With `bins` they are the ids of the bins in `[lower, upper]`.
+///
+/// returns (args, offset_from_active)
+pub fn range_args(
+    env: &Env,
+    shape: LiquidityShape,
+    width: Option<u32>,
+    bins: Option<(i32, i32)>,
+    active_bin: Option<i32>,
+    amounts: (i128, i128),
+    active_reserves: (i128, i128),
+) -> Result<(Vec<DepositArgs>, bool), String> {
+    let (distribution, bin_offset) = match (width, bins) {
+        (Some(width), None) => (Distribution::new(shape.into(), width), 0),
+        (None, Some((lower, upper))) => {
+            let active_bin = active_bin.ok_or("the active bin of the pool is not known, use --active-bin")?;
+            if lower > upper {
+                return Err("the lowest bin of the range is above the highest".into());
+            }
+            (Distribution::range(shape.into(), lower - active_bin, upper - active_bin), active_bin)
+        }
+        _ => return Err("use one of --width, --min-price and --max-price, or --min-bin and --max-bin".into()),
+    };
+
+    let mut args = vec![env];
+    for offset in distribution.offsets() {
+        let amount = distribution.amount(offset, amounts.0, amounts.1, active_reserves.0, active_reserves.1);
+        if amount > 0 {
+            args.push_back(DepositArgs { is_remove: false, bin_id_or_offset: offset + bin_offset, amount });
+        }
+    }
+
+    if args.is_empty() {
+        return Err("the amounts are too small to deposit".into());
+    }
+
+    Ok((args, bins.is_none()))
+}
+
+/// Creates a ledger with two stellar asset contracts and a pool, and funds some users.
+pub fn sandbox(flags: &mut Flags) -> Result<(), String> {
+    let out: PathBuf = flags.require("out")?;
+    let bin_step = flags.get("bin-step")?.unwrap_or(10);
+    let users = flags.get("users")?.unwrap_or(1);
+    let mint = parse_amount(&flags.get::<String>("mint")?.unwrap_or("1000000".into()), 7)?;
+    let active_bin = match flags.get::<String>("price")? {
+        // stellar assets have 7 decimals
+        Some(price) => bin_from_human_price(bin_step, 7, 7, &price)?,
+        None => flags.get("active-bin")?.unwrap_or(0),
+    };
+
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_x = env.register_stellar_asset_contract_v2(admin.clone()).address();
+    let token_y = env.register_stellar_asset_contract_v2(admin.clone()).address();
+
+    let config = Config {
+        token_x: token_x.clone(),
+        token_y: token_y.clone(),
+        bin_step,
+        active_bin,
+        fee: flags.get("fee")?.unwrap_or(30),
+        flash_loan_fee: flags.get("flash-loan-fee")?.unwrap_or(10),
+        ttl_threshold: TTL_THRESHOLD,
+        ttl_extend_to: TTL_EXTEND_TO,
+        decimals_x: 0,
+        decimals_y: 0,
+        admin,
+    };
+    let pool = env.register(Contract, (config,));
+
+    let users: std::vec::Vec<Address> = (0..users).map(|_| Address::generate(&env)).collect();
+    for user in &users {
+        StellarAssetClient::new(&env, &token_x).mint(user, &mint);
+        StellarAssetClient::new(&env, &token_y).mint(user, &mint);
+    }
+
+    Ledger::new(env, pool.clone()).save(&out)?;
+
+    let users: std::vec::Vec<String> = users.iter().map(address_string).collect();
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&json!({
+            "snapshot": out,
+            "pool": address_string(&pool),
+            "token_x": address_string(&token_x),
+            "token_y": address_string(&token_y),
+            "users": users,
+        }))
+        .unwrap()
+    );
+
+    Ok(())
+}
+
+/// Mints a stellar asset of the pool in a sandbox snapshot.
+pub fn mint(flags: &mut Flags) -> Result<(), String> {
+    let target = Target::from_flags(flags)?;
+    let ledger = target.ledger()?;
+    let pool = PoolInfo::new(&target, flags)?;
+
+    let (token, is_x) = pool.token(&ledger.env, &flags.require::<String>("token")?)?;
+    let to = parse_address(&ledger.env, &flags.require::<String>("to")?)?;
+    let amount = parse_amount(&flags.require::<String>("amount")?, if is_x { pool.decimals_x } else { pool.decimals_y })?;
+
+    StellarAssetClient::new(&ledger.env, &token).mint(&to, &amount);
+    if let Target::Snapshot { out, .. } = &target {
+        ledger.save(out)?;
+    }
+
+    println!("balance: {}", format_amount(TokenClient::new(&ledger.env, &token).balance(&to), if is_x { pool.decimals_x } else { pool.decimals_y }));
+    Ok(())
+}
+
+fn print_json(value: serde_json::Value) {
+    println!("{}", serde_json::to_string_pretty(&value).unwrap());
+}
+
+fn snapshot_ledger(flags: &mut Flags) -> Result<Ledger, String> {
+    let path: PathBuf = flags.require("snapshot")?;
+    Ledger::load(&path, &flags.require::<String>("contract")?)
+}
+
+/// Prints the config, reserves and totals of the pool.
+pub fn config(flags: &mut Flags) -> Result<(), String> {
+    let ledger = snapshot_ledger(flags)?;
+    let config = ledger.config()?;
+    let reserves = ledger.call(Contract::get_reserves)?;
+    let totals = ledger.call(Contract::get_bin_totals)?;
+    let price = human_price(config.bin_step, config.decimals_x, config.decimals_y, config.active_bin);
+
+    let value = json!({
+        "token_x": address_string(&config.token_x),
+        "token_y": address_string(&config.token_y),
+        "bin_step": config.bin_step,
+        "active_bin": config.active_bin,
+        "price": price,
+        "fee": config.fee,
+        "flash_loan_fee": config.flash_loan_fee,
+        "ttl_threshold": config.ttl_threshold,
+        "ttl_extend_to": config.ttl_extend_to,
+        "decimals_x": config.decimals_x,
+        "decimals_y": config.decimals_y,
+        "reserve_x": format_amount(reserves.reserve_x, config.decimals_x),
+        "reserve_y": format_amount(reserves.reserve_y, config.decimals_y),
+        "bin_reserve_x": format_amount(totals.total_reserve_x, config.decimals_x),
+        "bin_reserve_y": format_amount(totals.total_reserve_y, config.decimals_y),
+    });
+
+    if flags.switch("json") {
+        print_json(value);
+    } else {
+        for (key, value) in value.as_object().unwrap() {
+            println!("{key:<16}{}", value.as_str().map(str::to_string).unwrap_or(value.to_string()));
+        }
+    }
+
+    Ok(())
+}
+
+/// Prints the bins in a range, by default the 10 bins on each side of the active bin.
+pub fn bins(flags: &mut Flags) -> Result<(), String> {
+    let ledger = snapshot_ledger(flags)?;
+    let config = ledger.config()?;
+    let from = flags.get("from")?.unwrap_or(config.active_bin - 10);
+    let to = flags.get("to")?.unwrap_or(config.active_bin + 10);
+    let all = flags.switch("all");
+
+    let mut bins = std::vec::Vec::new();
+    // `get_bins_range` returns at most 300 bins
+    for chunk_start in (from..=to).step_by(300) {
+        let chunk_end = (chunk_start + 299).min(to);
+        bins.extend(ledger.call(|env| Contract::get_bins_range(env, chunk_start, chunk_end))?);
+    }
+    bins.retain(|bin| all || bin.reserve_x != 0 || bin.reserve_y != 0 || bin.shares != 0);
+
+    let mut table = Table::new(&["bin", "price", "reserve_x", "reserve_y", "shares", ""]);
+    let mut rows = std::vec::Vec::new();
+    for bin in bins.iter() {
+        let price = human_price(config.bin_step, config.decimals_x, config.decimals_y, bin.bin_id);
+        let reserve_x = format_amount(bin.reserve_x, config.decimals_x);
+        let reserve_y = format_amount(bin.reserve_y, config.decimals_y);
+        let active = if bin.bin_id == config.active_bin { "active" } else { "" };
+
+        rows.push(json!({
+            "bin": bin.bin_id,
+            "price": price,
+            "reserve_x": reserve_x,
+            "reserve_y": reserve_y,
+            "shares": bin.shares.to_string(),
+            "active": bin.bin_id == config.active_bin,
+        }));
+        table.row(vec_of([bin.bin_id.to_string(), price, reserve_x, reserve_y, bin.shares.to_string(), active.into()]));
+    }
+
+    if flags.switch("json") {
+        print_json(json!(rows));
+    } else {
+        print!("{table}");
+    }
+
+    Ok(())
+}
+
+fn vec_of<const N: usize>(cells: [String; N]) -> std::vec::Vec<String> {
+    cells.to_vec()
+}
+
+/// returns the amounts of the tokens that a position can withdraw, per bin: (bin_id, shares, amount_x, amount_y)
+fn position_amounts(ledger: &Ledger, position: &Position) -> Result<std::vec::Vec<(i32, i128, i128, i128)>, String> {
+    position
+        .bin_shares
+        .iter()
+        .map(|bin_shares| {
+            let bin = ledger.call(|env| Contract::get_bins_range(env, bin_shares.bin_id, bin_shares.bin_id))?.get_unchecked(0);
+            let (amount_x, amount_y) = amounts_to_remove(bin_shares.shares, bin.shares, bin.reserve_x, bin.reserve_y);
+            Ok((bin_shares.bin_id, bin_shares.shares, amount_x, amount_y))
+        })
+        .collect()
+}
+
+/// Prints the bins of a position, with the amounts that it can withdraw.
+pub fn position(flags: &mut Flags) -> Result<(), String> {
+    let ledger = snapshot_ledger(flags)?;
+    let config = ledger.config()?;
+    let owner = parse_address(&ledger.env, &flags.require::<String>("owner")?)?;
+    let position_id: i32 = flags.get("position-id")?.unwrap_or(0);
+
+    let position = ledger
+        .call(|env| Contract::get_position(env, owner, position_id))?
+        .ok_or("the position does not exist")?;
+    let amounts = position_amounts(&ledger, &position)?;
+
+    if flags.switch("json") {
+        let bins: std::vec::Vec<_> = amounts
+            .iter()
+            .map(|(bin_id, shares, amount_x, amount_y)| {
+                json!({
+                    "bin": bin_id,
+                    "price": human_price(config.bin_step, config.decimals_x, config.decimals_y, *bin_id),
+                    "shares": shares.to_string(),
+                    "amount_x": format_amount(*amount_x, config.decimals_x),
+                    "amount_y": format_amount(*amount_y, config.decimals_y),
+                })
+            })
+            .collect();
+        print_json(json!(bins));
+    } else {
+        let mut table = Table::new(&["bin", "price", "shares", "amount_x", "amount_y"]);
+        for (bin_id, shares, amount_x, amount_y) in &amounts {
+            table.row(vec_of([
+                bin_id.to_string(),
+                human_price(config.bin_step, config.decimals_x, config.decimals_y, *bin_id),
+                shares.to_string(),
+                format_amount(*amount_x, config.decimals_x),
+                format_amount(*amount_y, config.decimals_y),
+            ]));
+        }
+        let (total_x, total_y) = amounts.iter().fold((0, 0), |(x, y), (_, _, amount_x, amount_y)| (x + amount_x, y + amount_y));
+        table.row(vec_of(["total".into(), String::new(), String::new(), format_amount(total_x, config.decimals_x), format_amount(total_y, config.decimals_y)]));
+        print!("{table}");
+    }
+
+    Ok(())
+}
+
+/// Prints all of the positions in the snapshot, optionally of one owner.
+pub fn positions(flags: &mut Flags) -> Result<(), String> {
+    let ledger = snapshot_ledger(flags)?;
+    let config = ledger.config()?;
+    let owner = flags.get::<String>("owner")?.map(|owner| parse_address(&ledger.env, &owner)).transpose()?;
+
+    let mut rows = std::vec::Vec::new();
+    for (position_owner, position_id, position) in ledger.positions()? {
+        if owner.as_ref().is_some_and(|owner| *owner != position_owner) {
+            continue;
+        }
+
+        let amounts = position_amounts(&ledger, &position)?;
+        let (amount_x, amount_y) = amounts.iter().fold((0, 0), |(x, y), (_, _, amount_x, amount_y)| (x + amount_x, y + amount_y));
+        let lowest_bin = amounts.first().map(|bin| bin.0).unwrap_or_default();
+        let highest_bin = amounts.last().map(|bin| bin.0).unwrap_or_default();
+
+        rows.push((address_string(&position_owner), position_id, lowest_bin, highest_bin, amount_x, amount_y));
+    }
+
+    if flags.switch("json") {
+        let positions: std::vec::Vec<_> = rows
+            .iter()
+            .map(|(owner, position_id, lowest_bin, highest_bin, amount_x, amount_y)| {
+                json!({
+                    "owner": owner,
+                    "position_id": position_id,
+                    "lowest_bin": lowest_bin,
+                    "highest_bin": highest_bin,
+                    "amount_x": format_amount(*amount_x, config.decimals_x),
+                    "amount_y": format_amount(*amount_y, config.decimals_y),
+                })
+            })
+            .collect();
+        print_json(json!(positions));
+    } else {
+        let mut table = Table::new(&["owner", "id", "bins", "amount_x", "amount_y"]);
+        for (owner, position_id, lowest_bin, highest_bin, amount_x, amount_y) in &rows {
+            table.row(vec_of([
+                owner.clone(),
+                position_id.to_string(),
+                format!("{lowest_bin}..{highest_bin}"),
+                format_amount(*amount_x, config.decimals_x),
+                format_amount(*amount_y, config.decimals_y),
+            ]));
+        }
+        print!("{table}");
+    }
+
+    Ok(())
+}
+
+/// Deploys a pool. With a snapshot the contract of this repo is registered, otherwise the wasm with `--wasm-hash` is deployed.
+pub fn deploy(flags: &mut Flags) -> Result<(), String> {
+    let target = Target::from_flags(flags)?;
+    let env = target.env().clone();
+
+    let token_x = parse_address(&env, &flags.require::<String>("token-x")?)?;
+    let token_y = parse_address(&env, &flags.require::<String>("token-y")?)?;
+    let bin_step = flags.get("bin-step")?.unwrap_or(10);
+
+    let active_bin = match flags.get::<String>("price")? {
+        Some(price) => {
+            let (decimals_x, decimals_y) = match &target {
+                Target::Snapshot { .. } => (TokenClient::new(&env, &token_x).decimals(), TokenClient::new(&env, &token_y).decimals()),
+                Target::Tx { .. } => (flags.get("decimals-x")?.unwrap_or(7), flags.get("decimals-y")?.unwrap_or(7)),
+            };
+            bin_from_human_price(bin_step, decimals_x, decimals_y, &price)?
+        }
+        None => flags.require("active-bin")?,
+    };
+    // the source of the transaction is the admin, unless another one is given
+    let admin = match (flags.get::<String>("admin")?, &target) {
+        (Some(admin), _) => parse_address(&env, &admin)?,
+        (None, Target::Tx { builder, .. }) => builder.source.account.clone(),
+        (None, Target::Snapshot { .. }) => return Err("missing --admin".into()),
+    };
+
+    let config = Config {
+        token_x,
+        token_y,
+        bin_step,
+        active_bin,
+        fee: flags.get("fee")?.unwrap_or(30),
+        flash_loan_fee: flags.get("flash-loan-fee")?.unwrap_or(10),
+        ttl_threshold: flags.get("ttl-threshold")?.unwrap_or(TTL_THRESHOLD),
+        ttl_extend_to: flags.get("ttl-extend-to")?.unwrap_or(TTL_EXTEND_TO),
+        // read from the tokens by the contract
+        decimals_x: 0,
+        decimals_y: 0,
+        admin,
+    };
+
+    let salt = flags.get::<String>("salt")?.map(|salt| parse_hex(&salt)).transpose()?;
+
+    match target {
+        Target::Snapshot { env, out, .. } => {
+            // like a deploy, the address comes from the salt. `register` would count addresses from the start in every env
+            let preimage = (config.clone(), BytesN::from_array(&env, &salt.unwrap_or_default())).to_xdr(&env);
+            let hash = ScAddress::Contract(Hash(env.crypto().sha256(&preimage).to_array()));
+            let pool = Address::try_from_val(&env, &ScVal::Address(hash)).unwrap();
+            if has_contract(&env, &pool) {
+                return Err(format!("there is already a contract at {}, use another --salt", address_string(&pool)));
+            }
+
+            std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| env.register_at(&pool, Contract, (config,))))
+                .map_err(|_| "the constructor failed")?;
+            Ledger::new(env, pool.clone()).save(&out)?;
+            println!("pool: {}", address_string(&pool));
+        }
+        Target::Tx { builder, .. } => {
+            let wasm_hash = parse_hex(&flags.require::<String>("wasm-hash")?)?;
+            // the salt decides the address, by default it is the sequence number so every deploy gets a new address
+            let salt = salt.unwrap_or_else(|| {
+                let mut salt = [0u8; 32];
+                salt[24..].copy_from_slice(&builder.source.sequence.to_be_bytes());
+                salt
+            });
+            println!("{}", builder.deploy(wasm_hash, salt, (config,))?);
+        }
+    }
+
+    Ok(())
+}
+
+fn parse_hex(value: &str) -> Result<[u8; 32], String> {
+    let invalid = || format!("expected 32 bytes in hex, got {value}");
+    if value.len() != 64 {
+        return Err(invalid());
+    }
+
+    let mut bytes = [0u8; 32];
+    for (i, byte) in bytes.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&value[2 * i..2 * i + 2], 16).map_err(|_| invalid())?;
+    }
+    Ok(bytes)
+}
+
+/// Deposits in a range of bins with a shape, see `range_args`.
+pub fn modify_liquidity(flags: &mut Flags) -> Result<(), String> {
+    let target = Target::from_flags(flags)?;
+    let pool = PoolInfo::new(&target, flags)?;
+    let env = target.env().clone();
+
+    let from = parse_address(&env, &flags.require::<String>("from")?)?;
+    let position_id: i32 = flags.get("position-id")?.unwrap_or(0);
+    let shape = parse_shape(&flags.get::<String>("shape")?.unwrap_or("spot".into()))?;
+    let amount_x = parse_amount(&flags.get::<String>("amount-x")?.unwrap_or("0".into()), pool.decimals_x)?;
+    let amount_y = parse_amount(&flags.get::<String>("amount-y")?.unwrap_or("0".into()), pool.decimals_y)?;
+
+    let width = flags.get("width")?;
+    let bins = match (flags.get::<String>("min-price")?, flags.get::<String>("max-price")?, flags.get("min-bin")?, flags.get("max-bin")?) {
+        (Some(min_price), Some(max_price), None, None) => Some((pool.bin_from_price(&min_price)?, pool.bin_from_price(&max_price)?)),
+        (None, None, Some(min_bin), Some(max_bin)) => Some((min_bin, max_bin)),
+        (None, None, None, None) => None,
+        _ => return Err("use --min-price with --max-price, or --min-bin with --max-bin".into()),
+    };
+
+    let (args, offset_from_active) = range_args(
+        &env,
+        shape,
+        width,
+        bins,
+        pool.active_bin,
+        (amount_x, amount_y),
+        (pool.active_reserve_x, pool.active_reserve_y),
+    )?;
+
+    target.invoke(
+        "modify_liquidity",
+        (from.clone(), position_id, args.clone(), offset_from_active),
+        |env| Contract::modify_liquidity(env, from, position_id, args, offset_from_active),
+    )
+}
+
+/// Removes a percentage of a position.
+pub fn remove(flags: &mut Flags) -> Result<(), String> {
+    let target = Target::from_flags(flags)?;
+    let pool = PoolInfo::new(&target, flags)?;
+    let env = target.env().clone();
+
+    let owner = parse_address(&env, &flags.require::<String>("owner")?)?;
+    let position_id: i32 = flags.get("position-id")?.unwrap_or(0);
+    let bps: u32 = flags.get("bps")?.unwrap_or(10_000);
+    let min_x = parse_amount(&flags.get::<String>("min-x")?.unwrap_or("0".into()), pool.decimals_x)?;
+    let min_y = parse_amount(&flags.get::<String>("min-y")?.unwrap_or("0".into()), pool.decimals_y)?;
+
+    target.invoke(
+        "remove_position_pct",
+        (owner.clone(), position_id, bps, min_x, min_y),
+        |env| Contract::remove_position_pct(env, owner, position_id, bps, min_x, min_y),
+    )
+}
+
+/// Sells an exact amount of one of the tokens, the output goes to `--to` or else to `--from`.
+pub fn swap(flags: &mut Flags) -> Result<(), String> {
+    let target = Target::from_flags(flags)?;
+    let pool = PoolInfo::new(&target, flags)?;
+    let env = target.env().clone();
+
+    let from = parse_address(&env, &flags.require::<String>("from")?)?;
+    let (in_token, is_x_in) = pool.token(&env, &flags.require::<String>("in-token")?)?;
+    let (decimals_in, decimals_out) = if is_x_in { (pool.decimals_x, pool.decimals_y) } else { (pool.decimals_y, pool.decimals_x) };
+    let amount_in = parse_amount(&flags.require::<String>("amount-in")?, decimals_in)?;
+    let min_out = parse_amount(&flags.require::<String>("min-out")?, decimals_out)?;
+
+    match flags.get::<String>("to")? {
+        Some(to) => {
+            let to = parse_address(&env, &to)?;
+            target.invoke(
+                "swap_exact_amount_in_to",
+                (from.clone(), to.clone(), amount_in, min_out, in_token.clone()),
+                |env| Contract::swap_exact_amount_in_to(env, from, to, amount_in, min_out, in_token),
+            )
+        }
+        None => target.invoke(
+            "swap_exact_amount_in",
+            (from.clone(), amount_in, min_out, in_token.clone()),
+            |env| Contract::swap_exact_amount_in(env, from, amount_in, min_out, in_token),
+        ),
+    }
+}
+
+/// Sends the tokens that the pool holds on top of its reserves to `--to`.
+pub fn skim(flags: &mut Flags) -> Result<(), String> {
+    let target = Target::from_flags(flags)?;
+    let to = parse_address(target.env(), &flags.require::<String>("to")?)?;
+
+    target.invoke("skim", (to.clone(),), |env| Contract::skim(env, to))
+}
+
+/// Adds the tokens that the pool holds on top of its reserves to the active bin.
+pub fn sync(flags: &mut Flags) -> Result<(), String> {
+    let target = Target::from_flags(flags)?;
+
+    target.invoke("sync", (), Contract::sync)
+}
+
+/// Extends the TTL of bins, positions, limit orders and approvals,
+/// `--bins 1,2,3 --positions OWNER:ID,OWNER:ID --orders OWNER:ID --operators OWNER:OPERATOR`.
+pub fn bump(flags: &mut Flags) -> Result<(), String> {
+    let target = Target::from_flags(flags)?;
+    let env = target.env().clone();
+
+    let mut bins: Vec<i32> = vec![&env];
+    for bin in flags.get::<String>("bins")?.unwrap_or_default().split(',').filter(|bin| !bin.is_empty()) {
+        bins.push_back(bin.trim().parse().map_err(|_| format!("invalid bin {bin}"))?);
+    }
+
+    let mut owner_ids = |flag: &str| -> Result<Vec<(Address, i32)>, String> {
+        let mut ids: Vec<(Address, i32)> = vec![&env];
+        for owner_id in flags.get::<String>(flag)?.unwrap_or_default().split(',').filter(|owner_id| !owner_id.is_empty()) {
+            let (owner, id) = owner_id.split_once(':').ok_or_else(|| format!("expected OWNER:ID, got {owner_id}"))?;
+            ids.push_back((parse_address(&env, owner.trim())?, id.trim().parse().map_err(|_| format!("invalid id {id}"))?));
+        }
+        Ok(ids)
+    };
+    let positions = owner_ids("positions")?;
+    let orders = owner_ids("orders")?;
+
+    let mut operators: Vec<(Address, Address)> = vec![&env];
+    for pair in flags.get::<String>("operators")?.unwrap_or_default().split(',').filter(|pair| !pair.is_empty()) {
+        let (owner, operator) = pair.split_once(':').ok_or_else(|| format!("expected OWNER:OPERATOR, got {pair}"))?;
+        operators.push_back((parse_address(&env, owner.trim())?, parse_address(&env, operator.trim())?));
+    }
+
+    target.invoke("bump", (bins.clone(), positions.clone(), orders.clone(), operators.clone()), |env| {
+        Contract::bump(env, bins, positions, orders, operators)
+    })
+}
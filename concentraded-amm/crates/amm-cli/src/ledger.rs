@@ -0,0 +1,121 @@
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::path::Path;
+use std::str::FromStr;
+use amm::storage::{Config, DataKey, Position};
+use amm::Contract;
+use soroban_ledger_snapshot::LedgerSnapshot;
+use soroban_sdk::xdr::{LedgerKey, LedgerKeyContractData, ScAddress, ScVal};
+use soroban_sdk::{Address, Env, TryFromVal, Val};
+
+/// A pool in a ledger snapshot, like the ones from `stellar snapshot create` or the tests.
+///
+/// The calls run the code of this repo against the state in the snapshot, so the pool may also be deployed from an older wasm
+/// as long as the storage layout is the same. Authorization is mocked, the snapshot is a sandbox.
+pub struct Ledger {
+    pub env: Env,
+    pub contract: Address,
+}
+
+impl Ledger {
+    pub fn new(env: Env, contract: Address) -> Self {
+        env.mock_all_auths();
+        Ledger { env, contract }
+    }
+
+    pub fn load(path: &Path, contract: &str) -> Result<Self, String> {
+        let env = Ledger::read_env(path)?;
+        let contract = parse_address(&env, contract)?;
+
+        Ok(Ledger::new(env, contract))
+    }
+
+    /// returns an env with the state of a ledger snapshot
+    pub fn read_env(path: &Path) -> Result<Env, String> {
+        let snapshot = LedgerSnapshot::read_file(path).map_err(|e| format!("{}: {e}", path.display()))?;
+        Ok(Env::from_ledger_snapshot(snapshot))
+    }
+
+    /// Writes the state to a snapshot. The nonces of the mocked authorizations are left out, a new env starts counting them
+    /// from the same value so they would clash with the next call of the same address.
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let mut snapshot = self.env.to_ledger_snapshot();
+        snapshot.ledger_entries.retain(|(key, _)| {
+            !matches!(&**key, LedgerKey::ContractData(LedgerKeyContractData { key: ScVal::LedgerKeyNonce(_), .. }))
+        });
+
+        snapshot.write_file(path).map_err(|e| format!("{}: {e}", path.display()))
+    }
+
+    /// Runs `f` as the pool contract, for example `|env| Contract::get_config(env)`.
+    ///
+    /// returns the panic message as the error if the call fails, like the contract failing a check.
+    pub fn call<T>(&self, f: impl FnOnce(Env) -> T) -> Result<T, String> {
+        let env = self.env.clone();
+        catch_unwind(AssertUnwindSafe(|| self.env.as_contract(&self.contract, || f(env)))).map_err(|panic| {
+            panic
+                .downcast_ref::<String>()
+                .cloned()
+                .or_else(|| panic.downcast_ref::<&str>().map(|s| s.to_string()))
+                .unwrap_or_else(|| "the call failed".into())
+        })
+    }
+
+    pub fn config(&self) -> Result<Config, String> {
+        self.call(Contract::get_config)
+    }
+
+    /// returns all of the positions of the pool, by scanning the entries of the snapshot
+    pub fn positions(&self) -> Result<Vec<(Address, i32, Position)>, String> {
+        let contract = sc_address(&self.env, &self.contract);
+        let mut positions = Vec::new();
+
+        for (key, _) in self.env.to_ledger_snapshot().ledger_entries {
+            let LedgerKey::ContractData(LedgerKeyContractData { contract: entry_contract, key, .. }) = *key else {
+                continue;
+            };
+            if entry_contract != contract {
+                continue;
+            }
+
+            let Ok(key) = Val::try_from_val(&self.env, &key).map(|val| DataKey::try_from_val(&self.env, &val)) else {
+                continue;
+            };
+            let Ok(DataKey::Position(owner, position_id)) = key else {
+                continue;
+            };
+            if let Some(position) = self.call(|env| Contract::get_position(env, owner.clone(), position_id))? {
+                positions.push((owner, position_id, position));
+            }
+        }
+
+        Ok(positions)
+    }
+}
+
+/// returns true if the ledger has entries of the contract at `address`
+pub fn has_contract(env: &Env, address: &Address) -> bool {
+    let address = sc_address(env, address);
+    env.to_ledger_snapshot().ledger_entries.iter().any(|(key, _)| {
+        matches!(&**key, LedgerKey::ContractData(LedgerKeyContractData { contract, .. }) if *contract == address)
+    })
+}
+
+/// Parses a `G...` account or `C...` contract address.
+pub fn parse_address(env: &Env, strkey: &str) -> Result<Address, String> {
+    // `Address::from_str` panics on invalid addresses
+    let address = ScAddress::from_str(strkey).map_err(|_| format!("invalid address {strkey}"))?;
+    Address::try_from_val(env, &ScVal::Address(address)).map_err(|_| format!("invalid address {strkey}"))
+}
+
+/// The `G...` or `C...` strkey of `address`.
+pub fn address_string(address: &Address) -> String {
+    let strkey: soroban_sdk::String = address.to_string();
+    strkey.to_string()
+}
+
+pub fn sc_address(env: &Env, address: &Address) -> ScAddress {
+    match ScVal::try_from_val(env, &address.to_val()) {
+        Ok(ScVal::Address(address)) => address,
+        _ => unreachable!("an address converts to an address"),
+    }
+}
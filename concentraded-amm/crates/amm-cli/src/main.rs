@@ -0,0 +1,79 @@
+//! Command-line tool for pools: builds transactions for the contract, and inspects or changes pools in ledger snapshots.
+mod args;
+mod commands;
+mod ledger;
+mod output;
+mod tx;
+mod units;
+mod test;
+
+use std::process::ExitCode;
+use crate::args::Flags;
+
+const USAGE: &str = "\
+usage: amm-cli <command> [options]
+
+Commands that change a pool run against a ledger snapshot with --snapshot FILE [--out FILE],
+or print an unsigned transaction with --source G... --sequence N [--fee STROOPS].
+The transaction still has to be simulated and signed, for example with `stellar tx simulate` and `stellar tx sign`.
+Amounts and prices are in whole tokens, prices are the amount of x that 1 y is worth.
+
+sandbox            --out FILE [--bin-step 10] [--fee 30] [--flash-loan-fee 10] [--active-bin N | --price P] [--users 1] [--mint 1000000]
+                   creates a snapshot with two stellar assets and a pool, and mints both to new users
+mint               --snapshot FILE --contract C... --token x|y --to ADDRESS --amount A
+
+config             --snapshot FILE --contract C... [--json]
+bins               --snapshot FILE --contract C... [--from BIN] [--to BIN] [--all] [--json]
+position           --snapshot FILE --contract C... --owner ADDRESS [--position-id 0] [--json]
+positions          --snapshot FILE --contract C... [--owner ADDRESS] [--json]
+
+deploy             --token-x C... --token-y C... [--bin-step 10] [--fee 30] [--flash-loan-fee 10] [--admin G...] (--active-bin N | --price P)
+                   [--ttl-threshold N] [--ttl-extend-to N] [--salt HEX], for a transaction also --wasm-hash HEX [--decimals-x 7] [--decimals-y 7]
+modify-liquidity   --contract C... --from ADDRESS [--position-id 0] [--amount-x A] [--amount-y A] [--shape spot|curve|bid-ask]
+                   (--width N | --min-price P --max-price P | --min-bin N --max-bin N)
+remove             --contract C... --owner ADDRESS [--position-id 0] [--bps 10000] [--min-x A] [--min-y A]
+swap               --contract C... --from ADDRESS --in-token x|y|C... --amount-in A --min-out A [--to ADDRESS]
+skim               --contract C... --to ADDRESS
+sync               --contract C...
+bump               --contract C... [--bins 1,2,3] [--positions OWNER:ID,...] [--orders OWNER:ID,...] [--operators OWNER:OPERATOR,...]
+
+Without a snapshot, the pool is described with [--token-x C...] [--token-y C...] [--bin-step N] [--active-bin N] [--decimals-x 7] [--decimals-y 7].";
+
+fn run(args: impl IntoIterator<Item = String>) -> Result<(), String> {
+    let mut args = args.into_iter();
+    let command = args.next().ok_or(USAGE)?;
+    let mut flags = Flags::parse(args)?;
+
+    match command.as_str() {
+        "sandbox" => commands::sandbox(&mut flags)?,
+        "mint" => commands::mint(&mut flags)?,
+        "config" => commands::config(&mut flags)?,
+        "bins" => commands::bins(&mut flags)?,
+        "position" => commands::position(&mut flags)?,
+        "positions" => commands::positions(&mut flags)?,
+        "deploy" => commands::deploy(&mut flags)?,
+        "modify-liquidity" => commands::modify_liquidity(&mut flags)?,
+        "remove" => commands::remove(&mut flags)?,
+        "swap" => commands::swap(&mut flags)?,
+        "skim" => commands::skim(&mut flags)?,
+        "sync" => commands::sync(&mut flags)?,
+        "bump" => commands::bump(&mut flags)?,
+        "help" | "--help" => println!("{USAGE}"),
+        _ => return Err(format!("unknown command {command}\n\n{USAGE}")),
+    }
+
+    flags.finish()
+}
+
+fn main() -> ExitCode {
+    // failed contract calls are reported as errors, without the panic output
+    std::panic::set_hook(Box::new(|_| {}));
+
+    match run(std::env::args().skip(1)) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
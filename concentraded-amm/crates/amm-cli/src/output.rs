@@ -0,0 +1,51 @@
+use std::fmt;
+
+/// A table that is printed with aligned columns. Numbers are aligned to the right.
+pub struct Table {
+    headers: Vec<&'static str>,
+    rows: Vec<Vec<String>>,
+}
+
+impl Table {
+    pub fn new(headers: &[&'static str]) -> Self {
+        Table { headers: headers.to_vec(), rows: Vec::new() }
+    }
+
+    pub fn row(&mut self, row: Vec<String>) {
+        assert_eq!(self.headers.len(), row.len());
+        self.rows.push(row);
+    }
+}
+
+impl fmt::Display for Table {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let widths: Vec<usize> = (0..self.headers.len())
+            .map(|column| {
+                self.rows
+                    .iter()
+                    .map(|row| row[column].len())
+                    .chain([self.headers[column].len()])
+                    .max()
+                    .unwrap()
+            })
+            .collect();
+
+        let headers: Vec<String> = self.headers.iter().map(|header| header.to_string()).collect();
+        for row in [&headers].into_iter().chain(&self.rows) {
+            let cells: Vec<String> = row
+                .iter()
+                .zip(&widths)
+                .map(|(cell, width)| {
+                    if cell.starts_with(|c: char| c.is_ascii_digit() || c == '-') {
+                        format!("{cell:>width$}")
+                    } else {
+                        format!("{cell:<width$}")
+                    }
+                })
+                .collect();
+            writeln!(f, "{}", cells.join("  ").trim_end())?;
+        }
+
+        Ok(())
+    }
+}
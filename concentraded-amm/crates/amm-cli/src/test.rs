@@ -0,0 +1,244 @@
+#![cfg(test)]
+use std::path::PathBuf;
+use amm::storage::{Config, LiquidityShape};
+use amm::Contract;
+use amm_math::price::{get_max_bin_id, SCALE};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::xdr::{HostFunction, Limits, OperationBody, ReadXdr, TransactionEnvelope};
+use soroban_sdk::{Address, Env};
+use crate::args::Flags;
+use crate::commands::{bin_from_human_price, human_price, range_args};
+use crate::ledger::{address_string, parse_address, sc_address, Ledger};
+use crate::run;
+use crate::units::{format_amount, format_price, parse_amount, parse_price};
+
+const ACCOUNT: &str = "GAAZI4TCR3TY5OJHCTJC2A4QSY6CJWJH5IAJTGKIN2ER7LBNVKOCCWN7";
+
+fn args(args: &str) -> Vec<String> {
+    args.split_whitespace().map(str::to_string).collect()
+}
+
+struct Sandbox {
+    path: PathBuf,
+    pool: String,
+    token_x: String,
+    user: String,
+}
+
+/// a snapshot with a pool at bin 0 and a user with 1000 of both tokens
+fn sandbox(name: &str) -> Sandbox {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_x = env.register_stellar_asset_contract_v2(admin.clone()).address();
+    let token_y = env.register_stellar_asset_contract_v2(admin.clone()).address();
+    let pool = env.register(
+        Contract,
+        (Config {
+            token_x: token_x.clone(),
+            token_y: token_y.clone(),
+            bin_step: 10,
+            active_bin: 0,
+            fee: 30,
+            flash_loan_fee: 10,
+            ttl_threshold: 100,
+            ttl_extend_to: 1000,
+            decimals_x: 0,
+            decimals_y: 0,
+            admin,
+        },),
+    );
+
+    let user = Address::generate(&env);
+    StellarAssetClient::new(&env, &token_x).mint(&user, &1000_0000000);
+    StellarAssetClient::new(&env, &token_y).mint(&user, &1000_0000000);
+
+    let path = std::env::temp_dir().join(format!("amm-cli-{}-{name}.json", std::process::id()));
+    Ledger::new(env, pool.clone()).save(&path).unwrap();
+
+    Sandbox {
+        path,
+        pool: address_string(&pool),
+        token_x: address_string(&token_x),
+        user: address_string(&user),
+    }
+}
+
+#[test]
+fn units() {
+    assert_eq!(parse_amount("1.5", 7), Ok(1_5000000));
+    assert!(parse_amount("-1", 7).is_err());
+    assert_eq!(parse_amount("12", 0), Ok(12));
+    assert!(parse_amount("0.00000001", 7).is_err());
+    assert!(parse_amount("1.2.3", 7).is_err());
+    assert!(parse_amount("", 7).is_err());
+
+    assert_eq!(format_amount(1_5000000, 7), "1.5000000");
+    assert_eq!(format_amount(-1, 7), "-0.0000001");
+    assert_eq!(format_amount(12, 0), "12");
+
+    assert_eq!(parse_price("1"), Ok(SCALE));
+    assert_eq!(parse_price("0.5"), Ok(SCALE / 2));
+    assert!(parse_price("-1").is_err());
+    assert_eq!(format_price(SCALE / 2, 8), "0.5");
+    assert_eq!(format_price(SCALE * 1234 + SCALE / 2, 3), "1234");
+}
+
+#[test]
+fn flags() {
+    let mut flags = Flags::parse(args("--width 3 --json --active-bin -5 --width 4")).unwrap();
+    assert_eq!(flags.get::<u32>("width"), Ok(Some(4)));
+    assert_eq!(flags.get::<i32>("active-bin"), Ok(Some(-5)));
+    assert!(flags.switch("json"));
+    assert!(!flags.switch("all"));
+    assert_eq!(flags.require::<u32>("width"), Err("missing --width".to_string()));
+    assert_eq!(flags.finish(), Ok(()));
+
+    let mut flags = Flags::parse(args("--bps ten --unknown")).unwrap();
+    assert!(flags.get::<u32>("bps").is_err());
+    assert_eq!(flags.finish(), Err("unknown option --unknown".to_string()));
+
+    assert!(Flags::parse(args("positional")).is_err());
+}
+
+#[test]
+fn prices_and_bins() {
+    assert_eq!(bin_from_human_price(10, 7, 7, "1"), Ok(0));
+    // the price of bin 405 is just below 1.5, bin 406 is above it
+    assert_eq!(bin_from_human_price(10, 7, 7, "1.5"), Ok(405));
+    assert_eq!(human_price(10, 7, 7, 405), "1.4989991");
+    assert_eq!(human_price(10, 7, 18, get_max_bin_id(10)), "too large");
+    // 1 whole y is worth 1 whole x, but a unit of y is worth 10 units of x
+    assert_eq!(bin_from_human_price(10, 7, 6, "1"), bin_from_human_price(10, 7, 7, "10"));
+    assert!(bin_from_human_price(10, 7, 7, "1000000000000000000000000000000").is_err());
+}
+
+#[test]
+fn ranges() {
+    let env = Env::default();
+
+    // offsets from the active bin, y above it, x below it and both in it
+    let (args, offset_from_active) = range_args(&env, LiquidityShape::Spot, Some(1), None, None, (300, 300), (0, 0)).unwrap();
+    assert!(offset_from_active);
+    let deposits: Vec<(i32, i128)> = args.iter().map(|arg| (arg.bin_id_or_offset, arg.amount)).collect();
+    assert_eq!(deposits, [(-1, 150), (0, 300), (1, 150)]);
+
+    // a range of bins above the active bin only takes y, bid-ask puts more of it further from the price
+    let (args, offset_from_active) =
+        range_args(&env, LiquidityShape::BidAsk, None, Some((12, 13)), Some(10), (100, 300), (0, 0)).unwrap();
+    assert!(!offset_from_active);
+    let deposits: Vec<(i32, i128)> = args.iter().map(|arg| (arg.bin_id_or_offset, arg.amount)).collect();
+    assert_eq!(deposits, [(12, 128), (13, 171)]);
+
+    assert!(range_args(&env, LiquidityShape::Spot, None, Some((3, 1)), Some(0), (100, 100), (0, 0)).is_err());
+    assert!(range_args(&env, LiquidityShape::Spot, None, Some((1, 3)), None, (100, 100), (0, 0)).is_err());
+    assert!(range_args(&env, LiquidityShape::Spot, Some(1), Some((1, 3)), Some(0), (100, 100), (0, 0)).is_err());
+    assert!(range_args(&env, LiquidityShape::Spot, Some(1), None, None, (0, 0), (0, 0)).is_err());
+}
+
+#[test]
+fn snapshot_commands() {
+    let sandbox = sandbox("commands");
+    let snapshot = format!("--snapshot {} --contract {}", sandbox.path.display(), sandbox.pool);
+
+    run(args(&format!("modify-liquidity {snapshot} --from {} --amount-x 100 --amount-y 100 --width 2 --shape curve", sandbox.user))).unwrap();
+    run(args(&format!("swap {snapshot} --from {} --in-token x --amount-in 10 --min-out 9", sandbox.user))).unwrap();
+    // failed calls are errors and leave the snapshot as it was
+    assert_eq!(
+        run(args(&format!("swap {snapshot} --from {} --in-token y --amount-in 1 --min-out 1000", sandbox.user))),
+        Err("Insufficient output amount".to_string())
+    );
+    run(args(&format!("bins {snapshot} --json"))).unwrap();
+    run(args(&format!("positions {snapshot}"))).unwrap();
+    assert!(run(args(&format!("config {snapshot} --csv"))).is_err());
+
+    let ledger = Ledger::load(&sandbox.path, &sandbox.pool).unwrap();
+    let positions = ledger.positions().unwrap();
+    assert_eq!(positions.len(), 1);
+    assert_eq!(address_string(&positions[0].0), sandbox.user);
+    assert_eq!(positions[0].2.bin_shares.len(), 5);
+
+    let token_x = parse_address(&ledger.env, &sandbox.token_x).unwrap();
+    let user = parse_address(&ledger.env, &sandbox.user).unwrap();
+    assert_eq!(TokenClient::new(&ledger.env, &token_x).balance(&user), 890_0000001);
+
+    // the output of a swap can go to someone else
+    let merchant = address_string(&Address::generate(&ledger.env));
+    run(args(&format!("swap {snapshot} --from {} --in-token y --amount-in 1 --min-out 0.9 --to {merchant}", sandbox.user))).unwrap();
+    let ledger = Ledger::load(&sandbox.path, &sandbox.pool).unwrap();
+    let token_x = TokenClient::new(&ledger.env, &parse_address(&ledger.env, &sandbox.token_x).unwrap());
+    assert!(token_x.balance(&parse_address(&ledger.env, &merchant).unwrap()) > 9000000);
+    assert_eq!(token_x.balance(&parse_address(&ledger.env, &sandbox.user).unwrap()), 890_0000001);
+
+    // the same user can call again after the snapshot is reloaded
+    run(args(&format!("remove {snapshot} --owner {}", sandbox.user))).unwrap();
+    let ledger = Ledger::load(&sandbox.path, &sandbox.pool).unwrap();
+    assert!(ledger.positions().unwrap().is_empty());
+
+    std::fs::remove_file(&sandbox.path).unwrap();
+}
+
+#[test]
+fn snapshot_deploy() {
+    let sandbox = sandbox("deploy");
+    let snapshot = format!("--snapshot {}", sandbox.path.display());
+    let deploy = format!("deploy {snapshot} --token-x {} --token-y {} --price 2 --admin {}", sandbox.token_x, sandbox.token_x, sandbox.user);
+    run(args(&deploy)).unwrap();
+    // the same salt gives the same address
+    assert!(run(args(&deploy)).unwrap_err().starts_with("there is already a contract at"));
+    run(args(&format!("{deploy} --salt {}", "01".repeat(32)))).unwrap();
+
+    std::fs::remove_file(&sandbox.path).unwrap();
+}
+
+#[test]
+fn transactions() {
+    let env = Env::default();
+    let pool = address_string(&Address::generate(&env));
+    let token = address_string(&Address::generate(&env));
+
+    let tx = format!("--source {ACCOUNT} --sequence 8 --contract {pool}");
+    run(args(&format!("swap {tx} --from {ACCOUNT} --token-x {token} --in-token x --amount-in 1 --min-out 0"))).unwrap();
+    run(args(&format!("modify-liquidity {tx} --from {ACCOUNT} --amount-y 5 --active-bin 0 --min-bin 1 --max-bin 4"))).unwrap();
+    // the tokens are not known without a snapshot
+    assert!(run(args(&format!("swap {tx} --from {ACCOUNT} --in-token y --amount-in 1 --min-out 0"))).is_err());
+    // the source has to be an account that can pay the fee
+    assert!(run(args(&format!("sync --source {pool} --sequence 8 --contract {pool}"))).is_err());
+
+    let builder = crate::tx::TxBuilder {
+        env: env.clone(),
+        source: crate::tx::TxSource { account: parse_address(&env, ACCOUNT).unwrap(), sequence: 8, fee: 100 },
+    };
+    let contract = parse_address(&env, &pool).unwrap();
+    let xdr = builder.invoke(&contract, "sync", ()).unwrap();
+
+    let TransactionEnvelope::Tx(envelope) = TransactionEnvelope::from_xdr_base64(xdr, Limits::none()).unwrap() else {
+        panic!("expected a v1 envelope");
+    };
+    assert_eq!(envelope.tx.seq_num.0, 8);
+    assert_eq!(envelope.tx.fee, 100);
+    assert!(envelope.signatures.is_empty());
+    let OperationBody::InvokeHostFunction(op) = &envelope.tx.operations[0].body else {
+        panic!("expected an invocation");
+    };
+    let HostFunction::InvokeContract(invoke) = &op.host_function else {
+        panic!("expected a contract call");
+    };
+    assert_eq!(invoke.contract_address, sc_address(&env, &contract));
+    assert_eq!(invoke.function_name.to_utf8_string_lossy(), "sync");
+    assert!(invoke.args.is_empty());
+
+    let xdr = builder.deploy([1; 32], [2; 32], (5u32,)).unwrap();
+    let TransactionEnvelope::Tx(envelope) = TransactionEnvelope::from_xdr_base64(xdr, Limits::none()).unwrap() else {
+        panic!("expected a v1 envelope");
+    };
+    let OperationBody::InvokeHostFunction(op) = &envelope.tx.operations[0].body else {
+        panic!("expected an invocation");
+    };
+    let HostFunction::CreateContractV2(create) = &op.host_function else {
+        panic!("expected a deploy");
+    };
+    assert_eq!(create.constructor_args.len(), 1);
+}
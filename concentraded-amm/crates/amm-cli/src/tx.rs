@@ -0,0 +1,94 @@
+use soroban_sdk::xdr::{
+    ContractExecutable, ContractIdPreimage, ContractIdPreimageFromAddress, CreateContractArgsV2, Hash, HostFunction, InvokeContractArgs,
+    InvokeHostFunctionOp, Limits, Memo, MuxedAccount, Operation, OperationBody, Preconditions, PublicKey, ScAddress, ScSymbol, ScVal,
+    SequenceNumber, Transaction, TransactionEnvelope, TransactionExt, TransactionV1Envelope, Uint256, WriteXdr,
+};
+use soroban_sdk::{Address, Env, IntoVal, TryFromVal, Val, Vec};
+use crate::ledger::sc_address;
+
+/// The account that sends a transaction.
+#[derive(Clone, Debug)]
+pub struct TxSource {
+    /// a `G...` account
+    pub account: Address,
+    /// the sequence number of the transaction, one more than the current sequence number of the account
+    pub sequence: i64,
+    /// the inclusion fee in stroops, the resource fee is added when the transaction is simulated
+    pub fee: u32,
+}
+
+/// Builds unsigned transactions. They still have to be simulated, to add the footprint and authorization, and signed,
+/// for example with `stellar tx simulate` and `stellar tx sign`.
+pub struct TxBuilder {
+    pub env: Env,
+    pub source: TxSource,
+}
+
+impl TxBuilder {
+    /// returns the transaction envelope, base64 encoded, that invokes `function` of `contract` with `args`
+    pub fn invoke(&self, contract: &Address, function: &str, args: impl IntoVal<Env, Vec<Val>>) -> Result<String, String> {
+        let args: Vec<Val> = args.into_val(&self.env);
+
+        let host_function = HostFunction::InvokeContract(InvokeContractArgs {
+            contract_address: sc_address(&self.env, contract),
+            function_name: ScSymbol(function.try_into().map_err(|_| format!("invalid function name {function}"))?),
+            args: self.to_sc_vals(args)?.try_into().map_err(|_| "too many arguments")?,
+        });
+
+        self.envelope(host_function)
+    }
+
+    /// returns the transaction envelope, base64 encoded, that deploys the wasm with `wasm_hash` and calls its constructor with `args`.
+    ///
+    /// The address of the contract is derived from the source account and `salt`.
+    pub fn deploy(&self, wasm_hash: [u8; 32], salt: [u8; 32], args: impl IntoVal<Env, Vec<Val>>) -> Result<String, String> {
+        let args: Vec<Val> = args.into_val(&self.env);
+
+        let host_function = HostFunction::CreateContractV2(CreateContractArgsV2 {
+            contract_id_preimage: ContractIdPreimage::Address(ContractIdPreimageFromAddress {
+                address: sc_address(&self.env, &self.source.account),
+                salt: Uint256(salt),
+            }),
+            executable: ContractExecutable::Wasm(Hash(wasm_hash)),
+            constructor_args: self.to_sc_vals(args)?.try_into().map_err(|_| "too many arguments")?,
+        });
+
+        self.envelope(host_function)
+    }
+
+    fn to_sc_vals(&self, args: Vec<Val>) -> Result<std::vec::Vec<ScVal>, String> {
+        args.iter()
+            .map(|arg| ScVal::try_from_val(&self.env, &arg).map_err(|e| format!("invalid argument: {e:?}")))
+            .collect()
+    }
+
+    fn envelope(&self, host_function: HostFunction) -> Result<String, String> {
+        let ScAddress::Account(account) = sc_address(&self.env, &self.source.account) else {
+            return Err("the source must be a G... account".into());
+        };
+        let PublicKey::PublicKeyTypeEd25519(key) = account.0;
+
+        let operation = Operation {
+            source_account: None,
+            body: OperationBody::InvokeHostFunction(InvokeHostFunctionOp {
+                host_function,
+                auth: Default::default(),
+            }),
+        };
+
+        let envelope = TransactionEnvelope::Tx(TransactionV1Envelope {
+            tx: Transaction {
+                source_account: MuxedAccount::Ed25519(key),
+                fee: self.source.fee,
+                seq_num: SequenceNumber(self.source.sequence),
+                cond: Preconditions::None,
+                memo: Memo::None,
+                operations: [operation].try_into().unwrap(),
+                ext: TransactionExt::V0,
+            },
+            signatures: Default::default(),
+        });
+
+        envelope.to_xdr_base64(Limits::none()).map_err(|e| e.to_string())
+    }
+}
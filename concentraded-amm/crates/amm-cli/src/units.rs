@@ -0,0 +1,90 @@
+use amm_math::price::{SCALE, SCALE_OFFSET};
+use ethnum::U256;
+
+/// Splits a decimal number like `12.345` into its digits and the number of decimals: (12345, 3)
+fn parse_decimal(value: &str) -> Result<(U256, u32), String> {
+    let invalid = || format!("invalid number {value}");
+    let (whole, fraction) = value.split_once('.').unwrap_or((value, ""));
+
+    if whole.is_empty() && fraction.is_empty() || !(whole.chars().chain(fraction.chars())).all(|c| c.is_ascii_digit()) {
+        return Err(invalid());
+    }
+
+    let digits = U256::from_str_radix(&format!("{whole}{fraction}"), 10).map_err(|_| invalid())?;
+    Ok((digits, fraction.len() as u32))
+}
+
+/// Converts a human amount like `1.5` to the smallest unit of a token with `decimals`.
+pub fn parse_amount(value: &str, decimals: u32) -> Result<i128, String> {
+    let (digits, fraction_digits) = parse_decimal(value)?;
+
+    if fraction_digits > decimals {
+        return Err(format!("{value} has more than {decimals} decimals"));
+    }
+
+    let amount = digits * U256::from(10u8).pow(decimals - fraction_digits);
+    if amount > U256::from(i128::MAX as u128) {
+        return Err(format!("{value} is too large"));
+    }
+
+    Ok(amount.as_i128())
+}
+
+/// Formats an amount in the smallest unit of a token with `decimals`, like `1.5000000`.
+pub fn format_amount(amount: i128, decimals: u32) -> String {
+    let sign = if amount < 0 { "-" } else { "" };
+    let digits = format!("{:0>width$}", amount.unsigned_abs(), width = decimals as usize + 1);
+    let (whole, fraction) = digits.split_at(digits.len() - decimals as usize);
+
+    if decimals == 0 {
+        format!("{sign}{whole}")
+    } else {
+        format!("{sign}{whole}.{fraction}")
+    }
+}
+
+/// Converts a decimal price like `0.25` to 128.128 fixed point, rounded down.
+pub fn parse_price(value: &str) -> Result<U256, String> {
+    let (digits, fraction_digits) = parse_decimal(value)?;
+    let price = digits
+        .checked_mul(SCALE)
+        .ok_or_else(|| format!("{value} is too large"))?
+        / U256::from(10u8).pow(fraction_digits);
+
+    if price == U256::ZERO {
+        return Err(format!("invalid price {value}"));
+    }
+
+    Ok(price)
+}
+
+/// Formats a 128.128 fixed point price with `significant` significant digits, rounded down.
+pub fn format_price(price: U256, significant: u32) -> String {
+    let whole = price >> SCALE_OFFSET;
+    let mut fraction = price & U256::from(u128::MAX);
+
+    let mut result = whole.to_string();
+    let mut remaining = if whole == U256::ZERO { significant } else { significant.saturating_sub(result.len() as u32) };
+    let mut leading_zeros = whole == U256::ZERO;
+    let mut fraction_digits = String::new();
+
+    while remaining > 0 && fraction != U256::ZERO {
+        fraction *= 10;
+        let digit = fraction >> SCALE_OFFSET;
+        fraction &= U256::from(u128::MAX);
+
+        fraction_digits.push_str(&digit.to_string());
+        // zeros after the point don't count as significant digits
+        leading_zeros &= digit == U256::ZERO;
+        if !leading_zeros {
+            remaining -= 1;
+        }
+    }
+
+    let fraction_digits = fraction_digits.trim_end_matches('0');
+    if !fraction_digits.is_empty() {
+        result.push('.');
+        result.push_str(fraction_digits);
+    }
+    result
+}
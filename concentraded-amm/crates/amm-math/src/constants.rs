@@ -0,0 +1,4 @@
+/// 100% expressed in basis points
+pub const MAX_BPS: u32 = 10_000u32;
+/// prices are supported between about 2^-MAX_PRICE_EXPONENT and 2^MAX_PRICE_EXPONENT, see `price::get_max_bin_id`
+pub const MAX_PRICE_EXPONENT: i128 = 96;
@@ -0,0 +1,15 @@
+//! The math of the pool, shared by the contract and off-chain code like bots and backtests.
+//!
+//! Everything except `pool` works without `std`, so the contract can use it.
+//! `pool` has an in-memory `PoolState` that gives the same results as the contract.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub mod constants;
+pub mod math;
+pub mod price;
+pub mod shares;
+pub mod swap;
+#[cfg(feature = "std")]
+pub mod pool;
+
+mod test;
@@ -0,0 +1,75 @@
+use ethnum::I256;
+
+pub const BONE: i128 = 10i128.pow(18);
+
+/********** Scaling Utils **********/
+
+/// Upscale a number to 18 decimals and 256 bits for use in pool math
+pub fn upscale(amount: i128, scalar: i128) -> I256 {
+    I256::from(amount) * I256::from(scalar)
+}
+
+/// Downscale a number from 18 decimals and 256 bits to i128 to represent a token amount.
+///
+/// Rounds floor if there is any remainder. returns `None` if the result does not fit in an i128.
+pub fn downscale_floor(amount: I256, scalar: i128) -> Option<i128> {
+    let result = floor_div(amount, I256::from(scalar));
+    i128::try_from(result).ok()
+}
+
+/// returns `x * y / z` rounded down, `z` must be positive.
+pub fn mul_div_floor(x: I256, y: I256, z: I256) -> I256 {
+    floor_div(x * y, z)
+}
+
+/// returns `x * y / z` rounded down for token amounts, without overflowing in the multiplication.
+///
+/// Panics if the result does not fit in an i128.
+pub fn mul_div_floor_i128(x: i128, y: i128, z: i128) -> i128 {
+    downscale_floor(mul_div_floor(I256::from(x), I256::from(y), I256::from(z)), 1).expect("Math overflow")
+}
+
+/// returns `x / y` rounded towards negative infinity, `y` must be positive.
+fn floor_div(x: I256, y: I256) -> I256 {
+    let result = x / y;
+    if x % y < 0 {
+        result - 1
+    } else {
+        result
+    }
+}
+
+/// Calculate log2(x) where x and the result are fixed point numbers with 18 decimals.
+///
+/// The integer part is found by halving or doubling `x` until it is in [1, 2),
+/// the fractional bits are found by repeatedly squaring. Rounds down, the error is below 1e-16.
+///
+/// Requires that `x` is positive
+pub fn log2(x: i128) -> i128 {
+    assert!(x > 0);
+
+    let mut result = 0;
+    let mut y = x;
+
+    while y < BONE {
+        y *= 2;
+        result -= BONE;
+    }
+    while y >= 2 * BONE {
+        y /= 2;
+        result += BONE;
+    }
+
+    // y is in [1, 2), so y^2 is in [1, 4) and fits in an i128
+    let mut delta = BONE / 2;
+    while delta > 0 {
+        y = y * y / BONE;
+        if y >= 2 * BONE {
+            result += delta;
+            y /= 2;
+        }
+        delta /= 2;
+    }
+
+    result
+}
@@ -0,0 +1,238 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use crate::shares::{active_bin_deposit, amounts_to_remove, shares_to_mint};
+use crate::swap::{swap_bins, BinWalk, ReserveSwap, SwapResult};
+
+/// The reserves and total shares of a bin.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct BinState {
+    pub reserve_x: i128,
+    pub reserve_y: i128,
+    pub shares: i128,
+}
+
+/// The unsold part of the limit orders in a bin, like `OrderBin` of the contract.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct OrderState {
+    pub sell_x: bool,
+    pub remaining: i128,
+    pub proceeds: i128,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SimError {
+    /// the swap ran out of bins with liquidity, the contract fails with `Not enough liquidity`
+    NotEnoughLiquidity,
+    /// the amount is zero or negative
+    InvalidAmount,
+    /// shares were removed that the bin does not have
+    InsufficientShares,
+}
+
+impl fmt::Display for SimError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SimError::NotEnoughLiquidity => write!(f, "Not enough liquidity"),
+            SimError::InvalidAmount => write!(f, "Invalid amount"),
+            SimError::InsufficientShares => write!(f, "Insufficient shares"),
+        }
+    }
+}
+
+impl std::error::Error for SimError {}
+
+/// An in-memory copy of a pool, to predict the results of swaps and liquidity changes without calling the contract.
+///
+/// Load it with the bins from `get_bin_vec` or `get_bins_range`, the shares from `get_shares_vec` and the orders from `get_order_bin`,
+/// and the active bin, bin step and fee from `get_config`. The results match the contract, including the rounding.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PoolState {
+    pub bin_step: u32,
+    pub fee: u32,
+    pub active_bin: i32,
+    /// only bins with reserves or shares are kept
+    pub bins: BTreeMap<i32, BinState>,
+    pub orders: BTreeMap<i32, OrderState>,
+}
+
+impl PoolState {
+    pub fn new(bin_step: u32, fee: u32, active_bin: i32) -> Self {
+        PoolState {
+            bin_step,
+            fee,
+            active_bin,
+            ..PoolState::default()
+        }
+    }
+
+    /// Sets the reserves of a bin, for example from the bins of a `get_bin_vec` snapshot. Empty bins are skipped.
+    pub fn load_bin(&mut self, bin_id: i32, reserve_x: i128, reserve_y: i128) {
+        let bin = self.bins.entry(bin_id).or_default();
+        bin.reserve_x = reserve_x;
+        bin.reserve_y = reserve_y;
+        self.remove_if_empty(bin_id);
+    }
+
+    /// Sets the total shares of a bin, for example from a `get_shares_vec` snapshot. Only needed to simulate liquidity changes.
+    pub fn load_shares(&mut self, bin_id: i32, shares: i128) {
+        self.bins.entry(bin_id).or_default().shares = shares;
+        self.remove_if_empty(bin_id);
+    }
+
+    /// Sets the limit orders of a bin, from `get_order_bin`.
+    pub fn load_orders(&mut self, bin_id: i32, sell_x: bool, remaining: i128, proceeds: i128) {
+        if remaining > 0 {
+            self.orders.insert(bin_id, OrderState { sell_x, remaining, proceeds });
+        } else {
+            self.orders.remove(&bin_id);
+        }
+    }
+
+    pub fn bin(&self, bin_id: i32) -> BinState {
+        self.bins.get(&bin_id).copied().unwrap_or_default()
+    }
+
+    /// returns the sums of the reserves of all bins: (reserve_x, reserve_y)
+    pub fn total_reserves(&self) -> (i128, i128) {
+        self.bins
+            .values()
+            .fold((0, 0), |(x, y), bin| (x + bin.reserve_x, y + bin.reserve_y))
+    }
+
+    /// Simulates `swap_exact_amount_in` and applies it to the state.
+    pub fn swap_exact_amount_in(&mut self, amount_in: i128, is_x_in: bool) -> Result<SwapResult, SimError> {
+        if amount_in <= 0 {
+            return Err(SimError::InvalidAmount);
+        }
+
+        // the state is only changed if the swap succeeds, like the contract
+        let mut state = self.clone();
+        let result = swap_bins(&mut state, self.bin_step, self.fee, self.active_bin, amount_in, is_x_in)
+            .ok_or(SimError::NotEnoughLiquidity)?;
+
+        state.active_bin = result.active_bin;
+        *self = state;
+
+        Ok(result)
+    }
+
+    /// Simulates `swap_exact_amount_in` without changing the state.
+    pub fn quote_exact_amount_in(&self, amount_in: i128, is_x_in: bool) -> Result<SwapResult, SimError> {
+        self.clone().swap_exact_amount_in(amount_in, is_x_in)
+    }
+
+    /// Simulates depositing `amount` in `bin_id`, like a `DepositArgs` that adds liquidity.
+    ///
+    /// returns (x_amount, y_amount, shares_minted)
+    pub fn deposit(&mut self, bin_id: i32, amount: i128) -> Result<(i128, i128, i128), SimError> {
+        if amount <= 0 {
+            return Err(SimError::InvalidAmount);
+        }
+
+        let active_bin = self.active_bin;
+        let bin = self.bins.entry(bin_id).or_default();
+
+        let (amount_x, amount_y) = if bin_id == active_bin {
+            active_bin_deposit(amount, bin.reserve_x, bin.reserve_y)
+        } else if bin_id < active_bin {
+            (amount, 0)
+        } else {
+            (0, amount)
+        };
+
+        let shares = shares_to_mint(amount, bin.shares, bin.reserve_x + bin.reserve_y);
+
+        bin.reserve_x += amount_x;
+        bin.reserve_y += amount_y;
+        bin.shares += shares;
+
+        Ok((amount_x, amount_y, shares))
+    }
+
+    /// Simulates burning `shares` of `bin_id`, like a `DepositArgs` that removes liquidity.
+    ///
+    /// returns (x_amount, y_amount)
+    pub fn remove(&mut self, bin_id: i32, shares: i128) -> Result<(i128, i128), SimError> {
+        if shares <= 0 {
+            return Err(SimError::InvalidAmount);
+        }
+
+        let bin = self.bins.get_mut(&bin_id).ok_or(SimError::InsufficientShares)?;
+        if bin.shares < shares {
+            return Err(SimError::InsufficientShares);
+        }
+
+        let (amount_x, amount_y) = amounts_to_remove(shares, bin.shares, bin.reserve_x, bin.reserve_y);
+
+        bin.reserve_x -= amount_x;
+        bin.reserve_y -= amount_y;
+        bin.shares -= shares;
+        self.remove_if_empty(bin_id);
+
+        Ok((amount_x, amount_y))
+    }
+
+    fn remove_if_empty(&mut self, bin_id: i32) {
+        if self.bins.get(&bin_id) == Some(&BinState::default()) {
+            self.bins.remove(&bin_id);
+        }
+    }
+}
+
+impl BinWalk for PoolState {
+    fn liquidity_out(&mut self, bin_id: i32, is_x_in: bool) -> (i128, i128) {
+        let bin = self.bin(bin_id);
+        let reserve_out = if is_x_in { bin.reserve_y } else { bin.reserve_x };
+
+        // orders that sell the token that we are buying
+        let orders_out = self
+            .orders
+            .get(&bin_id)
+            .filter(|orders| orders.sell_x != is_x_in)
+            .map(|orders| orders.remaining)
+            .unwrap_or(0);
+
+        (reserve_out, orders_out)
+    }
+
+    fn swap_reserve(&mut self, bin_id: i32, is_x_in: bool, swapped: ReserveSwap) {
+        let bin = self.bins.entry(bin_id).or_default();
+
+        if is_x_in {
+            bin.reserve_x += swapped.amount_in;
+            bin.reserve_y -= swapped.amount_out;
+        } else {
+            bin.reserve_y += swapped.amount_in;
+            bin.reserve_x -= swapped.amount_out;
+        }
+    }
+
+    fn swap_orders(&mut self, bin_id: i32, _is_x_in: bool, swapped: ReserveSwap) {
+        let orders = self.orders.get_mut(&bin_id).unwrap();
+        orders.remaining -= swapped.amount_out;
+        orders.proceeds += swapped.amount_in;
+
+        // filled orders can only be claimed, they are not part of the liquidity anymore
+        if orders.remaining == 0 {
+            self.orders.remove(&bin_id);
+        }
+    }
+
+    fn next_bin(&mut self, bin_id: i32, step: i32) -> Option<i32> {
+        let next_bin = if step > 0 {
+            self.bins.range(bin_id + 1..).next().map(|(id, _)| *id)
+        } else {
+            self.bins.range(..bin_id).next_back().map(|(id, _)| *id)
+        };
+        let next_order = if step > 0 {
+            self.orders.range(bin_id + 1..).next().map(|(id, _)| *id)
+        } else {
+            self.orders.range(..bin_id).next_back().map(|(id, _)| *id)
+        };
+
+        match (next_bin, next_order) {
+            (Some(a), Some(b)) => Some(if step > 0 { a.min(b) } else { a.max(b) }),
+            (a, b) => a.or(b),
+        }
+    }
+}
@@ -0,0 +1,170 @@
+use ethnum::U256;
+use crate::constants::{MAX_BPS, MAX_PRICE_EXPONENT};
+use crate::math::{log2, BONE};
+
+/// Prices are 128.128 binary fixed point numbers, like in Liquidity Book.
+pub const SCALE_OFFSET: u32 = 128;
+/// 1 in 128.128 fixed point
+pub const SCALE: U256 = U256::from_words(1, 0);
+
+/// returns `1 + bin_step / 10_000` in 128.128 fixed point, rounded down
+pub fn get_base(bin_step: u32) -> U256 {
+    SCALE + (U256::from(bin_step) << SCALE_OFFSET) / U256::from(MAX_BPS)
+}
+
+/// Calculate x^y where x is a 128.128 fixed point number and y an integer, using binary exponentiation.
+///
+/// Like in Liquidity Book, a base above 1 is inverted first, so that all of the squares are below 1 and fit in 128 bits.
+/// Every multiplication rounds down, so the result is off by at most a few units in the last fractional bit.
+///
+/// Requires that `|y| < 2^20`
+pub fn pow(x: U256, y: i32) -> U256 {
+    let mut invert = y < 0;
+    let mut abs_y = y.unsigned_abs();
+    assert!(abs_y < 0x100000, "Pow overflow");
+
+    if abs_y == 0 {
+        return SCALE;
+    }
+
+    let mut squared = x;
+    if x > U256::from(u128::MAX) {
+        squared = U256::MAX / squared;
+        invert = !invert;
+    }
+
+    let mut result = SCALE;
+    loop {
+        if abs_y & 1 == 1 {
+            result = (result * squared) >> SCALE_OFFSET;
+        }
+        abs_y >>= 1;
+        if abs_y == 0 {
+            break;
+        }
+        squared = (squared * squared) >> SCALE_OFFSET;
+    }
+
+    assert!(result != U256::ZERO, "Pow underflow");
+
+    if invert {
+        U256::MAX / result
+    } else {
+        result
+    }
+}
+
+/// returns the largest bin id that is supported for `bin_step`. The supported bins are `[-max, max]`.
+///
+/// The range is `MAX_PRICE_EXPONENT / log2(1 + bin_step / 10_000)`, rounded down, so the prices of the supported bins are between about
+/// 2^-96 and 2^96. That is enough for pairs with very different decimals and prices, while the smallest prices
+/// still have 32 significant bits, and amounts converted with the largest prices still fit in 256 bits.
+pub fn get_max_bin_id(bin_step: u32) -> i32 {
+    (MAX_PRICE_EXPONENT * BONE / log2(BONE + bin_step as i128 * BONE / MAX_BPS as i128)) as i32
+}
+
+/// returns the price of y in x in bin `id`: the amount of x that 1 y is worth, in 128.128 fixed point.
+///
+/// The price is `(1 + bin_step / 10_000) ^ id`.
+pub fn price_from_bin(bin_step: u32, id: i32) -> U256 {
+    pow(get_base(bin_step), id)
+}
+
+/// returns `x * y >> 128`, rounded down or up. `x` must fit in 128 bits.
+///
+/// `y` is split in two halves, so that the intermediate products fit in 256 bits.
+fn mul_shift(x: U256, y: U256, round_up: bool) -> U256 {
+    let (y_high, y_low) = y.into_words();
+    let low = x * U256::from(y_low);
+
+    let mut result = x * U256::from(y_high) + (low >> SCALE_OFFSET);
+    if round_up && low & U256::from(u128::MAX) != U256::ZERO {
+        result += 1;
+    }
+    result
+}
+
+/// returns `(x << 128) / y`, rounded down or up. `x` must fit in 128 bits.
+fn shift_div(x: U256, y: U256, round_up: bool) -> U256 {
+    let numerator = x << SCALE_OFFSET;
+    let mut result = numerator / y;
+    if round_up && numerator % y != U256::ZERO {
+        result += 1;
+    }
+    result
+}
+
+/// returns the amount of the other token that `amount_in` is worth in a bin with `price`, rounded down
+pub fn get_amount_out(price: U256, amount_in: u128, is_x_in: bool) -> U256 {
+    if is_x_in {
+        shift_div(U256::from(amount_in), price, false)
+    } else {
+        mul_shift(U256::from(amount_in), price, false)
+    }
+}
+
+/// returns the amount of the input token that is needed to get `amount_out` in a bin with `price`, rounded up
+pub fn get_amount_in(price: U256, amount_out: u128, is_x_in: bool) -> U256 {
+    if is_x_in {
+        mul_shift(U256::from(amount_out), price, true)
+    } else {
+        shift_div(U256::from(amount_out), price, true)
+    }
+}
+
+/// returns the id of the bin for `price`, the price of y in x in 128.128 fixed point.
+///
+/// Rounds down: the result is the largest bin id with `price_from_bin(id) <= price`,
+/// so prices between two bins map to the lower bin.
+///
+/// The id is estimated with `log2(price) / log2(base)` and then corrected by comparing with `price_from_bin`,
+/// so the result is exact, even though the log is approximated.
+///
+/// Requires that `price` is between the prices of the lowest and highest supported bins.
+pub fn id_from_price(bin_step: u32, price: U256) -> i32 {
+    assert!(price > U256::ZERO, "Invalid price");
+
+    // log2(price) = (msb - 128) + log2(mantissa), with the mantissa in [1, 2) scaled by 1e18
+    let msb = 255 - price.leading_zeros();
+    let top_bits = if msb >= 60 { price >> (msb - 60) } else { price << (60 - msb) };
+    let mantissa = (top_bits.as_i128() * BONE) >> 60;
+    let log2_price = (msb as i128 - SCALE_OFFSET as i128) * BONE + log2(mantissa);
+
+    let mut id = (log2_price / log2(BONE + bin_step as i128 * BONE / MAX_BPS as i128)) as i32;
+
+    while price_from_bin(bin_step, id) > price {
+        id -= 1;
+    }
+    while price_from_bin(bin_step, id + 1) <= price {
+        id += 1;
+    }
+
+    id
+}
+
+/// returns 10^exp
+fn pow10(exp: u32) -> U256 {
+    U256::from(10u8).pow(exp)
+}
+
+/// Converts a price between the raw amounts of the tokens to a human price: the amount of whole x tokens that 1 whole y token is worth.
+///
+/// Both prices are in 128.128 fixed point. Rounds down, returns `None` if the price does not fit in 256 bits.
+pub fn price_to_human(price: U256, decimals_x: u32, decimals_y: u32) -> Option<U256> {
+    if decimals_y >= decimals_x {
+        price.checked_mul(pow10(decimals_y - decimals_x))
+    } else {
+        Some(price / pow10(decimals_x - decimals_y))
+    }
+}
+
+/// Converts a human price, the amount of whole x tokens that 1 whole y token is worth, to a price between the raw amounts of the tokens.
+///
+/// Both prices are in 128.128 fixed point. Rounds down, returns `None` if the price does not fit in 256 bits.
+pub fn price_from_human(human_price: U256, decimals_x: u32, decimals_y: u32) -> Option<U256> {
+    if decimals_x >= decimals_y {
+        human_price.checked_mul(pow10(decimals_x - decimals_y))
+    } else {
+        Some(human_price / pow10(decimals_y - decimals_x))
+    }
+}
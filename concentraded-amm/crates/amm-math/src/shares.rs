@@ -0,0 +1,145 @@
+use ethnum::I256;
+use core::ops::RangeInclusive;
+use crate::math::{downscale_floor, mul_div_floor, mul_div_floor_i128, upscale, BONE};
+
+/// returns the shares to mint for depositing `in_amount` in a bin with `bin_shares` shares and `total_reserve` of both tokens.
+///
+/// The first deposit gets one share per token, after that the shares are proportional to the reserves. Rounds down.
+pub fn shares_to_mint(in_amount: i128, bin_shares: i128, total_reserve: i128) -> i128 {
+    if bin_shares == 0 {
+        in_amount
+    } else {
+        mul_div_floor_i128(in_amount, bin_shares, total_reserve)
+    }
+}
+
+/// Splits a deposit of `amount` in the active bin over x and y, in the same ratio as the reserves of the bin.
+///
+/// An empty bin gets half of each, otherwise x is rounded down.
+/// This prevents depositing in any ratio and withdrawing for a trade without fees.
+///
+/// returns (x_amount, y_amount)
+pub fn active_bin_deposit(amount: i128, reserve_x: i128, reserve_y: i128) -> (i128, i128) {
+    let total_reserve = reserve_x + reserve_y;
+
+    if total_reserve == 0 {
+        let half_amount = amount / 2;
+        // handle odd amounts
+        (half_amount, amount - half_amount)
+    } else {
+        let x = downscale_floor(
+            mul_div_floor(upscale(amount, BONE), upscale(reserve_x, BONE), upscale(total_reserve, BONE)),
+            BONE,
+        )
+        .expect("Math overflow");
+        (x, amount - x)
+    }
+}
+
+/// returns the amounts to withdraw for burning `shares` of a bin with `bin_shares` shares: (x_amount, y_amount)
+///
+/// The part of the bin is calculated first with 18 decimals and then applied to each reserve, both are rounded down.
+pub fn amounts_to_remove(shares: i128, bin_shares: i128, reserve_x: i128, reserve_y: i128) -> (i128, i128) {
+    let bone = I256::from(BONE);
+    let removal_ratio = mul_div_floor(upscale(shares, BONE), bone, upscale(bin_shares, BONE));
+
+    let remove = |reserve: i128| {
+        downscale_floor(mul_div_floor(removal_ratio, upscale(reserve, BONE), bone), BONE).expect("Math overflow")
+    };
+
+    (remove(reserve_x), remove(reserve_y))
+}
+
+/// How liquidity is spread over the bins around the active bin, like `LiquidityShape` of the contract.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Shape {
+    /// The same weight in every bin.
+    Spot,
+    /// Most of the liquidity around the active bin, decreasing towards the edges.
+    Curve,
+    /// Most of the liquidity at the edges, decreasing towards the active bin.
+    BidAsk,
+}
+
+/// Spreads amounts of x and y over a range of bins around the active bin.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Distribution {
+    pub shape: Shape,
+    /// the offsets from the active bin of the lowest and highest bins of the range
+    pub lower: i32,
+    pub upper: i32,
+    /// the sums of the weights of the bins in the range that get x and y, the active bin gets both
+    weight_x: i128,
+    weight_y: i128,
+}
+
+impl Distribution {
+    /// Spreads over the bins in `[-width, width]`, like `rebalance_position` of the contract.
+    pub fn new(shape: Shape, width: u32) -> Self {
+        Distribution::range(shape, -(width as i32), width as i32)
+    }
+
+    /// Spreads over the bins with offsets in `[lower, upper]`. The range does not have to contain the active bin.
+    ///
+    /// The shapes are centered on the active bin, so `Curve` has the most liquidity in the bins of the range that are closest to the price.
+    pub fn range(shape: Shape, lower: i32, upper: i32) -> Self {
+        assert!(lower <= upper, "Invalid range");
+
+        let mut distribution = Distribution { shape, lower, upper, weight_x: 0, weight_y: 0 };
+        distribution.weight_x = (lower..=upper.min(0)).map(|offset| distribution.weight(offset)).sum();
+        distribution.weight_y = (lower.max(0)..=upper).map(|offset| distribution.weight(offset)).sum();
+        distribution
+    }
+
+    /// the offsets of the bins of the range, in ascending order
+    pub fn offsets(&self) -> RangeInclusive<i32> {
+        self.lower..=self.upper
+    }
+
+    pub fn weight(&self, offset: i32) -> i128 {
+        let distance = offset.unsigned_abs() as i128;
+        let width = self.upper.max(-self.lower).max(0) as i128;
+
+        match self.shape {
+            Shape::Spot => 1,
+            Shape::Curve => width + 1 - distance,
+            Shape::BidAsk => distance + 1,
+        }
+    }
+
+    /// returns the amount to deposit in the bin at `offset` from the active bin, which has `active_reserve_x` and `active_reserve_y`.
+    ///
+    /// `amount_x` is divided over the active bin and the bins to the left of it, and `amount_y` over the active bin and the bins to the right of it.
+    /// Deposits in the active bin follow the current distribution of the bin, so only the part of the x and y amounts that matches it is deposited there.
+    ///
+    /// The amounts are rounded down, so the sum over all offsets may be less than the amounts passed in.
+    pub fn amount(&self, offset: i32, amount_x: i128, amount_y: i128, active_reserve_x: i128, active_reserve_y: i128) -> i128 {
+        if !self.offsets().contains(&offset) {
+            return 0;
+        }
+
+        let weight = self.weight(offset);
+        let max_x = if offset <= 0 { mul_div_floor_i128(amount_x, weight, self.weight_x) } else { 0 };
+        let max_y = if offset >= 0 { mul_div_floor_i128(amount_y, weight, self.weight_y) } else { 0 };
+
+        if offset < 0 {
+            max_x
+        } else if offset > 0 {
+            max_y
+        } else {
+            let total_reserve = active_reserve_x + active_reserve_y;
+
+            if total_reserve == 0 {
+                // first deposit is split 50/50
+                2 * max_x.min(max_y)
+            } else if active_reserve_x == 0 {
+                max_y
+            } else if active_reserve_y == 0 {
+                max_x
+            } else {
+                mul_div_floor_i128(max_x, total_reserve, active_reserve_x)
+                    .min(mul_div_floor_i128(max_y, total_reserve, active_reserve_y))
+            }
+        }
+    }
+}
@@ -0,0 +1,138 @@
+use ethnum::U256;
+use crate::constants::MAX_BPS;
+use crate::math::mul_div_floor_i128;
+use crate::price::{get_amount_in, get_amount_out, price_from_bin};
+
+/// The result of swapping against the reserve of one bin.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ReserveSwap {
+    /// the amount of the input token that was used, including the fee. This is added to the reserve.
+    pub amount_in: i128,
+    /// the amount of the output token that was taken from the reserve
+    pub amount_out: i128,
+}
+
+impl ReserveSwap {
+    /// returns the part of `amount_in` that is the fee and stays with the LPs of the bin.
+    /// Same rounding as `swap_against_reserve`, the input without the fee is rounded down.
+    pub fn fee(&self, fee: u32) -> i128 {
+        self.amount_in - mul_div_floor_i128(self.amount_in, (MAX_BPS - fee) as i128, MAX_BPS as i128)
+    }
+}
+
+/// Swaps as much as possible of `amount_in_remaining` against `reserve_out` at `price`.
+///
+/// The fee is taken from the input, all of the input goes to the reserve.
+/// The output is rounded down and the input that is needed to empty the reserve is rounded up.
+pub fn swap_against_reserve(price: U256, fee: u32, is_x_in: bool, amount_in_remaining: i128, reserve_out: i128) -> ReserveSwap {
+    let max_bps = U256::from(MAX_BPS);
+    let fee_complement = U256::from(MAX_BPS - fee);
+
+    // the input that is needed to get all of the reserve, first without and then with the fee
+    let max_amount_in = get_amount_in(price, reserve_out as u128, is_x_in);
+    let max_amount_in = if max_amount_in > U256::from(i128::MAX as u128) {
+        None
+    } else {
+        Some((max_amount_in * max_bps + fee_complement - 1) / fee_complement)
+    };
+
+    match max_amount_in {
+        // we use all of the reserve
+        Some(max_amount_in) if max_amount_in <= U256::from(amount_in_remaining as u128) => ReserveSwap {
+            amount_in: max_amount_in.as_i128(),
+            amount_out: reserve_out,
+        },
+        // we can finish the trade
+        _ => {
+            let amount_in_without_fee = (U256::from(amount_in_remaining as u128) * fee_complement / max_bps).as_u128();
+            let amount_out = get_amount_out(price, amount_in_without_fee, is_x_in).min(U256::from(reserve_out as u128));
+
+            ReserveSwap {
+                amount_in: amount_in_remaining,
+                amount_out: amount_out.as_i128(),
+            }
+        }
+    }
+}
+
+/// Access to the bins for `swap_bins`. The contract implements it on top of storage and `PoolState` in memory.
+pub trait BinWalk {
+    /// returns the amounts of the token that is bought in `bin_id`: (LP reserve, unsold limit orders)
+    fn liquidity_out(&mut self, bin_id: i32, is_x_in: bool) -> (i128, i128);
+
+    /// `swapped.amount_in` of the sold token is added to the reserve of `bin_id` and `swapped.amount_out` of the bought token is taken from it.
+    fn swap_reserve(&mut self, bin_id: i32, is_x_in: bool, swapped: ReserveSwap);
+
+    /// `swapped.amount_out` of the limit orders in `bin_id` is sold for `swapped.amount_in`.
+    fn swap_orders(&mut self, bin_id: i32, is_x_in: bool, swapped: ReserveSwap);
+
+    /// returns the next bin after `bin_id`, in the direction of `step`, that may have liquidity.
+    ///
+    /// Bins without liquidity may be returned, they are skipped. `None` means there is no liquidity left in that direction.
+    fn next_bin(&mut self, bin_id: i32, step: i32) -> Option<i32>;
+}
+
+/// The result of `swap_bins`.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct SwapResult {
+    /// the amount of the other token that the swap produced
+    pub amount_out: i128,
+    /// the last bin that was swapped against, this becomes the active bin
+    pub active_bin: i32,
+    /// the part of the input that went to the LPs, the rest went to limit orders
+    pub bins_in: i128,
+    /// the part of the output that came from the LPs, the rest came from limit orders
+    pub bins_out: i128,
+}
+
+/// Swaps `amount_in` against the bins, starting at `active_bin`. x is sold if `is_x_in`, otherwise y.
+///
+/// In each bin we first swap against the liquidity of the LPs and then against the limit orders.
+/// Selling x moves to the bins on the right, selling y to the bins on the left.
+///
+/// returns `None` if there is not enough liquidity to swap all of `amount_in`.
+pub fn swap_bins<B: BinWalk>(bins: &mut B, bin_step: u32, fee: u32, active_bin: i32, amount_in: i128, is_x_in: bool) -> Option<SwapResult> {
+    let step = if is_x_in { 1 } else { -1 };
+
+    let mut result = SwapResult { active_bin, ..SwapResult::default() };
+    let mut amount_in_remaining = amount_in;
+    let mut bin_id = active_bin;
+
+    loop {
+        let (reserve_out, orders_out) = bins.liquidity_out(bin_id, is_x_in);
+
+        // here we check that there is liq for the swap
+        if reserve_out > 0 || orders_out > 0 {
+            result.active_bin = bin_id;
+            let price = price_from_bin(bin_step, bin_id);
+
+            // here we swap using the current bin
+            if reserve_out > 0 {
+                let swapped = swap_against_reserve(price, fee, is_x_in, amount_in_remaining, reserve_out);
+
+                amount_in_remaining -= swapped.amount_in;
+                result.amount_out += swapped.amount_out;
+                result.bins_in += swapped.amount_in;
+                result.bins_out += swapped.amount_out;
+
+                bins.swap_reserve(bin_id, is_x_in, swapped);
+            }
+
+            // the orders are filled once the liquidity of the bin is used
+            if orders_out > 0 && amount_in_remaining > 0 {
+                let swapped = swap_against_reserve(price, fee, is_x_in, amount_in_remaining, orders_out);
+
+                amount_in_remaining -= swapped.amount_in;
+                result.amount_out += swapped.amount_out;
+
+                bins.swap_orders(bin_id, is_x_in, swapped);
+            }
+
+            if amount_in_remaining == 0 {
+                return Some(result);
+            }
+        }
+
+        bin_id = bins.next_bin(bin_id, step)?;
+    }
+}
@@ -0,0 +1,164 @@
+#![cfg(test)]
+// amounts are written as `whole_fraction` with 7 decimals, like the stellar assets.
+#![allow(clippy::inconsistent_digit_grouping)]
+use ethnum::{I256, U256};
+use crate::math::{downscale_floor, log2, mul_div_floor, upscale, BONE};
+use crate::pool::{BinState, OrderState, PoolState, SimError};
+use crate::price::{get_amount_in, get_amount_out, price_from_bin};
+use crate::shares::{active_bin_deposit, amounts_to_remove, shares_to_mint, Distribution, Shape};
+
+#[test]
+fn scaling_rounds_down() {
+    assert_eq!(Some(1), downscale_floor(upscale(15, BONE / 10), BONE));
+    assert_eq!(Some(-2), downscale_floor(upscale(-15, BONE / 10), BONE));
+    assert_eq!(None, downscale_floor(upscale(i128::MAX, 2), 1));
+    assert_eq!(I256::from(3), mul_div_floor(I256::from(10), I256::from(2), I256::from(6)));
+}
+
+#[test]
+fn log2_is_precise() {
+    assert_eq!(0, log2(BONE));
+    assert_eq!(3 * BONE, log2(8 * BONE));
+    assert_eq!(-2 * BONE, log2(BONE / 4));
+    // log2(1.5) = 0.584962500721156181...
+    let error = 584_962_500_721_156_181 - log2(3 * BONE / 2);
+    assert!((0..100).contains(&error));
+}
+
+#[test]
+fn liquidity_math() {
+    assert_eq!((5, 6), active_bin_deposit(11, 0, 0));
+    // 1/3 of the reserves is x, rounded down
+    assert_eq!((3, 7), active_bin_deposit(10, 100, 200));
+
+    assert_eq!(1_000_000_0, shares_to_mint(1_000_000_0, 0, 0));
+    assert_eq!(500_000_0, shares_to_mint(1_000_000_0, 1_000_000_0, 2_000_000_0));
+
+    assert_eq!((3, 33), amounts_to_remove(1, 3, 10, 100));
+    assert_eq!((10, 100), amounts_to_remove(3, 3, 10, 100));
+}
+
+/// 10 y in each of the bins `[1, 3]`, 10 x in each of the bins `[-3, -1]` and both in the active bin 0
+fn create_pool(fee: u32) -> PoolState {
+    let mut pool = PoolState::new(10, fee, 0);
+    for bin_id in -3..=3 {
+        pool.deposit(bin_id, 10_000_000_0).unwrap();
+    }
+    pool
+}
+
+#[test]
+fn swap_walks_the_bins() {
+    let mut pool = create_pool(0);
+
+    // the 5 y of the active bin and the 10 y of bin 1
+    let amount_in = get_amount_in(price_from_bin(10, 0), 5_000_000_0, true) + get_amount_in(price_from_bin(10, 1), 10_000_000_0, true);
+    let amount_in = amount_in.as_i128();
+
+    let result = pool.swap_exact_amount_in(amount_in, true).unwrap();
+    assert_eq!(15_000_000_0, result.amount_out);
+    assert_eq!(1, result.active_bin);
+    assert_eq!(1, pool.active_bin);
+    assert_eq!(BinState { reserve_x: 10_010_000_0, reserve_y: 0, shares: 10_000_000_0 }, pool.bin(1));
+    assert_eq!((35_000_000_0 + amount_in, 20_000_000_0), pool.total_reserves());
+
+    // one more unit of x moves to bin 2
+    let result = pool.swap_exact_amount_in(1, true).unwrap();
+    assert_eq!(0, result.amount_out);
+    assert_eq!(2, pool.active_bin);
+}
+
+#[test]
+fn swap_takes_the_fee_from_the_input() {
+    let mut pool = create_pool(100);
+
+    // 1% of 1 x is the fee, the rest is worth 0.99 y in the active bin
+    let result = pool.swap_exact_amount_in(1_000_000_0, true).unwrap();
+    assert_eq!(get_amount_out(price_from_bin(10, 0), 990_000_0, true).as_i128(), result.amount_out);
+    // the fee stays in the bin
+    assert_eq!(6_000_000_0, pool.bin(0).reserve_x);
+}
+
+#[test]
+fn quote_does_not_change_the_state() {
+    let mut pool = create_pool(10);
+    let before = pool.clone();
+
+    let quote = pool.quote_exact_amount_in(20_000_000_0, false).unwrap();
+    assert_eq!(before, pool);
+    assert_eq!(Ok(quote), pool.swap_exact_amount_in(20_000_000_0, false));
+    assert!(quote.active_bin < 0);
+
+    let before = pool.clone();
+    assert_eq!(Err(SimError::NotEnoughLiquidity), pool.swap_exact_amount_in(100_000_000_0, false));
+    assert_eq!(Err(SimError::InvalidAmount), pool.swap_exact_amount_in(0, false));
+    assert_eq!(before, pool);
+}
+
+#[test]
+fn orders_are_filled_after_the_liquidity_of_the_bin() {
+    let mut pool = create_pool(0);
+    // bin 5 only has orders, bin 2 has both
+    pool.load_orders(5, false, 1_000_000_0, 0);
+    pool.load_orders(2, false, 1_000_000_0, 0);
+    // orders that sell x are not used when x is sold
+    pool.load_orders(4, true, 1_000_000_0, 0);
+
+    // the 35 y of the LPs, the order in bin 2 and a part of the order in bin 5
+    let result = pool.swap_exact_amount_in(36_500_000_0, true).unwrap();
+    assert_eq!(5, result.active_bin);
+    assert_eq!(35_000_000_0, result.bins_out);
+    assert!(result.amount_out > result.bins_out);
+    assert!(!pool.orders.contains_key(&2));
+    assert_eq!(Some(&OrderState { sell_x: true, remaining: 1_000_000_0, proceeds: 0 }), pool.orders.get(&4));
+}
+
+#[test]
+fn remove_gives_back_at_most_the_deposit() {
+    let mut pool = create_pool(0);
+
+    let (x, y, shares) = pool.deposit(0, 1_000_000_1).unwrap();
+    assert_eq!((500_000_0, 500_000_1), (x, y));
+
+    let (x_out, y_out) = pool.remove(0, shares).unwrap();
+    assert!(x_out <= x && y_out <= y);
+    assert_eq!(Err(SimError::InsufficientShares), pool.remove(0, 10_000_000_1));
+    assert_eq!(Err(SimError::InsufficientShares), pool.remove(10, 1));
+
+    pool.remove(3, 10_000_000_0).unwrap();
+    assert!(!pool.bins.contains_key(&3));
+}
+
+#[test]
+fn load_from_snapshot() {
+    let mut pool = PoolState::new(10, 0, 0);
+    pool.load_bin(0, 10, 10);
+    pool.load_bin(1, 0, 0);
+    pool.load_shares(0, 20);
+
+    assert_eq!(BinState { reserve_x: 10, reserve_y: 10, shares: 20 }, pool.bin(0));
+    assert_eq!(1, pool.bins.len());
+    assert_eq!(U256::ONE << 128u32, price_from_bin(pool.bin_step, pool.active_bin));
+}
+
+#[test]
+fn distributions() {
+    let curve = Distribution::new(Shape::Curve, 2);
+    assert_eq!([1, 2, 3, 2, 1], [-2, -1, 0, 1, 2].map(|offset| curve.weight(offset)));
+    let bid_ask = Distribution::new(Shape::BidAsk, 2);
+    assert_eq!([3, 2, 1, 2, 3], [-2, -1, 0, 1, 2].map(|offset| bid_ask.weight(offset)));
+
+    // the weights of one side are 1 + 2 + 3
+    assert_eq!(100, curve.amount(-2, 600, 1200, 0, 0));
+    assert_eq!(400, curve.amount(1, 600, 1200, 0, 0));
+    // an empty active bin gets the same amount of x and y
+    assert_eq!(600, curve.amount(0, 600, 1200, 0, 0));
+    // otherwise only the part that matches the bin is deposited, here 1/3 x
+    assert_eq!(900, curve.amount(0, 600, 1200, 100, 200));
+    assert_eq!(600, curve.amount(0, 600, 1200, 0, 200));
+    assert_eq!(0, curve.amount(3, 600, 1200, 0, 0));
+
+    // a range above the price only gets y, the weights are 2 + 1
+    let above = Distribution::range(Shape::Curve, 1, 2);
+    assert_eq!([0, 800, 400], [0, 1, 2].map(|offset| above.amount(offset, 600, 1200, 0, 0)));
+}
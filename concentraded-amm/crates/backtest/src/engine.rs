@@ -0,0 +1,323 @@
+use std::collections::BTreeMap;
+use amm_math::math::mul_div_floor_i128;
+use amm_math::pool::{PoolState, SimError};
+use amm_math::price::{get_max_bin_id, id_from_price, price_from_bin, price_from_human, SCALE_OFFSET};
+use amm_math::shares::amounts_to_remove;
+use amm_math::swap::{swap_against_reserve, swap_bins, BinWalk, ReserveSwap, SwapResult};
+use ethnum::U256;
+use crate::report::{InventoryPoint, Report};
+use crate::series::{Event, Side};
+use crate::strategy::Strategy;
+
+/// The pool and the tokens of the LP at the start of a backtest.
+#[derive(Clone, Copy, Debug)]
+pub struct Settings {
+    pub bin_step: u32,
+    /// the swap fee in basis points
+    pub fee: u32,
+    pub decimals_x: u32,
+    pub decimals_y: u32,
+    /// the tokens of the LP, in the smallest unit of the tokens
+    pub amount_x: i128,
+    pub amount_y: i128,
+    /// the human price that the pool starts at. If not set, the first price of the series is used.
+    pub start_price: Option<f64>,
+}
+
+/// Converts a positive float to a 128.128 fixed point number, keeping the 53 bits of precision of the float.
+pub fn to_fixed(price: f64) -> Option<U256> {
+    if !(price > 0.0 && price.is_finite()) {
+        return None;
+    }
+
+    // price = mantissa * 2^(exponent - 52), with the mantissa in [2^52, 2^53)
+    let exponent = price.log2().floor() as i32;
+    let mantissa = U256::from((price / 2f64.powi(exponent) * 2f64.powi(52)) as u64);
+    let shift = SCALE_OFFSET as i32 + exponent - 52;
+
+    match shift {
+        0.. if shift < 203 => Some(mantissa << shift as u32),
+        0.. => None,
+        _ => Some(mantissa >> shift.unsigned_abs()),
+    }
+}
+
+pub fn to_f64(price: U256) -> f64 {
+    price.as_f64() / 2f64.powi(SCALE_OFFSET as i32)
+}
+
+/// Wraps the pool to credit the position with its part of the fees, in every bin that a swap goes through.
+///
+/// The fee is the part of the input of a bin that is not swapped. It stays in the bin and is divided over the shares of the bin.
+struct FeeTracker<'a> {
+    pool: &'a mut PoolState,
+    position: &'a BTreeMap<i32, i128>,
+    fees_x: i128,
+    fees_y: i128,
+}
+
+impl BinWalk for FeeTracker<'_> {
+    fn liquidity_out(&mut self, bin_id: i32, is_x_in: bool) -> (i128, i128) {
+        self.pool.liquidity_out(bin_id, is_x_in)
+    }
+
+    fn swap_reserve(&mut self, bin_id: i32, is_x_in: bool, swapped: ReserveSwap) {
+        let shares = self.position.get(&bin_id).copied().unwrap_or(0);
+
+        if shares > 0 {
+            let earned = mul_div_floor_i128(swapped.fee(self.pool.fee), shares, self.pool.bin(bin_id).shares);
+
+            if is_x_in {
+                self.fees_x += earned;
+            } else {
+                self.fees_y += earned;
+            }
+        }
+
+        self.pool.swap_reserve(bin_id, is_x_in, swapped)
+    }
+
+    fn swap_orders(&mut self, bin_id: i32, is_x_in: bool, swapped: ReserveSwap) {
+        self.pool.swap_orders(bin_id, is_x_in, swapped)
+    }
+
+    fn next_bin(&mut self, bin_id: i32, step: i32) -> Option<i32> {
+        self.pool.next_bin(bin_id, step)
+    }
+}
+
+/// Replays a series against an in-memory pool that only has the liquidity of the strategy.
+///
+/// Deposits, removals and swaps go through `PoolState`, so they are rounded exactly like the contract.
+pub struct Backtest<'a> {
+    settings: Settings,
+    strategy: &'a dyn Strategy,
+    pool: PoolState,
+    /// the shares of the LP in each bin
+    position: BTreeMap<i32, i128>,
+    /// the active bin when the position was deposited
+    center: i32,
+    /// the tokens of the LP that are not in the pool
+    idle_x: i128,
+    idle_y: i128,
+    /// the market price of y in x, between the raw amounts
+    price: f64,
+    report: Report,
+}
+
+impl<'a> Backtest<'a> {
+    pub fn new(settings: Settings, strategy: &'a dyn Strategy, start_price: f64) -> Result<Self, String> {
+        let active_bin = bin_for_price(&settings, start_price)?;
+
+        let mut backtest = Backtest {
+            settings,
+            strategy,
+            pool: PoolState::new(settings.bin_step, settings.fee, active_bin),
+            position: BTreeMap::new(),
+            center: active_bin,
+            idle_x: settings.amount_x,
+            idle_y: settings.amount_y,
+            price: raw_price(&settings, start_price),
+            report: Report::new(strategy.name()),
+        };
+
+        backtest.report.start_value = backtest.value(settings.amount_x, settings.amount_y);
+        backtest.deploy()?;
+
+        Ok(backtest)
+    }
+
+    /// Runs `strategy` over `events` and returns the report.
+    pub fn run(settings: Settings, strategy: &dyn Strategy, events: &[Event]) -> Result<Report, String> {
+        let start_price = settings
+            .start_price
+            .or_else(|| events.iter().find_map(|event| match event {
+                Event::Price { price, .. } => Some(*price),
+                _ => None,
+            }))
+            .ok_or("the series has no prices, set the start price")?;
+
+        let mut backtest = Backtest::new(settings, strategy, start_price)?;
+        for event in events {
+            backtest.step(event)?;
+        }
+
+        Ok(backtest.finish())
+    }
+
+    pub fn pool(&self) -> &PoolState {
+        &self.pool
+    }
+
+    pub fn position(&self) -> &BTreeMap<i32, i128> {
+        &self.position
+    }
+
+    /// Applies one event of the series, rebalances if the strategy wants to and records the inventory.
+    pub fn step(&mut self, event: &Event) -> Result<(), String> {
+        self.report.events += 1;
+
+        match *event {
+            Event::Price { price, .. } => {
+                self.arbitrage(bin_for_price(&self.settings, price)?);
+                self.price = raw_price(&self.settings, price);
+            }
+            Event::Trade { side, amount, .. } => {
+                match self.swap(amount, side == Side::X) {
+                    Ok(_) => self.report.trades += 1,
+                    Err(_) => self.report.failed_trades += 1,
+                }
+                // without a price series the pool is the market
+                self.price = to_f64(price_from_bin(self.settings.bin_step, self.pool.active_bin));
+            }
+        }
+
+        if self.strategy.should_rebalance(self.center, self.pool.active_bin) {
+            self.withdraw()?;
+            self.deploy()?;
+            self.report.rebalances += 1;
+        }
+
+        let (amount_x, amount_y) = self.inventory();
+        self.report.inventory.push(InventoryPoint {
+            time: event.time(),
+            active_bin: self.pool.active_bin,
+            price: self.price,
+            amount_x,
+            amount_y,
+            value: self.value(amount_x, amount_y),
+        });
+
+        Ok(())
+    }
+
+    /// returns the tokens of the LP, in the position and outside of it: (amount_x, amount_y)
+    pub fn inventory(&self) -> (i128, i128) {
+        self.position.iter().fold((self.idle_x, self.idle_y), |(x, y), (bin_id, shares)| {
+            let bin = self.pool.bin(*bin_id);
+            let (amount_x, amount_y) = amounts_to_remove(*shares, bin.shares, bin.reserve_x, bin.reserve_y);
+            (x + amount_x, y + amount_y)
+        })
+    }
+
+    pub fn finish(mut self) -> Report {
+        let (amount_x, amount_y) = self.inventory();
+
+        self.report.final_x = amount_x;
+        self.report.final_y = amount_y;
+        self.report.end_value = self.value(amount_x, amount_y);
+        self.report.hold_value = self.value(self.settings.amount_x, self.settings.amount_y);
+        self.report.fees_value = self.value(self.report.fees_x, self.report.fees_y);
+        self.report.impermanent_loss = self.report.end_value - self.report.fees_value - self.report.hold_value;
+
+        self.report
+    }
+
+    /// the value of the amounts in x at the market price
+    fn value(&self, amount_x: i128, amount_y: i128) -> f64 {
+        amount_x as f64 + amount_y as f64 * self.price
+    }
+
+    /// Swaps like `swap_exact_amount_in`, and credits the position with its part of the fees.
+    fn swap(&mut self, amount_in: i128, is_x_in: bool) -> Result<SwapResult, SimError> {
+        let mut pool = self.pool.clone();
+        let mut tracker = FeeTracker { pool: &mut pool, position: &self.position, fees_x: 0, fees_y: 0 };
+
+        let result = swap_bins(&mut tracker, self.pool.bin_step, self.pool.fee, self.pool.active_bin, amount_in, is_x_in)
+            .ok_or(SimError::NotEnoughLiquidity)?;
+
+        self.report.fees_x += tracker.fees_x;
+        self.report.fees_y += tracker.fees_y;
+        pool.active_bin = result.active_bin;
+        self.pool = pool;
+
+        Ok(result)
+    }
+
+    /// Buys all of the liquidity between the active bin and `target_bin` that is cheaper than the market, so the pool is at the market price.
+    ///
+    /// The input is the exact amount that is needed to empty those bins, including the fee.
+    /// The active bin is then set to `target_bin`, like the pool would be after the next swap at the market price.
+    fn arbitrage(&mut self, target_bin: i32) {
+        let active_bin = self.pool.active_bin;
+        let is_x_in = target_bin > active_bin;
+        let bins = if is_x_in { active_bin..target_bin } else { target_bin + 1..active_bin + 1 };
+
+        let amount_in: i128 = self
+            .pool
+            .bins
+            .range(bins)
+            .map(|(bin_id, bin)| {
+                let reserve_out = if is_x_in { bin.reserve_y } else { bin.reserve_x };
+                if reserve_out == 0 {
+                    return 0;
+                }
+                let price = price_from_bin(self.pool.bin_step, *bin_id);
+                swap_against_reserve(price, self.pool.fee, is_x_in, i128::MAX, reserve_out).amount_in
+            })
+            .sum();
+
+        if amount_in > 0 {
+            self.swap(amount_in, is_x_in).expect("The arbitrage only buys liquidity that is in the pool");
+            self.report.arbitrages += 1;
+        }
+
+        self.pool.active_bin = target_bin;
+    }
+
+    /// Deposits all of the idle tokens around the active bin, with the distribution of the strategy.
+    fn deploy(&mut self) -> Result<(), String> {
+        let distribution = self.strategy.distribution();
+        let active_bin = self.pool.active_bin;
+        let active = self.pool.bin(active_bin);
+        let max_bin_id = get_max_bin_id(self.settings.bin_step);
+
+        // the amounts are calculated before depositing, like `shape_liquidity` of the contract
+        let deposits: Vec<(i32, i128)> = distribution
+            .offsets()
+            .map(|offset| (active_bin + offset, distribution.amount(offset, self.idle_x, self.idle_y, active.reserve_x, active.reserve_y)))
+            .filter(|(bin_id, amount)| *amount > 0 && bin_id.abs() <= max_bin_id)
+            .collect();
+
+        for (bin_id, amount) in deposits {
+            let (amount_x, amount_y, shares) = self.pool.deposit(bin_id, amount).map_err(|e| e.to_string())?;
+            self.idle_x -= amount_x;
+            self.idle_y -= amount_y;
+            *self.position.entry(bin_id).or_default() += shares;
+        }
+        self.position.retain(|_, shares| *shares > 0);
+        self.center = active_bin;
+
+        Ok(())
+    }
+
+    /// Removes all of the liquidity of the position.
+    fn withdraw(&mut self) -> Result<(), String> {
+        for (bin_id, shares) in std::mem::take(&mut self.position) {
+            let (amount_x, amount_y) = self.pool.remove(bin_id, shares).map_err(|e| e.to_string())?;
+            self.idle_x += amount_x;
+            self.idle_y += amount_y;
+        }
+
+        Ok(())
+    }
+}
+
+/// returns the bin of a human price, with the rounding of `get_id_from_human_price` of the contract
+fn bin_for_price(settings: &Settings, human_price: f64) -> Result<i32, String> {
+    let max_bin_id = get_max_bin_id(settings.bin_step);
+    let price = to_fixed(human_price)
+        .and_then(|price| price_from_human(price, settings.decimals_x, settings.decimals_y))
+        .ok_or_else(|| format!("invalid price {human_price}"))?;
+
+    if price < price_from_bin(settings.bin_step, -max_bin_id) || price >= price_from_bin(settings.bin_step, max_bin_id) {
+        return Err(format!("price {human_price} is outside of the supported bins"));
+    }
+
+    Ok(id_from_price(settings.bin_step, price))
+}
+
+/// returns the price of y in x between the raw amounts, for the values in the report
+fn raw_price(settings: &Settings, human_price: f64) -> f64 {
+    human_price * 10f64.powi(settings.decimals_x as i32 - settings.decimals_y as i32)
+}
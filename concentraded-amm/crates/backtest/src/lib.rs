@@ -0,0 +1,9 @@
+//! Replays a price or trade series against an in-memory pool and reports how an LP strategy did.
+//!
+//! The pool is the `PoolState` of `amm-math`, so shares, fees and swaps are rounded exactly like the contract.
+pub mod engine;
+pub mod report;
+pub mod series;
+pub mod strategy;
+
+mod test;
@@ -0,0 +1,100 @@
+//! Runs a backtest from the command line and prints the report.
+//!
+//! ```text
+//! backtest <series.csv|series.json> [--strategy fixed|recenter|bid-ask] [--width 10] [--trigger 5]
+//!     [--bin-step 10] [--fee 30] [--decimals-x 7] [--decimals-y 7] [--amount-x N] [--amount-y N] [--start-price P] [--json]
+//! ```
+use std::path::PathBuf;
+use std::process::ExitCode;
+use backtest::engine::{Backtest, Settings};
+use backtest::series;
+use backtest::strategy::{BidAsk, FixedRange, Recenter, Strategy};
+
+struct Args {
+    series: PathBuf,
+    strategy: String,
+    width: u32,
+    trigger: Option<u32>,
+    settings: Settings,
+    json: bool,
+}
+
+fn parse_value<T: std::str::FromStr>(flag: &str, value: Option<String>) -> Result<T, String> {
+    value
+        .ok_or_else(|| format!("{flag} needs a value"))?
+        .parse()
+        .map_err(|_| format!("invalid value for {flag}"))
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Args, String> {
+    let mut series = None;
+    let mut parsed = Args {
+        series: PathBuf::new(),
+        strategy: "fixed".into(),
+        width: 10,
+        trigger: None,
+        settings: Settings {
+            bin_step: 10,
+            fee: 30,
+            decimals_x: 7,
+            decimals_y: 7,
+            amount_x: 10_000_000_000_000,
+            amount_y: 10_000_000_000_000,
+            start_price: None,
+        },
+        json: false,
+    };
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--strategy" => parsed.strategy = parse_value(&arg, args.next())?,
+            "--width" => parsed.width = parse_value(&arg, args.next())?,
+            "--trigger" => parsed.trigger = Some(parse_value(&arg, args.next())?),
+            "--bin-step" => parsed.settings.bin_step = parse_value(&arg, args.next())?,
+            "--fee" => parsed.settings.fee = parse_value(&arg, args.next())?,
+            "--decimals-x" => parsed.settings.decimals_x = parse_value(&arg, args.next())?,
+            "--decimals-y" => parsed.settings.decimals_y = parse_value(&arg, args.next())?,
+            "--amount-x" => parsed.settings.amount_x = parse_value(&arg, args.next())?,
+            "--amount-y" => parsed.settings.amount_y = parse_value(&arg, args.next())?,
+            "--start-price" => parsed.settings.start_price = Some(parse_value(&arg, args.next())?),
+            "--json" => parsed.json = true,
+            _ if arg.starts_with("--") => return Err(format!("unknown option {arg}")),
+            _ => series = Some(PathBuf::from(arg)),
+        }
+    }
+
+    parsed.series = series.ok_or("missing the series file")?;
+    Ok(parsed)
+}
+
+fn run() -> Result<(), String> {
+    let args = parse_args(std::env::args().skip(1))?;
+
+    let strategy: Box<dyn Strategy> = match args.strategy.as_str() {
+        "fixed" => Box::new(FixedRange { width: args.width }),
+        "recenter" => Box::new(Recenter { width: args.width, trigger: args.trigger.unwrap_or(args.width.div_ceil(2)) }),
+        "bid-ask" => Box::new(BidAsk { width: args.width }),
+        other => return Err(format!("unknown strategy {other}, expected fixed, recenter or bid-ask")),
+    };
+
+    let events = series::load(&args.series)?;
+    let report = Backtest::run(args.settings, strategy.as_ref(), &events)?;
+
+    if args.json {
+        println!("{}", serde_json::to_string_pretty(&report).map_err(|e| e.to_string())?);
+    } else {
+        println!("{report}");
+    }
+
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,65 @@
+use serde::Serialize;
+use std::fmt;
+
+/// The tokens of the LP after a step of the series.
+#[derive(Clone, Copy, Debug, Serialize)]
+pub struct InventoryPoint {
+    pub time: u64,
+    pub active_bin: i32,
+    /// the market price of y in x, between the raw amounts
+    pub price: f64,
+    /// the tokens in the position and outside of it
+    pub amount_x: i128,
+    pub amount_y: i128,
+    /// the value of the tokens in x
+    pub value: f64,
+}
+
+/// The results of a backtest. Token amounts are in the smallest unit of the tokens, values are in the smallest unit of x at the market price.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct Report {
+    pub strategy: String,
+    pub events: usize,
+    pub trades: usize,
+    /// trades that failed because the pool did not have enough liquidity
+    pub failed_trades: usize,
+    /// swaps that moved the pool to the market price
+    pub arbitrages: usize,
+    pub rebalances: usize,
+    /// the fees that the position earned, which stay in the bins and are part of the inventory
+    pub fees_x: i128,
+    pub fees_y: i128,
+    /// the value of the fees at the last price
+    pub fees_value: f64,
+    pub start_value: f64,
+    /// the value of the starting tokens at the last price, if they were not deposited
+    pub hold_value: f64,
+    pub end_value: f64,
+    /// `end_value - fees_value - hold_value`, negative if providing liquidity lost value compared to holding
+    pub impermanent_loss: f64,
+    pub final_x: i128,
+    pub final_y: i128,
+    pub inventory: Vec<InventoryPoint>,
+}
+
+impl Report {
+    pub fn new(strategy: String) -> Self {
+        Report { strategy, ..Report::default() }
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "strategy:          {}", self.strategy)?;
+        writeln!(f, "events:            {}", self.events)?;
+        writeln!(f, "trades:            {} ({} failed)", self.trades, self.failed_trades)?;
+        writeln!(f, "arbitrages:        {}", self.arbitrages)?;
+        writeln!(f, "rebalances:        {}", self.rebalances)?;
+        writeln!(f, "fees earned:       {} x, {} y (value {:.0})", self.fees_x, self.fees_y, self.fees_value)?;
+        writeln!(f, "start value:       {:.0}", self.start_value)?;
+        writeln!(f, "hold value:        {:.0}", self.hold_value)?;
+        writeln!(f, "end value:         {:.0}", self.end_value)?;
+        writeln!(f, "impermanent loss:  {:.0}", self.impermanent_loss)?;
+        write!(f, "final inventory:   {} x, {} y", self.final_x, self.final_y)
+    }
+}
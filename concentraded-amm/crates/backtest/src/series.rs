@@ -0,0 +1,83 @@
+use serde::Deserialize;
+use std::fs;
+use std::path::Path;
+
+/// The token that a trade sells.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Side {
+    X,
+    Y,
+}
+
+/// One step of a series.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Event {
+    /// The market price of y in x, in whole tokens. An arbitrageur moves the pool to this price.
+    Price { time: u64, price: f64 },
+    /// A swap that sells `amount` of the `side` token, in the smallest unit of the token.
+    Trade { time: u64, side: Side, amount: i128 },
+}
+
+impl Event {
+    pub fn time(&self) -> u64 {
+        match self {
+            Event::Price { time, .. } | Event::Trade { time, .. } => *time,
+        }
+    }
+}
+
+/// A row of a csv or json series. A row has either a `price`, or a `side` and an `amount`.
+#[derive(Deserialize)]
+struct Row {
+    time: u64,
+    #[serde(default)]
+    price: Option<f64>,
+    #[serde(default)]
+    side: Option<Side>,
+    #[serde(default)]
+    amount: Option<i128>,
+}
+
+impl Row {
+    fn into_event(self, index: usize) -> Result<Event, String> {
+        match (self.price, self.side, self.amount) {
+            (Some(price), None, None) if price > 0.0 && price.is_finite() => Ok(Event::Price { time: self.time, price }),
+            (None, Some(side), Some(amount)) if amount > 0 => Ok(Event::Trade { time: self.time, side, amount }),
+            _ => Err(format!("row {index}: expected a positive `price`, or a `side` and a positive `amount`")),
+        }
+    }
+}
+
+/// Parses a csv series with a header, for example `time,price` or `time,side,amount`.
+/// Both kinds of rows can be mixed with a `time,price,side,amount` header and empty cells.
+pub fn parse_csv(data: &str) -> Result<Vec<Event>, String> {
+    csv::ReaderBuilder::new()
+        .trim(csv::Trim::All)
+        .from_reader(data.as_bytes())
+        .deserialize::<Row>()
+        .enumerate()
+        .map(|(index, row)| row.map_err(|e| e.to_string())?.into_event(index))
+        .collect()
+}
+
+/// Parses a json array of rows, like `[{"time": 1, "price": 0.5}, {"time": 2, "side": "x", "amount": 1000}]`.
+pub fn parse_json(data: &str) -> Result<Vec<Event>, String> {
+    serde_json::from_str::<Vec<Row>>(data)
+        .map_err(|e| e.to_string())?
+        .into_iter()
+        .enumerate()
+        .map(|(index, row)| row.into_event(index))
+        .collect()
+}
+
+/// Reads a series from a `.csv` or `.json` file.
+pub fn load(path: &Path) -> Result<Vec<Event>, String> {
+    let data = fs::read_to_string(path).map_err(|e| format!("{}: {e}", path.display()))?;
+
+    match path.extension().and_then(|ext| ext.to_str()) {
+        Some("csv") => parse_csv(&data),
+        Some("json") => parse_json(&data),
+        _ => Err(format!("{}: expected a .csv or .json file", path.display())),
+    }
+}
@@ -0,0 +1,74 @@
+use amm_math::shares::{Distribution, Shape};
+
+/// An LP strategy: how liquidity is spread around the active bin, and when it is moved.
+///
+/// A rebalance removes all of the liquidity of the position and deposits it again around the active bin.
+pub trait Strategy {
+    fn name(&self) -> String;
+
+    /// the liquidity that is deposited around the active bin, with all of the tokens that are not in the position
+    fn distribution(&self) -> Distribution;
+
+    /// returns true if the position, which was deposited around `center`, should be moved to `active_bin`
+    fn should_rebalance(&self, center: i32, active_bin: i32) -> bool;
+}
+
+/// The same liquidity in every bin of `[-width, width]`, which is never moved.
+pub struct FixedRange {
+    pub width: u32,
+}
+
+impl Strategy for FixedRange {
+    fn name(&self) -> String {
+        format!("fixed range (width {})", self.width)
+    }
+
+    fn distribution(&self) -> Distribution {
+        Distribution::new(Shape::Spot, self.width)
+    }
+
+    fn should_rebalance(&self, _center: i32, _active_bin: i32) -> bool {
+        false
+    }
+}
+
+/// The same liquidity in every bin of `[-width, width]`, moved to the active bin once the price moved `trigger` bins away from the center.
+pub struct Recenter {
+    pub width: u32,
+    pub trigger: u32,
+}
+
+impl Strategy for Recenter {
+    fn name(&self) -> String {
+        format!("recenter (width {}, trigger {})", self.width, self.trigger)
+    }
+
+    fn distribution(&self) -> Distribution {
+        Distribution::new(Shape::Spot, self.width)
+    }
+
+    fn should_rebalance(&self, center: i32, active_bin: i32) -> bool {
+        active_bin.abs_diff(center) >= self.trigger
+    }
+}
+
+/// Most of the liquidity at the edges of `[-width, width]`, moved to the active bin once the price left the range.
+///
+/// The position buys more the further the price moves, and sells it again when the price comes back.
+pub struct BidAsk {
+    pub width: u32,
+}
+
+impl Strategy for BidAsk {
+    fn name(&self) -> String {
+        format!("bid-ask (width {})", self.width)
+    }
+
+    fn distribution(&self) -> Distribution {
+        Distribution::new(Shape::BidAsk, self.width)
+    }
+
+    fn should_rebalance(&self, center: i32, active_bin: i32) -> bool {
+        active_bin.abs_diff(center) > self.width
+    }
+}
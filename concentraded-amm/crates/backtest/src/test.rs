@@ -0,0 +1,140 @@
+#![cfg(test)]
+// amounts are written as `whole_fraction` with 7 decimals, like the stellar assets.
+#![allow(clippy::inconsistent_digit_grouping)]
+use amm_math::price::SCALE;
+use crate::engine::{to_f64, to_fixed, Backtest, Settings};
+use crate::series::{parse_csv, parse_json, Event, Side};
+use crate::strategy::{BidAsk, FixedRange, Recenter};
+
+fn settings() -> Settings {
+    Settings {
+        bin_step: 10,
+        fee: 30,
+        decimals_x: 7,
+        decimals_y: 7,
+        amount_x: 1_000_000_0000000,
+        amount_y: 1_000_000_0000000,
+        start_price: Some(1.0),
+    }
+}
+
+fn prices(prices: &[f64]) -> Vec<Event> {
+    prices
+        .iter()
+        .enumerate()
+        .map(|(time, price)| Event::Price { time: time as u64, price: *price })
+        .collect()
+}
+
+#[test]
+fn parse_series() {
+    let csv = "time,price,side,amount\n1,0.5,,\n2,,x,1000\n3, 0.25 ,,\n";
+    let expected = vec![
+        Event::Price { time: 1, price: 0.5 },
+        Event::Trade { time: 2, side: Side::X, amount: 1000 },
+        Event::Price { time: 3, price: 0.25 },
+    ];
+    assert_eq!(expected, parse_csv(csv).unwrap());
+    assert_eq!(prices(&[2.0]), parse_csv("time,price\n0,2\n").unwrap());
+
+    let json = r#"[{"time": 1, "price": 0.5}, {"time": 2, "side": "x", "amount": 1000}, {"time": 3, "price": 0.25}]"#;
+    assert_eq!(expected, parse_json(json).unwrap());
+
+    assert!(parse_csv("time,price\n0,-1\n").is_err());
+    assert!(parse_json(r#"[{"time": 1, "side": "y"}]"#).is_err());
+}
+
+#[test]
+fn fixed_point_prices() {
+    assert_eq!(Some(SCALE), to_fixed(1.0));
+    assert_eq!(Some(SCALE * 3 / 2), to_fixed(1.5));
+    assert_eq!(Some(SCALE >> 40), to_fixed(2f64.powi(-40)));
+    assert_eq!(0.123456789, to_f64(to_fixed(0.123456789).unwrap()));
+    assert_eq!(None, to_fixed(0.0));
+}
+
+#[test]
+fn round_trip_without_swaps() {
+    let report = Backtest::run(settings(), &FixedRange { width: 10 }, &prices(&[1.0, 1.0])).unwrap();
+
+    // the deposits and removals round down, but nothing is lost without swaps
+    assert_eq!(1_000_000_0000000, report.final_x);
+    assert_eq!(1_000_000_0000000, report.final_y);
+    assert_eq!(0, report.arbitrages);
+    assert_eq!(0, report.rebalances);
+    assert_eq!(0.0, report.impermanent_loss);
+}
+
+#[test]
+fn fees_of_the_only_lp() {
+    let events = [
+        Event::Trade { time: 0, side: Side::X, amount: 1_000_0000000 },
+        Event::Trade { time: 1, side: Side::Y, amount: 500_0000000 },
+    ];
+    let report = Backtest::run(settings(), &FixedRange { width: 0 }, &events).unwrap();
+
+    // all of the liquidity is in the active bin, so the position gets the whole fee of 0.3%
+    assert_eq!(2, report.trades);
+    assert_eq!(3_0000000, report.fees_x);
+    assert_eq!(1_5000000, report.fees_y);
+    // the price is 1, so the output is the input without the fee
+    assert_eq!(1_000_000_0000000 + 1_000_0000000 - (500_0000000 - 1_5000000), report.final_x);
+    assert_eq!(1_000_000_0000000 + 500_0000000 - (1_000_0000000 - 3_0000000), report.final_y);
+    assert_eq!(2, report.inventory.len());
+}
+
+#[test]
+fn fixed_range_sells_y_when_the_price_goes_up() {
+    let events = prices(&[1.0, 1.005, 1.05]);
+    let report = Backtest::run(settings(), &FixedRange { width: 10 }, &events).unwrap();
+
+    assert_eq!(0, report.rebalances);
+    // the second price moves the pool in the range, the third moves it out of the range
+    assert_eq!(2, report.arbitrages);
+    assert!(report.final_x > 1_000_000_0000000);
+    // only the y that could not be deposited because of the rounding is left
+    assert!(report.final_y <= 10);
+    assert!(report.fees_x > 0);
+    assert_eq!(0, report.fees_y);
+    assert!(report.impermanent_loss < 0.0);
+    assert!(report.end_value < report.hold_value);
+
+    // the inventory is recorded after every event
+    let inventory: Vec<i32> = report.inventory.iter().map(|point| point.active_bin).collect();
+    assert_eq!(vec![0, 4, 48], inventory);
+}
+
+#[test]
+fn recentering_follows_the_price() {
+    let events = prices(&[1.0, 1.003, 1.006, 1.012, 1.024]);
+    let strategy = Recenter { width: 10, trigger: 5 };
+
+    let mut backtest = Backtest::new(settings(), &strategy, 1.0).unwrap();
+    for event in &events {
+        backtest.step(event).unwrap();
+    }
+
+    // the position is deposited around the last active bin
+    let active_bin = backtest.pool().active_bin;
+    assert_eq!(23, active_bin);
+    assert_eq!(active_bin - 10, *backtest.position().keys().next().unwrap());
+    // all of the y was sold while the price went up, so there is nothing to deposit in the active bin and the bins to the right of it
+    assert_eq!(active_bin - 1, *backtest.position().keys().last().unwrap());
+    assert!(backtest.inventory().1 <= 10);
+
+    let report = backtest.finish();
+    assert_eq!(3, report.rebalances);
+    assert_eq!(4, report.arbitrages);
+}
+
+#[test]
+fn bid_ask_rebalances_out_of_range() {
+    let events = prices(&[1.0, 1.005, 1.0, 0.995, 0.98]);
+    let report = Backtest::run(settings(), &BidAsk { width: 10 }, &events).unwrap();
+
+    // only the last price is out of the range
+    assert_eq!(1, report.rebalances);
+    // the price came back, so the fees of both sides were earned
+    assert!(report.fees_x > 0);
+    assert!(report.fees_y > 0);
+}
@@ -0,0 +1,121 @@
+use rusqlite::types::{FromSql, FromSqlError, FromSqlResult, ToSql, ToSqlOutput, ValueRef};
+
+/// Token amounts and shares don't fit in the 64 bit integers of sqlite, so they are stored as text.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Amount(pub i128);
+
+impl ToSql for Amount {
+    fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
+        Ok(ToSqlOutput::from(self.0.to_string()))
+    }
+}
+
+impl FromSql for Amount {
+    fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+        value.as_str()?.parse().map(Amount).map_err(|e| FromSqlError::Other(Box::new(e)))
+    }
+}
+
+/// The state of the pools is kept up to date with every event, next to the history of swaps and liquidity changes.
+/// `events` has the ids of the events that were applied, so a fixture or a page of rpc events can be ingested twice.
+pub const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS pools (
+    pool TEXT PRIMARY KEY,
+    token_x TEXT NOT NULL,
+    token_y TEXT NOT NULL,
+    bin_step INTEGER NOT NULL,
+    fee INTEGER NOT NULL,
+    decimals_x INTEGER NOT NULL,
+    decimals_y INTEGER NOT NULL,
+    active_bin INTEGER NOT NULL,
+    -- events up to this ledger are in the state, from the snapshot or the event that created the pool
+    start_ledger INTEGER NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS events (
+    id TEXT PRIMARY KEY,
+    pool TEXT NOT NULL,
+    ledger INTEGER NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS swaps (
+    id TEXT PRIMARY KEY,
+    pool TEXT NOT NULL,
+    ledger INTEGER NOT NULL,
+    closed_at TEXT NOT NULL,
+    sender TEXT NOT NULL,
+    is_x_in INTEGER NOT NULL,
+    amount_in TEXT NOT NULL,
+    amount_out TEXT NOT NULL,
+    -- the fee that went to the LPs, the fee of limit orders is not included
+    fee TEXT NOT NULL,
+    active_bin INTEGER NOT NULL
+);
+CREATE INDEX IF NOT EXISTS swaps_by_ledger ON swaps (pool, ledger);
+
+CREATE TABLE IF NOT EXISTS flash_loans (
+    id TEXT PRIMARY KEY,
+    pool TEXT NOT NULL,
+    ledger INTEGER NOT NULL,
+    receiver TEXT NOT NULL,
+    is_x INTEGER NOT NULL,
+    amount TEXT NOT NULL,
+    fee TEXT NOT NULL
+);
+CREATE INDEX IF NOT EXISTS flash_loans_by_ledger ON flash_loans (pool, ledger);
+
+CREATE TABLE IF NOT EXISTS liquidity (
+    id TEXT PRIMARY KEY,
+    pool TEXT NOT NULL,
+    ledger INTEGER NOT NULL,
+    closed_at TEXT NOT NULL,
+    owner TEXT NOT NULL,
+    position_id INTEGER NOT NULL,
+    amount_x TEXT NOT NULL,
+    amount_y TEXT NOT NULL
+);
+CREATE INDEX IF NOT EXISTS liquidity_by_owner ON liquidity (pool, owner);
+
+CREATE TABLE IF NOT EXISTS bins (
+    pool TEXT NOT NULL,
+    bin_id INTEGER NOT NULL,
+    reserve_x TEXT NOT NULL,
+    reserve_y TEXT NOT NULL,
+    shares TEXT NOT NULL,
+    PRIMARY KEY (pool, bin_id)
+);
+
+CREATE TABLE IF NOT EXISTS bin_history (
+    pool TEXT NOT NULL,
+    bin_id INTEGER NOT NULL,
+    ledger INTEGER NOT NULL,
+    event_id TEXT NOT NULL,
+    reserve_x TEXT NOT NULL,
+    reserve_y TEXT NOT NULL,
+    shares TEXT NOT NULL
+);
+CREATE INDEX IF NOT EXISTS bin_history_by_bin ON bin_history (pool, bin_id, event_id);
+
+CREATE TABLE IF NOT EXISTS position_bins (
+    pool TEXT NOT NULL,
+    owner TEXT NOT NULL,
+    position_id INTEGER NOT NULL,
+    bin_id INTEGER NOT NULL,
+    shares TEXT NOT NULL,
+    PRIMARY KEY (pool, owner, position_id, bin_id)
+);
+CREATE INDEX IF NOT EXISTS position_bins_by_bin ON position_bins (pool, bin_id);
+
+CREATE TABLE IF NOT EXISTS positions (
+    pool TEXT NOT NULL,
+    owner TEXT NOT NULL,
+    position_id INTEGER NOT NULL,
+    deposited_x TEXT NOT NULL,
+    deposited_y TEXT NOT NULL,
+    withdrawn_x TEXT NOT NULL,
+    withdrawn_y TEXT NOT NULL,
+    fees_x TEXT NOT NULL,
+    fees_y TEXT NOT NULL,
+    PRIMARY KEY (pool, owner, position_id)
+);
+";
@@ -0,0 +1,25 @@
+use std::fmt;
+
+#[derive(Debug)]
+pub enum Error {
+    Db(rusqlite::Error),
+    /// an event, fixture or snapshot that can't be read
+    Invalid(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Db(e) => write!(f, "database: {e}"),
+            Error::Invalid(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<rusqlite::Error> for Error {
+    fn from(e: rusqlite::Error) -> Self {
+        Error::Db(e)
+    }
+}
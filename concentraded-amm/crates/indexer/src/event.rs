@@ -0,0 +1,117 @@
+use std::path::Path;
+use amm::events::{BinChange, SwapEvent};
+use amm::storage::{BinShares, Config};
+use serde::{Deserialize, Serialize};
+use soroban_sdk::xdr::{Limits, ReadXdr, ScVal};
+use soroban_sdk::{Env, TryFromVal, Val};
+use crate::error::Error;
+
+/// A contract event as returned by the `getEvents` method of stellar rpc.
+/// The topics and the value are base64 encoded `ScVal`s.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RawEvent {
+    /// sorts in the order of the events
+    pub id: String,
+    pub ledger: u32,
+    #[serde(default)]
+    pub ledger_closed_at: String,
+    pub contract_id: String,
+    pub topic: Vec<String>,
+    pub value: String,
+}
+
+/// A fixture is a list of events, or the result of `getEvents` with the list in `events`.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum EventFile {
+    List(Vec<RawEvent>),
+    Result { events: Vec<RawEvent> },
+}
+
+pub fn parse_events(json: &str) -> Result<Vec<RawEvent>, Error> {
+    match serde_json::from_str(json).map_err(|e| Error::Invalid(format!("invalid events: {e}")))? {
+        EventFile::List(events) | EventFile::Result { events } => Ok(events),
+    }
+}
+
+pub fn load_events(path: &Path) -> Result<Vec<RawEvent>, Error> {
+    let json = std::fs::read_to_string(path).map_err(|e| Error::Invalid(format!("{}: {e}", path.display())))?;
+    parse_events(&json)
+}
+
+/// An event of the pool, see `amm::events`.
+#[derive(Clone, Debug)]
+pub enum PoolEvent {
+    Created(Config),
+    Liquidity { owner: String, position_id: i32, changes: Vec<BinChange> },
+    Swap { from: String, swap: SwapEvent },
+    FlashLoan { receiver: String, token: String, amount: i128, fee: i128, bin_id: i32 },
+    Sync { amount_x: i128, amount_y: i128, bin_id: i32 },
+    Loss { changes: Vec<BinChange> },
+    Transfer { from: String, to: String, position_id: i32, shares: Vec<BinShares> },
+}
+
+fn sc_val(xdr: &str) -> Result<ScVal, Error> {
+    ScVal::from_xdr_base64(xdr, Limits::none()).map_err(|e| Error::Invalid(format!("invalid xdr: {e}")))
+}
+
+fn decode<T: TryFromVal<Env, Val>>(env: &Env, value: &ScVal) -> Result<T, Error> {
+    let val = Val::try_from_val(env, value).map_err(|e| Error::Invalid(format!("invalid value: {e:?}")))?;
+    T::try_from_val(env, &val).map_err(|_| Error::Invalid(format!("unexpected value {value:?}")))
+}
+
+fn position_id(topic: Option<&ScVal>) -> Result<i32, Error> {
+    match topic {
+        Some(ScVal::I32(position_id)) => Ok(*position_id),
+        topic => Err(Error::Invalid(format!("expected a position id, got {topic:?}"))),
+    }
+}
+
+fn address(topic: Option<&ScVal>) -> Result<String, Error> {
+    match topic {
+        Some(ScVal::Address(address)) => Ok(address.to_string()),
+        topic => Err(Error::Invalid(format!("expected an address, got {topic:?}"))),
+    }
+}
+
+impl PoolEvent {
+    /// Decodes an event of the pool. returns `None` for events that the indexer does not use.
+    pub fn decode(env: &Env, event: &RawEvent) -> Result<Option<Self>, Error> {
+        let topics = event.topic.iter().map(|topic| sc_val(topic)).collect::<Result<Vec<_>, _>>()?;
+        let value = sc_val(&event.value)?;
+
+        let name = match topics.first() {
+            Some(ScVal::Symbol(name)) => name.to_utf8_string_lossy(),
+            _ => return Ok(None),
+        };
+
+        let event = match name.as_str() {
+            "created" => PoolEvent::Created(decode(env, &value)?),
+            "liquidity" => PoolEvent::Liquidity {
+                owner: address(topics.get(1))?,
+                position_id: position_id(topics.get(2))?,
+                changes: decode::<soroban_sdk::Vec<BinChange>>(env, &value)?.iter().collect(),
+            },
+            "swap" => PoolEvent::Swap { from: address(topics.get(1))?, swap: decode(env, &value)? },
+            "flashloan" => {
+                let (amount, fee, bin_id) = decode(env, &value)?;
+                PoolEvent::FlashLoan { receiver: address(topics.get(1))?, token: address(topics.get(2))?, amount, fee, bin_id }
+            }
+            "sync" => {
+                let (amount_x, amount_y, bin_id) = decode(env, &value)?;
+                PoolEvent::Sync { amount_x, amount_y, bin_id }
+            }
+            "loss" => PoolEvent::Loss { changes: decode::<soroban_sdk::Vec<BinChange>>(env, &value)?.iter().collect() },
+            "batch_transfer" => PoolEvent::Transfer {
+                from: address(topics.get(1))?,
+                to: address(topics.get(2))?,
+                position_id: position_id(topics.get(3))?,
+                shares: decode::<soroban_sdk::Vec<BinShares>>(env, &value)?.iter().collect(),
+            },
+            _ => return Ok(None),
+        };
+
+        Ok(Some(event))
+    }
+}
@@ -0,0 +1,424 @@
+use std::collections::BTreeMap;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::path::Path;
+use std::str::FromStr;
+use amm::events::{BinChange, SwapEvent};
+use amm::storage::{BinShares, DataKey};
+use amm::Contract;
+use amm_math::math::mul_div_floor_i128;
+use amm_math::shares::amounts_to_remove;
+use amm_math::swap::ReserveSwap;
+use rusqlite::{params, Connection, OptionalExtension};
+use soroban_ledger_snapshot::LedgerSnapshot;
+use soroban_sdk::xdr::{LedgerKey, LedgerKeyContractData, ScAddress, ScVal};
+use soroban_sdk::{Address, Env, TryFromVal, Val};
+use crate::db::{Amount, SCHEMA};
+use crate::error::Error;
+use crate::event::{PoolEvent, RawEvent};
+use crate::query::Pool;
+
+/// Keeps a sqlite database of pools up to date with their events.
+///
+/// A pool is indexed from the event that created it, or from a ledger snapshot with `load_snapshot`.
+/// Events of other contracts are skipped, so the events of a whole ledger range can be ingested.
+pub struct Indexer {
+    pub(crate) conn: Connection,
+    /// decodes the values of the events
+    env: Env,
+}
+
+/// where an event is applied
+struct EventContext<'a> {
+    pool: &'a Pool,
+    id: &'a str,
+    ledger: u32,
+    closed_at: &'a str,
+}
+
+#[derive(Clone, Copy, Default)]
+struct BinRow {
+    reserve_x: i128,
+    reserve_y: i128,
+    shares: i128,
+}
+
+impl Indexer {
+    pub fn open(path: &Path) -> Result<Self, Error> {
+        Indexer::new(Connection::open(path)?)
+    }
+
+    pub fn in_memory() -> Result<Self, Error> {
+        Indexer::new(Connection::open_in_memory()?)
+    }
+
+    fn new(conn: Connection) -> Result<Self, Error> {
+        conn.execute_batch(SCHEMA)?;
+        Ok(Indexer { conn, env: Env::default() })
+    }
+
+    /// Loads the state of `pool` from a ledger snapshot, like the ones from `stellar snapshot create`.
+    /// Events of the pool up to the ledger of the snapshot are skipped, they are part of the state.
+    ///
+    /// There is no history before the snapshot, so the positions in it start with their amounts at the snapshot as the deposit.
+    pub fn load_snapshot(&mut self, path: &Path, pool: &str) -> Result<(), Error> {
+        let snapshot = LedgerSnapshot::read_file(path).map_err(|e| Error::Invalid(format!("{}: {e}", path.display())))?;
+        let ledger = snapshot.sequence_number;
+        let env = Env::from_ledger_snapshot(snapshot);
+
+        let address = ScAddress::from_str(pool).map_err(|_| Error::Invalid(format!("invalid address {pool}")))?;
+        let contract = Address::try_from_val(&env, &ScVal::Address(address.clone())).unwrap();
+        let config = catch_unwind(AssertUnwindSafe(|| env.as_contract(&contract, || Contract::get_config(env.clone()))))
+            .map_err(|_| Error::Invalid(format!("{pool} is not a pool in the snapshot")))?;
+
+        // the bins and positions are found by their keys
+        let mut bins = BTreeMap::new();
+        let mut positions = Vec::new();
+        for (key, _) in env.to_ledger_snapshot().ledger_entries {
+            let LedgerKey::ContractData(LedgerKeyContractData { contract: entry_contract, key, .. }) = *key else {
+                continue;
+            };
+            if entry_contract != address {
+                continue;
+            }
+            let Ok(key) = Val::try_from_val(&env, &key) else {
+                continue;
+            };
+
+            match DataKey::try_from_val(&env, &key) {
+                Ok(DataKey::BinVec(vec_id)) => {
+                    let reserves = env.as_contract(&contract, || Contract::get_bin_vec(env.clone(), vec_id));
+                    let shares = env.as_contract(&contract, || Contract::get_shares_vec(env.clone(), vec_id));
+                    for (bin, shares) in reserves.iter().zip(shares.iter()) {
+                        if bin.reserve_x != 0 || bin.reserve_y != 0 || shares.shares != 0 {
+                            bins.insert(bin.bin_id, BinRow { reserve_x: bin.reserve_x, reserve_y: bin.reserve_y, shares: shares.shares });
+                        }
+                    }
+                }
+                Ok(DataKey::Position(owner, position_id)) => {
+                    let position = env.as_contract(&contract, || Contract::get_position(env.clone(), owner.clone(), position_id));
+                    if let Some(position) = position {
+                        positions.push((address_string(&owner), position_id, position));
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        let pool = Pool::new(pool, &config, ledger);
+        let tx = self.conn.transaction()?;
+        insert_pool(&tx, &pool)?;
+
+        let event_id = format!("snapshot-{ledger}");
+        for (bin_id, bin) in &bins {
+            set_bin(&tx, &pool.pool, *bin_id, ledger, &event_id, *bin)?;
+        }
+        for (owner, position_id, position) in positions {
+            let (mut amount_x, mut amount_y) = (0, 0);
+            for bin_shares in position.bin_shares.iter() {
+                let bin = bins.get(&bin_shares.bin_id).copied().unwrap_or_default();
+                let (x, y) = amounts_to_remove(bin_shares.shares, bin.shares, bin.reserve_x, bin.reserve_y);
+                amount_x += x;
+                amount_y += y;
+                add_position_shares(&tx, &pool.pool, &owner, position_id, bin_shares.bin_id, bin_shares.shares)?;
+            }
+            add_position_flows(&tx, &pool.pool, &owner, position_id, amount_x, amount_y)?;
+        }
+
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// Applies the events of the indexed pools, in the order of their ids.
+    ///
+    /// Events of other contracts, events that were already applied and events that are part of a snapshot are skipped.
+    /// returns the number of events that were applied
+    pub fn ingest(&mut self, events: &[RawEvent]) -> Result<usize, Error> {
+        let mut events: Vec<&RawEvent> = events.iter().collect();
+        events.sort_by(|a, b| a.id.cmp(&b.id));
+
+        let tx = self.conn.transaction()?;
+        let mut applied = 0;
+
+        for raw in events {
+            let pool = match get_pool(&tx, &raw.contract_id)? {
+                Some(pool) => {
+                    if raw.ledger <= pool.start_ledger {
+                        continue;
+                    }
+                    pool
+                }
+                None => {
+                    // any contract can publish a `created` event, the ones that don't decode are not pools
+                    if let Ok(Some(PoolEvent::Created(config))) = PoolEvent::decode(&self.env, raw) {
+                        // events after the creation in the same ledger still have to be applied
+                        let pool = Pool::new(&raw.contract_id, &config, raw.ledger - 1);
+                        insert_pool(&tx, &pool)?;
+                        pool
+                    } else {
+                        continue;
+                    }
+                }
+            };
+
+            let Some(event) = PoolEvent::decode(&self.env, raw)? else {
+                continue;
+            };
+
+            let inserted = tx.execute(
+                "INSERT OR IGNORE INTO events (id, pool, ledger) VALUES (?1, ?2, ?3)",
+                params![raw.id, pool.pool, raw.ledger],
+            )?;
+            if inserted == 0 {
+                continue;
+            }
+
+            let context = EventContext { pool: &pool, id: &raw.id, ledger: raw.ledger, closed_at: &raw.ledger_closed_at };
+            match event {
+                PoolEvent::Created(_) => {}
+                PoolEvent::Liquidity { owner, position_id, changes } => apply_liquidity(&tx, &context, &owner, position_id, &changes)?,
+                PoolEvent::Swap { from, swap } => apply_swap(&tx, &context, &from, &swap)?,
+                PoolEvent::FlashLoan { receiver, token, amount, fee, bin_id } => {
+                    let is_x = token == pool.token_x;
+                    tx.execute(
+                        "INSERT INTO flash_loans (id, pool, ledger, receiver, is_x, amount, fee) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+                        params![raw.id, pool.pool, raw.ledger, receiver, is_x, Amount(amount), Amount(fee)],
+                    )?;
+                    if fee > 0 {
+                        distribute_fee(&tx, &pool.pool, bin_id, is_x, fee)?;
+                        let (amount_x, amount_y) = if is_x { (fee, 0) } else { (0, fee) };
+                        add_to_bin(&tx, &context, bin_id, amount_x, amount_y, 0)?;
+                    }
+                }
+                PoolEvent::Sync { amount_x, amount_y, bin_id } => add_to_bin(&tx, &context, bin_id, amount_x, amount_y, 0)?,
+                PoolEvent::Loss { changes } => {
+                    for change in &changes {
+                        add_to_bin(&tx, &context, change.bin_id, change.amount_x, change.amount_y, 0)?;
+                    }
+                }
+                PoolEvent::Transfer { from, to, position_id, shares } => apply_transfer(&tx, &pool.pool, &from, &to, position_id, &shares)?,
+            }
+
+            applied += 1;
+        }
+
+        tx.commit()?;
+        Ok(applied)
+    }
+}
+
+/// The `G...` or `C...` strkey of `address`.
+pub fn address_string(address: &Address) -> String {
+    let strkey: soroban_sdk::String = address.to_string();
+    strkey.to_string()
+}
+
+fn insert_pool(conn: &Connection, pool: &Pool) -> Result<(), Error> {
+    if get_pool(conn, &pool.pool)?.is_some() {
+        return Err(Error::Invalid(format!("{} is already indexed", pool.pool)));
+    }
+
+    conn.execute(
+        "INSERT INTO pools (pool, token_x, token_y, bin_step, fee, decimals_x, decimals_y, active_bin, start_ledger)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+        params![pool.pool, pool.token_x, pool.token_y, pool.bin_step, pool.fee, pool.decimals_x, pool.decimals_y, pool.active_bin, pool.start_ledger],
+    )?;
+    Ok(())
+}
+
+pub(crate) fn get_pool(conn: &Connection, pool: &str) -> Result<Option<Pool>, Error> {
+    Ok(conn
+        .query_row(
+            "SELECT pool, token_x, token_y, bin_step, fee, decimals_x, decimals_y, active_bin, start_ledger FROM pools WHERE pool = ?1",
+            [pool],
+            |row| {
+                Ok(Pool {
+                    pool: row.get(0)?,
+                    token_x: row.get(1)?,
+                    token_y: row.get(2)?,
+                    bin_step: row.get(3)?,
+                    fee: row.get(4)?,
+                    decimals_x: row.get(5)?,
+                    decimals_y: row.get(6)?,
+                    active_bin: row.get(7)?,
+                    start_ledger: row.get(8)?,
+                })
+            },
+        )
+        .optional()?)
+}
+
+fn get_bin(conn: &Connection, pool: &str, bin_id: i32) -> Result<BinRow, Error> {
+    Ok(conn
+        .query_row("SELECT reserve_x, reserve_y, shares FROM bins WHERE pool = ?1 AND bin_id = ?2", params![pool, bin_id], |row| {
+            Ok(BinRow {
+                reserve_x: row.get::<_, Amount>(0)?.0,
+                reserve_y: row.get::<_, Amount>(1)?.0,
+                shares: row.get::<_, Amount>(2)?.0,
+            })
+        })
+        .optional()?
+        .unwrap_or_default())
+}
+
+/// Stores the new state of a bin, and adds it to the history of the bin.
+fn set_bin(conn: &Connection, pool: &str, bin_id: i32, ledger: u32, event_id: &str, bin: BinRow) -> Result<(), Error> {
+    conn.execute(
+        "INSERT OR REPLACE INTO bins (pool, bin_id, reserve_x, reserve_y, shares) VALUES (?1, ?2, ?3, ?4, ?5)",
+        params![pool, bin_id, Amount(bin.reserve_x), Amount(bin.reserve_y), Amount(bin.shares)],
+    )?;
+    conn.execute(
+        "INSERT INTO bin_history (pool, bin_id, ledger, event_id, reserve_x, reserve_y, shares) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+        params![pool, bin_id, ledger, event_id, Amount(bin.reserve_x), Amount(bin.reserve_y), Amount(bin.shares)],
+    )?;
+    Ok(())
+}
+
+fn add_to_bin(conn: &Connection, context: &EventContext, bin_id: i32, amount_x: i128, amount_y: i128, shares: i128) -> Result<(), Error> {
+    let mut bin = get_bin(conn, &context.pool.pool, bin_id)?;
+    bin.reserve_x += amount_x;
+    bin.reserve_y += amount_y;
+    bin.shares += shares;
+    set_bin(conn, &context.pool.pool, bin_id, context.ledger, context.id, bin)
+}
+
+fn add_position_shares(conn: &Connection, pool: &str, owner: &str, position_id: i32, bin_id: i32, shares: i128) -> Result<(), Error> {
+    let current: Amount = conn
+        .query_row(
+            "SELECT shares FROM position_bins WHERE pool = ?1 AND owner = ?2 AND position_id = ?3 AND bin_id = ?4",
+            params![pool, owner, position_id, bin_id],
+            |row| row.get(0),
+        )
+        .optional()?
+        .unwrap_or_default();
+
+    let shares = current.0 + shares;
+    if shares == 0 {
+        conn.execute(
+            "DELETE FROM position_bins WHERE pool = ?1 AND owner = ?2 AND position_id = ?3 AND bin_id = ?4",
+            params![pool, owner, position_id, bin_id],
+        )?;
+    } else {
+        conn.execute(
+            "INSERT OR REPLACE INTO position_bins (pool, owner, position_id, bin_id, shares) VALUES (?1, ?2, ?3, ?4, ?5)",
+            params![pool, owner, position_id, bin_id, Amount(shares)],
+        )?;
+    }
+    Ok(())
+}
+
+/// Adds the amounts that went in and out of a position. Positive amounts were deposited, negative ones withdrawn.
+fn add_position_flows(conn: &Connection, pool: &str, owner: &str, position_id: i32, amount_x: i128, amount_y: i128) -> Result<(), Error> {
+    conn.execute(
+        "INSERT OR IGNORE INTO positions (pool, owner, position_id, deposited_x, deposited_y, withdrawn_x, withdrawn_y, fees_x, fees_y)
+         VALUES (?1, ?2, ?3, '0', '0', '0', '0', '0', '0')",
+        params![pool, owner, position_id],
+    )?;
+
+    let columns = [("deposited_x", amount_x.max(0)), ("deposited_y", amount_y.max(0)), ("withdrawn_x", (-amount_x).max(0)), ("withdrawn_y", (-amount_y).max(0))];
+    for (column, amount) in columns {
+        if amount != 0 {
+            add_to_position_column(conn, pool, owner, position_id, column, amount)?;
+        }
+    }
+    Ok(())
+}
+
+fn add_to_position_column(conn: &Connection, pool: &str, owner: &str, position_id: i32, column: &str, amount: i128) -> Result<(), Error> {
+    let current: Amount = conn.query_row(
+        &format!("SELECT {column} FROM positions WHERE pool = ?1 AND owner = ?2 AND position_id = ?3"),
+        params![pool, owner, position_id],
+        |row| row.get(0),
+    )?;
+    conn.execute(
+        &format!("UPDATE positions SET {column} = ?4 WHERE pool = ?1 AND owner = ?2 AND position_id = ?3"),
+        params![pool, owner, position_id, Amount(current.0 + amount)],
+    )?;
+    Ok(())
+}
+
+/// Credits the positions in a bin with their part of a fee, rounded down like their shares of the bin.
+fn distribute_fee(conn: &Connection, pool: &str, bin_id: i32, is_x: bool, fee: i128) -> Result<(), Error> {
+    let bin_shares = get_bin(conn, pool, bin_id)?.shares;
+    if bin_shares == 0 {
+        return Ok(());
+    }
+
+    let mut statement = conn.prepare("SELECT owner, position_id, shares FROM position_bins WHERE pool = ?1 AND bin_id = ?2")?;
+    let holders = statement
+        .query_map(params![pool, bin_id], |row| Ok((row.get::<_, String>(0)?, row.get::<_, i32>(1)?, row.get::<_, Amount>(2)?.0)))?
+        .collect::<Result<Vec<_>, _>>()?;
+
+    for (owner, position_id, shares) in holders {
+        let earned = mul_div_floor_i128(fee, shares, bin_shares);
+        if earned > 0 {
+            add_to_position_column(conn, pool, &owner, position_id, if is_x { "fees_x" } else { "fees_y" }, earned)?;
+        }
+    }
+    Ok(())
+}
+
+fn apply_liquidity(conn: &Connection, context: &EventContext, owner: &str, position_id: i32, changes: &[BinChange]) -> Result<(), Error> {
+    let (mut amount_x, mut amount_y) = (0, 0);
+
+    for change in changes {
+        add_to_bin(conn, context, change.bin_id, change.amount_x, change.amount_y, change.shares)?;
+        add_position_shares(conn, &context.pool.pool, owner, position_id, change.bin_id, change.shares)?;
+        amount_x += change.amount_x;
+        amount_y += change.amount_y;
+    }
+
+    add_position_flows(conn, &context.pool.pool, owner, position_id, amount_x, amount_y)?;
+    conn.execute(
+        "INSERT INTO liquidity (id, pool, ledger, closed_at, owner, position_id, amount_x, amount_y) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+        params![context.id, context.pool.pool, context.ledger, context.closed_at, owner, position_id, Amount(amount_x), Amount(amount_y)],
+    )?;
+    Ok(())
+}
+
+/// Moves the shares between the positions, the bins don't change.
+/// The flows of the positions stay as they are, so the position of `to` shows the value of the shares it received as profit.
+fn apply_transfer(conn: &Connection, pool: &str, from: &str, to: &str, position_id: i32, shares: &[BinShares]) -> Result<(), Error> {
+    add_position_flows(conn, pool, to, position_id, 0, 0)?;
+
+    for bin_shares in shares {
+        add_position_shares(conn, pool, from, position_id, bin_shares.bin_id, -bin_shares.shares)?;
+        add_position_shares(conn, pool, to, position_id, bin_shares.bin_id, bin_shares.shares)?;
+    }
+    Ok(())
+}
+
+fn apply_swap(conn: &Connection, context: &EventContext, from: &str, swap: &SwapEvent) -> Result<(), Error> {
+    let mut lp_fee = 0;
+
+    for bin_swap in swap.bins.iter() {
+        let fee = ReserveSwap { amount_in: bin_swap.amount_in, amount_out: bin_swap.amount_out }.fee(context.pool.fee);
+        // the shares don't change, so the fee can be divided before the reserves are updated
+        distribute_fee(conn, &context.pool.pool, bin_swap.bin_id, swap.is_x_in, fee)?;
+        lp_fee += fee;
+
+        if swap.is_x_in {
+            add_to_bin(conn, context, bin_swap.bin_id, bin_swap.amount_in, -bin_swap.amount_out, 0)?;
+        } else {
+            add_to_bin(conn, context, bin_swap.bin_id, -bin_swap.amount_out, bin_swap.amount_in, 0)?;
+        }
+    }
+
+    conn.execute(
+        "INSERT INTO swaps (id, pool, ledger, closed_at, sender, is_x_in, amount_in, amount_out, fee, active_bin)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)",
+        params![
+            context.id,
+            context.pool.pool,
+            context.ledger,
+            context.closed_at,
+            from,
+            swap.is_x_in,
+            Amount(swap.amount_in),
+            Amount(swap.amount_out),
+            Amount(lp_fee),
+            swap.active_bin
+        ],
+    )?;
+    conn.execute("UPDATE pools SET active_bin = ?2 WHERE pool = ?1", params![context.pool.pool, swap.active_bin])?;
+    Ok(())
+}
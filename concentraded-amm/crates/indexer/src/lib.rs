@@ -0,0 +1,12 @@
+//! Indexes the events of pools into a sqlite database: swaps, liquidity changes, the reserves of the bins over time,
+//! volumes, fees and the PnL of positions.
+//!
+//! A pool starts from the event that created it or from a ledger snapshot. The events are the ones from the `getEvents` method
+//! of stellar rpc, from rpc itself or from a recorded fixture, see `event::RawEvent`.
+pub mod db;
+pub mod error;
+pub mod event;
+pub mod indexer;
+pub mod query;
+
+mod test;
@@ -0,0 +1,109 @@
+//! Fills the database of the indexer and queries it from the command line. Queries print json.
+//!
+//! ```text
+//! indexer ingest --db pool.db --events events.json [--snapshot snapshot.json --pool C...]
+//! indexer pool|bins|positions --db pool.db --pool C...
+//! indexer swaps|volume --db pool.db --pool C... [--from-ledger N] [--to-ledger N]
+//! indexer liquidity --db pool.db --pool C... [--owner G...]
+//! indexer bin-history --db pool.db --pool C... --bin N
+//! indexer position --db pool.db --pool C... --owner G... [--position N]
+//! ```
+use std::path::PathBuf;
+use std::process::ExitCode;
+use indexer::event::load_events;
+use indexer::indexer::Indexer;
+use serde::Serialize;
+
+#[derive(Default)]
+struct Args {
+    command: String,
+    db: Option<PathBuf>,
+    events: Option<PathBuf>,
+    snapshot: Option<PathBuf>,
+    pool: Option<String>,
+    owner: Option<String>,
+    position: i32,
+    bin: Option<i32>,
+    from_ledger: u32,
+    to_ledger: Option<u32>,
+}
+
+fn parse_value<T: std::str::FromStr>(flag: &str, value: Option<String>) -> Result<T, String> {
+    value
+        .ok_or_else(|| format!("{flag} needs a value"))?
+        .parse()
+        .map_err(|_| format!("invalid value for {flag}"))
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Args, String> {
+    let mut parsed = Args { command: args.next().ok_or("missing the command")?, ..Default::default() };
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--db" => parsed.db = Some(parse_value(&arg, args.next())?),
+            "--events" => parsed.events = Some(parse_value(&arg, args.next())?),
+            "--snapshot" => parsed.snapshot = Some(parse_value(&arg, args.next())?),
+            "--pool" => parsed.pool = Some(parse_value(&arg, args.next())?),
+            "--owner" => parsed.owner = Some(parse_value(&arg, args.next())?),
+            "--position" => parsed.position = parse_value(&arg, args.next())?,
+            "--bin" => parsed.bin = Some(parse_value(&arg, args.next())?),
+            "--from-ledger" => parsed.from_ledger = parse_value(&arg, args.next())?,
+            "--to-ledger" => parsed.to_ledger = Some(parse_value(&arg, args.next())?),
+            _ => return Err(format!("unknown option {arg}")),
+        }
+    }
+
+    Ok(parsed)
+}
+
+fn print(value: &impl Serialize) -> Result<(), String> {
+    println!("{}", serde_json::to_string_pretty(value).map_err(|e| e.to_string())?);
+    Ok(())
+}
+
+fn run() -> Result<(), String> {
+    let args = parse_args(std::env::args().skip(1))?;
+    let mut indexer = Indexer::open(&args.db.ok_or("missing --db")?).map_err(|e| e.to_string())?;
+    let pool = args.pool.ok_or("missing --pool");
+    let ledgers = args.from_ledger..=args.to_ledger.unwrap_or(u32::MAX);
+
+    match args.command.as_str() {
+        "ingest" => {
+            if let Some(snapshot) = args.snapshot {
+                indexer.load_snapshot(&snapshot, &pool?).map_err(|e| e.to_string())?;
+            }
+            if let Some(events) = args.events {
+                let events = load_events(&events).map_err(|e| e.to_string())?;
+                let applied = indexer.ingest(&events).map_err(|e| e.to_string())?;
+                eprintln!("applied {applied} of {} events", events.len());
+            }
+            Ok(())
+        }
+        "pool" => print(&indexer.pool(&pool?).map_err(|e| e.to_string())?.ok_or("unknown pool")?),
+        "bins" => print(&indexer.bins(&pool?).map_err(|e| e.to_string())?),
+        "positions" => print(&indexer.positions(&pool?).map_err(|e| e.to_string())?),
+        "swaps" => print(&indexer.swaps(&pool?, ledgers).map_err(|e| e.to_string())?),
+        "volume" => print(&indexer.volume(&pool?, ledgers).map_err(|e| e.to_string())?),
+        "liquidity" => print(&indexer.liquidity_changes(&pool?, args.owner.as_deref()).map_err(|e| e.to_string())?),
+        "bin-history" => print(&indexer.bin_history(&pool?, args.bin.ok_or("missing --bin")?).map_err(|e| e.to_string())?),
+        "position" => print(
+            &indexer
+                .position(&pool?, &args.owner.ok_or("missing --owner")?, args.position)
+                .map_err(|e| e.to_string())?
+                .ok_or("unknown position")?,
+        ),
+        other => Err(format!(
+            "unknown command {other}, expected ingest, pool, bins, positions, swaps, volume, liquidity, bin-history or position"
+        )),
+    }
+}
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,293 @@
+use std::ops::RangeInclusive;
+use amm::storage::Config;
+use amm_math::price::{get_amount_out, price_from_bin};
+use amm_math::shares::amounts_to_remove;
+use rusqlite::{params, Row};
+use serde::Serialize;
+use crate::db::Amount;
+use crate::error::Error;
+use crate::indexer::{address_string, get_pool, Indexer};
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct Pool {
+    pub pool: String,
+    pub token_x: String,
+    pub token_y: String,
+    pub bin_step: u32,
+    pub fee: u32,
+    pub decimals_x: u32,
+    pub decimals_y: u32,
+    /// the active bin after the last swap
+    pub active_bin: i32,
+    /// the ledger of the snapshot, or the ledger before the pool was created
+    pub start_ledger: u32,
+}
+
+impl Pool {
+    pub fn new(pool: &str, config: &Config, start_ledger: u32) -> Self {
+        Pool {
+            pool: pool.to_string(),
+            token_x: address_string(&config.token_x),
+            token_y: address_string(&config.token_y),
+            bin_step: config.bin_step,
+            fee: config.fee,
+            decimals_x: config.decimals_x,
+            decimals_y: config.decimals_y,
+            active_bin: config.active_bin,
+            start_ledger,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct Swap {
+    pub id: String,
+    pub ledger: u32,
+    pub closed_at: String,
+    pub sender: String,
+    pub is_x_in: bool,
+    pub amount_in: i128,
+    pub amount_out: i128,
+    /// the part of `amount_in` that went to the LPs
+    pub fee: i128,
+    /// the active bin after the swap
+    pub active_bin: i32,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct LiquidityChange {
+    pub id: String,
+    pub ledger: u32,
+    pub closed_at: String,
+    pub owner: String,
+    pub position_id: i32,
+    /// positive amounts were deposited, negative ones withdrawn
+    pub amount_x: i128,
+    pub amount_y: i128,
+}
+
+/// The state of a bin, now or after an event.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct BinState {
+    pub bin_id: i32,
+    pub ledger: u32,
+    pub reserve_x: i128,
+    pub reserve_y: i128,
+    pub shares: i128,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct Volume {
+    pub swaps: u64,
+    /// the x that was sold and bought
+    pub volume_x: i128,
+    pub volume_y: i128,
+    /// the fees of swaps and flash loans that went to the LPs
+    pub fees_x: i128,
+    pub fees_y: i128,
+}
+
+/// What a position did, from its deposits and withdrawals and what it holds now.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct PositionPnl {
+    pub owner: String,
+    pub position_id: i32,
+    pub deposited_x: i128,
+    pub deposited_y: i128,
+    pub withdrawn_x: i128,
+    pub withdrawn_y: i128,
+    /// what the position can withdraw now
+    pub amount_x: i128,
+    pub amount_y: i128,
+    /// the fees that the position earned, they are part of the amounts that it withdrew or holds
+    pub fees_x: i128,
+    pub fees_y: i128,
+    /// `amount + withdrawn - deposited` of both tokens, valued in x at the price of the active bin
+    pub pnl_x: i128,
+}
+
+fn amount(row: &Row, index: usize) -> rusqlite::Result<i128> {
+    Ok(row.get::<_, Amount>(index)?.0)
+}
+
+impl Indexer {
+    pub fn pool(&self, pool: &str) -> Result<Option<Pool>, Error> {
+        get_pool(&self.conn, pool)
+    }
+
+    pub fn swaps(&self, pool: &str, ledgers: RangeInclusive<u32>) -> Result<Vec<Swap>, Error> {
+        let mut statement = self.conn.prepare(
+            "SELECT id, ledger, closed_at, sender, is_x_in, amount_in, amount_out, fee, active_bin FROM swaps
+             WHERE pool = ?1 AND ledger BETWEEN ?2 AND ?3 ORDER BY id",
+        )?;
+        let swaps = statement.query_map(params![pool, ledgers.start(), ledgers.end()], |row| {
+            Ok(Swap {
+                id: row.get(0)?,
+                ledger: row.get(1)?,
+                closed_at: row.get(2)?,
+                sender: row.get(3)?,
+                is_x_in: row.get(4)?,
+                amount_in: amount(row, 5)?,
+                amount_out: amount(row, 6)?,
+                fee: amount(row, 7)?,
+                active_bin: row.get(8)?,
+            })
+        })?;
+
+        Ok(swaps.collect::<Result<_, _>>()?)
+    }
+
+    /// returns the liquidity changes of the pool, or only those of `owner`
+    pub fn liquidity_changes(&self, pool: &str, owner: Option<&str>) -> Result<Vec<LiquidityChange>, Error> {
+        let mut statement = self.conn.prepare(
+            "SELECT id, ledger, closed_at, owner, position_id, amount_x, amount_y FROM liquidity
+             WHERE pool = ?1 AND (?2 IS NULL OR owner = ?2) ORDER BY id",
+        )?;
+        let changes = statement.query_map(params![pool, owner], |row| {
+            Ok(LiquidityChange {
+                id: row.get(0)?,
+                ledger: row.get(1)?,
+                closed_at: row.get(2)?,
+                owner: row.get(3)?,
+                position_id: row.get(4)?,
+                amount_x: amount(row, 5)?,
+                amount_y: amount(row, 6)?,
+            })
+        })?;
+
+        Ok(changes.collect::<Result<_, _>>()?)
+    }
+
+    pub fn volume(&self, pool: &str, ledgers: RangeInclusive<u32>) -> Result<Volume, Error> {
+        let mut volume = Volume::default();
+
+        for swap in self.swaps(pool, ledgers.clone())? {
+            volume.swaps += 1;
+            let (x, y) = if swap.is_x_in { (swap.amount_in, swap.amount_out) } else { (swap.amount_out, swap.amount_in) };
+            volume.volume_x += x;
+            volume.volume_y += y;
+            if swap.is_x_in {
+                volume.fees_x += swap.fee;
+            } else {
+                volume.fees_y += swap.fee;
+            }
+        }
+
+        let mut statement = self.conn.prepare("SELECT is_x, fee FROM flash_loans WHERE pool = ?1 AND ledger BETWEEN ?2 AND ?3")?;
+        let fees = statement.query_map(params![pool, ledgers.start(), ledgers.end()], |row| Ok((row.get::<_, bool>(0)?, amount(row, 1)?)))?;
+        for fee in fees {
+            match fee? {
+                (true, fee) => volume.fees_x += fee,
+                (false, fee) => volume.fees_y += fee,
+            }
+        }
+
+        Ok(volume)
+    }
+
+    /// returns the bins with reserves or shares, at the ledger of their last change
+    pub fn bins(&self, pool: &str) -> Result<Vec<BinState>, Error> {
+        let mut statement = self.conn.prepare(
+            "SELECT bin_id, reserve_x, reserve_y, shares,
+                 (SELECT MAX(ledger) FROM bin_history WHERE bin_history.pool = bins.pool AND bin_history.bin_id = bins.bin_id)
+             FROM bins WHERE pool = ?1 ORDER BY bin_id",
+        )?;
+        let bins = statement.query_map([pool], |row| {
+            Ok(BinState {
+                bin_id: row.get(0)?,
+                reserve_x: amount(row, 1)?,
+                reserve_y: amount(row, 2)?,
+                shares: amount(row, 3)?,
+                ledger: row.get(4)?,
+            })
+        })?;
+
+        Ok(bins
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter()
+            .filter(|bin| bin.reserve_x != 0 || bin.reserve_y != 0 || bin.shares != 0)
+            .collect())
+    }
+
+    /// returns the state of a bin after every event that changed it
+    pub fn bin_history(&self, pool: &str, bin_id: i32) -> Result<Vec<BinState>, Error> {
+        let mut statement = self.conn.prepare(
+            "SELECT ledger, reserve_x, reserve_y, shares FROM bin_history WHERE pool = ?1 AND bin_id = ?2 ORDER BY rowid",
+        )?;
+        let history = statement.query_map(params![pool, bin_id], |row| {
+            Ok(BinState {
+                bin_id,
+                ledger: row.get(0)?,
+                reserve_x: amount(row, 1)?,
+                reserve_y: amount(row, 2)?,
+                shares: amount(row, 3)?,
+            })
+        })?;
+
+        Ok(history.collect::<Result<_, _>>()?)
+    }
+
+    pub fn positions(&self, pool: &str) -> Result<Vec<PositionPnl>, Error> {
+        let mut statement = self.conn.prepare("SELECT owner, position_id FROM positions WHERE pool = ?1 ORDER BY owner, position_id")?;
+        let keys = statement
+            .query_map([pool], |row| Ok((row.get::<_, String>(0)?, row.get::<_, i32>(1)?)))?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        keys.iter()
+            .map(|(owner, position_id)| Ok(self.position(pool, owner, *position_id)?.unwrap()))
+            .collect()
+    }
+
+    pub fn position(&self, pool: &str, owner: &str, position_id: i32) -> Result<Option<PositionPnl>, Error> {
+        let Some(info) = self.pool(pool)? else {
+            return Ok(None);
+        };
+
+        let flows = self
+            .conn
+            .query_row(
+                "SELECT deposited_x, deposited_y, withdrawn_x, withdrawn_y, fees_x, fees_y FROM positions
+                 WHERE pool = ?1 AND owner = ?2 AND position_id = ?3",
+                params![pool, owner, position_id],
+                |row| Ok([amount(row, 0)?, amount(row, 1)?, amount(row, 2)?, amount(row, 3)?, amount(row, 4)?, amount(row, 5)?]),
+            )
+            .map(Some)
+            .or_else(|e| if e == rusqlite::Error::QueryReturnedNoRows { Ok(None) } else { Err(e) })?;
+        let Some([deposited_x, deposited_y, withdrawn_x, withdrawn_y, fees_x, fees_y]) = flows else {
+            return Ok(None);
+        };
+
+        // what the shares can withdraw, with the rounding of the contract
+        let mut statement = self.conn.prepare(
+            "SELECT position_bins.shares, bins.shares, bins.reserve_x, bins.reserve_y FROM position_bins
+             JOIN bins ON bins.pool = position_bins.pool AND bins.bin_id = position_bins.bin_id
+             WHERE position_bins.pool = ?1 AND position_bins.owner = ?2 AND position_bins.position_id = ?3",
+        )?;
+        let (mut amount_x, mut amount_y) = (0, 0);
+        for bin in statement.query_map(params![pool, owner, position_id], |row| {
+            Ok((amount(row, 0)?, amount(row, 1)?, amount(row, 2)?, amount(row, 3)?))
+        })? {
+            let (shares, bin_shares, reserve_x, reserve_y) = bin?;
+            let (x, y) = amounts_to_remove(shares, bin_shares, reserve_x, reserve_y);
+            amount_x += x;
+            amount_y += y;
+        }
+
+        let pnl_y = amount_y + withdrawn_y - deposited_y;
+        let pnl_y_in_x = get_amount_out(price_from_bin(info.bin_step, info.active_bin), pnl_y.unsigned_abs(), false).as_i128();
+
+        Ok(Some(PositionPnl {
+            owner: owner.to_string(),
+            position_id,
+            deposited_x,
+            deposited_y,
+            withdrawn_x,
+            withdrawn_y,
+            amount_x,
+            amount_y,
+            fees_x,
+            fees_y,
+            pnl_x: amount_x + withdrawn_x - deposited_x + pnl_y.signum() * pnl_y_in_x,
+        }))
+    }
+}
@@ -0,0 +1,311 @@
+#![cfg(test)]
+// amounts are written as `whole_fraction` with 7 decimals, like the stellar assets.
+#![allow(clippy::inconsistent_digit_grouping)]
+use std::path::{Path, PathBuf};
+use amm::storage::{BinShares, Config, DepositArgs};
+use amm::{Contract, ContractClient};
+use soroban_ledger_snapshot::LedgerSnapshot;
+use soroban_sdk::testutils::{Address as _, Events as _, Ledger as _};
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::xdr::{Limits, ScVal, WriteXdr};
+use soroban_sdk::{contract, contractimpl, vec, Address, Bytes, Env, TryFromVal};
+use crate::event::{load_events, parse_events, PoolEvent, RawEvent};
+use crate::indexer::{address_string, Indexer};
+
+/// Pays back a flash loan with the fee.
+#[contract]
+struct FlashReceiver;
+
+#[contractimpl]
+impl FlashReceiver {
+    pub fn on_flash_loan(env: Env, token: Address, amount: i128, fee: i128, _data: Bytes) {
+        let pool = env.storage().instance().get(&0u32).unwrap();
+        TokenClient::new(&env, &token).transfer(&env.current_contract_address(), &pool, &(amount + fee));
+    }
+}
+
+/// Turns the events of the last call into the events of the rpc, in `ledger`.
+fn record(env: &Env, events: &mut Vec<RawEvent>) {
+    let ledger = env.ledger().sequence();
+
+    for (contract, topics, value) in env.events().all().iter() {
+        let xdr = |val| ScVal::try_from_val(env, &val).unwrap().to_xdr_base64(Limits::none()).unwrap();
+        let index = events.iter().filter(|event| event.ledger == ledger).count();
+
+        events.push(RawEvent {
+            // like the ids of rpc, the order of the event in the ledger
+            id: format!("{:019}-{index:010}", (ledger as u64) << 32),
+            ledger,
+            ledger_closed_at: format!("2025-01-01T00:{:02}:{:02}Z", ledger * 5 / 60, ledger * 5 % 60),
+            contract_id: address_string(&contract),
+            topic: topics.iter().map(xdr).collect(),
+            value: xdr(value),
+        });
+    }
+}
+
+struct Scenario {
+    pool: String,
+    alice: String,
+    carol: String,
+    events: Vec<RawEvent>,
+    /// the state after the first two ledgers
+    snapshot: LedgerSnapshot,
+    /// the bins of the contract at the end, with reserves or shares: (bin_id, reserve_x, reserve_y, shares)
+    bins: Vec<(i32, i128, i128, i128)>,
+    /// what the positions of alice and carol can withdraw at the end
+    alice_amounts: (i128, i128),
+    carol_amounts: (i128, i128),
+    /// what alice can withdraw from the shares that carol transferred to her
+    transferred_amounts: (i128, i128),
+}
+
+/// A pool with two LPs, swaps in both directions, a removal, a flash loan, a sync and a transfer of shares, over 5 ledgers.
+fn scenario() -> Scenario {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_sequence_number(10);
+    let mut events = Vec::new();
+
+    let admin = Address::generate(&env);
+    let token_x = env.register_stellar_asset_contract_v2(admin.clone()).address();
+    let token_y = env.register_stellar_asset_contract_v2(admin.clone()).address();
+    let pool = env.register(
+        Contract,
+        (Config {
+            token_x: token_x.clone(),
+            token_y: token_y.clone(),
+            bin_step: 10,
+            active_bin: 0,
+            fee: 30,
+            flash_loan_fee: 10,
+            ttl_threshold: 100,
+            ttl_extend_to: 1000,
+            decimals_x: 0,
+            decimals_y: 0,
+            admin,
+        },),
+    );
+    record(&env, &mut events);
+    let client = ContractClient::new(&env, &pool);
+
+    let [alice, bob, carol] = [(); 3].map(|_| Address::generate(&env));
+    for user in [&alice, &bob, &carol] {
+        StellarAssetClient::new(&env, &token_x).mint(user, &10_000_0000000);
+        StellarAssetClient::new(&env, &token_y).mint(user, &10_000_0000000);
+    }
+
+    let deposit = |from: &Address, position_id: i32, bins: &[(i32, i128)]| {
+        let mut args = vec![&env];
+        for (bin_id, amount) in bins {
+            args.push_back(DepositArgs { is_remove: false, bin_id_or_offset: *bin_id, amount: *amount });
+        }
+        client.modify_liquidity(from, &position_id, &args, &false);
+    };
+
+    deposit(&alice, 0, &[(-2, 100_0000000), (-1, 100_0000000), (0, 200_0000000), (1, 100_0000000), (2, 100_0000000)]);
+    record(&env, &mut events);
+
+    env.ledger().set_sequence_number(11);
+    client.swap_exact_amount_in(&bob, &150_0000000, &0, &token_x);
+    record(&env, &mut events);
+    let snapshot = env.to_ledger_snapshot();
+
+    env.ledger().set_sequence_number(12);
+    deposit(&carol, 1, &[(-1, 50_0000000), (3, 50_0000000)]);
+    record(&env, &mut events);
+
+    env.ledger().set_sequence_number(13);
+    client.swap_exact_amount_in(&bob, &300_0000000, &0, &token_y);
+    record(&env, &mut events);
+    client.remove_position_pct(&alice, &0, &5_000, &0, &0);
+    record(&env, &mut events);
+
+    env.ledger().set_sequence_number(14);
+    let receiver = env.register(FlashReceiver, ());
+    env.as_contract(&receiver, || env.storage().instance().set(&0u32, &pool));
+    StellarAssetClient::new(&env, &token_y).mint(&receiver, &1_0000000);
+    client.flash_loan(&receiver, &token_y, &100_0000000, &Bytes::new(&env));
+    record(&env, &mut events);
+    StellarAssetClient::new(&env, &token_x).mint(&pool, &5_0000000);
+    client.sync();
+    record(&env, &mut events);
+    let shares = client.get_position(&carol, &1).unwrap().bin_shares.get_unchecked(1).shares;
+    client.batch_transfer(&carol, &alice, &1, &vec![&env, BinShares { bin_id: 3, shares: shares / 2 }]);
+    record(&env, &mut events);
+
+    let bins = client
+        .get_bins_range(&-10, &10)
+        .iter()
+        .filter(|bin| bin.reserve_x != 0 || bin.reserve_y != 0 || bin.shares != 0)
+        .map(|bin| (bin.bin_id, bin.reserve_x, bin.reserve_y, bin.shares))
+        .collect();
+
+    let withdraw = |owner: &Address, position_id: i32| {
+        // what a full removal would send, without changing the pool
+        let position = client.get_position(owner, &position_id).unwrap();
+        let mut amounts = (0, 0);
+        for bin_shares in position.bin_shares.iter() {
+            let bin = client.get_bins_range(&bin_shares.bin_id, &bin_shares.bin_id).get_unchecked(0);
+            let (x, y) = amm_math::shares::amounts_to_remove(bin_shares.shares, bin.shares, bin.reserve_x, bin.reserve_y);
+            amounts = (amounts.0 + x, amounts.1 + y);
+        }
+        amounts
+    };
+
+    Scenario {
+        pool: address_string(&pool),
+        alice: address_string(&alice),
+        carol: address_string(&carol),
+        alice_amounts: withdraw(&alice, 0),
+        carol_amounts: withdraw(&carol, 1),
+        transferred_amounts: withdraw(&alice, 1),
+        events,
+        snapshot,
+        bins,
+    }
+}
+
+fn bins(indexer: &Indexer, pool: &str) -> Vec<(i32, i128, i128, i128)> {
+    indexer
+        .bins(pool)
+        .unwrap()
+        .iter()
+        .map(|bin| (bin.bin_id, bin.reserve_x, bin.reserve_y, bin.shares))
+        .collect()
+}
+
+fn fixture(name: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures").join(name)
+}
+
+#[test]
+fn events_are_decoded() {
+    let scenario = scenario();
+    let env = Env::default();
+
+    let names: Vec<&str> = scenario
+        .events
+        .iter()
+        .filter(|event| event.contract_id == scenario.pool)
+        .map(|event| match PoolEvent::decode(&env, event).unwrap().unwrap() {
+            PoolEvent::Created(_) => "created",
+            PoolEvent::Liquidity { .. } => "liquidity",
+            PoolEvent::Swap { .. } => "swap",
+            PoolEvent::FlashLoan { .. } => "flashloan",
+            PoolEvent::Sync { .. } => "sync",
+            PoolEvent::Loss { .. } => "loss",
+            PoolEvent::Transfer { .. } => "batch_transfer",
+        })
+        .collect();
+    assert_eq!(names, ["created", "liquidity", "swap", "liquidity", "swap", "liquidity", "flashloan", "sync", "batch_transfer"]);
+
+    // the transfers of the tokens are not events of the pool
+    let transfer = scenario.events.iter().find(|event| event.contract_id != scenario.pool).unwrap();
+    assert!(PoolEvent::decode(&env, transfer).unwrap().is_none());
+
+    // a page of `getEvents`
+    let page = format!("{{\"events\": {}, \"latestLedger\": 14}}", serde_json::to_string(&scenario.events).unwrap());
+    assert_eq!(parse_events(&page).unwrap(), scenario.events);
+}
+
+#[test]
+fn replay_matches_the_contract() {
+    let scenario = scenario();
+    let mut indexer = Indexer::in_memory().unwrap();
+
+    assert_eq!(indexer.ingest(&scenario.events).unwrap(), 9);
+    assert_eq!(bins(&indexer, &scenario.pool), scenario.bins);
+    // ingesting the same events again does nothing
+    assert_eq!(indexer.ingest(&scenario.events).unwrap(), 0);
+    assert_eq!(bins(&indexer, &scenario.pool), scenario.bins);
+
+    let alice = indexer.position(&scenario.pool, &scenario.alice, 0).unwrap().unwrap();
+    assert_eq!((alice.amount_x, alice.amount_y), scenario.alice_amounts);
+    let carol = indexer.position(&scenario.pool, &scenario.carol, 1).unwrap().unwrap();
+    assert_eq!((carol.amount_x, carol.amount_y), scenario.carol_amounts);
+    let transferred = indexer.position(&scenario.pool, &scenario.alice, 1).unwrap().unwrap();
+    assert_eq!((transferred.amount_x, transferred.amount_y), scenario.transferred_amounts);
+    assert_eq!(indexer.positions(&scenario.pool).unwrap().len(), 3);
+
+    let pool = indexer.pool(&scenario.pool).unwrap().unwrap();
+    assert_eq!((pool.bin_step, pool.fee, pool.decimals_x, pool.start_ledger), (10, 30, 7, 9));
+}
+
+#[test]
+fn snapshot_and_later_events() {
+    let scenario = scenario();
+    let path = std::env::temp_dir().join(format!("indexer-{}.json", std::process::id()));
+    scenario.snapshot.write_file(&path).unwrap();
+
+    let mut indexer = Indexer::in_memory().unwrap();
+    indexer.load_snapshot(&path, &scenario.pool).unwrap();
+    assert!(indexer.load_snapshot(&path, &scenario.pool).is_err());
+    assert!(indexer.load_snapshot(&path, &scenario.alice).is_err());
+    std::fs::remove_file(&path).unwrap();
+
+    // the events of the first two ledgers are in the snapshot
+    assert_eq!(indexer.ingest(&scenario.events).unwrap(), 6);
+    assert_eq!(bins(&indexer, &scenario.pool), scenario.bins);
+    assert!(indexer.swaps(&scenario.pool, 0..=u32::MAX).unwrap().iter().all(|swap| swap.ledger > 11));
+
+    // alice starts with what she held at the snapshot
+    let alice = indexer.position(&scenario.pool, &scenario.alice, 0).unwrap().unwrap();
+    assert_eq!((alice.amount_x, alice.amount_y), scenario.alice_amounts);
+    // her 300 x and the 150 x that bob swapped into her bins
+    assert_eq!(alice.deposited_x, 450_0000000);
+}
+
+#[test]
+fn fixture_history() {
+    let events = load_events(&fixture("events.json")).unwrap();
+    let pool = events.iter().find(|event| event.topic.len() == 1).unwrap().contract_id.clone();
+
+    let mut indexer = Indexer::in_memory().unwrap();
+    indexer.ingest(&events).unwrap();
+
+    let swaps = indexer.swaps(&pool, 0..=u32::MAX).unwrap();
+    assert_eq!(swaps.len(), 2);
+    assert!(swaps[0].is_x_in && !swaps[1].is_x_in);
+    assert_eq!(swaps[0].amount_in, 150_0000000);
+    // 0.3% of the input, rounded up
+    assert_eq!(swaps[0].fee, 4500001);
+    assert_eq!(swaps[1].ledger, 13);
+
+    let volume = indexer.volume(&pool, 13..=14).unwrap();
+    assert_eq!(volume.swaps, 1);
+    assert_eq!(volume.volume_y, 300_0000000);
+    // the swap, rounded up in the two bins it crossed, and the flash loan of 100 with a 0.1% fee
+    assert_eq!(volume.fees_y, 9000002 + 1000000);
+    assert_eq!(volume.fees_x, 0);
+    assert_eq!(indexer.volume(&pool, 0..=10).unwrap(), Default::default());
+
+    // the active bin at the end, through the deposits, the second swap, the removal, the flash loan fee and the sync
+    assert_eq!(swaps[1].active_bin, -1);
+    let history = indexer.bin_history(&pool, -1).unwrap();
+    assert_eq!(history.iter().map(|bin| bin.ledger).collect::<Vec<_>>(), [10, 12, 13, 13, 14, 14]);
+    assert_eq!(history[0].reserve_x + history[0].reserve_y, 100_0000000);
+
+    let changes = indexer.liquidity_changes(&pool, None).unwrap();
+    assert_eq!(changes.len(), 3);
+    let alice = changes[0].owner.clone();
+    assert!(changes[2].amount_x < 0 && changes[2].amount_y < 0);
+    assert_eq!(indexer.liquidity_changes(&pool, Some(&alice)).unwrap().len(), 2);
+
+    // alice earned fees in both tokens, and lost value to the swaps that moved through her range
+    let alice = indexer.position(&pool, &alice, 0).unwrap().unwrap();
+    assert!(alice.fees_x > 0 && alice.fees_y > 0);
+    assert_eq!(alice.deposited_x + alice.deposited_y, 600_0000000);
+    // position 1 of alice has the shares that carol transferred to it
+    let transferred = indexer.position(&pool, &alice.owner, 1).unwrap().unwrap();
+    assert!(transferred.amount_x + transferred.amount_y > 0);
+    assert!(indexer.position(&pool, &alice.owner, 2).unwrap().is_none());
+}
+
+/// Writes the fixture from the scenario, run it with `cargo test -p indexer -- --ignored` when the events change.
+#[test]
+#[ignore]
+fn record_fixture() {
+    let events = scenario().events;
+    std::fs::write(fixture("events.json"), serde_json::to_string_pretty(&events).unwrap() + "\n").unwrap();
+}
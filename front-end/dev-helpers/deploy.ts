@@ -42,7 +42,16 @@ async function initContract(){
         {conf: {
                 active_bin: -1300,
                 bin_step: 10,
+                flash_loan_fee: 10,
+                // a week and a month in ledgers
+                ttl_threshold: 7 * 17280,
+                ttl_extend_to: 30 * 17280,
+                // read from the tokens by the contract
+                decimals_x: 0,
+                decimals_y: 0,
                 fee: 10,
+                // may add incentives
+                admin: keypair.publicKey(),
                 token_y: ("CDLZFC3SYJYDZT7K67VZ75HPJVIEUVNIXF47ZG2FB2RMQQVU2HHGCYSC"),
                 token_x: other_asset.contractId(Networks.TESTNET),
             }},